@@ -90,7 +90,7 @@ pub struct BenchmarkDecl {
 /// An external function declaration.
 #[derive(Debug, Clone)]
 pub struct ExternDecl {
-    /// ABI string (e.g., "C", "system")
+    /// ABI string (e.g., "C", "Rask")
     pub abi: String,
     /// Function name
     pub name: String,
@@ -125,12 +125,31 @@ pub struct FnDecl {
     pub span: Span,
 }
 
+impl FnDecl {
+    /// `@test` or `@test(...)`.
+    pub fn is_test(&self) -> bool {
+        self.attrs.iter().any(|a| a == "test" || a.starts_with("test("))
+    }
+
+    /// `N` from `@test(timeout_ms = N)`.
+    pub fn test_timeout_ms(&self) -> Option<u64> {
+        self.attrs.iter().find_map(|a| {
+            let args = a.strip_prefix("test(")?.strip_suffix(')')?;
+            args.split(',').find_map(|arg| {
+                let (key, value) = arg.split_once('=')?;
+                if key.trim() == "timeout_ms" { value.trim().parse().ok() } else { None }
+            })
+        })
+    }
+}
+
 /// A `using` context clause on a function signature.
 #[derive(Debug, Clone)]
 pub struct ContextClause {
     pub name: Option<String>,
     pub ty: String,
     pub is_frozen: bool,
+    pub span: Span,
 }
 
 /// A function parameter.
@@ -169,6 +188,15 @@ pub struct StructDecl {
     pub doc: Option<String>,
 }
 
+impl StructDecl {
+    /// Tuple struct (`struct Meters(f64)`): fields are positional and
+    /// named `0`, `1`, ... so `m.0` resolves like any named field.
+    pub fn is_tuple(&self) -> bool {
+        !self.fields.is_empty()
+            && self.fields.iter().enumerate().all(|(i, f)| f.name == i.to_string())
+    }
+}
+
 /// Field-level visibility.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FieldVisibility {
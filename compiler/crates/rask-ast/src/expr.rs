@@ -401,6 +401,8 @@ pub struct MatchArm {
     pub pattern: Pattern,
     pub guard: Option<Box<Expr>>,
     pub body: Box<Expr>,
+    /// Span of the pattern and guard
+    pub span: Span,
 }
 
 /// A pattern for matching.
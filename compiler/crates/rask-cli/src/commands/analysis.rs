@@ -125,7 +125,7 @@ pub fn cmd_ownership(path: &str, format: Format) {
     }
 }
 
-pub fn cmd_comptime(path: &str, format: Format) {
+pub fn cmd_comptime(path: &str, format: Format, emit_prints: bool) {
     let p = Path::new(path);
     let files: Vec<String> = if p.is_dir() {
         let f = collect_rk_files(p);
@@ -181,32 +181,26 @@ pub fn cmd_comptime(path: &str, format: Format) {
         rask_desugar::desugar(&mut parse_result.decls);
 
         let mut comptime_interp = rask_comptime::ComptimeInterpreter::new();
-        comptime_interp.register_functions(&parse_result.decls);
+        if emit_prints {
+            comptime_interp.emit_prints_to(Box::new(std::io::stdout()));
+        }
 
         if multi && format == Format::Human {
             println!("{} {} {}", "===".dimmed(), output::file_path(file), "===".dimmed());
         }
 
-        for decl in &parse_result.decls {
-            if let rask_ast::decl::DeclKind::Const(c) = &decl.kind {
-                if matches!(c.init.kind, rask_ast::expr::ExprKind::Comptime { .. }) {
-                    match comptime_interp.eval_expr(&c.init) {
-                        Ok(val) => {
-                            total_evaluated += 1;
-                            if format == Format::Human {
-                                println!("  {} const {} = {:?}", output::status_pass(), c.name, val);
-                            }
-                        }
-                        Err(e) => {
-                            total_errors += 1;
-                            if format == Format::Human {
-                                eprintln!("  {} const {}: {}", output::status_fail(), c.name, e);
-                            }
-                        }
-                    }
-                }
+        let (globals, errors) = comptime_interp.eval_program(&parse_result.decls);
+        total_evaluated += globals.len();
+        if format == Format::Human {
+            for global in &globals {
+                println!("  {} const {} = {:?}", output::status_pass(), global.name, global.value);
             }
         }
+        if !errors.is_empty() {
+            total_errors += errors.len();
+            let diags: Vec<Diagnostic> = errors.iter().map(|e| e.to_diagnostic()).collect();
+            show_diagnostics(&diags, &source, file, "comptime", format);
+        }
     }
 
     if format == Format::Human {
@@ -24,6 +24,30 @@ pub struct BuildOptions {
     /// Max parallel threads for dependency checking. Spec: struct.build/PP3.
     /// None = CPU count (default).
     pub jobs: Option<usize>,
+    /// Final artifact: linked executable, or stop at object/assembly.
+    pub emit: EmitKind,
+}
+
+/// What `rask build` produces (`--emit=`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmitKind {
+    /// Linked executable (default).
+    Exe,
+    /// Relocatable object file, not linked — for embedding in other programs.
+    Obj,
+    /// Textual machine-code listing, for inspecting generated code.
+    Asm,
+}
+
+impl EmitKind {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "exe" | "link" => Some(EmitKind::Exe),
+            "obj" => Some(EmitKind::Obj),
+            "asm" => Some(EmitKind::Asm),
+            _ => None,
+        }
+    }
 }
 
 impl Default for BuildOptions {
@@ -35,6 +59,7 @@ impl Default for BuildOptions {
             no_cache: false,
             force: false,
             jobs: None,
+            emit: EmitKind::Exe,
         }
     }
 }
@@ -705,30 +730,28 @@ pub fn cmd_build(path: &str, opts: BuildOptions) {
 
         let obj_path = out_dir.join(format!("{}.o", bin_name));
         let bin_path = out_dir.join(&bin_name);
+        let asm_path = out_dir.join(format!("{}.s", bin_name));
         let obj_str = obj_path.to_string_lossy().to_string();
         let bin_str = bin_path.to_string_lossy().to_string();
+        let asm_str = asm_path.to_string_lossy().to_string();
 
-        // Check compilation cache (XC1-XC2); --force bypasses
-        if !opts.no_cache && !opts.force {
+        // Check compilation cache (XC1-XC2); --force bypasses. The cache only
+        // holds objects, so asm listings always run codegen.
+        if !opts.no_cache && !opts.force && opts.emit != EmitKind::Asm {
             if let Some(cached_obj) = super::cache::lookup(&cache_dir, &cache_key) {
                 if opts.verbose {
                     println!("  {} (cache hit)", "Skipping codegen".dimmed());
                 }
                 if let Err(e) = std::fs::copy(&cached_obj, &obj_path) {
                     eprintln!("warning: cache copy failed: {}", e);
+                } else if opts.emit == EmitKind::Obj {
+                    report_finished(&obj_path, &opts.profile, start);
+                    return;
                 } else {
                     let release = opts.profile == "release";
                     match super::link::link_executable_with(&obj_str, &bin_str, &link_opts, release, opts.target.as_deref()) {
                         Ok(_) => {
-                            let elapsed = start.elapsed();
-                            println!();
-                            println!(
-                                "   {} {} ({}) [{:.2}s]",
-                                "Finished".green().bold(),
-                                bin_path.display(),
-                                opts.profile,
-                                elapsed.as_secs_f64()
-                            );
+                            report_finished(&bin_path, &opts.profile, start);
                             return;
                         }
                         Err(e) => {
@@ -772,17 +795,27 @@ pub fn cmd_build(path: &str, opts: BuildOptions) {
             } else {
                 rask_codegen::BuildMode::Debug
             };
+            let asm_out = (opts.emit == EmitKind::Asm).then_some(asm_str.as_str());
+            // Per-function cache: a whole-object miss still reuses every
+            // function whose lowered IR didn't change.
+            let fn_cache_dir = cache_dir.join("functions");
+            let fn_cache = (!opts.no_cache && !opts.force).then_some(fn_cache_dir.as_path());
             match super::compile::compile_to_object(
                 &result.mono, &result.typed, &result.decls, &comptime_globals,
-                None, None, target, &obj_str, build_mode, Some(&cfg),
-                &result.package_modules,
+                &result.resource_moves, None, None, target, &obj_str, asm_out, build_mode, Some(&cfg),
+                &result.package_modules, fn_cache,
             ) {
                 Ok(()) => {
                     if !opts.no_cache {
                         let _ = super::cache::store(&cache_dir, &cache_key, &obj_path);
                     }
+                    // --emit=obj/asm stop here; the object or listing is the artifact.
                     let release = opts.profile == "release";
-                    if let Err(e) = super::link::link_executable_with(&obj_str, &bin_str, &link_opts, release, opts.target.as_deref()) {
+                    let linked = match opts.emit {
+                        EmitKind::Exe => super::link::link_executable_with(&obj_str, &bin_str, &link_opts, release, opts.target.as_deref()),
+                        EmitKind::Obj | EmitKind::Asm => Ok(()),
+                    };
+                    if let Err(e) = linked {
                         eprintln!("link error: {}", e);
                         total_errors += 1;
                     }
@@ -802,23 +835,32 @@ pub fn cmd_build(path: &str, opts: BuildOptions) {
     }
 
     // === LC1 Step 8-9: Link + report ===
-    let elapsed = start.elapsed();
-    println!();
     if total_errors == 0 {
-        let bin_path = out_dir.join(&bin_name);
-        println!(
-            "   {} {} ({}) [{:.2}s]",
-            "Finished".green().bold(),
-            bin_path.display(),
-            opts.profile,
-            elapsed.as_secs_f64()
-        );
+        let artifact = match opts.emit {
+            EmitKind::Exe => out_dir.join(&bin_name),
+            EmitKind::Obj => out_dir.join(format!("{}.o", bin_name)),
+            EmitKind::Asm => out_dir.join(format!("{}.s", bin_name)),
+        };
+        report_finished(&artifact, &opts.profile, start);
     } else {
+        println!();
         eprintln!("{}", output::banner_fail("Build", total_errors));
         process::exit(1);
     }
 }
 
+fn report_finished(artifact: &Path, profile: &str, start: Instant) {
+    let elapsed = start.elapsed();
+    println!();
+    println!(
+        "   {} {} ({}) [{:.2}s]",
+        "Finished".green().bold(),
+        artifact.display(),
+        profile,
+        elapsed.as_secs_f64()
+    );
+}
+
 /// Regenerate rask.lock from current dependency state (LK3, PM6).
 pub fn cmd_update(path: &str) {
     use rask_resolve::PackageRegistry;
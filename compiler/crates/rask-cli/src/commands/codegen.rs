@@ -15,6 +15,7 @@ type PipelineResult = (
     std::collections::HashMap<String, rask_mir::ComptimeGlobalMeta>,
     Option<String>,
     Vec<String>,
+    std::collections::HashMap<rask_ast::NodeId, String>,
 );
 
 /// Run the full front-end pipeline + monomorphize + comptime eval via
@@ -52,7 +53,7 @@ fn run_pipeline(path: &str, format: Format) -> PipelineResult {
     let result = output.result.unwrap();
     let source = std::fs::read_to_string(path).ok();
     let package_names = vec![]; // package_modules is in CompileResult but not as names
-    (result.mono, result.typed, result.decls, result.comptime_globals, source, package_names)
+    (result.mono, result.typed, result.decls, result.comptime_globals, source, package_names, result.resource_moves)
 }
 
 
@@ -183,7 +184,7 @@ pub fn cmd_mono(path: &str, format: Format) {
 
 /// Dump MIR for a single file.
 pub fn cmd_mir(path: &str, format: Format) {
-    let (mono, typed, decls, comptime_globals, source, _package_names) = run_pipeline(path, format);
+    let (mono, typed, decls, comptime_globals, source, _package_names, resource_moves) = run_pipeline(path, format);
 
     // Lower each monomorphized function to MIR
     if format == Format::Human {
@@ -266,6 +267,7 @@ pub fn cmd_mir(path: &str, format: Format) {
         trait_coercions: &typed.trait_coercions,
         call_rewrites: &mono.call_rewrites,
         resource_types: &empty_resource_types,
+        resource_moves: &resource_moves,
     };
 
     let mut mir_errors = 0;
@@ -312,7 +314,7 @@ pub fn cmd_mir(path: &str, format: Format) {
 /// Dump MIR for a .rk file — runs the full pipeline up to MIR lowering
 /// and prints the MIR functions to stderr. Used for debugging codegen issues.
 pub fn cmd_dump_mir(path: &str, format: Format, release: bool) {
-    let (mono, typed, decls, comptime_globals, source, package_names) = run_pipeline(path, format);
+    let (mono, typed, decls, comptime_globals, source, package_names, resource_moves) = run_pipeline(path, format);
     let _ = release;
     let type_names = super::compile::build_type_names(&typed);
     let trait_methods = super::compile::build_trait_methods(&typed);
@@ -337,6 +339,7 @@ pub fn cmd_dump_mir(path: &str, format: Format, release: bool) {
         trait_coercions: &typed.trait_coercions,
         call_rewrites: &mono.call_rewrites,
         resource_types: &empty_resource_types,
+        resource_moves: &resource_moves,
     };
 
     let all_mono_decls = super::compile::build_mono_decls(&mono, &decls, true);
@@ -374,7 +377,7 @@ pub fn cmd_compile(path: &str, output_path: Option<&str>, format: Format, quiet:
         }
     }
 
-    let (mono, typed, decls, comptime_globals, source, package_names) = run_pipeline(path, format);
+    let (mono, typed, decls, comptime_globals, source, package_names, resource_moves) = run_pipeline(path, format);
     let profile = if release { "release" } else { "debug" };
     let cfg = rask_comptime::CfgConfig::from_target_or_host(target, profile, vec![]);
     let build_mode = if release { rask_codegen::BuildMode::Release } else { rask_codegen::BuildMode::Debug };
@@ -406,9 +409,9 @@ pub fn cmd_compile(path: &str, output_path: Option<&str>, format: Format, quiet:
 
     let package_modules: std::collections::HashSet<String> = package_names.into_iter().collect();
     if let Err(errors) = super::compile::compile_to_object(
-        &mono, &typed, &decls, &comptime_globals,
-        Some(path), source.as_deref(), target, &obj_path, build_mode, Some(&cfg),
-        &package_modules,
+        &mono, &typed, &decls, &comptime_globals, &resource_moves,
+        Some(path), source.as_deref(), target, &obj_path, None, build_mode, Some(&cfg),
+        &package_modules, None,
     ) {
         for e in &errors {
             eprintln!("{}: {}", output::error_label(), e);
@@ -210,13 +210,16 @@ pub fn compile_to_object(
     typed: &rask_types::TypedProgram,
     decls: &[rask_ast::decl::Decl],
     comptime_globals: &HashMap<String, rask_mir::ComptimeGlobalMeta>,
+    resource_moves: &HashMap<rask_ast::NodeId, String>,
     source_file: Option<&str>,
     source_text: Option<&str>,
     target: Option<&str>,
     obj_path: &str,
+    asm_path: Option<&str>,
     build_mode: rask_codegen::BuildMode,
     cfg: Option<&rask_comptime::CfgConfig>,
     package_modules: &std::collections::HashSet<String>,
+    fn_cache_dir: Option<&std::path::Path>,
 ) -> Result<(), Vec<String>> {
     let all_mono_decls = build_mono_decls(mono, decls, true);
     let line_map = source_text.map(rask_ast::LineMap::new);
@@ -229,6 +232,7 @@ pub fn compile_to_object(
         let mut interp = rask_comptime::ComptimeInterpreter::new();
         interp.inject_cfg(c);
         interp.register_functions(decls);
+        interp.register_types(decls);
         std::cell::RefCell::new(interp)
     });
 
@@ -248,6 +252,7 @@ pub fn compile_to_object(
         trait_coercions: &typed.trait_coercions,
         call_rewrites: &mono.call_rewrites,
         resource_types: &empty_resource_types,
+        resource_moves,
     };
 
     let (mir_functions, pipeline_result) = lower_to_mir(mono, &all_mono_decls, &mir_ctx, false)?;
@@ -281,8 +286,23 @@ pub fn compile_to_object(
             .map_err(|e| vec![e.to_string()])?;
     }
 
+    if asm_path.is_some() {
+        codegen.enable_asm_listing();
+    } else if let Some(dir) = fn_cache_dir {
+        // A cache that can't be opened just means a full compile
+        match rask_codegen::FunctionCache::open(dir) {
+            Ok(cache) => codegen.enable_function_cache(cache),
+            Err(e) => eprintln!("warning: function cache unavailable: {}", e),
+        }
+    }
+
     gen_functions(&mut codegen, &mir_functions)?;
 
+    if let (Some(path), Some(listing)) = (asm_path, codegen.asm_listing()) {
+        std::fs::write(path, listing)
+            .map_err(|e| vec![format!("emit asm: {}", e)])?;
+    }
+
     codegen.emit_object(obj_path)
         .map_err(|e| vec![format!("emit object: {}", e)])?;
 
@@ -379,7 +399,7 @@ pub fn extract_tests(decls: &mut Vec<Decl>, filter: Option<&str>) -> Vec<(String
                 });
             }
             // @test functions — include as tests, keep the original function too
-            DeclKind::Fn(f) if f.attrs.iter().any(|a| a == "test") && f.params.is_empty() => {
+            DeclKind::Fn(f) if f.is_test() && f.params.is_empty() => {
                 if let Some(pat) = filter {
                     if !f.name.contains(pat) {
                         continue;
@@ -480,6 +500,7 @@ pub fn compile_tests_to_object(
         let mut interp = rask_comptime::ComptimeInterpreter::new();
         interp.inject_cfg(c);
         interp.register_functions(decls);
+        interp.register_types(decls);
         std::cell::RefCell::new(interp)
     });
 
@@ -500,6 +521,7 @@ pub fn compile_tests_to_object(
         trait_coercions: &typed.trait_coercions,
         call_rewrites: &mono.call_rewrites,
         resource_types: &empty_resource_types,
+        resource_moves: &HashMap::new(),
     };
 
     let (mir_functions, pipeline_result) = lower_to_mir(mono, &all_mono_decls, &mir_ctx, true)?;
@@ -672,6 +694,7 @@ pub fn compile_benchmarks_to_object(
         let mut interp = rask_comptime::ComptimeInterpreter::new();
         interp.inject_cfg(c);
         interp.register_functions(decls);
+        interp.register_types(decls);
         std::cell::RefCell::new(interp)
     });
 
@@ -692,6 +715,7 @@ pub fn compile_benchmarks_to_object(
         trait_coercions: &typed.trait_coercions,
         call_rewrites: &mono.call_rewrites,
         resource_types: &empty_resource_types,
+        resource_moves: &HashMap::new(),
     };
 
     let (mut mir_functions, pipeline_result) = lower_to_mir(mono, &all_mono_decls, &mir_ctx, true)?;
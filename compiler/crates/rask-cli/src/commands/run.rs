@@ -16,6 +16,9 @@ pub struct TestOptions {
     pub seed: Option<String>,
 }
 
+/// Panic backtrace frames shown by default; `RASK_BACKTRACE=full` shows all.
+const BACKTRACE_FRAMES: usize = 16;
+
 pub fn cmd_run(path: &str, program_args: Vec<String>, format: Format) {
     let result = crate::run_check_or_exit(path, format);
 
@@ -37,10 +40,13 @@ pub fn cmd_run(path: &str, program_args: Vec<String>, format: Format) {
                 process::exit(code);
             }
         }
-        Err(diag) => {
+        Err(mut diag) => {
             // A panic is a bug, not an error return: exit 101, distinct from an
             // error propagated out of main (exit 1). (struct.targets/EX4, ctrl.panic/P4)
             let exit_code = if matches!(diag.error, rask_interp::RuntimeError::Panic(..)) { 101 } else { 1 };
+            if std::env::var("RASK_BACKTRACE").as_deref() != Ok("full") {
+                diag.trim_backtrace(BACKTRACE_FRAMES);
+            }
             let diagnostic = diag.to_diagnostic();
             if let Some((file_path, source)) = find_diagnostic_file(&diagnostic, &result.source_files) {
                 let file_name = file_path.to_string_lossy();
@@ -99,6 +105,7 @@ pub fn cmd_test_project(path: &str, filter: Option<String>, format: Format) {
         no_cache: false,
         force: false,
         jobs: None,
+        emit: super::build::EmitKind::Exe,
     };
 
     let prepared = super::build::prepare_build(path, opts);
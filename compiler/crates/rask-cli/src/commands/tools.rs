@@ -125,7 +125,15 @@ pub fn cmd_api(path: &str, format: Format, show_all: bool) {
     }
 }
 
-pub fn cmd_lint(path: &str, format: Format, rules: Vec<String>, excludes: Vec<String>) {
+pub fn cmd_lint(
+    path: &str,
+    format: Format,
+    rules: Vec<String>,
+    excludes: Vec<String>,
+    max_complexity: Option<usize>,
+    missing_docs: Option<rask_lint::Severity>,
+    public_return_type: Option<rask_lint::Severity>,
+) {
     let p = Path::new(path);
     let files: Vec<String> = if p.is_dir() {
         collect_rk_files(p)
@@ -150,10 +158,18 @@ pub fn cmd_lint(path: &str, format: Format, rules: Vec<String>, excludes: Vec<St
             }
         };
 
-        let opts = rask_lint::LintOpts {
+        let mut opts = rask_lint::LintOpts {
             rules: rules.clone(),
             excludes: excludes.clone(),
+            missing_docs_severity: missing_docs,
+            ..Default::default()
         };
+        if let Some(n) = max_complexity {
+            opts.complexity_threshold = n;
+        }
+        if let Some(severity) = public_return_type {
+            opts.public_return_type_severity = severity;
+        }
         let report = rask_lint::lint(&source, file, opts);
 
         total_errors += report.error_count;
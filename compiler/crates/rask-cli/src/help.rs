@@ -204,6 +204,7 @@ pub fn print_build_help() {
     println!("  {} {} Cross-compile for target", output::arg("--target"), output::arg("<triple>"));
     println!("  {}           Bypass all caching (build script + compilation)", output::arg("--force"));
     println!("  {} {}    Max parallel jobs (default: CPU count)", output::arg("--jobs"), output::arg("<N>"));
+    println!("  {}  Stop after codegen: relocatable object or assembly listing", output::arg("--emit=obj|asm"));
     println!("  {} {}       Verbose output", output::arg("-v"), output::arg("--verbose"));
     println!();
     println!("If no directory is specified, builds the current directory.");
@@ -487,6 +488,9 @@ pub fn print_lint_help() {
     println!("  {}           Output as structured JSON", output::arg("--json"));
     println!("  {} {}     Run specific lint rule(s)", output::arg("--rule"), output::arg("<pattern>"));
     println!("  {} {} Exclude specific rule(s)", output::arg("--exclude"), output::arg("<pattern>"));
+    println!("  {} {} Complexity threshold for style/complexity (default 15)", output::arg("--max-complexity"), output::arg("<n>"));
+    println!("  {} {} Enable style/missing-docs for public items (warn | error)", output::arg("--missing-docs"), output::arg("<level>"));
+    println!("  {} {} Severity of style/public-return-type (warn | error, default error)", output::arg("--public-return-type"), output::arg("<level>"));
     println!();
     println!("{}", output::section_header("Examples:"));
     println!("  {} {} {}           Lint a file",
@@ -618,6 +622,7 @@ pub fn print_comptime_help() {
     println!();
     println!("{}", output::section_header("Options:"));
     println!("  {}  Output comptime results as structured JSON", output::arg("--json"));
+    println!("  {}  Let print/println inside comptime code write to stdout", output::arg("--emit-prints"));
 }
 
 pub fn print_unsafe_help() {
@@ -286,7 +286,9 @@ fn main() {
                 eprintln!("{}: {} {} {}", "Usage".yellow(), output::command("rask"), output::command("comptime"), output::arg("<file.rk | dir>"));
                 process::exit(1);
             }
-            commands::analysis::cmd_comptime(cmd_args[2], format);
+            let emit_prints = cmd_args.contains(&"--emit-prints");
+            let target = find_positional_arg(&cmd_args, 2, &[]).unwrap_or(cmd_args[2]);
+            commands::analysis::cmd_comptime(target, format, emit_prints);
         }
         "unsafe" => {
             if cmd_args.contains(&"--help") || cmd_args.contains(&"-h") {
@@ -332,6 +334,7 @@ fn main() {
                     no_cache: false,
                     force: false,
                     jobs,
+                    emit: commands::build::EmitKind::Exe,
                 };
                 let run_args: Vec<String> = prog_args.iter().map(|s| s.to_string()).collect();
                 commands::run::cmd_run_project(file, run_args, opts);
@@ -507,8 +510,20 @@ fn main() {
             let jobs = extract_flag_value(&cmd_args, "--jobs")
                 .or_else(|| extract_flag_value(&cmd_args, "-j"))
                 .and_then(|s| s.parse::<usize>().ok());
-            let path = find_positional_arg(&cmd_args, 2, &["--profile", "--target", "--jobs", "-j"]).unwrap_or(".");
-            let opts = commands::build::BuildOptions { profile, verbose, target, no_cache, force, jobs };
+            let emit_arg = cmd_args.iter()
+                .find_map(|a| a.strip_prefix("--emit=").map(str::to_string))
+                .or_else(|| extract_flag_value(&cmd_args, "--emit"));
+            let emit = match emit_arg.as_deref().map(commands::build::EmitKind::parse) {
+                None => commands::build::EmitKind::Exe,
+                Some(Some(kind)) => kind,
+                Some(None) => {
+                    eprintln!("{}: unknown --emit kind '{}' (expected exe, obj, or asm)",
+                        output::error_label(), emit_arg.unwrap_or_default());
+                    process::exit(1);
+                }
+            };
+            let path = find_positional_arg(&cmd_args, 2, &["--profile", "--target", "--jobs", "-j", "--emit"]).unwrap_or(".");
+            let opts = commands::build::BuildOptions { profile, verbose, target, no_cache, force, jobs, emit };
             commands::build::cmd_build(path, opts);
         }
         "clean" => {
@@ -681,7 +696,27 @@ fn main() {
             }
             let rules = extract_repeated_flag(&cmd_args, "--rule");
             let excludes = extract_repeated_flag(&cmd_args, "--exclude");
-            let file_arg = find_positional_arg(&cmd_args, 2, &["--rule", "--exclude"]);
+            let max_complexity = extract_flag_value(&cmd_args, "--max-complexity")
+                .and_then(|s| s.parse::<usize>().ok());
+            let missing_docs = match extract_flag_value(&cmd_args, "--missing-docs").as_deref() {
+                None => None,
+                Some("warn") => Some(rask_lint::Severity::Warning),
+                Some("error") => Some(rask_lint::Severity::Error),
+                Some(other) => {
+                    eprintln!("{}: --missing-docs expects `warn` or `error`, got `{}`", output::error_label(), other);
+                    process::exit(1);
+                }
+            };
+            let public_return_type = match extract_flag_value(&cmd_args, "--public-return-type").as_deref() {
+                None => None,
+                Some("warn") => Some(rask_lint::Severity::Warning),
+                Some("error") => Some(rask_lint::Severity::Error),
+                Some(other) => {
+                    eprintln!("{}: --public-return-type expects `warn` or `error`, got `{}`", output::error_label(), other);
+                    process::exit(1);
+                }
+            };
+            let file_arg = find_positional_arg(&cmd_args, 2, &["--rule", "--exclude", "--max-complexity", "--missing-docs", "--public-return-type"]);
             let file = match file_arg {
                 Some(f) => f,
                 None => {
@@ -689,7 +724,7 @@ fn main() {
                     process::exit(1);
                 }
             };
-            commands::tools::cmd_lint(file, format, rules, excludes);
+            commands::tools::cmd_lint(file, format, rules, excludes, max_complexity, missing_docs, public_return_type);
         }
         "explain" => {
             if cmd_args.contains(&"--help") || cmd_args.contains(&"-h") {
@@ -5,9 +5,10 @@
 use cranelift::prelude::*;
 use cranelift_codegen::ir::{FuncRef, Function, GlobalValue, InstBuilder, MemFlags, SourceLoc, StackSlot, StackSlotData, StackSlotKind};
 use cranelift_codegen::ir::condcodes::{FloatCC, IntCC};
-use cranelift_frontend::{FunctionBuilder as ClifFunctionBuilder, FunctionBuilderContext};
+use cranelift_frontend::{FunctionBuilder as ClifFunctionBuilder, FunctionBuilderContext, Switch};
 use std::collections::{HashMap, HashSet};
 
+use rask_mir::analysis::pool_ops;
 use rask_mir::{BinOp, BlockId, LocalId, MirConst, MirFunction, MirOperand, MirRValue, MirStmt, MirStmtKind, MirTerminator, MirTerminatorKind, MirType, UnaryOp};
 use rask_mono::{StructLayout, EnumLayout};
 use rask_types::Type as RaskType;
@@ -169,7 +170,7 @@ impl<'a> FunctionBuilder<'a> {
     pub fn build(&mut self) -> CodegenResult<()> {
         // Pre-compute stack allocation sizes before builder borrows self.func.
         // Entries: (local_id, byte size) for each aggregate local.
-        let stack_allocs: Vec<(LocalId, u32)> = self.mir_fn.locals.iter()
+        let aggregate_allocs: Vec<(LocalId, u32)> = self.mir_fn.locals.iter()
             .filter(|l| !l.is_param)
             .filter_map(|l| {
                 let size = Self::resolve_type_alloc_size(
@@ -179,6 +180,17 @@ impl<'a> FunctionBuilder<'a> {
             })
             .collect();
 
+        // Aggregates that outlive the frame get heap storage instead of a
+        // stack slot. A returned aggregate that fits a register is returned
+        // by value, so its slot never outlives the frame.
+        let escaping = rask_mir::analysis::escape::escaping_aggregates(self.mir_fn);
+        let (heap_allocs, stack_allocs): (Vec<(LocalId, u32)>, Vec<(LocalId, u32)>) =
+            aggregate_allocs.into_iter().partition(|(id, size)| match escaping.get(id) {
+                Some(rask_mir::analysis::escape::AggregateEscape::Returned) => *size > 8,
+                Some(_) => true,
+                None => false,
+            });
+
         // Collect cleanup-only blocks (appear in CleanupReturn chains)
         // and their transitive sub-blocks (handler/done blocks reachable
         // from cleanup blocks). These are excluded from normal codegen
@@ -298,6 +310,22 @@ impl<'a> FunctionBuilder<'a> {
             builder.def_var(var, addr);
         }
 
+        // Escaping aggregates: the variable holds a rask_alloc'd block, the
+        // same shape as an aggregate parameter (a pointer not in
+        // stack_slot_map), so every access already goes through it. The
+        // block is not freed here — its new owner outlives this frame.
+        if !heap_allocs.is_empty() {
+            let alloc_ref = *self.func_refs.get("rask_alloc")
+                .ok_or_else(|| CodegenError::FunctionNotFound("rask_alloc".to_string()))?;
+            for (local_id, size) in &heap_allocs {
+                let size_val = builder.ins().iconst(types::I64, *size as i64);
+                let call_inst = builder.ins().call(alloc_ref, &[size_val]);
+                let addr = builder.inst_results(call_inst)[0];
+                let var = self.var_map[local_id];
+                builder.def_var(var, addr);
+            }
+        }
+
         // For main(): emit rask_set_origin_file(source_file) so .origin() includes file name
         if self.mir_fn.name == "main" {
             if let Some(file_name) = self.mir_fn.source_file.as_deref() {
@@ -334,6 +362,16 @@ impl<'a> FunctionBuilder<'a> {
             adapt_table: &self.adapt_table,
         };
 
+        // Pools any checked access in this function reads through, for
+        // deciding which calls can invalidate a validated handle.
+        let pool_locals: HashSet<LocalId> = self.mir_fn.blocks.iter()
+            .flat_map(|b| b.statements.iter())
+            .filter_map(|stmt| match &stmt.kind {
+                MirStmtKind::PoolCheckedAccess { pool, .. } => Some(*pool),
+                _ => None,
+            })
+            .collect();
+
         // Lower each block (skip cleanup-only blocks)
         for mir_block in &self.mir_fn.blocks {
             if cleanup_only.contains(&mir_block.id) {
@@ -346,6 +384,12 @@ impl<'a> FunctionBuilder<'a> {
                 builder.switch_to_block(cl_block);
             }
 
+            // (pool, handle) → slot pointer from this block's first checked
+            // access. Later accesses reuse the pointer instead of repeating
+            // the bounds/generation validation, until something could free
+            // or reallocate the slot (comp.gen-coalesce MT1–MT3, GC3).
+            let mut validated: HashMap<(LocalId, LocalId), Value> = HashMap::new();
+
             // Lower statements
             for stmt in &mir_block.statements {
                 Self::apply_srcloc(&mut builder, stmt.span);
@@ -356,7 +400,19 @@ impl<'a> FunctionBuilder<'a> {
                     ctx.current_line = line as u32;
                     ctx.current_col = col as u32;
                 }
+                pool_ops::invalidate_checks(stmt, &mut validated, &pool_locals);
+                if let MirStmtKind::PoolCheckedAccess { dst, pool, handle } = &stmt.kind {
+                    if let (Some(&ptr), Some(var)) = (validated.get(&(*pool, *handle)), ctx.var_map.get(dst)) {
+                        builder.def_var(*var, ptr);
+                        continue;
+                    }
+                }
                 Self::lower_stmt(&mut builder, stmt, &ctx)?;
+                if let MirStmtKind::PoolCheckedAccess { dst, pool, handle } = &stmt.kind {
+                    if let Some(var) = ctx.var_map.get(dst) {
+                        validated.insert((*pool, *handle), builder.use_var(*var));
+                    }
+                }
             }
 
             // Lower terminator
@@ -792,8 +848,14 @@ impl<'a> FunctionBuilder<'a> {
                             .map(|ss| ss.min(*src_size))
                             .unwrap_or(*src_size);
                         // If the field is pointer-sized, just store the pointer
-                        // value instead of deep-copying the source slot.
-                        if effective_size <= 8 {
+                        // value instead of deep-copying the source slot. Tuple
+                        // elements are always inline — Field reads hand back
+                        // their address — so even a small one is copied.
+                        let dst_is_tuple = matches!(
+                            Self::operand_mir_type(&MirOperand::Local(*addr), ctx.locals),
+                            Some(MirType::Tuple(_))
+                        );
+                        if effective_size <= 8 && !dst_is_tuple {
                             false
                         } else {
                         let src_var = ctx.var_map.get(src_id)
@@ -1661,7 +1723,17 @@ impl<'a> FunctionBuilder<'a> {
                 }
             } else if func.name == "panic_unwrap" {
                 // MIR already handled branching; this is the panic path.
-                if let Some(file_str) = ctx.source_file {
+                // `x! "msg"` carries its message as the only arg — report
+                // that instead of the generic unwrap text.
+                if let (Some(msg), Some(panic_ref)) = (args.first(), ctx.func_refs.get("panic_at")) {
+                    let msg_ptr = Self::lower_operand_as_cstr(builder, msg, ctx)?;
+                    let file_ptr = ctx.source_file.and_then(|f| ctx.string_globals.get(f))
+                        .map(|gv| builder.ins().global_value(types::I64, *gv))
+                        .unwrap_or_else(|| builder.ins().iconst(types::I64, 0));
+                    let line_val = builder.ins().iconst(types::I32, ctx.current_line as i64);
+                    let col_val = builder.ins().iconst(types::I32, ctx.current_col as i64);
+                    builder.ins().call(*panic_ref, &[file_ptr, line_val, col_val, msg_ptr]);
+                } else if let Some(file_str) = ctx.source_file {
                     if let (Some(func_ref), Some(gv)) = (
                         ctx.func_refs.get("panic_unwrap_at"),
                         ctx.string_globals.get(file_str),
@@ -2151,6 +2223,15 @@ impl<'a> FunctionBuilder<'a> {
         }
     }
 
+    /// Integer → float, by the source's signedness.
+    fn int_to_float(builder: &mut ClifFunctionBuilder, val: Value, to_ty: Type, unsigned: bool) -> Value {
+        if unsigned {
+            builder.ins().fcvt_from_uint(to_ty, val)
+        } else {
+            builder.ins().fcvt_from_sint(to_ty, val)
+        }
+    }
+
     /// Lower an explicit conversion form (type.primitives CV5–CV10).
     fn lower_convert(
         builder: &mut ClifFunctionBuilder,
@@ -2525,10 +2606,12 @@ impl<'a> FunctionBuilder<'a> {
                     }
                 } else if lhs_ty.is_int() && rhs_ty.is_float() {
                     // Convert int to float to match rhs
-                    (builder.ins().fcvt_from_sint(rhs_ty, lhs_val), rhs_val)
+                    (Self::int_to_float(builder, lhs_val, rhs_ty, is_unsigned), rhs_val)
                 } else if lhs_ty.is_float() && rhs_ty.is_int() {
                     // Convert int to float to match lhs
-                    (lhs_val, builder.ins().fcvt_from_sint(lhs_ty, rhs_val))
+                    let rhs_unsigned = Self::operand_mir_type(right, ctx.locals)
+                        .map_or(false, |t| t.is_unsigned());
+                    (lhs_val, Self::int_to_float(builder, rhs_val, lhs_ty, rhs_unsigned))
                 } else {
                     (lhs_val, rhs_val)
                 };
@@ -2745,6 +2828,16 @@ impl<'a> FunctionBuilder<'a> {
                 let val = Self::lower_operand(builder, value, ctx)?;
                 let target = mir_to_cranelift_type(target_ty)?;
                 let val_ty = builder.func.dfg.value_type(val);
+                // convert_value assumes signed integers; `u64 as f64` and
+                // `f64 as u32` need the unsigned conversions.
+                if val_ty.is_int() && target.is_float() {
+                    let src_unsigned = Self::operand_mir_type(value, ctx.locals)
+                        .map_or(false, |t| t.is_unsigned());
+                    return Ok(Self::int_to_float(builder, val, target, src_unsigned));
+                }
+                if val_ty.is_float() && target.is_int() && target_ty.is_unsigned() {
+                    return Ok(builder.ins().fcvt_to_uint_sat(target, val));
+                }
                 Ok(Self::convert_value(builder, val, val_ty, target))
             }
 
@@ -3023,6 +3116,12 @@ impl<'a> FunctionBuilder<'a> {
                         // Return pointer to stack slot data for copy_aggregate
                         Self::emit_return(builder, value.as_ref(), ctx)?;
                     }
+                } else if let Some(ptr) = Self::small_aggregate_behind_pointer(value.as_ref(), builder, ctx) {
+                    // A small aggregate the function only holds a pointer to
+                    // (a parameter, a field read): callers expect the packed
+                    // bytes, the same as a stack-slot return above.
+                    let loaded = builder.ins().load(types::I64, MemFlags::new(), ptr, 0);
+                    builder.ins().return_(&[loaded]);
                 } else if matches!(ctx.ret_ty, MirType::Result { .. } | MirType::Option(_)) {
                     // Function returns Result/Option but value is a plain scalar
                     // or non-stack-slotted local (e.g. `return 42` or
@@ -3170,7 +3269,22 @@ impl<'a> FunctionBuilder<'a> {
                     }
                 };
 
-                // Create comparison chain: each case gets a brif, falling through to next
+                let default_block = ctx.block_map.get(default)
+                    .ok_or_else(|| CodegenError::UnsupportedFeature("Switch default block not found".to_string()))?;
+
+                // Dense cases (C-like enum tags): one bounds check + jump table
+                if term.kind.dense_switch_range().is_some() {
+                    let mut switch = Switch::new();
+                    for (value, target_id) in cases {
+                        let target_block = ctx.block_map.get(target_id)
+                            .ok_or_else(|| CodegenError::UnsupportedFeature("Switch target block not found".to_string()))?;
+                        switch.set_entry(*value as u128, *target_block);
+                    }
+                    switch.emit(builder, scrutinee_val, *default_block);
+                    return Ok(());
+                }
+
+                // Sparse: comparison chain, each case gets a brif falling through to next
                 // Don't seal MIR blocks here — the final seal-all loop handles them
                 let mut comparison_blocks = Vec::new();
 
@@ -3188,8 +3302,6 @@ impl<'a> FunctionBuilder<'a> {
                     builder.switch_to_block(next_block);
                 }
 
-                let default_block = ctx.block_map.get(default)
-                    .ok_or_else(|| CodegenError::UnsupportedFeature("Switch default block not found".to_string()))?;
                 builder.ins().jump(*default_block, &[]);
 
                 // Seal comparison chain blocks (these aren't MIR blocks)
@@ -3359,6 +3471,28 @@ impl<'a> FunctionBuilder<'a> {
         None
     }
 
+    /// The pointer behind a returned Struct/Enum/Tuple local that has no
+    /// stack slot of its own but fits a register, so it must be returned
+    /// packed rather than as its address.
+    fn small_aggregate_behind_pointer(
+        value: Option<&MirOperand>,
+        builder: &mut ClifFunctionBuilder,
+        ctx: &CodegenCtx,
+    ) -> Option<Value> {
+        let Some(MirOperand::Local(id)) = value else { return None };
+        if ctx.stack_slot_map.contains_key(id) || !matches!(ctx.ret_ty,
+            MirType::Struct(_) | MirType::Enum(_) | MirType::Tuple(_))
+        {
+            return None;
+        }
+        let size = Self::resolve_type_alloc_size(ctx.ret_ty, ctx.struct_layouts, ctx.enum_layouts)?;
+        if size == 0 || size > 8 {
+            return None;
+        }
+        let var = ctx.var_map.get(id)?;
+        Some(builder.use_var(*var))
+    }
+
     /// Compute the actual allocation size for a MirType, resolving struct/enum
     /// sizes from layouts. Unlike MirType::size() which returns 8 for Struct/Enum
     /// (pointer size), this returns the true layout size. Needed for stack slots
@@ -3818,6 +3952,18 @@ impl<'a> FunctionBuilder<'a> {
                 CallAdapt::None
             }
 
+            // select send arm: [sel, tx, value] → [sel, tx, value_ptr]
+            "rask_select_add_send" => {
+                if args.len() >= 3 {
+                    let (_, is_struct) = Self::struct_elem_size(mir_args, 2, ctx);
+                    if !is_struct {
+                        let val = args[2];
+                        args[2] = Self::value_to_ptr(builder, val);
+                    }
+                }
+                CallAdapt::None
+            }
+
             // Receiver_recv_struct: replace elem_size arg with stack buffer address
             "Receiver_recv_struct" => {
                 let elem_size = match mir_args.get(1) {
@@ -3925,10 +4071,12 @@ impl<'a> FunctionBuilder<'a> {
 
             MirOperand::Constant(const_val) => {
                 match const_val {
-                    MirConst::Int(n) => {
-                        let ty = expected_ty.unwrap_or(types::I64);
-                        Ok(builder.ins().iconst(ty, *n))
-                    }
+                    MirConst::Int(n) => match expected_ty {
+                        // Integer literal in a float position (`const x: f32 = 1`)
+                        Some(t) if t == types::F32 => Ok(builder.ins().f32const(*n as f32)),
+                        Some(t) if t == types::F64 => Ok(builder.ins().f64const(*n as f64)),
+                        ty => Ok(builder.ins().iconst(ty.unwrap_or(types::I64), *n)),
+                    },
                     MirConst::Float(f) => {
                         // Only use expected_ty if it's a float type; ignore int expected types
                         let ty = match expected_ty {
@@ -708,7 +708,7 @@ pub fn stdlib_entries() -> Vec<StdlibEntry> {
         StdlibEntry::simple("rask_sleep_ns", "rask_green_sleep_ns", &[types::I64], None, false),
 
         // ── Concurrency: runtime init/shutdown ───────────────────────
-        StdlibEntry::simple("rask_runtime_init", "rask_runtime_init", &[types::I64], None, false),
+        StdlibEntry::simple("rask_runtime_init", "rask_runtime_init", &[types::I64, types::I64], None, false),
         StdlibEntry::simple("rask_runtime_shutdown", "rask_runtime_shutdown", &[], None, false),
         StdlibEntry::simple("rask_green_spawn", "rask_green_spawn", &[types::I64, types::I64, types::I64], Some(types::I64), true),
 
@@ -728,6 +728,21 @@ pub fn stdlib_entries() -> Vec<StdlibEntry> {
         StdlibEntry::simple("rask_channel_send_async", "rask_channel_send_async", &[types::I64, types::I64], Some(types::I64), false),
         StdlibEntry::simple("rask_channel_recv_async", "rask_channel_recv_async", &[types::I64], Some(types::I64), true),
 
+        // ── Select (conc.select) ───────────────────────────────────
+        StdlibEntry::simple("rask_select_new", "rask_select_new", &[types::I64], Some(types::I64), false),
+        StdlibEntry::simple("rask_select_add_recv", "rask_select_add_recv", &[types::I64, types::I64], None, false),
+        // Send arm: the value is passed by pointer, like Sender_send.
+        StdlibEntry {
+            mir_name: "rask_select_add_send", c_name: "rask_select_add_send",
+            params: &[types::I64, types::I64, types::I64], ret_ty: None, can_panic: false,
+            arg_adapt: ArgAdapt::Custom, ret_adapt: RetAdapt::None,
+        },
+        StdlibEntry::simple("rask_select_wait", "rask_select_wait", &[types::I64, types::I64], Some(types::I64), true),
+        StdlibEntry::simple("rask_select_poll", "rask_select_poll", &[types::I64, types::I64], Some(types::I64), false),
+        StdlibEntry::simple("rask_select_take_i64", "rask_select_take_i64", &[types::I64, types::I64], Some(types::I64), false),
+        StdlibEntry::simple("rask_select_take_into", "rask_select_take_into", &[types::I64, types::I64, types::I64], None, false),
+        StdlibEntry::simple("rask_select_free", "rask_select_free", &[types::I64], None, false),
+
         // ── Ensure hooks ──────────────────────────────────────────
         StdlibEntry::simple("rask_ensure_push", "rask_ensure_push", &[types::I64, types::I64], None, false),
         StdlibEntry::simple("rask_ensure_pop", "rask_ensure_pop", &[], None, false),
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+//! On-disk cache of compiled functions for incremental builds.
+//!
+//! Backs Cranelift's incremental cache: the key is a hash of the function's
+//! IR (including the signatures of everything it calls and the target ISA
+//! flags), the value is the compiled code. A function whose lowered IR is
+//! byte-identical to a previous build skips instruction selection and
+//! register allocation entirely.
+
+use std::borrow::Cow;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use cranelift_codegen::incremental_cache::CacheKvStore;
+
+/// Directory-backed key-value store, one file per compiled function.
+pub struct FunctionCache {
+    dir: PathBuf,
+    hits: usize,
+    misses: usize,
+}
+
+impl FunctionCache {
+    /// Open (creating if needed) a cache rooted at `dir`.
+    pub fn open(dir: &Path) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        Ok(FunctionCache { dir: dir.to_path_buf(), hits: 0, misses: 0 })
+    }
+
+    /// Functions reused from the cache so far.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Functions compiled from scratch (and stored) so far.
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    pub(crate) fn record(&mut self, hit: bool) {
+        if hit {
+            self.hits += 1;
+        } else {
+            self.misses += 1;
+        }
+    }
+
+    fn entry_path(&self, key: &[u8]) -> PathBuf {
+        let name: String = key.iter().map(|b| format!("{:02x}", b)).collect();
+        self.dir.join(name)
+    }
+}
+
+impl CacheKvStore for FunctionCache {
+    fn get(&self, key: &[u8]) -> Option<Cow<'_, [u8]>> {
+        fs::read(self.entry_path(key)).ok().map(Cow::Owned)
+    }
+
+    fn insert(&mut self, key: &[u8], val: Vec<u8>) {
+        // Write-then-rename so a crashed build never leaves a torn entry.
+        // A failed write only costs a recompile next time.
+        let path = self.entry_path(key);
+        let tmp = path.with_extension("tmp");
+        if fs::write(&tmp, &val).is_ok() {
+            let _ = fs::rename(&tmp, &path);
+        }
+    }
+}
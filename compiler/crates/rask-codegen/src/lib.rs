@@ -7,11 +7,13 @@ mod builder;
 pub mod closures;
 mod debug_info;
 pub mod dispatch;
+mod fn_cache;
 pub mod layouts;
 mod module;
 mod tests;
 pub mod vtable;
 
+pub use fn_cache::FunctionCache;
 pub use module::CodeGenerator;
 
 use std::collections::HashMap;
@@ -43,6 +43,10 @@ pub struct CodeGenerator {
     source_file_name: Option<String>,
     /// DI5: inline region metadata from the inlining pass (caller name → regions)
     inline_regions: HashMap<String, Vec<rask_mir::InlineRegion>>,
+    /// Textual listing of generated machine code, when requested (`--emit=asm`)
+    asm_listing: Option<String>,
+    /// Compiled-function cache for incremental builds, when enabled
+    fn_cache: Option<crate::FunctionCache>,
 }
 
 impl CodeGenerator {
@@ -51,6 +55,9 @@ impl CodeGenerator {
             .map_err(|e| CodegenError::CraneliftError(e.to_string()))?;
         let mut flag_builder = settings::builder();
         let _ = flag_builder.set("opt_level", "speed");
+        if wants_pic(&target_lexicon::Triple::host()) {
+            let _ = flag_builder.set("is_pic", "true");
+        }
         let isa = isa_builder.finish(settings::Flags::new(flag_builder))
             .map_err(|e| CodegenError::CraneliftError(e.to_string()))?;
 
@@ -78,6 +85,8 @@ impl CodeGenerator {
             line_map: None,
             source_file_name: None,
             inline_regions: HashMap::new(),
+            asm_listing: None,
+            fn_cache: None,
         })
     }
 
@@ -89,8 +98,7 @@ impl CodeGenerator {
 
         let mut flag_builder = settings::builder();
         let _ = flag_builder.set("opt_level", "speed");
-        // Set is_pic for position-independent code on relevant targets
-        if matches!(target.operating_system, target_lexicon::OperatingSystem::Linux) {
+        if wants_pic(&target) {
             let _ = flag_builder.set("is_pic", "true");
         }
         let flags = settings::Flags::new(flag_builder);
@@ -124,9 +132,33 @@ impl CodeGenerator {
             line_map: None,
             source_file_name: None,
             inline_regions: HashMap::new(),
+            asm_listing: None,
+            fn_cache: None,
         })
     }
 
+    /// Record a textual listing of each function's machine code as it is
+    /// generated. Call before gen_function(); read it back with asm_listing().
+    pub fn enable_asm_listing(&mut self) {
+        self.asm_listing.get_or_insert_with(String::new);
+    }
+
+    /// Machine-code listing collected so far, if enabled.
+    pub fn asm_listing(&self) -> Option<&str> {
+        self.asm_listing.as_deref()
+    }
+
+    /// Reuse compiled functions from `cache` when their IR is unchanged.
+    /// Asm listings need the instruction stream, so they bypass the cache.
+    pub fn enable_function_cache(&mut self, cache: crate::FunctionCache) {
+        self.fn_cache = Some(cache);
+    }
+
+    /// The function cache, for hit/miss reporting.
+    pub fn function_cache(&self) -> Option<&crate::FunctionCache> {
+        self.fn_cache.as_ref()
+    }
+
     /// Set debug info context for DWARF emission.
     /// Call before gen_function() if you want debug line tables.
     pub fn set_debug_context(&mut self, source_file: &str, line_map: LineMap) {
@@ -912,6 +944,7 @@ impl CodeGenerator {
             .ok_or_else(|| CodegenError::FunctionNotFound(mir_fn.name.clone()))?;
 
         self.ctx.clear();
+        self.ctx.set_disasm(self.asm_listing.is_some());
 
         // Build the signature (must match declaration)
         let is_main = mir_fn.name == "main";
@@ -929,8 +962,12 @@ impl CodeGenerator {
         // Pre-import all declared functions into this function's namespace.
         // This must happen before FunctionBuilder borrows ctx.func.
         // Runtime functions are statically linked — mark colocated for direct calls.
+        // Import in FuncId order so identical MIR yields identical IR (the
+        // function cache keys on it).
+        let mut imports: Vec<_> = self.func_ids.iter().collect();
+        imports.sort_by_key(|(_, fid)| **fid);
         let mut func_refs = HashMap::new();
-        for (name, fid) in &self.func_ids {
+        for (name, fid) in imports {
             let func_ref = self.module.declare_func_in_func(*fid, &mut self.ctx.func);
             self.ctx.func.stencil.dfg.ext_funcs[func_ref].colocated = true;
             func_refs.insert(name.clone(), func_ref);
@@ -1011,9 +1048,32 @@ impl CodeGenerator {
         }
 
         // Define the function in the module
-        self.module
-            .define_function(*func_id, &mut self.ctx)
-            .map_err(|e| CodegenError::CraneliftError(format!("{:?}", e)))?;
+        match self.fn_cache.as_mut().filter(|_| self.asm_listing.is_none()) {
+            Some(cache) => {
+                let mut ctrl_plane = cranelift_codegen::control::ControlPlane::default();
+                let (code, hit) = self.ctx
+                    .compile_with_cache(self.module.isa(), cache, &mut ctrl_plane)
+                    .map_err(|e| CodegenError::CraneliftError(format!("{:?}", e.inner)))?;
+                let alignment = code.buffer.alignment as u64;
+                let bytes = code.code_buffer().to_vec();
+                let relocs = code.buffer.relocs().to_vec();
+                cache.record(hit);
+                self.module
+                    .define_function_bytes(*func_id, &self.ctx.func, alignment, &bytes, &relocs)
+                    .map_err(|e| CodegenError::CraneliftError(format!("{:?}", e)))?;
+            }
+            None => {
+                self.module
+                    .define_function(*func_id, &mut self.ctx)
+                    .map_err(|e| CodegenError::CraneliftError(format!("{:?}", e)))?;
+            }
+        }
+
+        if let Some(listing) = self.asm_listing.as_mut() {
+            if let Some(vcode) = self.ctx.compiled_code().and_then(|c| c.vcode.as_ref()) {
+                listing.push_str(&format!("{}:\n{}\n", mir_fn.name, vcode));
+            }
+        }
 
         // Collect debug info (srclocs, variables, inline regions)
         if self.build_mode == BuildMode::Debug {
@@ -1331,6 +1391,12 @@ impl CodeGenerator {
     }
 }
 
+/// Position-independent code lets the object link into PIE executables and
+/// shared libraries. Windows COFF doesn't use it.
+fn wants_pic(triple: &target_lexicon::Triple) -> bool {
+    !matches!(triple.operating_system, target_lexicon::OperatingSystem::Windows)
+}
+
 impl crate::Backend for CodeGenerator {
     fn declare_runtime_functions(&mut self) -> CodegenResult<()> {
         self.declare_runtime_functions()
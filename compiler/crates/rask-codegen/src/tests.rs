@@ -472,6 +472,70 @@ mod tests {
         std::fs::remove_file(path).unwrap();
     }
 
+    #[test]
+    fn codegen_object_exports_mangled_symbol() {
+        use object::{Object, ObjectSymbol};
+
+        let name = rask_mono::mangle_name("identity", &[rask_types::Type::I32]);
+        let mir = MirFunction {
+            name: name.clone(),
+            params: vec![local(0, "x", MirType::I32, true)],
+            ret_ty: MirType::I32,
+            locals: vec![local(0, "x", MirType::I32, true)],
+            blocks: vec![
+                block(0, vec![], ret(Some(local_op(0)))),
+            ],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        };
+
+        let mut gen = CodeGenerator::new(crate::BuildMode::Release).unwrap();
+        gen.declare_runtime_functions().unwrap();
+        gen.declare_functions(&dummy_mono(), &[mir.clone()]).unwrap();
+        gen.gen_function(&mir).unwrap();
+
+        let path = "/tmp/rask_test_codegen_symbols.o";
+        gen.emit_object(path).unwrap();
+        let bytes = std::fs::read(path).unwrap();
+        std::fs::remove_file(path).unwrap();
+
+        let file = object::File::parse(&*bytes).unwrap();
+        // Mach-O prefixes C-level symbols with an underscore.
+        let found = file.symbols().any(|sym| {
+            sym.name().map_or(false, |n| n.trim_start_matches('_') == name.trim_start_matches('_'))
+                && sym.is_definition()
+        });
+        assert!(found, "object should define `{}`", name);
+    }
+
+    #[test]
+    fn codegen_asm_listing_names_functions() {
+        let mir = MirFunction {
+            name: "answer".to_string(),
+            params: vec![],
+            ret_ty: MirType::I32,
+            locals: vec![],
+            blocks: vec![
+                block(0, vec![], ret(Some(i32_const(42)))),
+            ],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        };
+
+        let mut gen = CodeGenerator::new(crate::BuildMode::Release).unwrap();
+        assert!(gen.asm_listing().is_none());
+        gen.enable_asm_listing();
+        gen.declare_runtime_functions().unwrap();
+        gen.declare_functions(&dummy_mono(), &[mir.clone()]).unwrap();
+        gen.gen_function(&mir).unwrap();
+
+        let listing = gen.asm_listing().unwrap();
+        assert!(listing.starts_with("answer:"), "listing: {}", listing);
+        assert!(listing.lines().count() > 1, "listing should contain instructions");
+    }
+
     // ═══════════════════════════════════════════════════════════
     // Type conversions (Cast)
     // ═══════════════════════════════════════════════════════════
@@ -506,6 +570,124 @@ mod tests {
         gen.gen_function(&mir).unwrap();
     }
 
+    // ═══════════════════════════════════════════════════════════
+    // Floating point
+    // ═══════════════════════════════════════════════════════════
+
+    fn float_const(f: f64) -> MirOperand {
+        MirOperand::Constant(MirConst::Float(f))
+    }
+
+    /// `func name(x: param_ty) -> ret_ty { return <rvalue over x> }`
+    fn unary_fn(name: &str, param_ty: MirType, ret_ty: MirType, rvalue: MirRValue) -> MirFunction {
+        MirFunction {
+            name: name.to_string(),
+            params: vec![local(0, "x", param_ty.clone(), true)],
+            ret_ty: ret_ty.clone(),
+            locals: vec![
+                local(0, "x", param_ty, true),
+                temp(1, ret_ty),
+            ],
+            blocks: vec![
+                block(0, vec![assign(1, rvalue)], ret(Some(local_op(1)))),
+            ],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        }
+    }
+
+    fn gen_ok(mir: &MirFunction) {
+        let mut gen = CodeGenerator::new(crate::BuildMode::Debug).unwrap();
+        gen.declare_runtime_functions().unwrap();
+        gen.declare_functions(&dummy_mono(), &[mir.clone()]).unwrap();
+        gen.gen_function(mir).unwrap();
+    }
+
+    #[test]
+    fn codegen_f32_arithmetic_stays_f32() {
+        // func f(x: f32) -> f32 { return x * 2.5 }
+        gen_ok(&unary_fn("f", MirType::F32, MirType::F32, MirRValue::BinaryOp {
+            op: BinOp::Mul,
+            left: local_op(0),
+            right: float_const(2.5),
+        }));
+    }
+
+    #[test]
+    fn codegen_f64_arithmetic() {
+        // func f(x: f64) -> f64 { return x / 3.0 - 1 }
+        let mut mir = unary_fn("f", MirType::F64, MirType::F64, MirRValue::BinaryOp {
+            op: BinOp::Div,
+            left: local_op(0),
+            right: float_const(3.0),
+        });
+        mir.blocks[0].statements.push(assign(1, MirRValue::BinaryOp {
+            op: BinOp::Sub,
+            left: local_op(1),
+            right: i32_const(1),
+        }));
+        gen_ok(&mir);
+    }
+
+    #[test]
+    fn codegen_float_comparison() {
+        // func f(x: f32) -> bool { return x < 0.5 }
+        gen_ok(&unary_fn("f", MirType::F32, MirType::Bool, MirRValue::BinaryOp {
+            op: BinOp::Lt,
+            left: local_op(0),
+            right: float_const(0.5),
+        }));
+    }
+
+    #[test]
+    fn codegen_cast_int_to_float() {
+        // func f(x: i32) -> f64 { return x as f64 }
+        gen_ok(&unary_fn("f", MirType::I32, MirType::F64, MirRValue::Cast {
+            value: local_op(0),
+            target_ty: MirType::F64,
+        }));
+        // Unsigned sources take the unsigned conversion
+        gen_ok(&unary_fn("g", MirType::U64, MirType::F32, MirRValue::Cast {
+            value: local_op(0),
+            target_ty: MirType::F32,
+        }));
+    }
+
+    #[test]
+    fn codegen_cast_float_to_int() {
+        // func f(x: f64) -> u32 { return x as u32 }
+        gen_ok(&unary_fn("f", MirType::F64, MirType::U32, MirRValue::Cast {
+            value: local_op(0),
+            target_ty: MirType::U32,
+        }));
+        // func g(x: f32) -> f64 { return x as f64 }
+        gen_ok(&unary_fn("g", MirType::F32, MirType::F64, MirRValue::Cast {
+            value: local_op(0),
+            target_ty: MirType::F64,
+        }));
+    }
+
+    #[test]
+    fn codegen_int_literal_in_float_position() {
+        // func f() -> f32 { const y: f32 = 1; return y }
+        let mir = MirFunction {
+            name: "f".to_string(),
+            params: vec![],
+            ret_ty: MirType::F32,
+            locals: vec![local(0, "y", MirType::F32, false)],
+            blocks: vec![
+                block(0, vec![
+                    assign(0, MirRValue::Use(i32_const(1))),
+                ], ret(Some(local_op(0)))),
+            ],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        };
+        gen_ok(&mir);
+    }
+
     // ═══════════════════════════════════════════════════════════
     // Runtime function calls
     // ═══════════════════════════════════════════════════════════
@@ -534,6 +716,129 @@ mod tests {
         gen.gen_function(&mir).unwrap();
     }
 
+    #[test]
+    fn codegen_select_runtime_calls() {
+        // select { rx -> v: v, tx <- 7: 0 } over channel handles in _0/_1
+        let mir = MirFunction {
+            name: "sel".to_string(),
+            params: vec![local(0, "rx", MirType::I64, true), local(1, "tx", MirType::I64, true)],
+            ret_ty: MirType::I64,
+            locals: vec![
+                local(0, "rx", MirType::I64, true),
+                local(1, "tx", MirType::I64, true),
+                temp(2, MirType::I64),
+                temp(3, MirType::I64),
+                local(4, "v", MirType::I64, false),
+            ],
+            blocks: vec![
+                block(0, vec![
+                    call(Some(2), "rask_select_new", vec![i32_const(2)]),
+                    call(None, "rask_select_add_recv", vec![local_op(2), local_op(0)]),
+                    call(None, "rask_select_add_send", vec![local_op(2), local_op(1), i32_const(7)]),
+                    call(Some(3), "rask_select_wait", vec![local_op(2), i32_const(0)]),
+                ], MirTerminator::dummy(MirTerminatorKind::Switch {
+                    value: local_op(3),
+                    cases: vec![(0, BlockId(1)), (1, BlockId(2))],
+                    default: BlockId(3),
+                })),
+                block(1, vec![
+                    call(Some(4), "rask_select_take_i64", vec![local_op(2), i32_const(0)]),
+                    call(None, "rask_select_free", vec![local_op(2)]),
+                ], ret(Some(local_op(4)))),
+                block(2, vec![
+                    call(None, "rask_select_free", vec![local_op(2)]),
+                ], ret(Some(i32_const(0)))),
+                block(3, vec![], MirTerminator::dummy(MirTerminatorKind::Unreachable)),
+            ],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        };
+
+        let mut gen = CodeGenerator::new(crate::BuildMode::Debug).unwrap();
+        gen.declare_runtime_functions().unwrap();
+        gen.declare_stdlib_functions().unwrap();
+        gen.declare_functions(&dummy_mono(), &[mir.clone()]).unwrap();
+        gen.gen_function(&mir).unwrap();
+    }
+
+    #[test]
+    fn codegen_unwrap_with_message_stays_checked() {
+        // func f(is_none: bool) -> i32 { ... x! "config missing" ... }
+        let mir = MirFunction {
+            name: "f".to_string(),
+            params: vec![local(0, "is_none", MirType::Bool, true)],
+            ret_ty: MirType::I32,
+            locals: vec![local(0, "is_none", MirType::Bool, true)],
+            blocks: vec![
+                block(0, vec![], branch(local_op(0), 1, 2)),
+                block(1, vec![
+                    call(None, "panic_unwrap", vec![
+                        MirOperand::Constant(MirConst::String("config missing".to_string())),
+                    ]),
+                ], MirTerminator::dummy(MirTerminatorKind::Unreachable)),
+                block(2, vec![], ret(Some(i32_const(1)))),
+            ],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        };
+
+        // Unwrap is a user-requested assertion — Release keeps the check too.
+        for mode in [crate::BuildMode::Debug, crate::BuildMode::Release] {
+            let mut gen = CodeGenerator::new(mode).unwrap();
+            gen.declare_runtime_functions().unwrap();
+            gen.declare_functions(&dummy_mono(), &[mir.clone()]).unwrap();
+            gen.register_strings(&[mir.clone()]).unwrap();
+            gen.gen_function(&mir).unwrap();
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════
+    // Function cache
+    // ═══════════════════════════════════════════════════════════
+
+    fn const_fn(name: &str, value: i64) -> MirFunction {
+        MirFunction {
+            name: name.to_string(),
+            params: vec![],
+            ret_ty: MirType::I32,
+            locals: vec![],
+            blocks: vec![block(0, vec![], ret(Some(i32_const(value))))],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        }
+    }
+
+    /// Compile `fns` into a fresh module backed by the cache at `dir`,
+    /// returning (hits, misses).
+    fn build_with_cache(dir: &std::path::Path, fns: &[MirFunction]) -> (usize, usize) {
+        let mut gen = CodeGenerator::new(crate::BuildMode::Release).unwrap();
+        gen.declare_runtime_functions().unwrap();
+        gen.declare_functions(&dummy_mono(), fns).unwrap();
+        gen.enable_function_cache(crate::FunctionCache::open(dir).unwrap());
+        for f in fns {
+            gen.gen_function(f).unwrap();
+        }
+        let cache = gen.function_cache().unwrap();
+        (cache.hits(), cache.misses())
+    }
+
+    #[test]
+    fn function_cache_reuses_unchanged_functions() {
+        let dir = std::env::temp_dir().join(format!("rask-fn-cache-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let first = build_with_cache(&dir, &[const_fn("f", 1), const_fn("g", 2)]);
+        assert_eq!(first, (0, 2));
+        // Same f, touched g: only g is recompiled
+        let second = build_with_cache(&dir, &[const_fn("f", 1), const_fn("g", 3)]);
+        assert_eq!(second, (1, 1));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
     // ═══════════════════════════════════════════════════════════
     // Error handling
     // ═══════════════════════════════════════════════════════════
@@ -1113,6 +1418,88 @@ mod tests {
         gen.gen_function(&mir).unwrap();
     }
 
+    fn pool_access(dst: u32, pool: u32, handle: u32) -> MirStmt {
+        MirStmt::dummy(MirStmtKind::PoolCheckedAccess {
+            dst: LocalId(dst),
+            pool: LocalId(pool),
+            handle: LocalId(handle),
+        })
+    }
+
+    /// `f(pool, handle) -> i64` running `stmts`, then returning `_4`.
+    fn pool_fn(stmts: Vec<MirStmt>) -> MirFunction {
+        MirFunction {
+            name: "f".to_string(),
+            params: vec![
+                local(0, "pool", MirType::I64, true),
+                local(1, "handle", MirType::I64, true),
+            ],
+            ret_ty: MirType::I64,
+            locals: vec![
+                local(0, "pool", MirType::I64, true),
+                local(1, "handle", MirType::I64, true),
+                temp(2, MirType::I64),
+                temp(3, MirType::I64),
+                temp(4, MirType::I64),
+            ],
+            blocks: vec![block(0, stmts, ret(Some(local_op(4))))],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        }
+    }
+
+    /// Release-mode Cranelift IR for `mir`, as built and before optimization.
+    fn release_clif(mir: &MirFunction) -> String {
+        use cranelift_codegen::ir::{AbiParam, Function, Signature, UserFuncName};
+        use cranelift_codegen::isa::CallConv;
+        use std::collections::{HashMap, HashSet};
+
+        let mut sig = Signature::new(CallConv::SystemV);
+        for p in &mir.params {
+            sig.params.push(AbiParam::new(crate::types::mir_to_cranelift_type(&p.ty).unwrap()));
+        }
+        sig.returns.push(AbiParam::new(crate::types::mir_to_cranelift_type(&mir.ret_ty).unwrap()));
+        let mut func = Function::with_name_signature(UserFuncName::default(), sig);
+        let (refs, globals, names) = (HashMap::new(), HashMap::new(), HashSet::new());
+        crate::builder::FunctionBuilder::new(
+            &mut func, mir, &refs, &[], &[], &globals, &globals, &globals, &names, &names,
+            crate::BuildMode::Release,
+        ).unwrap().build().unwrap();
+        func.display().to_string()
+    }
+
+    /// Each inline validation is a bounds check and a generation check,
+    /// each branching to its own trapping panic block.
+    fn validations(clif: &str) -> usize {
+        clif.lines().filter(|l| l.trim_start().starts_with("trap")).count() / 2
+    }
+
+    #[test]
+    fn repeated_pool_access_validates_once() {
+        // pool[h] three times in one block → one bounds + generation check
+        let mir = pool_fn(vec![
+            pool_access(2, 0, 1),
+            pool_access(3, 0, 1),
+            pool_access(4, 0, 1),
+        ]);
+        let clif = release_clif(&mir);
+        assert_eq!(validations(&clif), 1, "{}", clif);
+    }
+
+    #[test]
+    fn handle_reassignment_revalidates() {
+        // GC3: once the handle local is rewritten, the next access checks again
+        let mir = pool_fn(vec![
+            pool_access(2, 0, 1),
+            assign(1, MirRValue::Use(local_op(1))),
+            pool_access(3, 0, 1),
+            pool_access(4, 0, 1),
+        ]);
+        let clif = release_clif(&mir);
+        assert_eq!(validations(&clif), 2, "{}", clif);
+    }
+
     // ═══════════════════════════════════════════════════════════
     // Ensure push/pop (no-ops)
     // ═══════════════════════════════════════════════════════════
@@ -1976,6 +2363,79 @@ mod tests {
         gen.gen_function(&main_fn).unwrap();
     }
 
+    // ═══════════════════════════════════════════════════════════
+    // Tuples by value
+    // ═══════════════════════════════════════════════════════════
+
+    #[test]
+    fn codegen_tuple_param_returned_packed() {
+        // func id(p: (i32, i32)) -> (i32, i32) { return p }
+        //
+        // p arrives as a pointer; the 8-byte tuple goes back packed.
+        let pair = MirType::Tuple(vec![MirType::I32, MirType::I32]);
+
+        let mir = MirFunction {
+            name: "id".to_string(),
+            params: vec![local(0, "p", pair.clone(), true)],
+            ret_ty: pair.clone(),
+            locals: vec![local(0, "p", pair, true)],
+            blocks: vec![block(0, vec![], ret(Some(local_op(0))))],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        };
+
+        let mono = dummy_mono();
+        let mut gen = CodeGenerator::new(crate::BuildMode::Debug).unwrap();
+        gen.declare_runtime_functions().unwrap();
+        gen.declare_functions(&mono, &[mir.clone()]).unwrap();
+        gen.gen_function(&mir).unwrap();
+    }
+
+    #[test]
+    fn codegen_nested_tuple_store() {
+        // const t = ((1, 2), true); return t.0.1
+        //
+        //   _0: (i32, i32)
+        //   _1: ((i32, i32), bool)
+        //   store _1 + 0, _0   — copies the inner tuple's bytes
+        //   _2 = Field { base: _1, field_index: 0 }
+        //   _3 = Field { base: _2, field_index: 1 }
+        let pair = MirType::Tuple(vec![MirType::I32, MirType::I32]);
+        let nested = MirType::Tuple(vec![pair.clone(), MirType::Bool]);
+
+        let mir = MirFunction {
+            name: "nested".to_string(),
+            params: vec![],
+            ret_ty: MirType::I32,
+            locals: vec![
+                temp(0, pair.clone()),
+                temp(1, nested),
+                temp(2, pair),
+                temp(3, MirType::I32),
+            ],
+            blocks: vec![
+                block(0, vec![
+                    MirStmt::dummy(MirStmtKind::Store { addr: LocalId(0), offset: 0, value: i32_const(1), store_size: None }),
+                    MirStmt::dummy(MirStmtKind::Store { addr: LocalId(0), offset: 4, value: i32_const(2), store_size: None }),
+                    MirStmt::dummy(MirStmtKind::Store { addr: LocalId(1), offset: 0, value: local_op(0), store_size: None }),
+                    MirStmt::dummy(MirStmtKind::Store { addr: LocalId(1), offset: 8, value: MirOperand::Constant(MirConst::Bool(true)), store_size: None }),
+                    assign(2, MirRValue::Field { base: local_op(1), field_index: 0, byte_offset: None, field_size: None }),
+                    assign(3, MirRValue::Field { base: local_op(2), field_index: 1, byte_offset: None, field_size: None }),
+                ], ret(Some(local_op(3)))),
+            ],
+            entry_block: BlockId(0),
+            is_extern_c: false,
+            source_file: None,
+        };
+
+        let mono = dummy_mono();
+        let mut gen = CodeGenerator::new(crate::BuildMode::Debug).unwrap();
+        gen.declare_runtime_functions().unwrap();
+        gen.declare_functions(&mono, &[mir.clone()]).unwrap();
+        gen.gen_function(&mir).unwrap();
+    }
+
     // ── Helpers ──────────────────────────────────────────────────
 
     /// CodeGenerator with runtime + stdlib declared (for tests needing stdlib).
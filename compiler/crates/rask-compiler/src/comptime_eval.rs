@@ -5,20 +5,23 @@
 //! called directly by the CLI's test/bench paths, which build their own
 //! monomorphized program. It folds each comptime-initialized const, trying the
 //! MIR/Miri fast path first and falling back to the AST interpreter, and
-//! reports hard errors (overflow, divide-by-zero — type.overflow CT1/OV2) as
-//! `Diagnostic`s so they flow through the normal pipeline error path.
+//! reports hard errors (overflow, divide-by-zero — type.overflow CT1/OV2 —
+//! and comptime panics) as `Diagnostic`s so they flow through the normal
+//! pipeline error path. Statement-level `comptime { }` blocks in function
+//! bodies are evaluated too, so `assert` inside them acts as a static assert.
 
 use std::collections::HashMap;
 
 use rask_ast::decl::{Decl, DeclKind, FnDecl};
 use rask_ast::stmt::{Stmt, StmtKind};
 use rask_ast::{NodeId, Span};
-use rask_diagnostics::Diagnostic;
+use rask_comptime::is_comptime_init;
+use rask_diagnostics::{Diagnostic, ToDiagnostic};
 use rask_mir::ComptimeGlobalMeta;
 use rask_mono::MonoProgram;
-use rask_types::TypedProgram;
+use rask_types::{Type, TypeDef, TypedProgram};
 
-use crate::{is_comptime_init, CfgConfig};
+use crate::CfgConfig;
 
 /// Evaluate every comptime-initialized const. Returns the folded globals plus
 /// any hard-error diagnostics (empty on success). Soft failures — an
@@ -30,27 +33,37 @@ pub fn evaluate_comptime_globals(
     mono: &MonoProgram,
     cfg: Option<&CfgConfig>,
 ) -> (HashMap<String, ComptimeGlobalMeta>, Vec<Diagnostic>) {
+    // Data is embedded for the build target, not the host (cross builds).
+    let endian = cfg.map(|c| c.endian()).unwrap_or_default();
     let mut comptime_interp = rask_comptime::ComptimeInterpreter::new();
     if let Some(c) = cfg {
         comptime_interp.inject_cfg(c);
     }
     comptime_interp.register_functions(decls);
+    comptime_interp.register_types(decls);
 
-    // Collect (name, init) from top-level consts and function-body consts.
+    // Collect (name, init) from top-level consts and function-body consts,
+    // plus standalone comptime blocks (evaluated only for their checks).
     let mut comptime_consts: Vec<(String, &rask_ast::expr::Expr)> = Vec::new();
+    let mut comptime_blocks: Vec<(&[Stmt], Span)> = Vec::new();
     for decl in decls {
         match &decl.kind {
             DeclKind::Const(c) => {
                 if is_comptime_init(&c.init, decls) {
                     comptime_consts.push((c.name.clone(), &c.init));
+                } else if let Ok(value) = comptime_interp.eval_const(&c.init) {
+                    // Plain globals that fold are visible to comptime checks.
+                    comptime_interp.define_const(c.name.clone(), value);
                 }
             }
             DeclKind::Fn(f) => {
                 for stmt in &f.body {
-                    if let StmtKind::Const { name, init, .. } = &stmt.kind {
-                        if is_comptime_init(init, decls) {
+                    match &stmt.kind {
+                        StmtKind::Const { name, init, .. } if is_comptime_init(init, decls) => {
                             comptime_consts.push((name.clone(), init));
                         }
+                        StmtKind::Comptime(body) => comptime_blocks.push((body, stmt.span)),
+                        _ => {}
                     }
                 }
             }
@@ -61,10 +74,18 @@ pub fn evaluate_comptime_globals(
     let mut globals = HashMap::new();
     let mut diags = Vec::new();
 
+    // MIR lowering injects module consts as locals at function entry. The
+    // comptime ones aren't folded yet, so leave them out — otherwise a
+    // comptime func would start by re-running its own const's initializer.
+    let mir_decls: Vec<Decl> = decls.iter()
+        .filter(|d| !matches!(&d.kind, DeclKind::Const(c) if is_comptime_init(&c.init, decls)))
+        .cloned()
+        .collect();
+
     for (name, init) in comptime_consts {
         // MIR/Miri fast path.
         let mut hard = None;
-        if let Some(meta) = try_eval_comptime_mir(&name, init, typed, mono, decls, &mut hard) {
+        if let Some(meta) = try_eval_comptime_mir(&name, init, typed, mono, &mir_decls, endian, &mut hard) {
             globals.insert(name, meta);
             continue;
         }
@@ -77,26 +98,135 @@ pub fn evaluate_comptime_globals(
         // AST-interpreter fallback.
         comptime_interp.reset_branch_count();
         match comptime_interp.eval_expr(init) {
-            Ok(val) => {
-                if let Some(bytes) = val.serialize() {
-                    globals.insert(name, ComptimeGlobalMeta {
+            Ok(mut val) => {
+                if let Some(ty) = typed.node_types.get(&init.id) {
+                    if let Some(reason) = fit_error(&val, ty, typed) {
+                        diags.push(fit_diagnostic(&name, ty, typed, &reason, init.span));
+                        continue;
+                    }
+                    // L6: fold an integer in float position as the float
+                    if let Type::F32 | Type::F64 = ty {
+                        val = val.int_as_float(matches!(ty, Type::F32)).unwrap_or(val);
+                    }
+                }
+                if let Some(bytes) = val.serialize(endian) {
+                    globals.insert(name.clone(), ComptimeGlobalMeta {
                         bytes,
                         elem_count: val.elem_count(),
                         type_prefix: val.type_prefix().to_string(),
                     });
                 }
+                comptime_interp.define_const(name, val);
             }
-            Err(e) if e.is_hard() => {
-                let div0 = matches!(e, rask_comptime::ComptimeError::DivisionByZero);
-                diags.push(comptime_diagnostic(&e.to_string(), div0, init.span));
-            }
+            Err(e) if e.is_hard() => diags.push(comptime_interp.locate(e, init.span).to_diagnostic()),
             Err(_) => {} // soft: not foldable → runs at runtime
         }
     }
 
+    // Blocks that read runtime locals or comptime params fail softly and are
+    // left alone; only a hard failure (panic, overflow) is reported.
+    for (body, span) in comptime_blocks {
+        comptime_interp.reset_branch_count();
+        if let Err(e) = comptime_interp.eval_block_to_value(body) {
+            if e.is_hard() {
+                diags.push(comptime_interp.locate(e, span).to_diagnostic());
+            }
+        }
+    }
+
     (globals, diags)
 }
 
+/// Range of a fixed-width integer type; None for every other type.
+fn int_range(ty: &Type) -> Option<(i128, i128)> {
+    Some(match ty {
+        Type::I8 => (i8::MIN as i128, i8::MAX as i128),
+        Type::I16 => (i16::MIN as i128, i16::MAX as i128),
+        Type::I32 => (i32::MIN as i128, i32::MAX as i128),
+        Type::I64 => (i64::MIN as i128, i64::MAX as i128),
+        Type::U8 => (0, u8::MAX as i128),
+        Type::U16 => (0, u16::MAX as i128),
+        Type::U32 => (0, u32::MAX as i128),
+        Type::U64 => (0, u64::MAX as i128),
+        _ => return None,
+    })
+}
+
+fn type_label(ty: &Type, typed: &TypedProgram) -> String {
+    match ty {
+        Type::Named(id) => match typed.types.get(*id) {
+            Some(TypeDef::Struct { name, .. } | TypeDef::Enum { name, .. }) => name.clone(),
+            _ => ty.to_string(),
+        },
+        _ => ty.to_string(),
+    }
+}
+
+/// Why a folded value can't be stored in its declared type, if it can't.
+/// Comptime arithmetic on unsuffixed literals runs at i64, so a `u8` const
+/// can fold to 300; serializing that would silently truncate.
+fn fit_error(val: &rask_comptime::ComptimeValue, ty: &Type, typed: &TypedProgram) -> Option<String> {
+    use rask_comptime::ComptimeValue as V;
+
+    if let Some((min, max)) = int_range(ty) {
+        return match val.as_i128() {
+            Some(v) if (min..=max).contains(&v) => None,
+            Some(v) => Some(format!("`{}` is out of range for `{}` [{}, {}]", v, ty, min, max)),
+            None => Some(format!("expected `{}`, found `{}`", ty, val.type_name())),
+        };
+    }
+    match (ty, val) {
+        (Type::F32 | Type::F64, V::F32(_) | V::F64(_))
+        | (Type::Bool, V::Bool(_))
+        | (Type::Char, V::Char(_))
+        | (Type::String, V::String(_)) => None,
+        // An integer in float position is accepted; the caller folds it to the float (L6)
+        (Type::F32 | Type::F64, _) if val.as_i128().is_some() => None,
+        (Type::F32 | Type::F64 | Type::Bool | Type::Char | Type::String, _) => {
+            Some(format!("expected `{}`, found `{}`", ty, val.type_name()))
+        }
+        (Type::Array { elem, len }, V::Array(items)) => {
+            if items.len() != *len {
+                return Some(format!("expected {} elements, found {}", len, items.len()));
+            }
+            items.iter().find_map(|item| fit_error(item, elem, typed))
+        }
+        (Type::Named(id), _) => match (typed.types.get(*id), val) {
+            (Some(TypeDef::Enum { name, variants, .. }), V::Enum { variant, .. }) => {
+                if variants.iter().any(|(v, _)| v == variant) {
+                    None
+                } else {
+                    Some(format!("`{}` has no variant `{}`", name, variant))
+                }
+            }
+            (Some(TypeDef::Struct { name, fields, .. }), V::Struct { fields: got, .. }) => {
+                if let Some((missing, _)) = fields.iter().find(|(f, _)| !got.contains_key(f)) {
+                    return Some(format!("missing field `{}` of `{}`", missing, name));
+                }
+                if let Some(extra) = got.keys().find(|k| !fields.iter().any(|(f, _)| f == *k)) {
+                    return Some(format!("`{}` has no field `{}`", name, extra));
+                }
+                fields.iter().find_map(|(f, fty)| fit_error(&got[f], fty, typed))
+            }
+            (Some(TypeDef::Enum { name, .. } | TypeDef::Struct { name, .. }), _) => {
+                Some(format!("expected `{}`, found `{}`", name, val.type_name()))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn fit_diagnostic(name: &str, ty: &Type, typed: &TypedProgram, reason: &str, span: Span) -> Diagnostic {
+    Diagnostic::error(format!(
+        "comptime value of `{}` does not fit its type `{}`: {}",
+        name, type_label(ty, typed), reason
+    ))
+    .with_code("E0371")
+    .with_primary(span, "evaluated here")
+    .with_why("comptime results are embedded as data of the declared type; nothing is truncated silently")
+}
+
 /// Build a diagnostic for a hard comptime error at `span`. Overflow shares the
 /// R0010 code with the interpreter's runtime check; divide-by-zero shares R0001.
 fn comptime_diagnostic(message: &str, div_by_zero: bool, span: Span) -> Diagnostic {
@@ -120,6 +250,7 @@ fn try_eval_comptime_mir(
     typed: &TypedProgram,
     mono: &MonoProgram,
     decls: &[Decl],
+    endian: rask_comptime::Endian,
     hard_err: &mut Option<rask_miri::MiriError>,
 ) -> Option<ComptimeGlobalMeta> {
     use rask_ast::expr::ExprKind;
@@ -229,6 +360,7 @@ fn try_eval_comptime_mir(
         trait_coercions: &empty_coercions,
         call_rewrites: &empty_rewrites,
         resource_types: &empty_resource_types,
+        resource_moves: &HashMap::new(),
     };
 
     let mir_fn = rask_mir::lower::MirLowerer::lower_function(&synth_decl, decls, &mir_ctx)
@@ -266,9 +398,25 @@ fn try_eval_comptime_mir(
         Err(_) => return None,
     };
 
+    // An integer that doesn't fit the declared width goes to the AST
+    // interpreter, which reports the mismatch.
+    if let Some((min, max)) = typed.node_types.get(&init.id).and_then(int_range) {
+        let value = match result {
+            rask_miri::MiriValue::U64(v) => Some(v as i128),
+            rask_miri::MiriValue::I8(_) | rask_miri::MiriValue::I16(_)
+            | rask_miri::MiriValue::I32(_) | rask_miri::MiriValue::I64(_)
+            | rask_miri::MiriValue::U8(_) | rask_miri::MiriValue::U16(_)
+            | rask_miri::MiriValue::U32(_) => result.to_i64().map(i128::from),
+            _ => None,
+        };
+        if !value.map_or(false, |v| (min..=max).contains(&v)) {
+            return None;
+        }
+    }
+
     Some(ComptimeGlobalMeta {
         type_prefix: result.type_prefix().to_string(),
         elem_count: result.elem_count(),
-        bytes: result.serialize()?,
+        bytes: result.serialize(endian)?,
     })
 }
@@ -31,6 +31,7 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 use rask_ast::decl::{Decl, DeclKind};
+use rask_ast::NodeId;
 use rask_diagnostics::{Diagnostic, Severity, ToDiagnostic};
 
 mod derive;
@@ -71,6 +72,8 @@ pub struct CheckResult {
     pub effects: EffectMap,
     pub effect_warnings: Vec<EffectWarning>,
     pub frozen_diagnostics: Vec<FrozenDiagnostic>,
+    /// Resource consumption points from the ownership pass (NodeId → binding).
+    pub resource_moves: HashMap<NodeId, String>,
 }
 
 /// Result of the full compilation pipeline (through monomorphization).
@@ -80,6 +83,8 @@ pub struct CompileResult {
     pub decls: Vec<Decl>,
     pub comptime_globals: HashMap<String, ComptimeGlobalMeta>,
     pub package_modules: HashSet<String>,
+    /// Carried through from `CheckResult` for MIR lowering (ensure cancellation).
+    pub resource_moves: HashMap<NodeId, String>,
 }
 
 /// Output of any pipeline operation.
@@ -297,6 +302,7 @@ fn check_single(path: &str, config: &CompilerConfig) -> PipelineOutput<CheckResu
             effects,
             effect_warnings,
             frozen_diagnostics,
+            resource_moves: ownership_result.resource_moves,
         },
         diags,
     )
@@ -429,6 +435,7 @@ pub fn check_package(
             effects,
             effect_warnings,
             frozen_diagnostics,
+            resource_moves: ownership_result.resource_moves,
         },
         diags,
         source_files,
@@ -509,6 +516,9 @@ fn finalize_compile(
         Some(&check.typed.node_types),
     );
 
+    // Everything past this point is synthesized or from other packages.
+    let user_decl_count = check.decls.len();
+
     // --- Derive synthetic method bodies (compare, etc.) ---
     derive::generate_derived_methods(&mut check.decls, &check.typed);
 
@@ -539,6 +549,15 @@ fn finalize_compile(
         }
     };
 
+    // --- Unreachable private methods (advisory notes) ---
+    let user_decls = &check.decls[..user_decl_count];
+    let has_main = user_decls
+        .iter()
+        .any(|d| matches!(&d.kind, DeclKind::Fn(f) if f.name == "main"));
+    if has_main {
+        diags.extend(rask_mono::unreachable_methods(user_decls, &mono).into_iter().map(dead_code_note));
+    }
+
     // --- Evaluate comptime globals (single source of truth) ---
     // Hard errors (overflow, divide-by-zero) become pipeline diagnostics and
     // fail the build like any other pass — no separate handling downstream.
@@ -556,12 +575,28 @@ fn finalize_compile(
             decls: check.decls,
             comptime_globals,
             package_modules,
+            resource_moves: check.resource_moves,
         },
         diags,
         pkg_source_files,
     )
 }
 
+fn dead_code_note(dead: rask_mono::DeadCode) -> Diagnostic {
+    match dead {
+        rask_mono::DeadCode::Method { type_name, method, span } => {
+            Diagnostic::note(format!("method `{}.{}` is never used", type_name, method))
+                .with_primary(span, "not reachable from `main`")
+                .with_help("remove it, or make it `public` if it's part of the API")
+        }
+        rask_mono::DeadCode::Impl { type_name, method_count, span } => {
+            Diagnostic::note(format!("no method in this `extend {}` block is ever used", type_name))
+                .with_primary(span, format!("{} method(s) not reachable from `main`", method_count))
+                .with_help("remove the block, or make its methods `public` if they're part of the API")
+        }
+    }
+}
+
 // ============================================================================
 // Comptime global evaluation
 // ============================================================================
@@ -570,16 +605,6 @@ fn finalize_compile(
 // of truth used by both the pipeline (below) and the CLI's test/bench paths.
 pub use crate::comptime_eval::evaluate_comptime_globals;
 
-pub(crate) fn is_comptime_init(init: &rask_ast::expr::Expr, decls: &[Decl]) -> bool {
-    use rask_ast::expr::ExprKind;
-
-    matches!(&init.kind, ExprKind::Comptime { .. })
-        || matches!(&init.kind, ExprKind::Call { func, .. }
-            if matches!(&func.kind, ExprKind::Ident(name)
-                if decls.iter().any(|d| matches!(&d.kind,
-                    DeclKind::Fn(f) if f.name == *name && f.is_comptime))))
-}
-
 // ============================================================================
 // Helpers
 // ============================================================================
@@ -650,5 +675,9 @@ fn frozen_to_diagnostic(d: &FrozenDiagnostic) -> Diagnostic {
     } else {
         Diagnostic::warning(&d.message)
     };
-    diag.with_code(d.code).with_primary(d.span, "")
+    let diag = diag.with_code(d.code).with_primary(d.span, "");
+    match d.clause_span {
+        Some(span) => diag.with_secondary(span, "frozen here"),
+        None => diag,
+    }
 }
@@ -6,6 +6,7 @@
 //! error accumulation across stages and the divergence-fix behaviors
 //! (desugar diagnostics, default args, comptime cfg, etc.).
 
+use std::collections::{HashMap, HashSet};
 use std::io::Write;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -384,3 +385,2324 @@ fn er42_struct_with_linear_field_is_transitively_linear() {
     );
     let _ = std::fs::remove_file(&path);
 }
+
+// ═══════════════════════════════════════════════════════════════════════
+// Resources are linear: a second use is a copy, not a stale read
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn resource_used_twice_reports_linear_copy() {
+    let path = tmp_rk(r#"
+        @resource
+        struct File { path: string }
+        extend File { func close(take self) {} }
+
+        func bad(take f: File) {
+            const first = f
+            const second = f
+            first.close()
+            second.close()
+        }
+
+        func main() {}
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let copied: Vec<_> = output.diagnostics.iter()
+        .filter(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0820"))
+        .collect();
+    assert_eq!(copied.len(), 1,
+        "expected one E0820, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    assert_eq!(copied[0].message,
+        "`File` is a linear resource and cannot be copied; move it or borrow it");
+    assert!(!has_code(&output.diagnostics, "E0800"), "resource copy must not also report E0800");
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// CT3/CT6: call-site staging
+// ═══════════════════════════════════════════════════════════════════════
+
+fn has_code(diagnostics: &[rask_diagnostics::Diagnostic], code: &str) -> bool {
+    diagnostics.iter().any(|d| d.code.as_ref().map_or(false, |c| c.0 == code))
+}
+
+#[test]
+fn ct3_comptime_func_called_at_runtime_errors() {
+    let path = tmp_rk(r#"
+        comptime func table_size() -> i32 {
+            return 256
+        }
+
+        func main() {
+            const n = table_size()
+            println("{n}")
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(!output.succeeded(), "CT3: comptime-only call from runtime code must error");
+    assert!(has_code(&output.diagnostics, "E0358"),
+        "expected E0358, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn ct7_runtime_only_func_called_at_comptime_errors() {
+    // `has_config` reaches I/O only through `probe` — the transitive
+    // classification must still reject it in a comptime position.
+    let path = tmp_rk(r#"
+        import fs
+
+        func probe() -> bool {
+            return fs.exists("config.txt")
+        }
+
+        func has_config() -> bool {
+            return probe()
+        }
+
+        func main() {
+            const found = comptime has_config()
+            println("{found}")
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(!output.succeeded(), "CT7: runtime-only call at comptime must error");
+    let staging: Vec<_> = output.diagnostics.iter()
+        .filter(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0359"))
+        .collect();
+    assert_eq!(staging.len(), 1, "expected one E0359, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    assert!(staging[0].message.contains("`has_config`"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn staging_allows_matching_call_sites() {
+    // Comptime funcs under `comptime`, pure funcs in either phase, and
+    // runtime funcs from runtime code are all fine.
+    let path = tmp_rk(r#"
+        comptime func square(n: i32) -> i32 {
+            return double(n) * n / 2
+        }
+
+        func double(n: i32) -> i32 {
+            return n * 2
+        }
+
+        func greet() {
+            println("hello")
+        }
+
+        func main() {
+            const a = comptime square(4)
+            const b = double(a)
+            greet()
+            println("{b}")
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(!has_code(&output.diagnostics, "E0358") && !has_code(&output.diagnostics, "E0359"),
+        "no staging errors expected, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Array repeat lengths
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn array_repeat_constant_length_ok() {
+    let path = tmp_rk(r#"
+        const N = 4
+
+        func main() {
+            const a = [0; N * 2]
+            const b = [1; 3]
+            println("{a.len()} {b.len()}")
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(!has_code(&output.diagnostics, "E0360")
+            && !has_code(&output.diagnostics, "E0361")
+            && !has_code(&output.diagnostics, "E0362"),
+        "constant lengths must be accepted, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn array_repeat_runtime_length_errors() {
+    let path = tmp_rk(r#"
+        func make(n: i32) {
+            const a = [0; n]
+        }
+
+        func main() {
+            make(3)
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(has_code(&output.diagnostics, "E0361"),
+        "expected E0361 for runtime length, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn array_repeat_negative_length_errors() {
+    let path = tmp_rk(r#"
+        func main() {
+            const a = [0; 2 - 5]
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(has_code(&output.diagnostics, "E0362"),
+        "expected E0362 for negative length, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn interpolating_struct_without_to_string_errors() {
+    let path = tmp_rk(r#"
+        struct Point { x: i32, y: i32 }
+
+        func main() {
+            const p = Point { x: 1, y: 2 }
+            println("at {p}")
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let diag = output.diagnostics.iter()
+        .find(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0363"));
+    assert!(diag.is_some(),
+        "expected E0363 for non-displayable interpolation, got: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    assert!(diag.unwrap().message.contains("Point"));
+    let _ = std::fs::remove_file(&path);
+}
+
+#[test]
+fn interpolating_displayable_struct_ok() {
+    let path = tmp_rk(r#"
+        struct Point { x: i32, y: i32 }
+
+        extend Point {
+            func to_string(self) -> string {
+                return "({self.x}, {self.y})"
+            }
+        }
+
+        func main() {
+            const p = Point { x: 1, y: 2 }
+            println("at {p}")
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(!has_code(&output.diagnostics, "E0363"),
+        "unexpected E0363: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+fn comparison_diagnostics(op: &str) -> Vec<rask_diagnostics::Diagnostic> {
+    let path = tmp_rk(&format!(r#"
+        func main() {{
+            const n: i32 = 1
+            const s = "one"
+            if n {} s {{
+                println("same")
+            }}
+        }}
+    "#, op));
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    output.diagnostics
+}
+
+#[test]
+fn comparing_incompatible_types_errors() {
+    for op in ["==", "!=", "<", "<=", ">", ">="] {
+        let diagnostics = comparison_diagnostics(op);
+        let diag = diagnostics.iter()
+            .find(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0364"));
+        assert!(diag.is_some(),
+            "expected E0364 for `i32 {} string`, got: {:?}", op,
+            diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+        );
+        assert_eq!(diag.unwrap().message, "cannot compare `i32` with `string`");
+        assert!(!has_code(&diagnostics, "E0313"), "`{}` also reported method-not-found", op);
+    }
+}
+
+#[test]
+fn comparing_same_types_ok() {
+    let path = tmp_rk(r#"
+        func main() {
+            const a: i32 = 1
+            const b: i32 = 2
+            if a < b {
+                println("less")
+            }
+        }
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    assert!(!has_code(&output.diagnostics, "E0364"),
+        "unexpected E0364: {:?}",
+        output.diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>()
+    );
+    let _ = std::fs::remove_file(&path);
+}
+
+fn check_errors(src: &str) -> Vec<String> {
+    let path = tmp_rk(src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    output.diagnostics.iter()
+        .filter(|d| matches!(d.severity, rask_diagnostics::Severity::Error))
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+fn check_warnings(src: &str) -> Vec<String> {
+    let path = tmp_rk(src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    output.diagnostics.iter()
+        .filter(|d| matches!(d.severity, rask_diagnostics::Severity::Warning))
+        .map(|d| d.message.clone())
+        .collect()
+}
+
+#[test]
+fn empty_array_branch_takes_element_type_from_sibling() {
+    let ok = check_errors(r#"
+        func pick(flag: bool) -> i32 {
+            const xs = if flag { [] } else { [1] }
+            const n: i32 = xs[0]
+            return n
+        }
+        func main() { println(pick(true)) }
+    "#);
+    assert!(ok.is_empty(), "unexpected errors: {:?}", ok);
+
+    let bad = check_errors(r#"
+        func pick(flag: bool) -> string {
+            const xs = if flag { [] } else { [1] }
+            const s: string = xs[0]
+            return s
+        }
+        func main() { println(pick(true)) }
+    "#);
+    assert!(!bad.is_empty(), "element type should be inferred as i32");
+}
+
+#[test]
+fn binding_annotation_flows_into_branch_tails() {
+    let ok = check_errors(r#"
+        func pick(n: i32) -> i64 {
+            const xs: [i64; 2] = match n {
+                0 => [1, 2],
+                _ => {
+                    const k = n + 1;
+                    [3, 4]
+                },
+            }
+            return xs[0]
+        }
+        func main() { println(pick(0)) }
+    "#);
+    assert!(ok.is_empty(), "unexpected errors: {:?}", ok);
+
+    let bad = check_errors(r#"
+        func pick(flag: bool) -> i64 {
+            const xs: [string; 1] = if flag { [1] } else { [2] }
+            return 0
+        }
+        func main() { println(pick(true)) }
+    "#);
+    assert!(!bad.is_empty(), "integer literals can't satisfy a [string; 1] annotation");
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Comptime panics fail the build
+// ═══════════════════════════════════════════════════════════════════════
+
+fn compile_diagnostics(src: &str) -> Vec<rask_diagnostics::Diagnostic> {
+    let path = tmp_rk(src);
+    let output = rask_compiler::compile_file(path.to_str().unwrap(), vec![], &default_config());
+    let _ = std::fs::remove_file(&path);
+    output.diagnostics
+}
+
+#[test]
+fn failing_comptime_assert_is_compile_error() {
+    let diagnostics = compile_diagnostics(r#"
+        const LANES: i32 = 3
+        func main() {
+            comptime {
+                assert(LANES % 2 == 0)
+            }
+            println("ok")
+        }
+    "#);
+    assert!(has_code(&diagnostics, "E0365"),
+        "expected E0365, got: {:?}",
+        diagnostics.iter().map(|d| (&d.code, &d.message)).collect::<Vec<_>>());
+}
+
+#[test]
+fn comptime_panic_carries_message() {
+    let diagnostics = compile_diagnostics(r#"
+        func main() {
+            const size = comptime {
+                panic("bad config")
+                0
+            }
+            println(size)
+        }
+    "#);
+    let panic = diagnostics.iter()
+        .find(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0365"))
+        .expect("comptime panic should be reported");
+    assert!(panic.message.contains("bad config"), "message: {}", panic.message);
+}
+
+#[test]
+fn comptime_config_switch_skips_the_untaken_branch() {
+    // The dead branch would fail if evaluated; selecting around it must not.
+    let diagnostics = compile_diagnostics(r#"
+        const TARGET = "native"
+        const MODE = comptime if TARGET == "wasm" { 1 / 0 } else { 2 }
+
+        func main() {
+            const level = comptime if TARGET == "wasm" {
+                panic("no wasm")
+                1
+            } else {
+                2
+            }
+            const width = comptime match (TARGET) {
+                "wasm" => 1 / 0,
+                _ => 64,
+            }
+            println("{MODE} {level} {width}")
+        }
+    "#);
+    assert!(diagnostics.is_empty(), "diagnostics: {:?}",
+        diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>());
+
+    // The same branch is reported once it's the one taken.
+    let diagnostics = compile_diagnostics(r#"
+        const TARGET = "wasm"
+        func main() {
+            const level = comptime if TARGET == "wasm" {
+                panic("no wasm")
+                1
+            } else {
+                2
+            }
+            println(level)
+        }
+    "#);
+    assert!(has_code(&diagnostics, "E0365"), "diagnostics: {:?}",
+        diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>());
+}
+
+#[test]
+fn comptime_error_points_at_the_failing_call() {
+    let src = r#"
+        func main() {
+            const size = comptime {
+                const base = 4
+                panic("bad config")
+                base
+            }
+            println(size)
+        }
+    "#;
+    let diagnostics = compile_diagnostics(src);
+    let panic = diagnostics.iter()
+        .find(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0365"))
+        .expect("comptime panic should be reported");
+    let span = panic.labels[0].span;
+    assert_eq!(&src[span.start..span.end], r#"panic("bad config")"#);
+}
+
+#[test]
+fn comptime_panic_labels_each_comptime_call() {
+    let src = r#"
+        comptime func validate(n: i32) -> i32 {
+            if n > 2 {
+                panic("too many lanes")
+            }
+            return n
+        }
+        comptime func lanes(n: i32) -> i32 {
+            return validate(n * 2)
+        }
+        func main() {
+            const width = comptime { lanes(3) }
+            println(width)
+        }
+    "#;
+    let diagnostics = compile_diagnostics(src);
+    let panic = diagnostics.iter()
+        .find(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0365"))
+        .expect("comptime panic should be reported");
+    let labels: Vec<(&str, &str)> = panic.labels.iter()
+        .map(|l| (&src[l.span.start..l.span.end], l.message.as_deref().unwrap_or("")))
+        .collect();
+    assert_eq!(labels, vec![
+        (r#"panic("too many lanes")"#, "panicked during compile-time evaluation"),
+        ("validate(n * 2)", "in comptime `validate`, called here"),
+        ("lanes(3)", "in comptime `lanes`, called here"),
+    ]);
+}
+
+#[test]
+fn passing_comptime_assert_compiles() {
+    let diagnostics = compile_diagnostics(r#"
+        const LANES: i32 = 4
+        func main() {
+            comptime {
+                assert(LANES % 2 == 0)
+            }
+            println("ok")
+        }
+    "#);
+    assert!(!has_code(&diagnostics, "E0365"));
+}
+
+#[test]
+fn comptime_continue_inside_if_skips_iteration() {
+    let diagnostics = compile_diagnostics(r#"
+        comptime func odd_sum(n: i32) -> i32 {
+            mut total = 0
+            for i in 0..n {
+                if i % 2 == 0 {
+                    continue
+                }
+                total += i
+            }
+            return total
+        }
+        func main() {
+            comptime {
+                assert(odd_sum(6) == 9)
+            }
+            println("ok")
+        }
+    "#);
+    let errors: Vec<_> = diagnostics.iter()
+        .filter(|d| matches!(d.severity, rask_diagnostics::Severity::Error))
+        .map(|d| &d.message)
+        .collect();
+    assert!(errors.is_empty(), "got: {:?}", errors);
+}
+
+#[test]
+fn comptime_const_out_of_range_for_declared_type() {
+    let diagnostics = compile_diagnostics(r#"
+        comptime func compute() -> u8 {
+            return 200 + 100
+        }
+        const MASK: u8 = comptime compute()
+        func main() {
+            println(MASK)
+        }
+    "#);
+    let fit = diagnostics.iter()
+        .find(|d| d.code.as_ref().map_or(false, |c| c.0 == "E0371"))
+        .expect("out-of-range comptime const should be reported");
+    assert!(fit.message.contains("`300` is out of range for `u8`"), "message: {}", fit.message);
+}
+
+#[test]
+fn comptime_const_in_range_compiles() {
+    let diagnostics = compile_diagnostics(r#"
+        comptime func compute() -> u8 {
+            return 200 + 55
+        }
+        const MASK: u8 = comptime compute()
+        func main() {
+            println(MASK)
+        }
+    "#);
+    assert!(diagnostics.iter().all(|d| !matches!(d.severity, rask_diagnostics::Severity::Error)),
+        "unexpected errors: {:?}", diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>());
+}
+
+#[test]
+fn trait_impl_signature_mismatch_is_reported() {
+    let errors = check_errors(r#"
+        trait Show {
+            func show(self) -> string
+        }
+        struct Point { x: i32 }
+        extend Point with Show {
+            func show(self) -> i32 {
+                return self.x
+            }
+        }
+        func main() {}
+    "#);
+    assert!(errors.iter().any(|e|
+        e.contains("method `show` has signature `func show(self) -> i32`")
+            && e.contains("trait `Show` requires `func show(self) -> string`")),
+        "errors: {:?}", errors);
+}
+
+#[test]
+fn trait_impl_missing_method_is_reported() {
+    let errors = check_errors(r#"
+        trait Show {
+            func show(self) -> string
+            func label(self) -> string
+        }
+        struct Point { x: i32 }
+        extend Point with Show {
+            func label(self) -> string {
+                return "point"
+            }
+        }
+        func main() {}
+    "#);
+    assert!(errors.iter().any(|e|
+        e == "type `Point` does not implement all methods of trait `Show`: missing `show`"),
+        "errors: {:?}", errors);
+}
+
+#[test]
+fn trait_impl_matching_signatures_pass() {
+    let errors = check_errors(r#"
+        trait Shape {
+            func scaled(self, factor: i64) -> Self
+            func area(self) -> i64
+        }
+        struct Square { side: i64 }
+        extend Square with Shape {
+            func scaled(self, factor: i64) -> Square {
+                return Square { side: self.side * factor }
+            }
+            func area(self) -> i64 {
+                return self.side * self.side
+            }
+        }
+        func main() {}
+    "#);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+const TWO_RESETS: &str = r#"
+    trait A { func reset(mutate self) }
+    trait B { func reset(mutate self) }
+    struct Counter { n: i64 }
+    extend Counter with A {
+        func reset(mutate self) { self.n = 0 }
+    }
+    extend Counter with B {
+        func reset(mutate self) { self.n = 1 }
+    }
+"#;
+
+#[test]
+fn method_from_two_traits_is_ambiguous() {
+    let src = format!("{}{}", TWO_RESETS, r#"
+        func main() {
+            mut c = Counter { n: 5 }
+            c.reset()
+        }
+    "#);
+    let errors = check_errors(&src);
+    assert!(errors.iter().any(|e|
+        e == "ambiguous method `reset`: found in traits `A` and `B`; disambiguate with `A.reset(x)`"),
+        "errors: {:?}", errors);
+}
+
+#[test]
+fn inherent_method_beats_trait_methods() {
+    let inherent = r#"
+        extend Counter {
+            func reset(mutate self) { self.n = 2 }
+        }
+    "#;
+    let src = format!("{}{}{}", inherent, TWO_RESETS, r#"
+        func main() {
+            mut c = Counter { n: 5 }
+            c.reset()
+        }
+    "#);
+    let errors = check_errors(&src);
+    assert!(!errors.iter().any(|e| e.starts_with("ambiguous method")), "errors: {:?}", errors);
+}
+
+#[test]
+fn generic_return_type_inferred_from_vec_argument() {
+    let src = r#"
+        struct Point { x: i32, y: i32 }
+        func first<T>(xs: Vec<T>) -> T {
+            return xs[0]
+        }
+        func main() {
+            const points: Vec<Point> = Vec.new()
+            const p = first(points)
+            println(p.x)
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+
+    // T = Point flows into the result, so a conflicting annotation is caught
+    let errors = check_errors(&src.replace("const p = first(points)", "const p: i32 = first(points)"));
+    assert!(errors.iter().any(|e| e.contains("mismatch")), "errors: {:?}", errors);
+}
+
+#[test]
+fn generic_param_only_in_return_needs_explicit_type() {
+    let src = r#"
+        func make<T>() -> Vec<T> {
+            return Vec.new()
+        }
+        func main() {
+            make()
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.iter().any(|e| e == "cannot infer type parameter `T`"), "errors: {:?}", errors);
+
+    let errors = check_errors(&src.replace("make()\n", "make<i32>()\n"));
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn returning_slice_of_local_array_is_rejected() {
+    let src = r#"
+        func first_two() -> []i32 {
+            const a = [1, 2, 3]
+            return a[0..2]
+        }
+        func main() {
+            first_two()
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(
+        errors.iter().any(|e| e == "returns a borrow of local `a` which does not live long enough"),
+        "errors: {:?}", errors
+    );
+}
+
+#[test]
+fn returning_slice_of_parameter_is_allowed() {
+    let src = r#"
+        func first_two(a: [i32; 3]) -> []i32 {
+            return a[0..2]
+        }
+        func main() {
+            first_two([1, 2, 3])
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(!errors.iter().any(|e| e.starts_with("returns a borrow")), "errors: {:?}", errors);
+}
+
+#[test]
+fn using_pool_context_clause_is_accepted() {
+    let src = r#"
+        struct Player { hp: i32 }
+        func damage(h: Handle<Player>) using players: Pool<Player> {
+            h.hp -= 1
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(!errors.iter().any(|e| e.contains("not a context type")), "errors: {:?}", errors);
+}
+
+#[test]
+fn using_misspelled_context_type_is_rejected() {
+    let src = r#"
+        struct Player { hp: i32 }
+        func damage(h: Handle<Player>) using Poool<Player> {
+            h.hp -= 1
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(
+        errors.iter().any(|e| e == "`Poool<Player>` is not a context type"),
+        "errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn frozen_context_rejects_structural_mutation() {
+    let src = r#"
+        struct Player { hp: i32 }
+        func cull(h: Handle<Player>) using frozen players: Pool<Player> {
+            players.remove(h)
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(
+        errors.iter().any(|e| e.starts_with("structural mutation in frozen context")),
+        "errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn frozen_context_allows_reads() {
+    let src = r#"
+        struct Player { hp: i32 }
+        func health(h: Handle<Player>) using frozen players: Pool<Player> -> i32 {
+            return players[h].hp
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn frozen_context_does_not_satisfy_mutable_callee() {
+    let src = r#"
+        struct Player { hp: i32 }
+        func heal(h: Handle<Player>) using Pool<Player> {
+            h.hp += 1
+        }
+        func render(h: Handle<Player>) using frozen Pool<Player> {
+            heal(h)
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(
+        errors.iter().any(|e| e == "`heal` needs a mutable `Pool<Player>`, but `render` only has it frozen"),
+        "errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn match_arm_after_wildcard_is_unreachable() {
+    let src = r#"
+        enum Color { Red, Green, Blue }
+        func name(c: Color) -> string {
+            match c {
+                Color.Red => return "red",
+                _ => return "other",
+                Color.Blue => return "blue",
+            }
+        }
+        func main() {}
+    "#;
+    let warnings = check_warnings(src);
+    assert_eq!(
+        warnings.iter().filter(|w| *w == "unreachable match arm").count(),
+        1,
+        "warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn duplicated_literal_arm_is_unreachable() {
+    let src = r#"
+        func describe(n: i32) -> string {
+            match n {
+                1 => return "one",
+                2 => return "two",
+                1 => return "uno",
+                _ => return "many",
+            }
+        }
+        func main() {}
+    "#;
+    let warnings = check_warnings(src);
+    assert_eq!(
+        warnings.iter().filter(|w| *w == "unreachable match arm").count(),
+        1,
+        "warnings: {:?}",
+        warnings
+    );
+}
+
+#[test]
+fn spawn_block_join_yields_block_type() {
+    let src = r#"
+        func answer() -> i32 {
+            return 42
+        }
+        func run() -> i32 or JoinError {
+            mut total: i32 = 0
+            using Multitasking {
+                const h = spawn(|| answer())
+                const n: i32 = try h.join()
+                total = n
+            }
+            return total
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn spawn_block_join_rejects_wrong_type() {
+    let src = r#"
+        func answer() -> i32 {
+            return 42
+        }
+        func run() -> i32 or JoinError {
+            using Multitasking {
+                const h = spawn(|| answer())
+                const s: string = try h.join()
+            }
+            return 0
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.iter().any(|e| e == "mismatched types"), "errors: {:?}", errors);
+}
+
+#[test]
+fn spawn_accepts_a_task_name() {
+    let src = r#"
+        func answer() -> i32 {
+            return 42
+        }
+        func run() -> i32 or JoinError {
+            mut total: i32 = 0
+            using Multitasking {
+                const h = spawn(|| answer(), name: "answerer")
+                const n: i32 = try h.join()
+                total = n
+            }
+            return total
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn spawn_task_name_must_be_a_string() {
+    let src = r#"
+        func run() -> i32 or JoinError {
+            using Multitasking {
+                const h = spawn(|| 1, name: 7)
+                try h.join()
+            }
+            return 0
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.iter().any(|e| e == "mismatched types"), "errors: {:?}", errors);
+}
+
+#[test]
+fn spawn_captures_must_be_moved_copied_or_shared() {
+    let errors = check_errors(r#"
+        func run(take items: Vec<i32>, limit: i32, hits: Shared<i32>) {
+            using Multitasking {
+                const h = spawn(own || {
+                    const seen = hits
+                    items.len() + limit
+                })
+                h.detach()
+            }
+        }
+        func main() {}
+    "#);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+
+    let errors = check_errors(r#"
+        func run(items: Vec<i32>) {
+            using Multitasking {
+                const h = spawn(own || items.len())
+                h.detach()
+            }
+        }
+        func main() {}
+    "#);
+    assert_eq!(errors, vec!["cannot capture borrow `items` into a spawned task; move it or wrap in Shared"]);
+
+    // OS threads outlive the frame just the same.
+    let errors = check_errors(r#"
+        import thread.Thread
+        func run(items: Vec<i32>) {
+            const h = Thread.spawn(own || { items.len() })
+            h.detach()
+        }
+        func main() {}
+    "#);
+    assert_eq!(errors, vec!["cannot capture borrow `items` into a spawned task; move it or wrap in Shared"]);
+}
+
+#[test]
+fn using_known_contexts_with_worker_counts_is_accepted() {
+    let src = r#"
+        func main() {
+            using Multitasking {
+                const h = spawn(|| 1)
+                h.detach()
+            }
+            using ThreadPool(workers: 4) {}
+            using Multitasking(2) {}
+            using Multitasking(workers: 4, tasks: 16) {}
+            using ThreadPool(2, 8) {}
+            using Multitasking(tasks: 8) {}
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn using_misspelled_context_is_rejected_with_suggestion() {
+    let src = r#"
+        func main() {
+            using Multitaskng {}
+        }
+    "#;
+    let path = tmp_rk(src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let err = output.diagnostics.iter()
+        .find(|d| matches!(d.severity, rask_diagnostics::Severity::Error))
+        .expect("expected an error");
+    assert_eq!(err.message, "unknown context `Multitaskng` in `using` block");
+    let help = err.help.as_ref().map(|h| h.message.as_str()).unwrap_or("");
+    assert_eq!(help, "did you mean `Multitasking`?");
+}
+
+#[test]
+fn using_context_args_must_match_constructor() {
+    let src = r#"
+        func main() {
+            using ThreadPool(threads: 4) {}
+            using Multitasking(workers: "four") {}
+            using ThreadPool(1, 2, 3) {}
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.iter().any(|e| e == "`ThreadPool` has no parameter `threads`"), "errors: {:?}", errors);
+    assert!(errors.iter().any(|e| e == "mismatched types"), "errors: {:?}", errors);
+    assert!(errors.iter().any(|e| e == "expected 2 arguments, found 3"), "errors: {:?}", errors);
+}
+
+#[test]
+fn threadpool_block_with_value_returning_spawns_is_accepted() {
+    let src = r#"
+        import thread.ThreadPool
+
+        func square(n: i32) -> i32 {
+            return n * n
+        }
+
+        func pooled_square(base: i32) -> i32 or JoinError {
+            using ThreadPool(workers: 2) {
+                const a = ThreadPool.spawn(own || { square(base) })
+                const b = ThreadPool.spawn(own || { "done" })
+                const n = try a.join()
+                const label: string = try b.join()
+                if label != "done" {
+                    return 0
+                }
+                return n
+            }
+        }
+
+        func main() {
+            const result = pooled_square(7)
+        }
+    "#;
+    let diagnostics = compile_diagnostics(src);
+    assert!(diagnostics.is_empty(), "diagnostics: {:?}",
+        diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>());
+}
+
+#[test]
+fn threadpool_spawn_outside_block_is_rejected() {
+    let diagnostics = compile_diagnostics(r#"
+        import thread.ThreadPool
+        func main() {
+            const h = ThreadPool.spawn(own || { 1 })
+            h.detach()
+        }
+    "#);
+    assert!(has_code(&diagnostics, "E0380"), "diagnostics: {:?}",
+        diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>());
+}
+
+#[test]
+fn threadpool_spawn_rejects_captured_borrows() {
+    let errors = check_errors(r#"
+        import thread.ThreadPool
+        func total(v: Vec<i32>) {
+            using ThreadPool {
+                const h = ThreadPool.spawn(own || { v.len() + v.len() })
+                h.detach()
+            }
+        }
+        func main() {}
+    "#);
+    assert_eq!(
+        errors,
+        vec!["cannot capture borrow `v` into a spawned task; move it or wrap in Shared"],
+    );
+
+    // Owned, Copy, and Shared captures are fine.
+    let errors = check_errors(r#"
+        import thread.ThreadPool
+        func total(take v: Vec<i32>, n: i32, counter: Shared<i32>) {
+            using ThreadPool {
+                const h = ThreadPool.spawn(own || {
+                    const m = counter
+                    v.len() + n
+                })
+                h.detach()
+            }
+        }
+        func main() {}
+    "#);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn raw_deref_outside_unsafe_is_rejected() {
+    let src = r#"
+        func read(p: *i32) -> i32 {
+            return *p
+        }
+        func read_guarded(p: *i32) -> i32 {
+            return unsafe { *p }
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert_eq!(
+        errors.iter().filter(|e| e.contains("requires an `unsafe` block")).count(),
+        1,
+        "errors: {:?}",
+        errors
+    );
+    assert!(!check_warnings(src).iter().any(|w| w == "unnecessary `unsafe` block"));
+}
+
+#[test]
+fn unsafe_block_without_unsafe_ops_warns() {
+    let src = r#"
+        func main() {
+            const x = unsafe { 1 + 2 }
+            println("{x}")
+        }
+    "#;
+    let warnings = check_warnings(src);
+    assert!(warnings.iter().any(|w| w == "unnecessary `unsafe` block"), "warnings: {:?}", warnings);
+}
+
+#[test]
+fn main_accepts_no_params_or_string_args() {
+    for src in ["func main() {}", "func main(args: []string) {}"] {
+        let errors = check_errors(src);
+        assert!(errors.is_empty(), "{}: {:?}", src, errors);
+    }
+}
+
+#[test]
+fn main_with_other_params_is_rejected() {
+    let errors = check_errors("func main(count: i32) {}");
+    assert!(errors.iter().any(|e| e == "invalid `main` signature"), "errors: {:?}", errors);
+}
+
+#[test]
+fn extern_c_abi_is_accepted() {
+    let src = r#"
+        extern "C" func abs(x: i32) -> i32
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(!errors.iter().any(|e| e.starts_with("unknown ABI")), "errors: {:?}", errors);
+}
+
+#[test]
+fn unknown_abi_is_rejected_with_suggestion() {
+    let diags = compile_diagnostics(r#"
+        extern "Cdecl" func abs(x: i32) -> i32
+        func main() {}
+    "#);
+    let diag = diags.iter().find(|d| d.message == "unknown ABI `Cdecl`")
+        .unwrap_or_else(|| panic!("diagnostics: {:?}", diags));
+    assert!(has_code(&diags, "E0372"));
+    let help = diag.help.as_ref().map(|h| h.message.as_str()).unwrap_or("");
+    assert!(help.contains("\"C\""), "help: {}", help);
+}
+
+const METERS: &str = r#"
+    struct Meters(f64)
+
+    func total(a: Meters, b: Meters) -> Meters {
+        return Meters(a.0 + b.0)
+    }
+
+    func describe(m: Meters) -> f64 {
+        return match m {
+            Meters(v) => v,
+        }
+    }
+"#;
+
+#[test]
+fn tuple_struct_constructs_accesses_and_matches() {
+    let src = format!("{METERS}\nfunc main() {{ const m = total(Meters(1.5), Meters(2.0))\n const x: f64 = describe(m) + m.0 }}");
+    let errors = check_errors(&src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn tuple_struct_field_keeps_its_type() {
+    let src = format!("{METERS}\nfunc main() {{ const m = Meters(1.0)\n const s: string = m.0 }}");
+    let errors = check_errors(&src);
+    assert!(errors.iter().any(|e| e == "mismatched types"), "errors: {:?}", errors);
+}
+
+const CONFIG_BUILDER: &str = r#"
+    struct Config { name: string, x: i32, y: i32 }
+    extend Config {
+        func with_x(take self, x: i32) -> Config {
+            return Config { name: self.name, x: x, y: self.y }
+        }
+        func with_y(take self, y: i32) -> Config {
+            return Config { name: self.name, x: self.x, y: y }
+        }
+    }
+"#;
+
+#[test]
+fn take_self_builder_chain_is_allowed() {
+    let src = format!("{}{}", CONFIG_BUILDER, r#"
+        func main() {
+            const base = Config { name: "cfg", x: 0, y: 0 }
+            const c = base.with_x(1).with_y(2)
+            println(c.name)
+        }
+    "#);
+    let errors = check_errors(&src);
+    assert!(!errors.iter().any(|e| e.starts_with("use of moved value")), "errors: {:?}", errors);
+}
+
+#[test]
+fn builder_used_after_move_is_rejected() {
+    let src = format!("{}{}", CONFIG_BUILDER, r#"
+        func main() {
+            const builder = Config { name: "cfg", x: 0, y: 0 }
+            const b = builder
+            const c = builder.with_x(1)
+        }
+    "#);
+    let errors = check_errors(&src);
+    assert!(errors.iter().any(|e| e == "use of moved value: `builder`"), "errors: {:?}", errors);
+}
+
+#[test]
+fn generic_take_self_receiver_is_moved() {
+    let src = r#"
+        struct Pair<A, B> { first: A, second: B }
+        extend Pair<A, B> {
+            func swap(take self) -> Pair<B, A> {
+                return Pair { first: self.second, second: self.first }
+            }
+        }
+        func main() {
+            const p = Pair { first: "one", second: "two" }
+            const s = p.swap()
+            const t = p.swap()
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.iter().any(|e| e == "use of moved value: `p`"), "errors: {:?}", errors);
+}
+
+#[test]
+fn suffixed_literal_out_of_range_is_rejected() {
+    let errors = check_errors("func main() { const a = 300u8 }");
+    assert!(errors.iter().any(|e| e == "literal `300` out of range for `u8`"), "errors: {:?}", errors);
+    let errors = check_errors("func main() { const b = -129i8 }");
+    assert!(errors.iter().any(|e| e == "literal `-129` out of range for `i8`"), "errors: {:?}", errors);
+}
+
+#[test]
+fn suffixed_literal_matching_context_is_allowed() {
+    for src in [
+        "func main() { const a: u8 = 5u8 }",
+        "func main() { const b = -128i8 }",
+    ] {
+        let errors = check_errors(src);
+        assert!(errors.is_empty(), "{}: {:?}", src, errors);
+    }
+}
+
+#[test]
+fn suffixed_literal_conflicting_with_context_is_rejected() {
+    let errors = check_errors("func main() { const a: i32 = 5u8 }");
+    assert!(errors.iter().any(|e| e == "mismatched types"), "errors: {:?}", errors);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// TR5/TR7: concrete values passed as `any Trait`
+// ═══════════════════════════════════════════════════════════════════════
+
+const SHOW_POINT: &str = r#"
+    trait Show {
+        func show(self) -> string
+    }
+    struct Point { x: i32, y: i32 }
+    struct Blob { n: i32 }
+    extend Point with Show {
+        func show(self) -> string { return "point" }
+    }
+    func display(item: any Show) -> string {
+        return item.show()
+    }
+    func show_all(items: Vec<any Show>) {
+        for it in items { println(it.show()) }
+    }
+"#;
+
+#[test]
+fn concrete_value_coerces_to_trait_parameter() {
+    let src = format!("{}{}", SHOW_POINT, r#"
+        func main() {
+            println(display(Point { x: 1, y: 2 }))
+        }
+    "#);
+    let path = tmp_rk(&src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    assert_eq!(error_count(&output.diagnostics), 0, "{:?}", output.diagnostics);
+    let result = output.result.expect("expected success");
+    let coercions: Vec<_> = result.typed.trait_coercions.values().collect();
+    assert_eq!(coercions, vec!["Show"]);
+}
+
+#[test]
+fn non_implementing_value_is_rejected_for_trait_parameter() {
+    let src = format!("{}{}", SHOW_POINT, r#"
+        func main() {
+            println(display(Blob { n: 1 }))
+        }
+    "#);
+    let errors = check_errors(&src);
+    assert!(errors.iter().any(|e| e == "`Blob` does not implement `Show`"), "errors: {:?}", errors);
+}
+
+#[test]
+fn collection_of_concrete_values_does_not_coerce() {
+    let src = format!("{}{}", SHOW_POINT, r#"
+        func main() {
+            const points: Vec<Point> = Vec.new()
+            show_all(points)
+        }
+    "#);
+    let errors = check_errors(&src);
+    assert!(errors.iter().any(|e| e == "`Point` elements can't convert to `any Show` in place"),
+        "errors: {:?}", errors);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Method receivers: temporaries
+// ═══════════════════════════════════════════════════════════════════════
+
+const COUNTER: &str = r#"
+    struct Counter { n: i32 }
+    extend Counter {
+        func bump(mutate self) { self.n += 1 }
+        func into_n(take self) -> i32 { return self.n }
+    }
+"#;
+
+#[test]
+fn discarded_mutate_call_on_temporary_warns() {
+    let src = format!("{}{}", COUNTER, r#"
+        func main() {
+            Counter { n: 1 }.bump()
+        }
+    "#);
+    let warnings = check_warnings(&src);
+    assert!(
+        warnings.iter().any(|w| w == "`mutate self` method `bump` called on a temporary has no effect"),
+        "warnings: {:?}",
+        warnings
+    );
+    assert!(check_errors(&src).is_empty());
+}
+
+#[test]
+fn take_call_on_temporary_is_allowed() {
+    let src = format!("{}{}", COUNTER, r#"
+        func main() {
+            const n = Counter { n: 1 }.into_n()
+            mut c = Counter { n: 2 }
+            c.bump()
+            println("{n} {c.n}")
+        }
+    "#);
+    assert!(check_errors(&src).is_empty(), "errors: {:?}", check_errors(&src));
+    assert!(check_warnings(&src).is_empty(), "warnings: {:?}", check_warnings(&src));
+}
+
+#[test]
+fn take_call_on_binding_moves_it() {
+    let src = format!("{}{}", COUNTER, r#"
+        func main() {
+            const c = Counter { n: 1 }
+            const n = c.into_n()
+            println("{c.n}")
+        }
+    "#);
+    let errors = check_errors(&src);
+    assert!(errors.iter().any(|e| e == "use of moved value: `c`"), "errors: {:?}", errors);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Branch types in value position
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn incompatible_if_branches_name_both_types() {
+    let src = r#"
+        func label(c: bool) -> i32 {
+            return if c { 1 } else { "x" }
+        }
+        func main() {}
+    "#;
+    let path = tmp_rk(src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let diag = output.diagnostics.iter()
+        .find(|d| d.message == "branches have incompatible types `i32` and `string`")
+        .unwrap_or_else(|| panic!("diagnostics: {:?}", output.diagnostics));
+    assert!(diag.notes.iter().any(|n| n.contains("expects `i32`")), "notes: {:?}", diag.notes);
+}
+
+#[test]
+fn incompatible_match_arms_name_both_types() {
+    let src = r#"
+        func label(n: i32) -> string {
+            return match n {
+                0 => "zero",
+                1 => "one",
+                _ => true,
+            }
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(
+        errors.iter().any(|e| e == "branches have incompatible types `string` and `bool`"),
+        "errors: {:?}",
+        errors
+    );
+}
+
+#[test]
+fn diverging_branch_does_not_need_to_agree() {
+    let src = r#"
+        func label(n: i32) -> string {
+            return match n {
+                0 => "zero",
+                _ => panic("unexpected"),
+            }
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn loop_breaks_with_one_type_check() {
+    let src = r#"
+        func first_even(items: Vec<i32>) -> i32 {
+            mut i = 0
+            const found = loop {
+                if i >= items.len() { break -1 }
+                if items[i] % 2 == 0 { break items[i] }
+                i += 1
+            }
+            return found
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn loop_breaks_with_two_types_errors() {
+    let src = r#"
+        func pick(n: i32) {
+            const found = loop {
+                if n > 0 { break n * 2 }
+                if n < 0 { break "negative" }
+            }
+        }
+        func main() {}
+    "#;
+    let path = tmp_rk(src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let messages: Vec<_> = output.diagnostics.iter().map(|d| d.message.as_str()).collect();
+    assert!(messages.contains(&"loop breaks with both `i32` and `string`"), "diagnostics: {:?}", messages);
+    assert!(has_code(&output.diagnostics, "E0379"));
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// String indexing (std.strings/S9)
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn string_position_index_is_rejected() {
+    let src = r#"
+        func first(s: string) -> char {
+            return s[0]
+        }
+        func main() {}
+    "#;
+    let path = tmp_rk(src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let diag = output.diagnostics.iter()
+        .find(|d| d.message == "cannot index `string` by position")
+        .unwrap_or_else(|| panic!("diagnostics: {:?}", output.diagnostics));
+    let help = diag.help.as_ref().map(|h| h.message.as_str()).unwrap_or("");
+    assert!(help.contains("char_at") && help.contains("byte_at"), "help: {:?}", help);
+    // Only the index is reported — no follow-on return type mismatch.
+    let errors: Vec<_> = output.diagnostics.iter()
+        .filter(|d| matches!(d.severity, rask_diagnostics::Severity::Error))
+        .collect();
+    assert_eq!(errors.len(), 1, "errors: {:?}", errors);
+}
+
+#[test]
+fn string_char_at_and_byte_at_are_accepted() {
+    let src = r#"
+        func main() {
+            const s = "héllo"
+            const c: char? = s.char_at(0)
+            const b: u8? = s.byte_at(1)
+            const head = s[0..1].to_string()
+            println("{head}")
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Resource consumption points handed to MIR lowering (C1/C2)
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn resource_moves_record_each_consumption_point() {
+    let path = tmp_rk(r#"
+        @resource
+        struct File { path: string }
+        extend File { func close(take self) {} }
+
+        func finish(take f: File) { f.close() }
+
+        func work(take file: File, quit: bool) {
+            ensure file.close()
+            if quit { return }
+            finish(own file)
+        }
+        func main() {}
+    "#);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let result = output.result.unwrap_or_else(|| panic!("diagnostics: {:?}", output.diagnostics));
+    let mut moved: Vec<&str> = result.resource_moves.values().map(String::as_str).collect();
+    moved.sort();
+    // `f.close()` in `finish` and `own file` in `work`; the deferred
+    // `file.close()` inside `ensure` is not a consumption point.
+    assert_eq!(moved, vec!["f", "file"]);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// L6: literal kind in context
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn integer_literal_adopts_float_annotation() {
+    let src = r#"
+        func half(x: f64) -> f64 { return x / 2.0 }
+        func main() {
+            const x: f64 = 5
+            println("{half(x)} {half(3)}")
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn float_literal_for_integer_annotation_is_rejected() {
+    let src = r#"
+        func count() -> i64 { return 2.5 }
+        func main() {
+            const y: i32 = 5.0
+            println("{y} {count()}")
+        }
+    "#;
+    let errors = check_errors(src);
+    assert_eq!(
+        errors,
+        vec!["expected `i64`, found float literal", "expected `i32`, found float literal"],
+    );
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// E15: enum discriminants
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn explicit_discriminant_sequence_is_accepted() {
+    let src = r#"
+        enum ObjectKind: u8 {
+            Reserved = 0,
+            String = 1,
+            Array = 2,
+            Map = 255,
+        }
+        func main() {
+            const tag = ObjectKind.Map as u8
+            println("{tag}")
+        }
+    "#;
+    let errors = check_errors(src);
+    assert!(errors.is_empty(), "errors: {:?}", errors);
+}
+
+#[test]
+fn duplicate_explicit_discriminants_are_rejected() {
+    let src = r#"
+        enum Status: u16 {
+            Ok = 200,
+            Created = 201,
+            Accepted = 200,
+        }
+        func main() {}
+    "#;
+    let errors = check_errors(src);
+    assert_eq!(errors, vec!["duplicate discriminant value 200 in `Status`"]);
+}
+
+#[test]
+fn discriminant_outside_backing_type_is_rejected() {
+    let src = r#"
+        enum Flag: u8 {
+            Low = 0,
+            High = 300,
+        }
+        enum Sign {
+            Neg = -1,
+            Pos = 1,
+        }
+        func main() {}
+    "#;
+    let path = tmp_rk(src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let errors: Vec<_> = output.diagnostics.iter()
+        .filter(|d| matches!(d.severity, rask_diagnostics::Severity::Error))
+        .collect();
+    let messages: Vec<_> = errors.iter().map(|d| d.message.as_str()).collect();
+    assert_eq!(messages, vec![
+        "discriminant 300 on `Flag.High` is out of range",
+        "discriminant -1 on `Sign.Neg` is out of range",
+    ]);
+    assert_eq!(errors[0].code.as_ref().map(|c| c.0.as_str()), Some("E0376"));
+    let help = errors[1].help.as_ref().map(|h| h.message.as_str()).unwrap_or("");
+    assert!(help.contains("backing type"), "help: {}", help);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Unreachable methods: advisory notes from mono reachability
+// ═══════════════════════════════════════════════════════════════════════
+
+fn dead_code_notes(src: &str) -> Vec<String> {
+    compile_diagnostics(src).into_iter()
+        .filter(|d| matches!(d.severity, rask_diagnostics::Severity::Note))
+        .map(|d| d.message)
+        .collect()
+}
+
+#[test]
+fn uncalled_private_method_is_noted_public_is_not() {
+    let notes = dead_code_notes(r#"
+        struct Point {
+            x: i32
+            y: i32
+        }
+        extend Point {
+            func sum(self) -> i32 { return self.x + self.y }
+            func scaled(self, k: i32) -> i32 { return self.x * k }
+            public func norm(self) -> i32 { return self.x * self.x + self.y * self.y }
+        }
+        func main() {
+            const p = Point { x: 1, y: 2 }
+            println(p.sum())
+        }
+    "#);
+    assert_eq!(notes, vec!["method `Point.scaled` is never used"]);
+}
+
+#[test]
+fn block_of_dead_private_methods_is_noted_once() {
+    let notes = dead_code_notes(r#"
+        struct Counter {
+            n: i32
+        }
+        extend Counter {
+            func bump(self) -> i32 { return self.n + 1 }
+            func reset(self) -> i32 { return 0 }
+        }
+        func main() {
+            const c = Counter { n: 1 }
+            println(c.n)
+        }
+    "#);
+    assert_eq!(notes, vec!["no method in this `extend Counter` block is ever used"]);
+}
+
+#[test]
+fn dead_code_notes_do_not_fail_the_build() {
+    let src = r#"
+        struct Unit {
+            v: i32
+        }
+        extend Unit {
+            func unused(self) -> i32 { return self.v }
+        }
+        func main() {}
+    "#;
+    let path = tmp_rk(src);
+    let output = rask_compiler::compile_file(path.to_str().unwrap(), vec![], &default_config());
+    let _ = std::fs::remove_file(&path);
+    assert!(output.result.is_some(), "notes must not fail compilation");
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// GC5: public functions state their return type
+// ═══════════════════════════════════════════════════════════════════════
+
+fn missing_annotation_codes(src: &str) -> Vec<String> {
+    compile_diagnostics(src).into_iter()
+        .filter_map(|d| d.code.map(|c| c.0))
+        .filter(|c| c == "E0334")
+        .collect()
+}
+
+#[test]
+fn public_function_with_inferred_return_is_rejected() {
+    let codes = missing_annotation_codes(r#"
+        public func area(w: i32, h: i32) {
+            return w * h
+        }
+        func main() {
+            println(area(2, 3))
+        }
+    "#);
+    assert_eq!(codes, vec!["E0334"]);
+}
+
+#[test]
+fn public_function_with_explicit_return_is_accepted() {
+    let codes = missing_annotation_codes(r#"
+        public func area(w: i32, h: i32) -> i32 {
+            return w * h
+        }
+        func main() {
+            println(area(2, 3))
+        }
+    "#);
+    assert!(codes.is_empty(), "got: {:?}", codes);
+}
+
+#[test]
+fn private_function_may_infer_its_return() {
+    let codes = missing_annotation_codes(r#"
+        func area(w: i32, h: i32) {
+            return w * h
+        }
+        public func log(msg: string) {
+            println(msg)
+        }
+        func main() {
+            println(area(2, 3))
+            log("done")
+        }
+    "#);
+    assert!(codes.is_empty(), "got: {:?}", codes);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// String match: MIR lowering agrees with the interpreter
+// ═══════════════════════════════════════════════════════════════════════
+
+const STRING_MATCH_INPUTS: [&str; 10] = ["run", "build", "b", "test", "", "ruN", "rust", "tests", "RUN", "bench"];
+
+/// Run `classify` on every input through MIR lowering + the MIR
+/// interpreter, and through the tree-walking interpreter; return both.
+fn string_match_both_ways(src: &str) -> (Vec<String>, Vec<String>) {
+    let program = format!(
+        "{}\nfunc main() {{\n    for cmd in [{}] {{\n        println(classify(cmd))\n    }}\n}}\n",
+        src,
+        STRING_MATCH_INPUTS.iter().map(|s| format!("\"{}\"", s)).collect::<Vec<_>>().join(", "),
+    );
+
+    let lexed = rask_lexer::Lexer::new(&program).tokenize();
+    let parsed = rask_parser::Parser::new(lexed.tokens).parse();
+    assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+    let (mut interp, out) = rask_interp::Interpreter::with_captured_output();
+    interp.run(&parsed.decls).expect("interpreter run");
+    let interpreted = out.lock().unwrap().lines().map(str::to_string).collect();
+
+    let path = tmp_rk(&program);
+    let output = rask_compiler::compile_file(path.to_str().unwrap(), vec![], &default_config());
+    let _ = std::fs::remove_file(&path);
+    let compiled = output.result.unwrap_or_else(|| panic!("compile failed: {:?}",
+        output.diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>()));
+    let decl = compiled.decls.iter()
+        .find(|d| matches!(&d.kind, rask_ast::decl::DeclKind::Fn(f) if f.name == "classify"))
+        .expect("classify declared");
+    let ctx = rask_mir::lower::MirContext::empty_with_map(&compiled.typed.node_types);
+    let mut engine = rask_miri::MiriEngine::new(Box::new(rask_miri::PureStdlib));
+    for f in rask_mir::lower::MirLowerer::lower_function(decl, &compiled.decls, &ctx).expect("lowering") {
+        engine.register_function(f);
+    }
+    let lowered = STRING_MATCH_INPUTS.iter().map(|s| {
+        let v = engine.execute("classify", vec![rask_miri::MiriValue::String(s.to_string())])
+            .unwrap_or_else(|e| panic!("classify({:?}): {:?}", s, e));
+        v.to_i64().expect("integer result").to_string()
+    }).collect();
+    (lowered, interpreted)
+}
+
+#[test]
+fn string_match_switches_on_length_then_bytes() {
+    let (lowered, interpreted) = string_match_both_ways(r#"
+        func classify(cmd: string) -> i64 {
+            return match cmd {
+                "run" => 1,
+                "build" | "b" => 2,
+                "test" => 3,
+                "" => 4,
+                "ruN" => 5,
+                _ => 0,
+            }
+        }
+    "#);
+    assert_eq!(lowered, vec!["1", "2", "2", "3", "4", "5", "0", "0", "0", "0"]);
+    assert_eq!(lowered, interpreted);
+}
+
+#[test]
+fn string_match_guards_fall_through_in_order() {
+    let (lowered, interpreted) = string_match_both_ways(r#"
+        func classify(cmd: string) -> i64 {
+            return match cmd {
+                "run" if cmd.len() > 5 => 1,
+                other if other.len() == 4 => 4,
+                "run" | "test" => 2,
+                _ => 0,
+            }
+        }
+    "#);
+    assert_eq!(lowered, vec!["2", "0", "0", "4", "0", "0", "4", "0", "0", "0"]);
+    assert_eq!(lowered, interpreted);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// String literal patterns need a catch-all
+// ═══════════════════════════════════════════════════════════════════════
+
+#[test]
+fn string_match_with_catch_all_checks() {
+    let errors = check_errors(r#"
+        func kind(cmd: string) -> i32 {
+            return match cmd {
+                "run" => 1,
+                "build" | "b" => 2,
+                other => 3,
+            }
+        }
+        func main() {
+            println(kind("run"))
+        }
+    "#);
+    assert!(errors.is_empty(), "got: {:?}", errors);
+}
+
+#[test]
+fn string_match_without_catch_all_is_rejected() {
+    let errors = check_errors(r#"
+        func kind(cmd: string) -> i32 {
+            return match cmd {
+                "run" => 1,
+                "build" => 2,
+                _ if cmd.len() > 3 => 3,
+            }
+        }
+        func main() {
+            println(kind("run"))
+        }
+    "#);
+    assert_eq!(errors, vec!["match on `string` needs a catch-all arm"]);
+}
+
+#[test]
+fn string_pattern_must_match_scrutinee_type() {
+    let errors = check_errors(r#"
+        func kind(n: i32) -> i32 {
+            return match n {
+                "one" => 1,
+                _ => 0,
+            }
+        }
+        func main() {
+            println(kind(1))
+        }
+    "#);
+    assert!(!errors.is_empty(), "string pattern on an i32 scrutinee should not check");
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Optional chains: `a?.b?.c` is always optional
+// ═══════════════════════════════════════════════════════════════════════
+
+const OPTIONAL_CHAIN_TYPES: &str = r#"
+    struct Profile {
+        name: string
+    }
+    struct User {
+        profile: Profile?
+    }
+"#;
+
+#[test]
+fn optional_chain_has_optional_final_field_type() {
+    let src = format!("{}{}", OPTIONAL_CHAIN_TYPES, r#"
+        func display(user: User?) -> string {
+            const name = user?.profile?.name
+            return name ?? "guest"
+        }
+        func main() {
+            println(display(none))
+        }
+    "#);
+    let path = tmp_rk(&src);
+    let output = check_file(path.to_str().unwrap(), &default_config());
+    let _ = std::fs::remove_file(&path);
+    let result = output.result.unwrap_or_else(|| panic!("check failed: {:?}",
+        output.diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>()));
+    let display = result.decls.iter()
+        .find_map(|d| match &d.kind {
+            rask_ast::decl::DeclKind::Fn(f) if f.name == "display" => Some(f),
+            _ => None,
+        })
+        .expect("display declared");
+    let rask_ast::stmt::StmtKind::Const { init, .. } = &display.body[0].kind else {
+        panic!("expected `const name = ...`");
+    };
+    let ty = result.typed.node_types.get(&init.id).expect("chain is typed");
+    assert_eq!(ty.to_string(), "string?");
+}
+
+#[test]
+fn optional_chain_result_is_not_a_bare_value() {
+    let errors = check_errors(&format!("{}{}", OPTIONAL_CHAIN_TYPES, r#"
+        func display(user: User?) -> string {
+            const name: string = user?.profile?.name
+            return name
+        }
+        func main() {
+            println(display(none))
+        }
+    "#));
+    assert!(!errors.is_empty(), "`string?` should not assign to `string` without `??` or `!`");
+}
+
+#[test]
+fn optional_chain_unwraps_with_fallback_or_force() {
+    let errors = check_errors(&format!("{}{}", OPTIONAL_CHAIN_TYPES, r#"
+        func display(user: User?) -> string {
+            const fallback: string = user?.profile?.name ?? "guest"
+            const forced: string = user?.profile?.name!
+            return fallback.concat(forced)
+        }
+        func main() {
+            println(display(none))
+        }
+    "#));
+    assert!(errors.is_empty(), "got: {:?}", errors);
+}
+
+#[test]
+fn optional_chain_flattens_once_receiver_resolves() {
+    // `u`'s inner type is only known after the assignment, so `u?.profile`
+    // is typed before `profile: Profile?` is visible. Still `Profile?`.
+    let errors = check_errors(&format!("{}{}", OPTIONAL_CHAIN_TYPES, r#"
+        func find() -> User? {
+            return none
+        }
+        func display() -> string {
+            mut u = none
+            u = find()
+            const p: Profile? = u?.profile
+            return p?.name ?? "guest"
+        }
+        func main() {
+            println(display())
+        }
+    "#));
+    assert!(errors.is_empty(), "got: {:?}", errors);
+}
+
+// ═══════════════════════════════════════════════════════════════════════
+// Aggregates: MIR lowering agrees with the interpreter
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Lower `functions` through MIR with the program's real struct layouts,
+/// run `entry` on the MIR interpreter once per argument, and run `main`
+/// on the tree-walking interpreter; return both sets of output lines.
+fn lowering_drift(program: &str, functions: &[&str], entry: &str, args: &[i64]) -> (Vec<String>, Vec<String>) {
+    let (interpreted, run) = interpret(program);
+    run.expect("interpreter run");
+    let mut engine = lowered_engine(program, functions);
+    let lowered = args.iter().map(|&arg| {
+        match engine.execute(entry, vec![rask_miri::MiriValue::I64(arg)]) {
+            Ok(rask_miri::MiriValue::String(s)) => s,
+            other => panic!("{}({}): {:?}", entry, arg, other),
+        }
+    }).collect();
+    (lowered, interpreted)
+}
+
+/// Run `program` in the tree-walker, returning its stdout lines and the run result.
+fn interpret(program: &str) -> (Vec<String>, Result<(), rask_interp::RuntimeDiagnostic>) {
+    let lexed = rask_lexer::Lexer::new(program).tokenize();
+    let parsed = rask_parser::Parser::new(lexed.tokens).parse();
+    assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+    // The tree-walker recurses per expression; debug builds outgrow the
+    // default test-thread stack on anything with nested calls.
+    let decls = parsed.decls.clone();
+    std::thread::Builder::new()
+        .stack_size(16 * 1024 * 1024)
+        .spawn(move || {
+            let (mut interp, out) = rask_interp::Interpreter::with_captured_output();
+            let run = interp.run(&decls).map(|_| ());
+            let lines: Vec<String> = out.lock().unwrap().lines().map(str::to_string).collect();
+            (lines, run)
+        })
+        .unwrap()
+        .join()
+        .expect("interpreter thread")
+}
+
+/// Compile `program` and load the MIR for `functions` into a MIR engine.
+fn lowered_engine(program: &str, functions: &[&str]) -> rask_miri::MiriEngine {
+    let path = tmp_rk(program);
+    let output = rask_compiler::compile_file(path.to_str().unwrap(), vec![], &default_config());
+    let _ = std::fs::remove_file(&path);
+    let compiled = output.result.unwrap_or_else(|| panic!("compile failed: {:?}",
+        output.diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>()));
+    let type_names = compiled.typed.types.iter()
+        .enumerate()
+        .map(|(i, def)| {
+            let name = match def {
+                rask_types::TypeDef::Struct { name, .. }
+                | rask_types::TypeDef::Enum { name, .. }
+                | rask_types::TypeDef::Trait { name, .. }
+                | rask_types::TypeDef::Union { name, .. }
+                | rask_types::TypeDef::NominalAlias { name, .. } => name.clone(),
+            };
+            (rask_types::TypeId(i as u32), name)
+        })
+        .collect();
+    let ctx = rask_mir::lower::MirContext {
+        struct_layouts: &compiled.mono.struct_layouts,
+        enum_layouts: &compiled.mono.enum_layouts,
+        node_types: &compiled.typed.node_types,
+        type_names: &type_names,
+        comptime_globals: &HashMap::new(),
+        extern_funcs: &HashSet::new(),
+        package_modules: &HashSet::new(),
+        trait_methods: HashMap::new(),
+        line_map: None,
+        source_file: None,
+        shared_elem_types: std::cell::RefCell::new(HashMap::new()),
+        comptime_interp: None,
+        trait_coercions: &HashMap::new(),
+        call_rewrites: &HashMap::new(),
+        resource_types: &HashSet::new(),
+        resource_moves: &HashMap::new(),
+    };
+    let mut engine = rask_miri::MiriEngine::new(Box::new(rask_miri::PureStdlib));
+    engine.set_struct_layouts(compiled.mono.struct_layouts.clone());
+    engine.set_enum_layouts(compiled.mono.enum_layouts.clone());
+    for decl in &compiled.decls {
+        if matches!(&decl.kind, rask_ast::decl::DeclKind::Fn(f) if functions.contains(&f.name.as_str())) {
+            for f in rask_mir::lower::MirLowerer::lower_function(decl, &compiled.decls, &ctx).expect("lowering") {
+                engine.register_function(f);
+            }
+        }
+    }
+    engine
+}
+
+#[test]
+fn failed_unwrap_reports_its_message_when_lowered() {
+    let program = r#"
+func lookup(id: i64) -> i64? {
+    if id == 1 {
+        return 10
+    }
+    return none
+}
+
+func describe(id: i64) -> string {
+    const v = lookup(id)! "no such id"
+    if v > 5 {
+        return "big"
+    }
+    return "small"
+}
+
+func main() {
+    println(describe(1))
+    println(describe(2))
+}
+"#;
+    let (printed, run) = interpret(program);
+    assert_eq!(printed, vec!["big"]);
+    let interpreted = match run.map_err(|d| d.error) {
+        Err(rask_interp::RuntimeError::UnwrapError { message }) => message,
+        other => panic!("interpreter: {:?}", other),
+    };
+
+    let mut engine = lowered_engine(program, &["lookup", "describe"]);
+    let i64_arg = |n| vec![rask_miri::MiriValue::I64(n)];
+    assert!(matches!(engine.execute("describe", i64_arg(1)), Ok(rask_miri::MiriValue::String(s)) if s == "big"));
+    let lowered = match engine.execute("describe", i64_arg(2)) {
+        Err(rask_miri::MiriError::Panic(message)) => message,
+        other => panic!("lowered: {:?}", other),
+    };
+    assert_eq!(lowered, "no such id");
+    assert_eq!(lowered, interpreted);
+}
+
+#[test]
+fn optional_aggregate_return_wraps_as_some() {
+    let (lowered, interpreted) = lowering_drift(r#"
+struct Profile {
+    name: string
+}
+
+struct User {
+    profile: Profile?
+}
+
+func get_user(id: i64) -> User? {
+    if id == 1 {
+        return User { profile: Profile { name: "ada" } }
+    }
+    if id == 2 {
+        return User { profile: none }
+    }
+    return none
+}
+
+func user_name(id: i64) -> string {
+    return get_user(id)?.profile?.name ?? "guest"
+}
+
+func main() {
+    println(user_name(1))
+    println(user_name(2))
+    println(user_name(3))
+}
+"#, &["get_user", "user_name"], "user_name", &[1, 2, 3]);
+    assert_eq!(lowered, vec!["ada", "guest", "guest"]);
+    assert_eq!(lowered, interpreted);
+}
+
+#[test]
+fn tuples_pass_and_return_by_value() {
+    let (lowered, interpreted) = lowering_drift(r#"
+func swap(p: (i32, i32)) -> (i32, i32) {
+    return (p.1, p.0)
+}
+
+func tag(n: i64) -> (i32, string) {
+    if n > 1 {
+        return (20, "many")
+    }
+    return (10, "one")
+}
+
+func nest(n: i64) -> ((i32, i32), bool) {
+    return (swap((3, 4)), n > 1)
+}
+
+func unpack(p: ((i32, i32), bool)) -> i32 {
+    const inner = p.0
+    if p.1 {
+        return inner.0 * 10 + inner.1
+    }
+    return inner.1 * 10 + inner.0
+}
+
+func show(n: i64) -> string {
+    const s = swap((1, 2))
+    if s.0 != 2 || s.1 != 1 {
+        return "swap lost a field"
+    }
+    const t = tag(n)
+    if t.0 != 10 && t.0 != 20 {
+        return "tag lost its code"
+    }
+    const packed = unpack(nest(n))
+    if packed != 43 && packed != 34 {
+        return "nest lost a field"
+    }
+    if (t.0 == 20) != (packed == 43) {
+        return "nest lost its flag"
+    }
+    return t.1
+}
+
+func main() {
+    println(show(1))
+    println(show(2))
+}
+"#, &["swap", "tag", "nest", "unpack", "show"], "show", &[1, 2]);
+    assert_eq!(lowered, vec!["one", "many"]);
+    assert_eq!(lowered, interpreted);
+}
+
+#[test]
+fn optional_operators_on_non_optional_warn() {
+    let src = r#"
+        struct Point { x: i32 }
+        func main() {
+            const n: i32 = 5
+            const a = n ?? 0
+            const b = n!
+            const p = Point { x: 1 }
+            const c = p?.x
+            println("{a} {b}")
+        }
+    "#;
+    assert!(check_errors(src).is_empty(), "{:?}", check_errors(src));
+    let warnings = check_warnings(src);
+    assert!(warnings.contains(&"redundant `??` on non-optional `i32`".to_string()), "{:?}", warnings);
+    assert!(warnings.contains(&"redundant `!` on non-optional `i32`".to_string()), "{:?}", warnings);
+    assert!(warnings.contains(&"redundant `?.` on non-optional `Point`".to_string()), "{:?}", warnings);
+}
+
+#[test]
+fn optional_operators_on_optional_do_not_warn() {
+    let warnings = check_warnings(r#"
+        func main() {
+            const o: i32? = none
+            const a = o ?? 2
+            const b: i32? = 3
+            const c = b!
+            println("{a} {c}")
+        }
+    "#);
+    assert!(!warnings.iter().any(|w| w.starts_with("redundant")), "{:?}", warnings);
+}
+
+#[test]
+fn enumerate_binds_index_and_element() {
+    let errors = check_errors(r#"
+        func main() {
+            const items = Vec.from([10, 20])
+            for (i, x) in items.enumerate() {
+                println("{i}: {x}")
+            }
+            for (i, c) in "hi".enumerate() {
+                const up: char = c.to_uppercase()
+                println("{i}: {up}")
+            }
+        }
+    "#);
+    assert!(errors.is_empty(), "{:?}", errors);
+}
+
+#[test]
+fn field_and_method_with_same_name_warn_and_resolve_separately() {
+    let src = r#"
+        struct Buffer {
+            public len: i32
+        }
+        extend Buffer {
+            public func len(self) -> string {
+                return "method"
+            }
+        }
+        func main() {
+            const b = Buffer { len: 3 }
+            const field: i32 = b.len
+            const called: string = b.len()
+            println("{field} {called}")
+        }
+    "#;
+    assert!(check_errors(src).is_empty(), "{:?}", check_errors(src));
+    assert!(
+        check_warnings(src).contains(&"`Buffer` has both a field and a method named `len`".to_string()),
+        "{:?}",
+        check_warnings(src)
+    );
+
+    let swapped = check_errors(r#"
+        struct Buffer {
+            public len: i32
+        }
+        extend Buffer {
+            public func len(self) -> string {
+                return "method"
+            }
+        }
+        func main() {
+            const b = Buffer { len: 3 }
+            const field: string = b.len
+            const called: i32 = b.len()
+        }
+    "#);
+    assert_eq!(swapped.len(), 2, "{:?}", swapped);
+}
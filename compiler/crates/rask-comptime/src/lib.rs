@@ -4,16 +4,46 @@
 //! Evaluates `comptime` blocks and functions at compile time.
 //! Subject to restrictions: no I/O, no pools, no concurrency.
 
-use rask_ast::decl::{Decl, DeclKind, FnDecl};
+use rask_ast::decl::{Decl, DeclKind, FieldVisibility, FnDecl};
 use rask_ast::expr::{BinOp, Expr, ExprKind, Pattern, UnaryOp};
 use rask_ast::stmt::{ForBinding, Stmt, StmtKind};
+use rask_ast::Span;
 use std::collections::HashMap;
+use std::io::Write;
 use thiserror::Error;
 
 // ============================================================================
 // Build Configuration (CT11-CT16)
 // ============================================================================
 
+/// Byte order used when serializing comptime values into data sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+impl Endian {
+    /// Byte order for the arch component of a target triple.
+    pub fn from_arch(arch: &str) -> Self {
+        match arch {
+            "powerpc" | "powerpc64" | "s390x" | "sparc" | "sparc64" | "mips" | "mips64"
+            | "m68k" => Endian::Big,
+            _ if arch.ends_with("_be") || arch.ends_with("eb") => Endian::Big,
+            _ => Endian::Little,
+        }
+    }
+
+    /// Rearrange a scalar's little-endian bytes into this byte order.
+    pub fn reorder_le(self, mut bytes: Vec<u8>) -> Vec<u8> {
+        if self == Endian::Big {
+            bytes.reverse();
+        }
+        bytes
+    }
+}
+
 /// Build configuration for conditional compilation.
 ///
 /// Provides `cfg.os`, `cfg.arch`, `cfg.env`, `cfg.profile`, `cfg.debug`,
@@ -51,6 +81,11 @@ impl CfgConfig {
         }
     }
 
+    /// Byte order of the target, for embedding comptime globals.
+    pub fn endian(&self) -> Endian {
+        Endian::from_arch(&self.arch)
+    }
+
     /// Dispatch based on whether a target triple is provided.
     pub fn from_target_or_host(target: Option<&str>, profile: &str, features: Vec<String>) -> Self {
         match target {
@@ -353,6 +388,8 @@ pub enum ComptimeValue {
     Char(char),
     String(String),
     Array(Vec<ComptimeValue>),
+    /// Insertion-ordered entries. Keys compare by value; there is no hashing.
+    Map(Vec<(ComptimeValue, ComptimeValue)>),
     Tuple(Vec<ComptimeValue>),
     Struct {
         name: String,
@@ -389,6 +426,11 @@ impl PartialEq for ComptimeValue {
             (ComptimeValue::Char(a), ComptimeValue::Char(b)) => a == b,
             (ComptimeValue::String(a), ComptimeValue::String(b)) => a == b,
             (ComptimeValue::Array(a), ComptimeValue::Array(b)) => a == b,
+            // Same entries regardless of insertion order
+            (ComptimeValue::Map(a), ComptimeValue::Map(b)) => {
+                a.len() == b.len()
+                    && a.iter().all(|(k, v)| b.iter().any(|(k2, v2)| k == k2 && v == v2))
+            }
             (ComptimeValue::Tuple(a), ComptimeValue::Tuple(b)) => a == b,
             (
                 ComptimeValue::Struct { name: n1, fields: f1 },
@@ -424,6 +466,7 @@ impl ComptimeValue {
             ComptimeValue::Char(_) => "char",
             ComptimeValue::String(_) => "String",
             ComptimeValue::Array(_) => "Array",
+            ComptimeValue::Map(_) => "Map",
             ComptimeValue::Tuple(_) => "Tuple",
             ComptimeValue::Struct { .. } => "Struct",
             ComptimeValue::Enum { .. } => "Enum",
@@ -431,6 +474,76 @@ impl ComptimeValue {
         }
     }
 
+    /// User-facing text for `print`: strings unquoted, scalars plain.
+    fn display(&self) -> String {
+        match self {
+            ComptimeValue::Unit => "()".to_string(),
+            ComptimeValue::Bool(b) => b.to_string(),
+            ComptimeValue::I8(v) => v.to_string(),
+            ComptimeValue::I16(v) => v.to_string(),
+            ComptimeValue::I32(v) => v.to_string(),
+            ComptimeValue::I64(v) => v.to_string(),
+            ComptimeValue::U8(v) => v.to_string(),
+            ComptimeValue::U16(v) => v.to_string(),
+            ComptimeValue::U32(v) => v.to_string(),
+            ComptimeValue::U64(v) => v.to_string(),
+            ComptimeValue::F32(v) => v.to_string(),
+            ComptimeValue::F64(v) => v.to_string(),
+            ComptimeValue::Char(c) => c.to_string(),
+            ComptimeValue::String(s) => s.clone(),
+            other => format!("{:?}", other),
+        }
+    }
+
+    /// `Some(v)`, `None`, `Ok(v)`, or `Err(e)`.
+    fn wrapper(name: &str, variant: &str, data: Option<ComptimeValue>) -> ComptimeValue {
+        ComptimeValue::Enum {
+            name: name.to_string(),
+            variant: variant.to_string(),
+            data: data.map(Box::new),
+        }
+    }
+
+    /// True if aggregates nest more than `limit` levels deep. Scalars are
+    /// depth 0; the walk stops at the limit, so it never recurses past it.
+    fn nests_deeper_than(&self, limit: usize) -> bool {
+        let children: Vec<&ComptimeValue> = match self {
+            ComptimeValue::Array(items) | ComptimeValue::Tuple(items) => items.iter().collect(),
+            ComptimeValue::Map(entries) => entries.iter().flat_map(|(k, v)| [k, v]).collect(),
+            ComptimeValue::Struct { fields, .. } => fields.values().collect(),
+            ComptimeValue::Enum { data: Some(data), .. } => vec![data],
+            _ => return false,
+        };
+        limit == 0 || children.iter().any(|c| c.nests_deeper_than(limit - 1))
+    }
+
+    /// Bytes an aggregate holds directly, not counting its children's own
+    /// storage — each child was charged when it was built.
+    fn shallow_size(&self) -> usize {
+        let slot = std::mem::size_of::<ComptimeValue>();
+        match self {
+            ComptimeValue::Array(items) | ComptimeValue::Tuple(items) => items.len() * slot,
+            ComptimeValue::Map(entries) => entries.len() * 2 * slot,
+            ComptimeValue::Struct { fields, .. } => {
+                fields.keys().map(|k| k.len() + std::mem::size_of::<String>() + slot).sum()
+            }
+            ComptimeValue::Enum { data: Some(_), .. } => slot,
+            _ => 0,
+        }
+    }
+
+    /// Split an Option/Result into (is `Some`/`Ok`, payload). `None` for
+    /// anything else.
+    fn unwrap_parts(self) -> Option<(bool, ComptimeValue)> {
+        let ComptimeValue::Enum { name, variant, data } = self else { return None };
+        let present = match (name.as_str(), variant.as_str()) {
+            ("Option", "Some") | ("Result", "Ok") => true,
+            ("Option", "None") | ("Result", "Err") => false,
+            _ => return None,
+        };
+        Some((present, data.map_or(ComptimeValue::Unit, |d| *d)))
+    }
+
     /// Type prefix for method dispatch when embedded as a comptime global.
     pub fn type_prefix(&self) -> &'static str {
         match self {
@@ -470,6 +583,18 @@ impl ComptimeValue {
         }
     }
 
+    /// The value of any integer variant, widened losslessly.
+    pub fn as_i128(&self) -> Option<i128> {
+        self.as_int().map(|(v, _)| v)
+    }
+
+    /// type.primitives/L6: an integer in float position becomes that float.
+    /// `None` for anything that isn't an integer.
+    pub fn int_as_float(&self, f32: bool) -> Option<ComptimeValue> {
+        let v = self.as_i128()?;
+        Some(if f32 { ComptimeValue::F32(v as f32) } else { ComptimeValue::F64(v as f64) })
+    }
+
     /// The logical value (as i128) and width kind of an integer variant.
     fn as_int(&self) -> Option<(i128, CtInt)> {
         Some(match self {
@@ -485,6 +610,14 @@ impl ComptimeValue {
         })
     }
 
+    /// Integer or float zero (including `-0.0`).
+    fn is_numeric_zero(&self) -> bool {
+        match self.as_int() {
+            Some((v, _)) => v == 0,
+            None => self.as_f64() == Some(0.0),
+        }
+    }
+
     /// Convert to f64 (widening conversion for floats).
     pub fn as_f64(&self) -> Option<f64> {
         match self {
@@ -496,22 +629,22 @@ impl ComptimeValue {
 
     /// Serialize to a flat byte array for embedding in Cranelift data sections.
     /// Only supports primitive arrays — the main use case for comptime globals.
-    pub fn serialize(&self) -> Option<Vec<u8>> {
+    pub fn serialize(&self, endian: Endian) -> Option<Vec<u8>> {
         match self {
             ComptimeValue::Array(elems) => {
                 let mut bytes = Vec::new();
                 for elem in elems {
-                    bytes.extend(elem.serialize_element()?);
+                    bytes.extend(elem.serialize_element(endian)?);
                 }
                 Some(bytes)
             }
-            _ => self.serialize_element().map(|b| b.to_vec()),
+            _ => self.serialize_element(endian),
         }
     }
 
-    /// Serialize a single element to its native byte representation.
-    fn serialize_element(&self) -> Option<Vec<u8>> {
-        Some(match self {
+    /// Serialize a single element in the target's byte order.
+    fn serialize_element(&self, endian: Endian) -> Option<Vec<u8>> {
+        let le = match self {
             ComptimeValue::Bool(b) => vec![*b as u8],
             ComptimeValue::I8(v) => v.to_le_bytes().to_vec(),
             ComptimeValue::I16(v) => v.to_le_bytes().to_vec(),
@@ -525,7 +658,8 @@ impl ComptimeValue {
             ComptimeValue::F64(v) => v.to_le_bytes().to_vec(),
             ComptimeValue::Char(c) => (*c as u32).to_le_bytes().to_vec(),
             _ => return None,
-        })
+        };
+        Some(endian.reorder_le(le))
     }
 }
 
@@ -563,6 +697,9 @@ pub enum ComptimeError {
     #[error("index {index} out of bounds (length is {len})")]
     IndexOutOfBounds { index: usize, len: usize },
 
+    #[error("slice {start}..{end} out of bounds (length is {len})")]
+    SliceOutOfBounds { start: i64, end: i64, len: usize },
+
     #[error("cannot call runtime function `{0}` at comptime; mark it `comptime func` or restructure")]
     RuntimeFunctionCall(String),
 
@@ -584,6 +721,12 @@ pub enum ComptimeError {
     #[error("no field `{field}` on type `{ty}`")]
     NoSuchField { ty: String, field: String },
 
+    #[error("missing field `{field}` in `{ty}` literal")]
+    MissingField { ty: String, field: String },
+
+    #[error("no variant `{variant}` on enum `{ty}`")]
+    UnknownVariant { ty: String, variant: String },
+
     #[error("`{0}` is not a struct")]
     NotAStruct(String),
 
@@ -604,28 +747,176 @@ pub enum ComptimeError {
 
     #[error("comptime stack overflow (depth {0}); reduce recursion or increase limit")]
     StackOverflow(usize),
+
+    #[error("comptime value nested deeper than {0} levels; flatten the structure or build it at runtime")]
+    ValueTooDeep(usize),
+
+    #[error("size cannot be negative (got {0})")]
+    NegativeSize(i128),
+
+    #[error("negative exponent {0} in integer `pow`; use a float base for fractional powers")]
+    NegativeExponent(i128),
+
+    #[error("cannot mutate captured collection `{0}` in comptime closure; closures capture by value")]
+    CapturedCollectionMutation(String),
+
+    #[error("range step cannot be zero")]
+    ZeroStep,
+
+    /// Chaining off a call that returns nothing (`println(..).len()`).
+    #[error("cannot call method `{0}` on `()`")]
+    MethodOnUnit(String),
+
+    #[error("cannot call method `{0}` on a null value; the value may be None")]
+    MethodOnNone(String),
 }
 
 impl ComptimeError {
     /// Hard errors are genuine compile errors (not a reason to fall back or
-    /// skip): comptime overflow and divide-by-zero (type.overflow CT1, OV2).
+    /// skip): comptime overflow and divide-by-zero (type.overflow CT1, OV2),
+    /// and an explicit panic or failed assert — the static-assert idiom.
+    /// Mutating a captured collection is hard too: the write could only land
+    /// in the closure's private copy. So is a zero range step (SP3).
     pub fn is_hard(&self) -> bool {
-        matches!(self, ComptimeError::IntegerOverflow(_) | ComptimeError::DivisionByZero)
+        matches!(
+            self,
+            ComptimeError::IntegerOverflow(_)
+                | ComptimeError::DivisionByZero
+                | ComptimeError::NegativeExponent(_)
+                | ComptimeError::Panic(_)
+                | ComptimeError::CapturedCollectionMutation(_)
+                | ComptimeError::ZeroStep
+        )
+    }
+}
+
+/// Comptime error with the source location that raised it.
+#[derive(Debug)]
+pub struct ComptimeDiagnostic {
+    pub error: ComptimeError,
+    pub span: Span,
+    /// Comptime calls active when the error was raised, outermost first.
+    pub frames: Vec<ComptimeFrame>,
+}
+
+impl ComptimeDiagnostic {
+    pub fn new(error: ComptimeError, span: Span) -> Self {
+        Self { error, span, frames: Vec::new() }
+    }
+}
+
+/// One active `comptime func` call: which function, and where it was called.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComptimeFrame {
+    pub function: String,
+    pub call_site: Span,
+}
+
+impl std::fmt::Display for ComptimeDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.error)
     }
 }
 
+impl std::error::Error for ComptimeDiagnostic {}
+
+/// A comptime const and the value its initializer folded to.
+#[derive(Debug, Clone)]
+pub struct ComptimeGlobal {
+    pub name: String,
+    pub value: ComptimeValue,
+    /// The const's initializer.
+    pub span: Span,
+}
+
+/// Whether a const initializer must run at comptime: an explicit `comptime`
+/// expression, or a call to a `comptime func`.
+pub fn is_comptime_init(init: &Expr, decls: &[Decl]) -> bool {
+    matches!(&init.kind, ExprKind::Comptime { .. })
+        || matches!(&init.kind, ExprKind::Call { func, .. }
+            if matches!(&func.kind, ExprKind::Ident(name)
+                if decls.iter().any(|d| matches!(&d.kind,
+                    DeclKind::Fn(f) if f.name == *name && f.is_comptime))))
+}
+
+/// Evaluate every comptime const and `comptime { }` block in `decls` with a
+/// fresh interpreter. See [`ComptimeInterpreter::eval_program`].
+pub fn eval_comptime(decls: &[Decl]) -> (Vec<ComptimeGlobal>, Vec<ComptimeDiagnostic>) {
+    ComptimeInterpreter::new().eval_program(decls)
+}
+
+/// `Pair<T>` → `Pair`: declarations and literals may carry generic args.
+fn base_type_name(name: &str) -> &str {
+    name.split('<').next().unwrap_or(name)
+}
+
+/// A struct field as declared, for literal checks and `reflect.fields<T>()`.
+#[derive(Debug, Clone)]
+struct DeclaredField {
+    name: String,
+    ty: String,
+    is_public: bool,
+}
+
 /// Result type for comptime operations.
 pub type ComptimeResult<T> = Result<T, ComptimeError>;
 
+/// Index of `key` in a comptime map's entries: a linear scan with value
+/// equality, fine for the small maps comptime builds.
+fn map_position(
+    entries: &[(ComptimeValue, ComptimeValue)],
+    key: &ComptimeValue,
+) -> ComptimeResult<Option<usize>> {
+    if matches!(key, ComptimeValue::Closure { .. }) {
+        return Err(ComptimeError::NotSupported(
+            "closures as Map keys (closures are never equal)".to_string(),
+        ));
+    }
+    Ok(entries.iter().position(|(k, _)| k == key))
+}
+
+/// Ordering for `sort`: the same operand pairs `lt` accepts (integers of
+/// any width, floats), plus chars and strings. Anything else — or NaN —
+/// is not comparable.
+fn ct_order(a: &ComptimeValue, b: &ComptimeValue) -> ComptimeResult<std::cmp::Ordering> {
+    let ordering = match (a, b) {
+        (ComptimeValue::Char(x), ComptimeValue::Char(y)) => Some(x.cmp(y)),
+        (ComptimeValue::String(x), ComptimeValue::String(y)) => Some(x.cmp(y)),
+        _ => match (a.as_int(), b.as_int()) {
+            (Some((x, _)), Some((y, _))) => Some(x.cmp(&y)),
+            _ => match (a.as_f64(), b.as_f64()) {
+                (Some(x), Some(y)) => x.partial_cmp(&y),
+                _ => None,
+            },
+        },
+    };
+    ordering.ok_or_else(|| ComptimeError::TypeMismatch {
+        expected: "comparable types".to_string(),
+        found: format!("{} and {}", a.type_name(), b.type_name()),
+    })
+}
+
 /// Check if a name is a known type for static method dispatch at comptime.
 fn is_comptime_type(name: &str) -> bool {
     matches!(name, "Vec" | "Map" | "string")
 }
 
+/// `Option` and `Result` variants, known before any `register_types` so
+/// `Option.Some(5)` and `Result.Err(e)` construct like user enums.
+fn builtin_enum_variants() -> HashMap<String, Vec<String>> {
+    HashMap::from([
+        ("Option".to_string(), vec!["Some".to_string(), "None".to_string()]),
+        ("Result".to_string(), vec!["Ok".to_string(), "Err".to_string()]),
+    ])
+}
+
 // ============================================================================
 // Comptime Environment
 // ============================================================================
 
+/// CT69: how deeply comptime aggregates may nest.
+const MAX_VALUE_DEPTH: usize = 256;
+
 /// The comptime execution environment.
 #[derive(Debug, Default)]
 pub struct ComptimeEnv {
@@ -641,6 +932,13 @@ pub struct ComptimeEnv {
     call_depth: usize,
     /// Maximum allowed call depth (CT29).
     max_call_depth: usize,
+    /// Bytes charged for aggregates built so far (CT37).
+    allocated: usize,
+    /// Maximum bytes comptime construction may charge (CT37).
+    max_memory: usize,
+    /// Inside a closure call, how many of the bottom scopes are the
+    /// closure's captured copies. 0 outside closures.
+    capture_depth: usize,
 }
 
 impl ComptimeEnv {
@@ -652,6 +950,9 @@ impl ComptimeEnv {
             branch_quota: 1_000, // CT35: default 1,000
             call_depth: 0,
             max_call_depth: 256, // CT29: stack depth limit
+            allocated: 0,
+            max_memory: 256 * 1024 * 1024, // CT37: default 256 MB
+            capture_depth: 0,
         }
     }
 
@@ -663,12 +964,17 @@ impl ComptimeEnv {
             branch_quota: quota,
             call_depth: 0,
             max_call_depth: 256,
+            allocated: 0,
+            max_memory: 256 * 1024 * 1024,
+            capture_depth: 0,
         }
     }
 
-    /// Reset branch counter between independent comptime evaluations.
+    /// Reset the branch counter and memory charge between independent
+    /// comptime evaluations.
     pub fn reset_branch_count(&mut self) {
         self.branch_count = 0;
+        self.allocated = 0;
     }
 
     fn push_scope(&mut self) {
@@ -694,6 +1000,13 @@ impl ComptimeEnv {
         None
     }
 
+    /// True if `name` resolves to a closure's captured copy rather than a
+    /// binding made inside the closure call.
+    fn is_captured(&self, name: &str) -> bool {
+        self.scopes.iter().rposition(|scope| scope.contains_key(name))
+            .map_or(false, |idx| idx < self.capture_depth)
+    }
+
     fn assign(&mut self, name: &str, value: ComptimeValue) -> bool {
         for scope in self.scopes.iter_mut().rev() {
             if scope.contains_key(name) {
@@ -734,6 +1047,18 @@ impl ComptimeEnv {
     fn pop_call(&mut self) {
         self.call_depth = self.call_depth.saturating_sub(1);
     }
+
+    /// CT37: charge a construction against the memory ceiling. Charges
+    /// never refund — the budget bounds total construction work, which is
+    /// what a runaway builder exhausts.
+    fn charge(&mut self, bytes: usize) -> ComptimeResult<()> {
+        self.allocated = self.allocated.saturating_add(bytes);
+        if self.allocated > self.max_memory {
+            Err(ComptimeError::MemoryLimitExceeded)
+        } else {
+            Ok(())
+        }
+    }
 }
 
 // ============================================================================
@@ -767,6 +1092,48 @@ impl ControlFlow {
 // Comptime Interpreter
 // ============================================================================
 
+/// Bit width and signedness of a fixed-width integer type. Comptime casts
+/// and the type checker's `as` rules (CV1) read the same table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IntWidth {
+    pub bits: u32,
+    pub signed: bool,
+}
+
+impl IntWidth {
+    /// The width of an integer type by name, `i8` through `u128`.
+    pub fn of(name: &str) -> Option<IntWidth> {
+        let (signed, bits) = match name.as_bytes().first()? {
+            b'i' => (true, &name[1..]),
+            b'u' => (false, &name[1..]),
+            _ => return None,
+        };
+        let bits = match bits {
+            "8" => 8,
+            "16" => 16,
+            "32" => 32,
+            "64" => 64,
+            "128" => 128,
+            _ => return None,
+        };
+        Some(IntWidth { bits, signed })
+    }
+
+    /// Every value of this width fits in `to`: `to` is wider, and the
+    /// source is unsigned or shares its signedness.
+    pub fn widens_losslessly_to(self, to: IntWidth) -> bool {
+        to.bits > self.bits && (self.signed == to.signed || !self.signed)
+    }
+
+    /// `as` semantics: keep the low `bits`, sign-extending into signed widths.
+    pub fn wrap(self, v: i128) -> i128 {
+        if self.bits >= 128 { return v; }
+        let mask = (1i128 << self.bits) - 1;
+        let masked = v & mask;
+        if self.signed && (masked & (1i128 << (self.bits - 1))) != 0 { masked - (1i128 << self.bits) } else { masked }
+    }
+}
+
 /// The compile-time interpreter.
 /// Integer width of a comptime value, for width-aware overflow checks (CT1).
 /// I64 doubles as the unsuffixed-literal default.
@@ -777,15 +1144,19 @@ enum CtInt { I8, I16, I32, I64, U8, U16, U32, U64 }
 pub(crate) enum CtOp { Add, Sub, Mul, Div, Rem, Shl, Shr, BitAnd, BitOr, BitXor }
 
 impl CtInt {
-    fn signed(self) -> bool { matches!(self, CtInt::I8 | CtInt::I16 | CtInt::I32 | CtInt::I64) }
-    fn bits(self) -> u32 {
-        match self {
-            CtInt::I8 | CtInt::U8 => 8,
-            CtInt::I16 | CtInt::U16 => 16,
-            CtInt::I32 | CtInt::U32 => 32,
-            CtInt::I64 | CtInt::U64 => 64,
-        }
+    const ALL: [CtInt; 8] =
+        [CtInt::I8, CtInt::I16, CtInt::I32, CtInt::I64, CtInt::U8, CtInt::U16, CtInt::U32, CtInt::U64];
+
+    /// The comptime kind for an integer type name; `None` for 128-bit
+    /// widths, which comptime has no values for.
+    fn from_name(name: &str) -> Option<CtInt> {
+        CtInt::ALL.into_iter().find(|k| k.name() == name)
     }
+    fn width(self) -> IntWidth {
+        IntWidth::of(self.name()).expect("comptime integer kinds are fixed-width")
+    }
+    fn signed(self) -> bool { self.width().signed }
+    fn bits(self) -> u32 { self.width().bits }
     fn name(self) -> &'static str {
         match self {
             CtInt::I8 => "i8", CtInt::I16 => "i16", CtInt::I32 => "i32", CtInt::I64 => "i64",
@@ -816,11 +1187,7 @@ impl CtInt {
         }
     }
     fn wrap(self, v: i128) -> i128 {
-        let bits = self.bits();
-        if bits >= 128 { return v; }
-        let mask = (1i128 << bits) - 1;
-        let masked = v & mask;
-        if self.signed() && (masked & (1i128 << (bits - 1))) != 0 { masked - (1i128 << bits) } else { masked }
+        self.width().wrap(v)
     }
 }
 
@@ -877,28 +1244,129 @@ pub(crate) fn ct_checked_binop(kind: CtInt, op: CtOp, a: i128, b: i128) -> Compt
     }
 }
 
+/// Checked integer power (CT1). A negative exponent has no integer result,
+/// so it's an error rather than a silent switch to float.
+fn ct_checked_pow(kind: CtInt, base: i128, exp: i128) -> ComptimeResult<ComptimeValue> {
+    if exp < 0 {
+        return Err(ComptimeError::NegativeExponent(exp));
+    }
+    let overflow = || ComptimeError::IntegerOverflow(format!(
+        "{}.pow({}) exceeds {} range [{}, {}]", base, exp, kind.name(), kind.min(), kind.max()
+    ));
+    // |base| >= 2 overflows within 128 steps, so the loop stays short
+    let acc = match base {
+        0 => if exp == 0 { 1 } else { 0 },
+        1 => 1,
+        -1 => if exp % 2 == 0 { 1 } else { -1 },
+        _ => {
+            let mut acc: i128 = 1;
+            for _ in 0..exp {
+                acc = acc.checked_mul(base)
+                    .filter(|r| *r >= kind.min() && *r <= kind.max())
+                    .ok_or_else(overflow)?;
+            }
+            acc
+        }
+    };
+    Ok(kind.make(acc))
+}
+
 pub struct ComptimeInterpreter {
     env: ComptimeEnv,
+    /// Where `print`/`println` write. None (the default) makes them no-ops so
+    /// compilation never produces output; `rask comptime --emit-prints` sets it.
+    print_out: Option<Box<dyn std::io::Write + Send>>,
+    /// Declared fields per struct, once `register_types` has run.
+    /// Literals of unregistered structs are taken as written.
+    struct_fields: HashMap<String, Vec<DeclaredField>>,
+    /// Declared variant names per enum, once `register_types` has run.
+    enum_variants: HashMap<String, Vec<String>>,
+    /// Span of the innermost expression or statement the last error came
+    /// from. Cleared whenever evaluation starts, so it never goes stale.
+    error_span: Option<Span>,
+    /// Comptime function calls in progress, outermost first.
+    call_stack: Vec<ComptimeFrame>,
+    /// `call_stack` as it stood when `error_span` was recorded.
+    error_frames: Vec<ComptimeFrame>,
 }
 
 impl ComptimeInterpreter {
     pub fn new() -> Self {
         Self {
             env: ComptimeEnv::new(),
+            print_out: None,
+            struct_fields: HashMap::new(),
+            enum_variants: builtin_enum_variants(),
+            error_span: None,
+            call_stack: Vec::new(),
+            error_frames: Vec::new(),
         }
     }
 
     pub fn with_quota(quota: usize) -> Self {
         Self {
             env: ComptimeEnv::with_quota(quota),
+            print_out: None,
+            struct_fields: HashMap::new(),
+            enum_variants: builtin_enum_variants(),
+            error_span: None,
+            call_stack: Vec::new(),
+            error_frames: Vec::new(),
         }
     }
 
+    /// Make `print`/`println` write to `out` instead of being ignored.
+    /// Debugging aid for metaprogramming; the compile pipeline never sets it.
+    pub fn emit_prints_to(&mut self, out: Box<dyn std::io::Write + Send>) {
+        self.print_out = Some(out);
+    }
+
     /// Reset branch counter between independent comptime evaluations.
     pub fn reset_branch_count(&mut self) {
         self.env.reset_branch_count();
     }
 
+    /// Where the most recent error was raised, if the last evaluation failed.
+    pub fn error_span(&self) -> Option<Span> {
+        self.error_span
+    }
+
+    /// Attach the recorded error location and comptime call chain to
+    /// `error`, falling back to `span` (usually the comptime expression or
+    /// block as a whole).
+    pub fn locate(&self, error: ComptimeError, span: Span) -> ComptimeDiagnostic {
+        match self.error_span {
+            Some(at) => ComptimeDiagnostic { error, span: at, frames: self.error_frames.clone() },
+            None => ComptimeDiagnostic::new(error, span),
+        }
+    }
+
+    /// Record `span` as the error site if nothing deeper claimed it.
+    fn track<T>(&mut self, result: ComptimeResult<T>, span: Span) -> ComptimeResult<T> {
+        if result.is_err() && self.error_span.is_none() {
+            self.error_span = Some(span);
+            self.error_frames = self.call_stack.clone();
+        }
+        result
+    }
+
+    /// Override the CT37 memory ceiling (bytes).
+    pub fn set_memory_limit(&mut self, bytes: usize) {
+        self.env.max_memory = bytes;
+    }
+
+    /// Vet a freshly built aggregate: charge it against the memory ceiling
+    /// (CT37) and cap how deeply values nest (CT69). Construction isn't a
+    /// backwards branch, so without this a builder that never loops could
+    /// grow a value until the compiler runs out of memory or stack.
+    fn built(&mut self, value: ComptimeValue) -> ComptimeResult<ComptimeValue> {
+        self.env.charge(value.shallow_size())?;
+        if value.nests_deeper_than(MAX_VALUE_DEPTH) {
+            return Err(ComptimeError::ValueTooDeep(MAX_VALUE_DEPTH));
+        }
+        Ok(value)
+    }
+
     /// Inject the `cfg` build configuration into the comptime environment.
     pub fn inject_cfg(&mut self, cfg: &CfgConfig) {
         self.env.define("cfg".to_string(), cfg.to_comptime_value());
@@ -915,6 +1383,158 @@ impl ComptimeInterpreter {
         }
     }
 
+    /// Register struct and enum definitions so literals and variant
+    /// constructors are checked against them when built, not when a bad
+    /// field is finally read.
+    pub fn register_types(&mut self, decls: &[Decl]) {
+        for decl in decls {
+            match &decl.kind {
+                DeclKind::Struct(s) => {
+                    let fields = s.fields.iter().map(|f| DeclaredField {
+                        name: f.name.clone(),
+                        ty: f.ty.clone(),
+                        is_public: f.visibility == FieldVisibility::Public,
+                    }).collect();
+                    self.struct_fields.insert(base_type_name(&s.name).to_string(), fields);
+                }
+                DeclKind::Enum(e) => {
+                    let variants = e.variants.iter().map(|v| v.name.clone()).collect();
+                    self.enum_variants.insert(base_type_name(&e.name).to_string(), variants);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Evaluate a whole program's comptime code in source order: every
+    /// comptime const, top-level or in a function body, and every
+    /// statement-level `comptime { }` block. Each folded const comes back as
+    /// a global and is visible to the code after it. A failing const is
+    /// always reported; a block only on a hard error, since it may read
+    /// comptime params that exist only per call.
+    pub fn eval_program(&mut self, decls: &[Decl]) -> (Vec<ComptimeGlobal>, Vec<ComptimeDiagnostic>) {
+        self.register_functions(decls);
+        self.register_types(decls);
+
+        let mut globals = Vec::new();
+        let mut diags = Vec::new();
+        for decl in decls {
+            match &decl.kind {
+                DeclKind::Const(c) if is_comptime_init(&c.init, decls) => {
+                    self.eval_global(&c.name, &c.init, &mut globals, &mut diags);
+                }
+                DeclKind::Const(c) => {
+                    // Plain globals that fold are visible to comptime code.
+                    if let Ok(value) = self.eval_const(&c.init) {
+                        self.define_const(c.name.clone(), value);
+                    }
+                }
+                DeclKind::Fn(f) => {
+                    for stmt in &f.body {
+                        match &stmt.kind {
+                            StmtKind::Const { name, init, .. } if is_comptime_init(init, decls) => {
+                                self.eval_global(name, init, &mut globals, &mut diags);
+                            }
+                            StmtKind::Comptime(body) => {
+                                self.reset_branch_count();
+                                if let Err(e) = self.eval_block_to_value(body) {
+                                    if e.is_hard() {
+                                        diags.push(self.locate(e, stmt.span));
+                                    }
+                                }
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        (globals, diags)
+    }
+
+    fn eval_global(
+        &mut self,
+        name: &str,
+        init: &Expr,
+        globals: &mut Vec<ComptimeGlobal>,
+        diags: &mut Vec<ComptimeDiagnostic>,
+    ) {
+        match self.eval_const(init) {
+            Ok(value) => {
+                self.define_const(name.to_string(), value.clone());
+                globals.push(ComptimeGlobal { name: name.to_string(), value, span: init.span });
+            }
+            Err(e) => diags.push(self.locate(e, init.span)),
+        }
+    }
+
+    /// Whether a pattern naming `pat_name` (`Rect` or `Shape.Rect`) selects
+    /// the `variant` of enum `enum_name`.
+    fn variant_matches(pat_name: &str, enum_name: &str, variant: &str) -> bool {
+        match pat_name.split_once('.') {
+            Some((pat_enum, pat_variant)) => base_type_name(enum_name) == pat_enum && variant == pat_variant,
+            None => variant == pat_name,
+        }
+    }
+
+    /// A bare-name pattern that is a variant tag rather than a binding:
+    /// qualified (`Color.Red`) or a variant of a known enum (`Red`, `None`).
+    fn is_variant_pattern(&self, name: &str) -> bool {
+        name.contains('.') || self.enum_variants.values().any(|vs| vs.iter().any(|v| v == name))
+    }
+
+    /// Build an enum value, checking the variant against a registered enum.
+    fn construct_variant(
+        &mut self,
+        enum_name: &str,
+        variant: &str,
+        args: Vec<ComptimeValue>,
+    ) -> ComptimeResult<ComptimeValue> {
+        let known = self.enum_variants.get(enum_name).map_or(false, |vs| vs.iter().any(|v| v == variant));
+        if !known {
+            return Err(ComptimeError::UnknownVariant {
+                ty: enum_name.to_string(),
+                variant: variant.to_string(),
+            });
+        }
+        let data = match args.len() {
+            0 => None,
+            1 => args.into_iter().next(),
+            _ => Some(ComptimeValue::Tuple(args)),
+        };
+        self.built(ComptimeValue::wrapper(enum_name, variant, data))
+    }
+
+    /// Bind a named constant so later const evaluations can refer to it.
+    pub fn define_const(&mut self, name: String, value: ComptimeValue) {
+        self.env.define(name, value);
+    }
+
+    /// Evaluate a type-level constant for the type checker (array lengths,
+    /// repeat counts). Each call is an independent evaluation with a fresh
+    /// branch budget.
+    pub fn eval_const(&mut self, expr: &Expr) -> ComptimeResult<ComptimeValue> {
+        self.reset_branch_count();
+        self.eval_expr(expr)
+    }
+
+    /// Evaluate a constant that must be a non-negative integer fitting in
+    /// `usize` — the shape of every array length.
+    pub fn eval_const_usize(&mut self, expr: &Expr) -> ComptimeResult<usize> {
+        let value = self.eval_const(expr)?;
+        match value.as_int() {
+            Some((v, _)) if v < 0 => Err(ComptimeError::NegativeSize(v)),
+            Some((v, _)) => usize::try_from(v).map_err(|_| {
+                ComptimeError::IntegerOverflow(format!("{} does not fit in usize", v))
+            }),
+            None => Err(ComptimeError::TypeMismatch {
+                expected: "integer".to_string(),
+                found: value.type_name().to_string(),
+            }),
+        }
+    }
+
     /// Evaluate a comptime expression.
     pub fn eval_expr(&mut self, expr: &Expr) -> ComptimeResult<ComptimeValue> {
         match self.eval_expr_cf(expr)? {
@@ -926,6 +1546,12 @@ impl ComptimeInterpreter {
     }
 
     fn eval_expr_cf(&mut self, expr: &Expr) -> ComptimeResult<ControlFlow> {
+        self.error_span = None;
+        let result = self.eval_expr_kind(expr);
+        self.track(result, expr.span)
+    }
+
+    fn eval_expr_kind(&mut self, expr: &Expr) -> ComptimeResult<ControlFlow> {
         let value = match &expr.kind {
             // Literals. An explicit width suffix picks the variant so arithmetic
             // is checked at that width (type.overflow); unsuffixed defaults to
@@ -949,6 +1575,7 @@ impl ComptimeInterpreter {
             ExprKind::String(s) => ComptimeValue::String(s.clone()),
             ExprKind::Char(c) => ComptimeValue::Char(*c),
             ExprKind::Bool(b) => ComptimeValue::Bool(*b),
+            ExprKind::None => ComptimeValue::wrapper("Option", "None", None),
 
             // Identifier
             ExprKind::Ident(name) => {
@@ -972,17 +1599,30 @@ impl ComptimeInterpreter {
             // Function call
             ExprKind::Call { func, args } => {
                 let arg_exprs: Vec<_> = args.iter().map(|a| &a.expr).collect();
-                self.eval_call(func, &arg_exprs)?
+                self.eval_call(func, &arg_exprs, expr.span)?
             }
 
             // Method call (from desugared operators)
-            ExprKind::MethodCall { object, method, args, .. } => {
+            ExprKind::MethodCall { object, method, type_args, args } => {
+                // std.reflect: `reflect.fields<T>()` and friends (R1: comptime only)
+                if let ExprKind::Ident(module) = &object.kind {
+                    if module == "reflect" && self.env.get(module).is_none() {
+                        let arg_values: ComptimeResult<Vec<_>> = args.iter().map(|a| self.eval_expr(&a.expr)).collect();
+                        return Ok(ControlFlow::Normal(self.call_reflect(method, type_args.as_deref(), &arg_values?)?));
+                    }
+                }
                 let arg_exprs: Vec<_> = args.iter().map(|a| &a.expr).collect();
                 self.eval_method_call(object, method, &arg_exprs)?
             }
 
             // Field access
             ExprKind::Field { object, field } => {
+                // `Color.Red` — a unit variant of a registered enum
+                if let ExprKind::Ident(type_name) = &object.kind {
+                    if self.env.get(type_name).is_none() && self.enum_variants.contains_key(type_name) {
+                        return Ok(ControlFlow::Normal(self.construct_variant(type_name, field, Vec::new())?));
+                    }
+                }
                 let obj = self.eval_expr(object)?;
                 match obj {
                     ComptimeValue::Struct { name, fields } => {
@@ -1016,23 +1656,28 @@ impl ComptimeInterpreter {
                         }
                         arr[idx_val].clone()
                     }
-                    ComptimeValue::String(s) => {
-                        let chars: Vec<char> = s.chars().collect();
-                        if idx_val >= chars.len() {
-                            return Err(ComptimeError::IndexOutOfBounds {
-                                index: idx_val,
-                                len: chars.len(),
-                            });
-                        }
-                        ComptimeValue::Char(chars[idx_val])
+                    // std.strings/S9: strings aren't position-indexed —
+                    // `char_at` / `byte_at` name the unit explicitly.
+                    ComptimeValue::String(_) => {
+                        return Err(ComptimeError::NotSupported(
+                            "string indexing — use `s.char_at(i)` or `s.byte_at(i)`".to_string(),
+                        ));
                     }
                     _ => return Err(ComptimeError::TypeMismatch {
-                        expected: "Array or String".to_string(),
+                        expected: "Array".to_string(),
                         found: obj.type_name().to_string(),
                     }),
                 }
             }
 
+            // Loop expression: `const x = loop { break 42 }`
+            ExprKind::Loop { label, body } => {
+                if label.is_some() {
+                    return Err(ComptimeError::NotSupported("labeled loop".to_string()));
+                }
+                return self.eval_loop(body);
+            }
+
             // Block expression
             ExprKind::Block(stmts) => {
                 self.env.push_scope();
@@ -1091,48 +1736,77 @@ impl ComptimeInterpreter {
             // Array literal
             ExprKind::Array(elems) => {
                 let values: ComptimeResult<Vec<_>> = elems.iter().map(|e| self.eval_expr(e)).collect();
-                ComptimeValue::Array(values?)
+                self.built(ComptimeValue::Array(values?))?
+            }
+
+            // Array repeat: [value; count]. Charge before cloning so a huge
+            // count fails on the budget, not the allocator.
+            ExprKind::ArrayRepeat { value, count } => {
+                let value = self.eval_expr(value)?;
+                let count = self.eval_expr(count)?;
+                let n = match count.as_int() {
+                    Some((n, _)) if n < 0 => return Err(ComptimeError::NegativeSize(n)),
+                    Some((n, _)) => usize::try_from(n).unwrap_or(usize::MAX),
+                    None => return Err(ComptimeError::TypeMismatch {
+                        expected: "integer".to_string(),
+                        found: count.type_name().to_string(),
+                    }),
+                };
+                self.env.charge(n.saturating_mul(value.shallow_size().max(1)))?;
+                self.built(ComptimeValue::Array(vec![value; n]))?
             }
 
             // Tuple literal
             ExprKind::Tuple(elems) => {
                 let values: ComptimeResult<Vec<_>> = elems.iter().map(|e| self.eval_expr(e)).collect();
-                ComptimeValue::Tuple(values?)
+                self.built(ComptimeValue::Tuple(values?))?
             }
 
             // Struct literal
-            ExprKind::StructLit { name, fields, .. } => {
+            ExprKind::StructLit { name, fields, spread } => {
+                let declared = self.struct_fields.get(base_type_name(name)).cloned();
                 let mut field_values = HashMap::new();
                 for field in fields {
+                    if let Some(declared) = &declared {
+                        if !declared.iter().any(|f| f.name == field.name) {
+                            return Err(ComptimeError::NoSuchField {
+                                ty: name.clone(),
+                                field: field.name.clone(),
+                            });
+                        }
+                    }
                     let value = self.eval_expr(&field.value)?;
                     field_values.insert(field.name.clone(), value);
                 }
-                ComptimeValue::Struct {
+                // `..base` fills whatever the literal didn't name
+                if let Some(spread) = spread {
+                    match self.eval_expr(spread)? {
+                        ComptimeValue::Struct { fields: base, .. } => {
+                            for (k, v) in base {
+                                field_values.entry(k).or_insert(v);
+                            }
+                        }
+                        other => return Err(ComptimeError::NotAStruct(other.type_name().to_string())),
+                    }
+                }
+                if let Some(declared) = &declared {
+                    if let Some(missing) = declared.iter().find(|f| !field_values.contains_key(&f.name)) {
+                        return Err(ComptimeError::MissingField {
+                            ty: name.clone(),
+                            field: missing.name.clone(),
+                        });
+                    }
+                }
+                self.built(ComptimeValue::Struct {
                     name: name.clone(),
                     fields: field_values,
-                }
+                })?
             }
 
             // Range expression
+            // Ranges materialize as arrays
             ExprKind::Range { start, end, inclusive } => {
-                // For now, just create an array of the range
-                let start_val = if let Some(s) = start {
-                    self.eval_expr(s)?.as_i64().unwrap_or(0)
-                } else {
-                    0
-                };
-                let end_val = if let Some(e) = end {
-                    self.eval_expr(e)?.as_i64().unwrap_or(0)
-                } else {
-                    return Err(ComptimeError::NotSupported("unbounded range".to_string()));
-                };
-
-                let values: Vec<ComptimeValue> = if *inclusive {
-                    (start_val..=end_val).map(ComptimeValue::I64).collect()
-                } else {
-                    (start_val..end_val).map(ComptimeValue::I64).collect()
-                };
-                ComptimeValue::Array(values)
+                self.materialize_range(start.as_deref(), end.as_deref(), *inclusive, 1)?
             }
 
             // Nested comptime — already in comptime context, just evaluate the body
@@ -1140,6 +1814,23 @@ impl ComptimeInterpreter {
                 return self.eval_block(body);
             }
 
+            // assert(cond, msg) — a failure is a comptime panic (static assert)
+            ExprKind::Assert { condition, message } => {
+                let cond = self.eval_expr(condition)?;
+                let ok = cond.as_bool().ok_or_else(|| ComptimeError::TypeMismatch {
+                    expected: "bool".to_string(),
+                    found: cond.type_name().to_string(),
+                })?;
+                if !ok {
+                    let msg = match message {
+                        Some(m) => self.eval_expr(m)?.display(),
+                        None => "assertion failed".to_string(),
+                    };
+                    return Err(ComptimeError::Panic(msg));
+                }
+                ComptimeValue::Unit
+            }
+
             // Closure — capture current environment and store for later call
             ExprKind::Closure { params, body, .. } => {
                 let param_names: Vec<String> = params.iter().map(|p| p.name.clone()).collect();
@@ -1177,52 +1868,115 @@ impl ComptimeInterpreter {
                 }
             }
 
+            // Guard pattern: `const v = expr is Some(v) else { return }`.
+            // Bindings land in the enclosing scope so the rest of the block
+            // sees them; the else branch diverges, so propagate its flow.
+            ExprKind::GuardPattern { expr, pattern, else_branch } => {
+                let value = self.eval_expr(expr)?;
+                if self.pattern_matches(pattern, &value)? {
+                    self.bind_pattern(pattern, &value)?;
+                    match value {
+                        ComptimeValue::Enum { data: Some(payload), .. } => *payload,
+                        other => other,
+                    }
+                } else {
+                    match self.eval_expr_cf(else_branch)? {
+                        ControlFlow::Normal(_) => ComptimeValue::Unit,
+                        cf => return Ok(cf),
+                    }
+                }
+            }
+
             // Type cast: expr as Type
             ExprKind::Cast { expr, ty } => {
                 let val = self.eval_expr(expr)?;
-                match (&val, ty.as_str()) {
-                    // int → int
-                    (ComptimeValue::I64(n), "i8") => ComptimeValue::I8(*n as i8),
-                    (ComptimeValue::I64(n), "i16") => ComptimeValue::I16(*n as i16),
-                    (ComptimeValue::I64(n), "i32") => ComptimeValue::I32(*n as i32),
-                    (ComptimeValue::I64(n), "i64") => ComptimeValue::I64(*n),
-                    (ComptimeValue::I64(n), "u8") => ComptimeValue::U8(*n as u8),
-                    (ComptimeValue::I64(n), "u16") => ComptimeValue::U16(*n as u16),
-                    (ComptimeValue::I64(n), "u32") => ComptimeValue::U32(*n as u32),
-                    (ComptimeValue::I64(n), "u64") => ComptimeValue::U64(*n as u64),
-                    (ComptimeValue::I64(n), "f64") => ComptimeValue::F64(*n as f64),
-                    (ComptimeValue::I64(n), "f32") => ComptimeValue::F32(*n as f32),
-                    // char → int
-                    (ComptimeValue::Char(c), "i64" | "usize") => ComptimeValue::I64(*c as i64),
-                    (ComptimeValue::Char(c), "u32") => ComptimeValue::U32(*c as u32),
-                    (ComptimeValue::Char(c), "u8") => ComptimeValue::U8(*c as u8),
-                    // int → char
-                    (ComptimeValue::I64(n), "char") => {
-                        char::from_u32(*n as u32)
-                            .map(ComptimeValue::Char)
-                            .unwrap_or(ComptimeValue::Char('\0'))
+                // int → int: every {i,u}{8,16,32,64} pair, with `as`
+                // truncation and sign extension. char → int goes the same way.
+                let source = match &val {
+                    ComptimeValue::Char(c) => Some(*c as i128),
+                    _ => val.as_i128(),
+                };
+                if let (Some(n), Some(target)) = (source, CtInt::from_name(ty)) {
+                    target.make(target.wrap(n))
+                } else {
+                    match (&val, ty.as_str()) {
+                        // int → float
+                        (_, "f32" | "f64") if val.as_i128().is_some() => {
+                            val.int_as_float(ty == "f32").unwrap_or(val)
+                        }
+                        (ComptimeValue::Char(c), "usize") => ComptimeValue::I64(*c as i64),
+                        // int → char
+                        (ComptimeValue::I64(n), "char") => {
+                            char::from_u32(*n as u32)
+                                .map(ComptimeValue::Char)
+                                .unwrap_or(ComptimeValue::Char('\0'))
+                        }
+                        (ComptimeValue::U32(n), "char") => {
+                            char::from_u32(*n)
+                                .map(ComptimeValue::Char)
+                                .unwrap_or(ComptimeValue::Char('\0'))
+                        }
+                        // float → int
+                        (ComptimeValue::F64(f), "i64") => ComptimeValue::I64(*f as i64),
+                        (ComptimeValue::F64(f), "i32") => ComptimeValue::I32(*f as i32),
+                        (ComptimeValue::F64(f), "i16") => ComptimeValue::I16(*f as i16),
+                        // Identity / pass-through
+                        _ => val,
+                    }
+                }
+            }
+
+            // `expr!` — payload of Some/Ok; None/Err panics the evaluation.
+            ExprKind::Unwrap { expr: inner, message } => {
+                let val = self.eval_expr(inner)?;
+                let found = val.type_name();
+                let is_result = matches!(&val, ComptimeValue::Enum { name, .. } if name == "Result");
+                match val.unwrap_parts() {
+                    Some((true, payload)) => payload,
+                    Some((false, err)) => {
+                        let msg = message.clone().unwrap_or_else(|| if is_result {
+                            format!("unwrap failed: value was Err({:?})", err)
+                        } else {
+                            "unwrap failed: value was None".to_string()
+                        });
+                        return Err(ComptimeError::Panic(msg));
                     }
-                    (ComptimeValue::U32(n), "char") => {
-                        char::from_u32(*n)
-                            .map(ComptimeValue::Char)
-                            .unwrap_or(ComptimeValue::Char('\0'))
+                    None => return Err(ComptimeError::TypeMismatch {
+                        expected: "Option or Result".to_string(),
+                        found: found.to_string(),
+                    }),
+                }
+            }
+
+            // `try expr` — payload of Some/Ok; None/Err returns from the
+            // enclosing comptime function, like `return`.
+            ExprKind::Try { expr: inner, else_clause } => {
+                if matches!(inner.kind, ExprKind::Block(_)) {
+                    return Err(ComptimeError::NotSupported("`try` blocks".to_string()));
+                }
+                let val = self.eval_expr(inner)?;
+                let found = val.type_name();
+                let is_result = matches!(&val, ComptimeValue::Enum { name, .. } if name == "Result");
+                match val.unwrap_parts() {
+                    Some((true, payload)) => payload,
+                    Some((false, err)) => {
+                        let propagated = match else_clause {
+                            Some(clause) => {
+                                self.env.push_scope();
+                                self.env.define(clause.error_binding.clone(), err);
+                                let mapped = self.eval_expr(&clause.body);
+                                self.env.pop_scope();
+                                ComptimeValue::wrapper("Result", "Err", Some(mapped?))
+                            }
+                            None if is_result => ComptimeValue::wrapper("Result", "Err", Some(err)),
+                            None => ComptimeValue::wrapper("Option", "None", None),
+                        };
+                        return Ok(ControlFlow::Return(propagated));
                     }
-                    // float → int
-                    (ComptimeValue::F64(f), "i64") => ComptimeValue::I64(*f as i64),
-                    (ComptimeValue::F64(f), "i32") => ComptimeValue::I32(*f as i32),
-                    (ComptimeValue::F64(f), "i16") => ComptimeValue::I16(*f as i16),
-                    // int → int (small widths)
-                    (ComptimeValue::I32(n), "i64") => ComptimeValue::I64(*n as i64),
-                    (ComptimeValue::I16(n), "i64") => ComptimeValue::I64(*n as i64),
-                    (ComptimeValue::I8(n), "i64") => ComptimeValue::I64(*n as i64),
-                    (ComptimeValue::U8(n), "i64") => ComptimeValue::I64(*n as i64),
-                    (ComptimeValue::U16(n), "i64") => ComptimeValue::I64(*n as i64),
-                    (ComptimeValue::U32(n), "i64") => ComptimeValue::I64(*n as i64),
-                    (ComptimeValue::U64(n), "i64") => ComptimeValue::I64(*n as i64),
-                    (ComptimeValue::U8(n), "u32") => ComptimeValue::U32(*n as u32),
-                    (ComptimeValue::I32(n), "i16") => ComptimeValue::I16(*n as i16),
-                    // Identity / pass-through
-                    _ => val,
+                    None => return Err(ComptimeError::TypeMismatch {
+                        expected: "Option or Result".to_string(),
+                        found: found.to_string(),
+                    }),
                 }
             }
 
@@ -1263,18 +2017,56 @@ impl ComptimeInterpreter {
         Ok(ControlFlow::Normal(last_value))
     }
 
-    fn eval_stmt(&mut self, stmt: &Stmt) -> ComptimeResult<ControlFlow> {
+    /// `loop { ... }` — runs until `break`; the break value becomes the
+    /// loop's value so statement and expression positions behave alike.
+    fn eval_loop(&mut self, body: &[Stmt]) -> ComptimeResult<ControlFlow> {
+        loop {
+            self.env.count_branch()?;
+
+            self.env.push_scope();
+            match self.eval_block(body)? {
+                ControlFlow::Normal(_) | ControlFlow::Continue => {}
+                ControlFlow::Break(v) => {
+                    self.env.pop_scope();
+                    return Ok(ControlFlow::Normal(v.unwrap_or(ComptimeValue::Unit)));
+                }
+                cf @ ControlFlow::Return(_) => {
+                    self.env.pop_scope();
+                    return Ok(cf);
+                }
+            }
+            self.env.pop_scope();
+        }
+    }
+
+    fn eval_stmt(&mut self, stmt: &Stmt) -> ComptimeResult<ControlFlow> {
+        self.error_span = None;
+        let result = self.eval_stmt_kind(stmt);
+        self.track(result, stmt.span)
+    }
+
+    fn eval_stmt_kind(&mut self, stmt: &Stmt) -> ComptimeResult<ControlFlow> {
         match &stmt.kind {
             StmtKind::Expr(e) => self.eval_expr_cf(e),
 
-            StmtKind::Mut { name, init, .. } | StmtKind::Const { name, init, .. } => {
-                let value = self.eval_expr(init)?;
+            StmtKind::Mut { name, init, ty, .. } | StmtKind::Const { name, init, ty, .. } => {
+                let value = match self.eval_expr_cf(init)? {
+                    ControlFlow::Normal(v) => v,
+                    cf => return Ok(cf),
+                };
+                let value = match ty.as_deref() {
+                    Some(float @ ("f32" | "f64")) => value.int_as_float(float == "f32").unwrap_or(value),
+                    _ => value,
+                };
                 self.env.define(name.clone(), value);
                 Ok(ControlFlow::Normal(ComptimeValue::Unit))
             }
 
             StmtKind::MutTuple { patterns, init } | StmtKind::ConstTuple { patterns, init } => {
-                let value = self.eval_expr(init)?;
+                let value = match self.eval_expr_cf(init)? {
+                    ControlFlow::Normal(v) => v,
+                    cf => return Ok(cf),
+                };
                 if let ComptimeValue::Tuple(values) = value {
                     let names: Vec<&str> = rask_ast::stmt::tuple_pats_flat_names(patterns);
                     if values.len() != names.len() {
@@ -1296,7 +2088,10 @@ impl ComptimeInterpreter {
             }
 
             StmtKind::Assign { target, value } => {
-                let val = self.eval_expr(value)?;
+                let val = match self.eval_expr_cf(value)? {
+                    ControlFlow::Normal(v) => v,
+                    cf => return Ok(cf),
+                };
                 if let ExprKind::Ident(name) = &target.kind {
                     if !self.env.assign(name, val) {
                         return Err(ComptimeError::UndefinedVariable(name.clone()));
@@ -1309,7 +2104,10 @@ impl ComptimeInterpreter {
 
             StmtKind::Return(expr) => {
                 let value = if let Some(e) = expr {
-                    self.eval_expr(e)?
+                    match self.eval_expr_cf(e)? {
+                        ControlFlow::Normal(v) => v,
+                        cf => return Ok(cf),
+                    }
                 } else {
                     ComptimeValue::Unit
                 };
@@ -1366,25 +2164,7 @@ impl ComptimeInterpreter {
                 Ok(ControlFlow::Normal(ComptimeValue::Unit))
             }
 
-            StmtKind::Loop { body, .. } => {
-                loop {
-                    self.env.count_branch()?;
-
-                    self.env.push_scope();
-                    match self.eval_block(body)? {
-                        ControlFlow::Normal(_) | ControlFlow::Continue => {}
-                        ControlFlow::Break(v) => {
-                            self.env.pop_scope();
-                            return Ok(ControlFlow::Normal(v.unwrap_or(ComptimeValue::Unit)));
-                        }
-                        cf @ ControlFlow::Return(_) => {
-                            self.env.pop_scope();
-                            return Ok(cf);
-                        }
-                    }
-                    self.env.pop_scope();
-                }
-            }
+            StmtKind::Loop { body, .. } => self.eval_loop(body),
 
             StmtKind::For { binding, iter, body, .. } => {
                 let iter_val = self.eval_expr(iter)?;
@@ -1404,7 +2184,7 @@ impl ComptimeInterpreter {
                     match binding {
                         ForBinding::Single(name) => self.env.define(name.clone(), item),
                         ForBinding::Tuple(names) => {
-                            if let ComptimeValue::Array(fields) = item {
+                            if let ComptimeValue::Array(fields) | ComptimeValue::Tuple(fields) = item {
                                 for (i, name) in names.iter().enumerate() {
                                     let val = fields.get(i).cloned().unwrap_or(ComptimeValue::Unit);
                                     self.env.define(name.clone(), val);
@@ -1430,23 +2210,26 @@ impl ComptimeInterpreter {
             }
 
             StmtKind::Comptime(body) => {
-                // Already at comptime, just evaluate the block
+                // Already at comptime, just evaluate the block. No branch of
+                // its own: quota use must match the inlined body.
                 self.eval_block(body)
             }
 
             StmtKind::ComptimeFor { binding, iter, body } => {
-                // CT48: Evaluate the iterable and unroll
+                // CT48: Evaluate the iterable and unroll. Already at comptime,
+                // so each iteration is a backwards branch like a plain `for`.
                 let iter_val = self.eval_expr(iter)?;
                 match iter_val {
                     ComptimeValue::Array(items) => {
                         for item in items {
+                            self.env.count_branch()?;
                             self.env.push_scope();
                             match binding {
                                 rask_ast::stmt::ForBinding::Single(name) => {
                                     self.env.define(name.clone(), item);
                                 }
                                 rask_ast::stmt::ForBinding::Tuple(names) => {
-                                    if let ComptimeValue::Array(elems) = item {
+                                    if let ComptimeValue::Array(elems) | ComptimeValue::Tuple(elems) = item {
                                         for (i, name) in names.iter().enumerate() {
                                             let v = elems.get(i).cloned()
                                                 .unwrap_or(ComptimeValue::Unit);
@@ -1456,8 +2239,12 @@ impl ComptimeInterpreter {
                                 }
                             }
                             match self.eval_block(body)? {
-                                ControlFlow::Normal(_) => {}
-                                cf => {
+                                ControlFlow::Normal(_) | ControlFlow::Continue => {}
+                                ControlFlow::Break(v) => {
+                                    self.env.pop_scope();
+                                    return Ok(ControlFlow::Normal(v.unwrap_or(ComptimeValue::Unit)));
+                                }
+                                cf @ ControlFlow::Return(_) => {
                                     self.env.pop_scope();
                                     return Ok(cf);
                                 }
@@ -1576,7 +2363,7 @@ impl ComptimeInterpreter {
         }
     }
 
-    fn eval_call(&mut self, func: &Expr, args: &[&Expr]) -> ComptimeResult<ComptimeValue> {
+    fn eval_call(&mut self, func: &Expr, args: &[&Expr], call_site: Span) -> ComptimeResult<ComptimeValue> {
         // Evaluate arguments first
         let arg_values: ComptimeResult<Vec<_>> = args.iter().map(|a| self.eval_expr(a)).collect();
         let arg_values = arg_values?;
@@ -1586,7 +2373,10 @@ impl ComptimeInterpreter {
         if let ExprKind::Ident(name) = &func.kind {
             if let Some(func_decl) = self.env.get_function(name).cloned() {
                 self.env.count_branch()?;
-                return self.call_function(&func_decl, arg_values);
+                self.call_stack.push(ComptimeFrame { function: name.clone(), call_site });
+                let result = self.call_function(&func_decl, arg_values);
+                self.call_stack.pop();
+                return result;
             }
 
             // Check if it's a closure stored in a variable
@@ -1626,15 +2416,27 @@ impl ComptimeInterpreter {
     ) -> ComptimeResult<ComptimeValue> {
         // Static method call on a type: Vec.new(), Map.new()
         if let ExprKind::Ident(name) = &object.kind {
-            if !self.env.get(name).is_some() && is_comptime_type(name) {
+            if !self.env.get(name).is_some()
+                && (is_comptime_type(name) || self.enum_variants.contains_key(name))
+            {
                 let arg_values: ComptimeResult<Vec<_>> = args.iter().map(|a| self.eval_expr(a)).collect();
                 let arg_values = arg_values?;
                 return self.call_static_method(name, method, arg_values);
             }
         }
 
+        // SP1–SP4: `(a..b).step(s)` materializes the stepped sequence directly
+        if let (ExprKind::Range { start, end, inclusive }, "step", [step]) = (&object.kind, method, args) {
+            let step_val = self.eval_expr(step)?;
+            let step = step_val.as_i64().ok_or_else(|| ComptimeError::TypeMismatch {
+                expected: "integer step".to_string(),
+                found: step_val.type_name().to_string(),
+            })?;
+            return self.materialize_range(start.as_deref(), end.as_deref(), *inclusive, step);
+        }
+
         // Mutating Vec methods: push, pop — need to update the variable in-place
-        if matches!(method, "push" | "pop" | "insert" | "remove" | "clear") {
+        if matches!(method, "push" | "pop" | "insert" | "remove" | "clear" | "sort" | "reverse") {
             if let ExprKind::Ident(var_name) = &object.kind {
                 let arg_values: ComptimeResult<Vec<_>> = args.iter().map(|a| self.eval_expr(a)).collect();
                 let arg_values = arg_values?;
@@ -1646,10 +2448,65 @@ impl ComptimeInterpreter {
         let arg_values: ComptimeResult<Vec<_>> = args.iter().map(|a| self.eval_expr(a)).collect();
         let arg_values = arg_values?;
 
+        // `map` calls back into the interpreter, so it can't live with the
+        // read-only methods in call_primitive_method.
+        if let (ComptimeValue::Array(arr), "map") = (&obj, method) {
+            return self.map_array(arr, &arg_values);
+        }
+
         // Handle primitive methods (from desugared operators) + Vec read methods
         self.call_primitive_method(&obj, method, &arg_values)
     }
 
+    /// Type introspection over the types `register_types` saw (std.reflect).
+    fn call_reflect(
+        &self,
+        method: &str,
+        type_args: Option<&[String]>,
+        args: &[ComptimeValue],
+    ) -> ComptimeResult<ComptimeValue> {
+        let ty = type_args.and_then(|t| t.first()).ok_or_else(|| ComptimeError::TypeMismatch {
+            expected: format!("a type argument: `reflect.{}<T>()`", method),
+            found: "none".to_string(),
+        })?;
+        let base = base_type_name(ty);
+        match method {
+            "name_of" => Ok(ComptimeValue::String(ty.clone())),
+            "is_struct" => Ok(ComptimeValue::Bool(self.struct_fields.contains_key(base))),
+            "is_enum" => Ok(ComptimeValue::Bool(self.enum_variants.contains_key(base))),
+            "fields" => {
+                let declared = self.struct_fields.get(base)
+                    .ok_or_else(|| ComptimeError::NotAStruct(ty.clone()))?;
+                // offset/size need the layout, which isn't known until codegen
+                let infos = declared.iter().map(|f| {
+                    let mut info = HashMap::new();
+                    info.insert("name".to_string(), ComptimeValue::String(f.name.clone()));
+                    info.insert("type_name".to_string(), ComptimeValue::String(f.ty.clone()));
+                    info.insert("is_public".to_string(), ComptimeValue::Bool(f.is_public));
+                    info.insert("serial_name".to_string(), ComptimeValue::String(f.name.clone()));
+                    info.insert("is_skipped".to_string(), ComptimeValue::Bool(false));
+                    info.insert("has_default".to_string(), ComptimeValue::Bool(false));
+                    ComptimeValue::Struct { name: "FieldInfo".to_string(), fields: info }
+                }).collect();
+                Ok(ComptimeValue::Array(infos))
+            }
+            "has_field" => {
+                let declared = self.struct_fields.get(base)
+                    .ok_or_else(|| ComptimeError::NotAStruct(ty.clone()))?;
+                match args.first() {
+                    Some(ComptimeValue::String(name)) => {
+                        Ok(ComptimeValue::Bool(declared.iter().any(|f| &f.name == name)))
+                    }
+                    other => Err(ComptimeError::TypeMismatch {
+                        expected: "string".to_string(),
+                        found: other.map(|a| a.type_name()).unwrap_or("nothing").to_string(),
+                    }),
+                }
+            }
+            _ => Err(ComptimeError::NotSupported(format!("reflect.{} at comptime", method))),
+        }
+    }
+
     fn call_function(
         &mut self,
         func: &FnDecl,
@@ -1696,6 +2553,7 @@ impl ComptimeInterpreter {
 
         // Swap in the captured environment, preserving current env
         let saved_scopes = std::mem::replace(&mut self.env.scopes, captures.to_vec());
+        let saved_capture_depth = std::mem::replace(&mut self.env.capture_depth, captures.len());
 
         // New scope for parameters
         self.env.push_scope();
@@ -1707,6 +2565,7 @@ impl ComptimeInterpreter {
 
         // Restore original environment
         self.env.scopes = saved_scopes;
+        self.env.capture_depth = saved_capture_depth;
         self.env.pop_call();
 
         Ok(result?.value())
@@ -1741,7 +2600,12 @@ impl ComptimeInterpreter {
                 Err(ComptimeError::Panic(msg))
             }
             "println" | "print" => {
-                // At comptime, these are no-ops (or could be @comptime_print)
+                // No-ops unless an inspection tool asked to see them.
+                if let Some(out) = self.print_out.as_mut() {
+                    let text: Vec<String> = args.iter().map(ComptimeValue::display).collect();
+                    let newline = if name == "println" { "\n" } else { "" };
+                    let _ = write!(out, "{}{}", text.join(" "), newline);
+                }
                 Ok(ComptimeValue::Unit)
             }
             "assert" => {
@@ -1761,6 +2625,39 @@ impl ComptimeInterpreter {
                     Ok(ComptimeValue::Unit)
                 }
             }
+            "assert_eq" | "assert_ne" => {
+                if args.len() < 2 {
+                    return Err(ComptimeError::TypeMismatch {
+                        expected: "2 arguments".to_string(),
+                        found: format!("{} arguments", args.len()),
+                    });
+                }
+                let equal = self.call_primitive_method(&args[0], "eq", &args[1..2])?
+                    .as_bool()
+                    .unwrap_or(false);
+                if equal == (name == "assert_eq") {
+                    return Ok(ComptimeValue::Unit);
+                }
+                let msg = match args.get(2) {
+                    Some(ComptimeValue::String(s)) => s.clone(),
+                    _ => format!("{} failed", name),
+                };
+                Err(ComptimeError::Panic(format!(
+                    "{}: left = {}, right = {}",
+                    msg, args[0].display(), args[1].display()
+                )))
+            }
+            // Free-function forms of the numeric methods: `max(a, b)` is `a.max(b)`
+            "min" | "max" | "pow" if args.len() == 2 => {
+                self.call_primitive_method(&args[0], name, &args[1..])
+            }
+            "abs" if args.len() == 1 => self.call_primitive_method(&args[0], name, &[]),
+            "Some" if args.len() == 1 => {
+                self.built(ComptimeValue::wrapper("Option", "Some", args.into_iter().next()))
+            }
+            "Ok" | "Err" if args.len() == 1 => {
+                self.built(ComptimeValue::wrapper("Result", name, args.into_iter().next()))
+            }
             _ => Err(ComptimeError::UndefinedFunction(name.to_string())),
         }
     }
@@ -1774,16 +2671,75 @@ impl ComptimeInterpreter {
     ) -> ComptimeResult<ComptimeValue> {
         match (type_name, method) {
             ("Vec", "new") => Ok(ComptimeValue::Array(Vec::new())),
+            ("Map", "new") => Ok(ComptimeValue::Map(Vec::new())),
+            // Capacity isn't observable at comptime; the hint only sizes the
+            // allocation.
+            ("Vec", "with_capacity") if args.len() == 1 => match args[0].as_int() {
+                Some((n, _)) if n < 0 => Err(ComptimeError::NegativeSize(n)),
+                Some((n, _)) => {
+                    let n = usize::try_from(n).unwrap_or(usize::MAX);
+                    self.env.charge(n.saturating_mul(std::mem::size_of::<ComptimeValue>()))?;
+                    Ok(ComptimeValue::Array(Vec::with_capacity(n)))
+                }
+                None => Err(ComptimeError::TypeMismatch {
+                    expected: "integer".to_string(),
+                    found: args[0].type_name().to_string(),
+                }),
+            },
             ("Vec", "from") if args.len() == 1 => {
-                // Vec.from(array) — clone the array
+                // Vec.from(array) — clone the array. Ranges already evaluate
+                // to arrays; tuples convert when every element has one type.
                 match &args[0] {
                     ComptimeValue::Array(arr) => Ok(ComptimeValue::Array(arr.clone())),
+                    ComptimeValue::Tuple(elems) => {
+                        if let Some(first) = elems.first() {
+                            if let Some(odd) = elems.iter().find(|e| e.type_name() != first.type_name()) {
+                                return Err(ComptimeError::TypeMismatch {
+                                    expected: first.type_name().to_string(),
+                                    found: odd.type_name().to_string(),
+                                });
+                            }
+                        }
+                        Ok(ComptimeValue::Array(elems.clone()))
+                    }
                     _ => Err(ComptimeError::TypeMismatch {
                         expected: "Array".to_string(),
                         found: args[0].type_name().to_string(),
                     }),
                 }
             }
+            // Map.from([(k, v), ...]) — the collection literal for maps.
+            // Pairs may be tuples or 2-element arrays; a repeated key
+            // overwrites in place, like `insert`.
+            ("Map", "from") if args.len() == 1 => {
+                let pairs = match &args[0] {
+                    ComptimeValue::Array(pairs) => pairs,
+                    other => return Err(ComptimeError::TypeMismatch {
+                        expected: "Array of pairs".to_string(),
+                        found: other.type_name().to_string(),
+                    }),
+                };
+                let mut entries: Vec<(ComptimeValue, ComptimeValue)> = Vec::with_capacity(pairs.len());
+                for pair in pairs {
+                    let (key, value) = match pair {
+                        ComptimeValue::Tuple(kv) | ComptimeValue::Array(kv) if kv.len() == 2 => {
+                            (kv[0].clone(), kv[1].clone())
+                        }
+                        other => return Err(ComptimeError::TypeMismatch {
+                            expected: "(key, value) pair".to_string(),
+                            found: other.type_name().to_string(),
+                        }),
+                    };
+                    match map_position(&entries, &key)? {
+                        Some(i) => entries[i].1 = value,
+                        None => entries.push((key, value)),
+                    }
+                }
+                self.built(ComptimeValue::Map(entries))
+            }
+            _ if self.enum_variants.contains_key(type_name) => {
+                self.construct_variant(type_name, method, args)
+            }
             _ => Err(ComptimeError::NotSupported(
                 format!("static method {}.{}", type_name, method),
             )),
@@ -1800,9 +2756,17 @@ impl ComptimeInterpreter {
         let val = self.env.get(var_name)
             .ok_or_else(|| ComptimeError::UndefinedVariable(var_name.to_string()))?
             .clone();
+        // The write-back would land in the closure's captured copy and vanish
+        // when the call returns — refuse rather than silently drop it.
+        if self.env.is_captured(var_name) {
+            return Err(ComptimeError::CapturedCollectionMutation(var_name.to_string()));
+        }
 
         let mut arr = match val {
             ComptimeValue::Array(arr) => arr,
+            ComptimeValue::Map(entries) => {
+                return self.call_mutating_map_method(var_name, entries, method, args);
+            }
             _ => return Err(ComptimeError::TypeMismatch {
                 expected: "Vec/Array".to_string(),
                 found: val.type_name().to_string(),
@@ -1863,6 +2827,27 @@ impl ComptimeInterpreter {
                 arr.clear();
                 ComptimeValue::Unit
             }
+            "reverse" => {
+                arr.reverse();
+                ComptimeValue::Unit
+            }
+            "sort" => {
+                // Stable; the first incomparable pair aborts the sort.
+                let mut failure = None;
+                arr.sort_by(|a, b| {
+                    if failure.is_some() {
+                        return std::cmp::Ordering::Equal;
+                    }
+                    ct_order(a, b).unwrap_or_else(|e| {
+                        failure = Some(e);
+                        std::cmp::Ordering::Equal
+                    })
+                });
+                if let Some(e) = failure {
+                    return Err(e);
+                }
+                ComptimeValue::Unit
+            }
             _ => return Err(ComptimeError::NotSupported(
                 format!("mutating method .{}", method),
             )),
@@ -1875,12 +2860,217 @@ impl ComptimeInterpreter {
         Ok(result)
     }
 
+    /// The values of `start..end` (or `..=`) taken every `step`. A positive
+    /// step counts up, a negative one down (SP1/SP2); bounds running the
+    /// other way give an empty array (R4, RV2 — `10..0` is not a reverse
+    /// range). The length is computed up front so a huge range hits the
+    /// memory limit before anything is allocated.
+    fn materialize_range(
+        &mut self,
+        start: Option<&Expr>,
+        end: Option<&Expr>,
+        inclusive: bool,
+        step: i64,
+    ) -> ComptimeResult<ComptimeValue> {
+        if step == 0 {
+            return Err(ComptimeError::ZeroStep);
+        }
+        let start = match start {
+            Some(s) => self.range_bound(s)? as i128,
+            None => 0,
+        };
+        let end = match end {
+            Some(e) => self.range_bound(e)? as i128,
+            None => return Err(ComptimeError::NotSupported("unbounded range".to_string())),
+        };
+        let step = step as i128;
+
+        let len = if step > 0 {
+            let stop = if inclusive { end + 1 } else { end };
+            if stop > start { (stop - start + step - 1) / step } else { 0 }
+        } else {
+            let stop = if inclusive { end - 1 } else { end };
+            if start > stop { (start - stop - step - 1) / -step } else { 0 }
+        };
+        let len = usize::try_from(len).unwrap_or(usize::MAX);
+        self.env.charge(len.saturating_mul(std::mem::size_of::<ComptimeValue>()))?;
+
+        let values = (0..len as i128)
+            .map(|k| ComptimeValue::I64((start + k * step) as i64))
+            .collect();
+        Ok(ComptimeValue::Array(values))
+    }
+
+    fn range_bound(&mut self, bound: &Expr) -> ComptimeResult<i64> {
+        let value = self.eval_expr(bound)?;
+        match value.as_i64() {
+            Some(v) => Ok(v),
+            None if value.as_f64().is_some() => {
+                Err(ComptimeError::NotSupported("float range".to_string()))
+            }
+            None => Err(ComptimeError::TypeMismatch {
+                expected: "integer range bound".to_string(),
+                found: value.type_name().to_string(),
+            }),
+        }
+    }
+
+    /// `arr.map(|x| ...)`: a new array of the closure's results.
+    fn map_array(&mut self, arr: &[ComptimeValue], args: &[ComptimeValue]) -> ComptimeResult<ComptimeValue> {
+        let Some(ComptimeValue::Closure { params, body, captures }) = args.first() else {
+            return Err(ComptimeError::TypeMismatch {
+                expected: "closure".to_string(),
+                found: args.first().map(|a| a.type_name()).unwrap_or("nothing").to_string(),
+            });
+        };
+        let mut out = Vec::with_capacity(arr.len());
+        for elem in arr {
+            self.env.count_branch()?;
+            out.push(self.call_closure(params, body, captures, vec![elem.clone()])?);
+        }
+        self.built(ComptimeValue::Array(out))
+    }
+
+    /// Map `insert`/`remove`/`clear`, written back to the variable like the
+    /// Vec methods. A re-inserted key keeps its original position.
+    fn call_mutating_map_method(
+        &mut self,
+        var_name: &str,
+        mut entries: Vec<(ComptimeValue, ComptimeValue)>,
+        method: &str,
+        args: &[ComptimeValue],
+    ) -> ComptimeResult<ComptimeValue> {
+        let result = match (method, args) {
+            ("insert", [key, value]) => match map_position(&entries, key)? {
+                Some(i) => {
+                    let old = std::mem::replace(&mut entries[i].1, value.clone());
+                    ComptimeValue::wrapper("Option", "Some", Some(old))
+                }
+                None => {
+                    entries.push((key.clone(), value.clone()));
+                    ComptimeValue::wrapper("Option", "None", None)
+                }
+            },
+            ("remove", [key]) => match map_position(&entries, key)? {
+                Some(i) => ComptimeValue::wrapper("Option", "Some", Some(entries.remove(i).1)),
+                None => ComptimeValue::wrapper("Option", "None", None),
+            },
+            ("clear", []) => {
+                entries.clear();
+                ComptimeValue::Unit
+            }
+            _ => return Err(ComptimeError::NotSupported(
+                format!("Map method .{} with {} arguments", method, args.len()),
+            )),
+        };
+
+        if !self.env.assign(var_name, ComptimeValue::Map(entries)) {
+            return Err(ComptimeError::UndefinedVariable(var_name.to_string()));
+        }
+        Ok(result)
+    }
+
     fn call_primitive_method(
         &self,
         obj: &ComptimeValue,
         method: &str,
         args: &[ComptimeValue],
     ) -> ComptimeResult<ComptimeValue> {
+        // Nothing has methods on `()`, and `none` only compares — name the
+        // mistake instead of reporting an unsupported method.
+        match obj {
+            ComptimeValue::Unit => return Err(ComptimeError::MethodOnUnit(method.to_string())),
+            ComptimeValue::Enum { name, variant, .. }
+                if name == "Option" && variant == "None" && method != "eq" =>
+            {
+                return Err(ComptimeError::MethodOnNone(method.to_string()));
+            }
+            _ => {}
+        }
+
+        // Map read methods
+        if let ComptimeValue::Map(entries) = obj {
+            match (method, args) {
+                ("contains_key", [key]) => {
+                    return Ok(ComptimeValue::Bool(map_position(entries, key)?.is_some()));
+                }
+                ("get" | "get_clone", [key]) => {
+                    let found = map_position(entries, key)?.map(|i| entries[i].1.clone());
+                    return Ok(match found {
+                        Some(v) => ComptimeValue::wrapper("Option", "Some", Some(v)),
+                        None => ComptimeValue::wrapper("Option", "None", None),
+                    });
+                }
+                ("len", []) => return Ok(ComptimeValue::I64(entries.len() as i64)),
+                ("is_empty", []) => return Ok(ComptimeValue::Bool(entries.is_empty())),
+                ("keys", []) => {
+                    return Ok(ComptimeValue::Array(entries.iter().map(|(k, _)| k.clone()).collect()));
+                }
+                ("values", []) => {
+                    return Ok(ComptimeValue::Array(entries.iter().map(|(_, v)| v.clone()).collect()));
+                }
+                _ => {}
+            }
+        }
+
+        // Char classification and conversion, mirroring stdlib/char.rk
+        if let ComptimeValue::Char(c) = *obj {
+            let result = match method {
+                "is_digit" => Some(ComptimeValue::Bool(c.is_ascii_digit())),
+                "is_alphabetic" => Some(ComptimeValue::Bool(c.is_alphabetic())),
+                "is_alphanumeric" => Some(ComptimeValue::Bool(c.is_alphanumeric())),
+                "is_numeric" => Some(ComptimeValue::Bool(c.is_numeric())),
+                "is_whitespace" => Some(ComptimeValue::Bool(c.is_whitespace())),
+                "is_ascii" => Some(ComptimeValue::Bool(c.is_ascii())),
+                "is_uppercase" => Some(ComptimeValue::Bool(c.is_uppercase())),
+                "is_lowercase" => Some(ComptimeValue::Bool(c.is_lowercase())),
+                "to_uppercase" => Some(ComptimeValue::Char(c.to_uppercase().next().unwrap_or(c))),
+                "to_lowercase" => Some(ComptimeValue::Char(c.to_lowercase().next().unwrap_or(c))),
+                "len_utf8" => Some(ComptimeValue::I64(c.len_utf8() as i64)),
+                "to_digit" => {
+                    let radix = args.first().and_then(|a| a.as_i64()).ok_or_else(|| ComptimeError::TypeMismatch {
+                        expected: "integer radix".to_string(),
+                        found: args.first().map(|a| a.type_name()).unwrap_or("nothing").to_string(),
+                    })?;
+                    if !(2..=36).contains(&radix) {
+                        return Err(ComptimeError::Panic(format!("to_digit: radix {} out of range 2..=36", radix)));
+                    }
+                    Some(match c.to_digit(radix as u32) {
+                        Some(d) => ComptimeValue::wrapper("Option", "Some", Some(ComptimeValue::U32(d))),
+                        None => ComptimeValue::wrapper("Option", "None", None),
+                    })
+                }
+                _ => None,
+            };
+            if let Some(v) = result {
+                return Ok(v);
+            }
+        }
+
+        // `value.fields()`: (name, value) pairs in declaration order. The value's
+        // own map is unordered, so the order comes from the registered decl.
+        if let (ComptimeValue::Struct { name, fields }, "fields", []) = (obj, method, args) {
+            let declared = self.struct_fields.get(base_type_name(name))
+                .ok_or_else(|| ComptimeError::NotAStruct(name.clone()))?;
+            let pairs = declared.iter().filter_map(|f| {
+                let value = fields.get(&f.name)?.clone();
+                Some(ComptimeValue::Tuple(vec![ComptimeValue::String(f.name.clone()), value]))
+            }).collect();
+            return Ok(ComptimeValue::Array(pairs));
+        }
+
+        // Structural equality for aggregates (desugared `==`)
+        if method == "eq" && matches!(obj,
+            ComptimeValue::Array(_) | ComptimeValue::Map(_) | ComptimeValue::Tuple(_)
+            | ComptimeValue::Struct { .. } | ComptimeValue::Enum { .. })
+        {
+            let arg = args.first().ok_or_else(|| ComptimeError::TypeMismatch {
+                expected: "1 argument".to_string(),
+                found: "0 arguments".to_string(),
+            })?;
+            return Ok(ComptimeValue::Bool(obj == arg));
+        }
+
         // Vec/Array read methods
         if let ComptimeValue::Array(arr) = obj {
             match method {
@@ -1897,6 +3087,27 @@ impl ComptimeInterpreter {
                     });
                 }
                 "is_empty" => return Ok(ComptimeValue::Bool(arr.is_empty())),
+                // `T?`, as at runtime — an empty array is `none`, not an error.
+                "first" | "last" if args.is_empty() => {
+                    let end = if method == "first" { arr.first() } else { arr.last() };
+                    return Ok(match end {
+                        Some(v) => ComptimeValue::wrapper("Option", "Some", Some(v.clone())),
+                        None => ComptimeValue::wrapper("Option", "None", None),
+                    });
+                }
+                "slice" => {
+                    let bound = |i: usize| args.get(i).and_then(|a| a.as_i64()).ok_or_else(|| {
+                        ComptimeError::TypeMismatch {
+                            expected: "integer start and end".to_string(),
+                            found: args.get(i).map_or("nothing", |a| a.type_name()).to_string(),
+                        }
+                    });
+                    let (start, end) = (bound(0)?, bound(1)?);
+                    if start < 0 || start > end || end > arr.len() as i64 {
+                        return Err(ComptimeError::SliceOutOfBounds { start, end, len: arr.len() });
+                    }
+                    return Ok(ComptimeValue::Array(arr[start as usize..end as usize].to_vec()));
+                }
                 "contains" => {
                     let needle = args.first().ok_or_else(|| ComptimeError::TypeMismatch {
                         expected: "1 argument".to_string(),
@@ -1904,6 +3115,10 @@ impl ComptimeInterpreter {
                     })?;
                     return Ok(ComptimeValue::Bool(arr.contains(needle)));
                 }
+                // RV1: `(0..10).rev()` — the descending counterpart of a range
+                "rev" if args.is_empty() => {
+                    return Ok(ComptimeValue::Array(arr.iter().rev().cloned().collect()));
+                }
                 _ => {} // fall through to numeric/string methods
             }
         }
@@ -1915,12 +3130,7 @@ impl ComptimeInterpreter {
             "add" => self.ct_arith(obj, args, CtOp::Add, |a, b| a + b, "+"),
             "sub" => self.ct_arith(obj, args, CtOp::Sub, |a, b| a - b, "-"),
             "mul" => self.ct_arith(obj, args, CtOp::Mul, |a, b| a * b, "*"),
-            "div" => {
-                if args.first().and_then(|a| a.as_f64()) == Some(0.0) && obj.as_int().is_none() {
-                    return Err(ComptimeError::DivisionByZero);
-                }
-                self.ct_arith(obj, args, CtOp::Div, |a, b| a / b, "/")
-            }
+            "div" => self.ct_arith(obj, args, CtOp::Div, |a, b| a / b, "/"),
             "rem" => self.ct_arith(obj, args, CtOp::Rem, |a, b| a % b, "%"),
             "neg" => match obj.as_int() {
                 Some((v, kind)) => {
@@ -1959,7 +3169,86 @@ impl ComptimeInterpreter {
                     found: obj.type_name().to_string(),
                 }),
             },
+            "abs" => match obj.as_int() {
+                Some((v, kind)) if v.abs() > kind.max() => Err(ComptimeError::IntegerOverflow(format!(
+                    "abs({}) exceeds {} range [{}, {}]", v, kind.name(), kind.min(), kind.max()
+                ))),
+                Some((v, kind)) => Ok(kind.make(v.abs())),
+                None => match obj {
+                    ComptimeValue::F64(v) => Ok(ComptimeValue::F64(v.abs())),
+                    ComptimeValue::F32(v) => Ok(ComptimeValue::F32(v.abs())),
+                    _ => Err(ComptimeError::TypeMismatch {
+                        expected: "numeric".to_string(),
+                        found: obj.type_name().to_string(),
+                    }),
+                },
+            },
+            "min" | "max" => self.ct_min_max(obj, args, method == "max"),
+            "pow" => self.ct_pow(obj, args),
             // String methods
+            "char_at" | "byte_at" => {
+                let ComptimeValue::String(s) = obj else {
+                    return Err(ComptimeError::TypeMismatch {
+                        expected: "String".to_string(),
+                        found: obj.type_name().to_string(),
+                    });
+                };
+                let idx = args.first().and_then(|a| a.as_i64()).ok_or_else(|| {
+                    ComptimeError::TypeMismatch {
+                        expected: "integer index".to_string(),
+                        found: args.first().map_or("nothing", |a| a.type_name()).to_string(),
+                    }
+                })?;
+                // Out of range (including negative) is `None`, as at runtime.
+                let found = usize::try_from(idx).ok().and_then(|i| {
+                    if method == "char_at" {
+                        s.chars().nth(i).map(ComptimeValue::Char)
+                    } else {
+                        s.as_bytes().get(i).map(|&b| ComptimeValue::U8(b))
+                    }
+                });
+                Ok(match found {
+                    Some(v) => ComptimeValue::wrapper("Option", "Some", Some(v)),
+                    None => ComptimeValue::wrapper("Option", "None", None),
+                })
+            }
+            // Malformed input is an `Err` value, not a comptime error — the
+            // caller decides whether to unwrap, `try`, or fall back.
+            "parse_int" | "parse_float" => {
+                let ComptimeValue::String(s) = obj else {
+                    return Err(ComptimeError::TypeMismatch {
+                        expected: "String".to_string(),
+                        found: obj.type_name().to_string(),
+                    });
+                };
+                let text = s.trim();
+                let parsed = if method == "parse_int" {
+                    let radix = match args.first() {
+                        Some(arg) => arg.as_i64().ok_or_else(|| ComptimeError::TypeMismatch {
+                            expected: "integer radix".to_string(),
+                            found: arg.type_name().to_string(),
+                        })?,
+                        None => 10,
+                    };
+                    if !(2..=36).contains(&radix) {
+                        return Err(ComptimeError::Panic(format!(
+                            "parse_int radix must be between 2 and 36, got {}",
+                            radix
+                        )));
+                    }
+                    i64::from_str_radix(text, radix as u32)
+                        .map(ComptimeValue::I64)
+                        .map_err(|_| format!("invalid integer `{}`", s))
+                } else {
+                    text.parse::<f64>()
+                        .map(ComptimeValue::F64)
+                        .map_err(|_| format!("invalid float `{}`", s))
+                };
+                Ok(match parsed {
+                    Ok(v) => ComptimeValue::wrapper("Result", "Ok", Some(v)),
+                    Err(msg) => ComptimeValue::wrapper("Result", "Err", Some(ComptimeValue::String(msg))),
+                })
+            }
             "len" => {
                 match obj {
                     ComptimeValue::String(s) => Ok(ComptimeValue::I64(s.len() as i64)),
@@ -1970,6 +3259,20 @@ impl ComptimeInterpreter {
                     }),
                 }
             }
+            // `(index, element)` pairs; a string yields its chars.
+            "enumerate" if args.is_empty() => {
+                let items = match obj {
+                    ComptimeValue::String(s) => s.chars().map(ComptimeValue::Char).collect(),
+                    ComptimeValue::Array(arr) => arr.clone(),
+                    _ => return Err(ComptimeError::TypeMismatch {
+                        expected: "String or Array".to_string(),
+                        found: obj.type_name().to_string(),
+                    }),
+                };
+                Ok(ComptimeValue::Array(items.into_iter().enumerate()
+                    .map(|(i, v)| ComptimeValue::Tuple(vec![ComptimeValue::I64(i as i64), v]))
+                    .collect()))
+            }
             _ => Err(ComptimeError::NotSupported(format!("method {} on {}", method, obj.type_name()))),
         }
     }
@@ -2009,6 +3312,11 @@ impl ComptimeInterpreter {
             expected: "1 argument".to_string(),
             found: "0 arguments".to_string(),
         })?;
+        // Division and remainder by zero are compile errors for integers and
+        // floats alike — a comptime float never silently becomes inf or NaN.
+        if matches!(op, CtOp::Div | CtOp::Rem) && arg.is_numeric_zero() {
+            return Err(ComptimeError::DivisionByZero);
+        }
         if let Some(res) = self.ct_int_binop(obj, arg, op) {
             return res;
         }
@@ -2022,6 +3330,51 @@ impl ComptimeInterpreter {
         }
     }
 
+    /// `min`/`max` on two integers of compatible width, or two floats.
+    fn ct_min_max(&self, obj: &ComptimeValue, args: &[ComptimeValue], max: bool) -> ComptimeResult<ComptimeValue> {
+        let arg = args.first().ok_or_else(|| ComptimeError::TypeMismatch {
+            expected: "1 argument".to_string(),
+            found: "0 arguments".to_string(),
+        })?;
+        if let (Some((a, ka)), Some((b, kb))) = (obj.as_int(), arg.as_int()) {
+            let r = if max { a.max(b) } else { a.min(b) };
+            return Ok(ka.unify(kb).make(r));
+        }
+        match (obj, arg) {
+            (ComptimeValue::F32(a), ComptimeValue::F32(b)) => {
+                Ok(ComptimeValue::F32(if max { a.max(*b) } else { a.min(*b) }))
+            }
+            _ => match (obj.as_f64(), arg.as_f64()) {
+                (Some(a), Some(b)) => Ok(ComptimeValue::F64(if max { a.max(b) } else { a.min(b) })),
+                _ => Err(ComptimeError::TypeMismatch {
+                    expected: "matching numeric types".to_string(),
+                    found: format!("{} and {}", obj.type_name(), arg.type_name()),
+                }),
+            },
+        }
+    }
+
+    /// `pow`: integer base and exponent stay integer and are overflow-checked;
+    /// a float base takes an integer or float exponent.
+    fn ct_pow(&self, obj: &ComptimeValue, args: &[ComptimeValue]) -> ComptimeResult<ComptimeValue> {
+        let arg = args.first().ok_or_else(|| ComptimeError::TypeMismatch {
+            expected: "1 argument".to_string(),
+            found: "0 arguments".to_string(),
+        })?;
+        if let (Some((base, kind)), Some((exp, _))) = (obj.as_int(), arg.as_int()) {
+            return ct_checked_pow(kind, base, exp);
+        }
+        let exp = arg.as_f64().or_else(|| arg.as_int().map(|(e, _)| e as f64));
+        match (obj, exp) {
+            (ComptimeValue::F32(b), Some(e)) => Ok(ComptimeValue::F32(b.powf(e as f32))),
+            (ComptimeValue::F64(b), Some(e)) => Ok(ComptimeValue::F64(b.powf(e))),
+            _ => Err(ComptimeError::TypeMismatch {
+                expected: "numeric base and exponent".to_string(),
+                found: format!("{} and {}", obj.type_name(), arg.type_name()),
+            }),
+        }
+    }
+
     /// Integer-only operations (bitwise, shifts), width-aware.
     fn ct_int_only(
         &self,
@@ -2077,14 +3430,22 @@ impl ComptimeInterpreter {
     fn pattern_matches(&mut self, pattern: &Pattern, value: &ComptimeValue) -> ComptimeResult<bool> {
         match pattern {
             Pattern::Wildcard => Ok(true),
+            Pattern::Ident(name) if self.is_variant_pattern(name) => Ok(matches!(
+                value,
+                ComptimeValue::Enum { name: enum_name, variant, .. }
+                    if Self::variant_matches(name, enum_name, variant)
+            )),
             Pattern::Ident(_) => Ok(true), // Binds anything
             Pattern::Literal(lit) => {
                 let lit_val = self.eval_expr(lit)?;
-                Ok(lit_val == *value)
+                Ok(match (lit_val.as_int(), value.as_int()) {
+                    (Some((l, _)), Some((v, _))) => l == v,
+                    _ => lit_val == *value,
+                })
             }
             Pattern::Constructor { name, fields } => {
-                if let ComptimeValue::Enum { variant, data, .. } = value {
-                    if variant != name {
+                if let ComptimeValue::Enum { name: enum_name, variant, data } = value {
+                    if !Self::variant_matches(name, enum_name, variant) {
                         return Ok(false);
                     }
                     // Check fields match
@@ -2155,19 +3516,19 @@ impl ComptimeInterpreter {
                 Ok(false)
             }
             Pattern::Range { start, end } => {
+                // `start..=end`, both bounds inclusive. Integers compare by
+                // value across widths: an unsuffixed bound is i64 at comptime
+                // but the scrutinee may be a u8 or i32.
                 let start_val = self.eval_expr(start)?;
                 let end_val = self.eval_expr(end)?;
                 Ok(match (value, &start_val, &end_val) {
                     (ComptimeValue::Char(c), ComptimeValue::Char(s), ComptimeValue::Char(e)) => {
-                        c >= s && c <= e
+                        s <= c && c <= e
                     }
-                    (ComptimeValue::I64(n), ComptimeValue::I64(s), ComptimeValue::I64(e)) => {
-                        n >= s && n <= e
-                    }
-                    (ComptimeValue::I32(n), ComptimeValue::I32(s), ComptimeValue::I32(e)) => {
-                        n >= s && n <= e
-                    }
-                    _ => false,
+                    _ => match (value.as_int(), start_val.as_int(), end_val.as_int()) {
+                        (Some((n, _)), Some((s, _)), Some((e, _))) => s <= n && n <= e,
+                        _ => false,
+                    },
                 })
             }
             Pattern::TypePat { ty_name, .. } => {
@@ -2180,6 +3541,7 @@ impl ComptimeInterpreter {
     fn bind_pattern(&mut self, pattern: &Pattern, value: &ComptimeValue) -> ComptimeResult<()> {
         match pattern {
             Pattern::Wildcard => Ok(()),
+            Pattern::Ident(name) if self.is_variant_pattern(name) => Ok(()),
             Pattern::Ident(name) => {
                 self.env.define(name.clone(), value.clone());
                 Ok(())
@@ -2251,6 +3613,9 @@ impl Default for ComptimeInterpreter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rask_ast::decl::{Field, StructDecl};
+    use rask_ast::expr::{ArgMode, CallArg, MatchArm};
+    use rask_ast::{NodeId, Span};
 
     #[test]
     fn test_arithmetic() {
@@ -2260,4 +3625,1473 @@ mod tests {
         // For now, just verify the interpreter can be created
         assert_eq!(interp.env.branch_quota, 1_000); // CT35: default 1,000
     }
+
+    fn int(v: i64) -> Expr {
+        Expr { id: NodeId(0), kind: ExprKind::Int(v, None), span: Span::new(0, 0) }
+    }
+
+    fn method(object: Expr, method: &str, arg: Expr) -> Expr {
+        Expr {
+            id: NodeId(0),
+            kind: ExprKind::MethodCall {
+                object: Box::new(object),
+                method: method.to_string(),
+                type_args: None,
+                args: vec![CallArg { name: None, mode: ArgMode::Default, expr: arg }],
+            },
+            span: Span::new(0, 0),
+        }
+    }
+
+    #[test]
+    fn eval_const_usize_arithmetic_size() {
+        // [0; 4 * 8 + 2], desugared
+        let size = method(method(int(4), "mul", int(8)), "add", int(2));
+        let mut interp = ComptimeInterpreter::new();
+        assert_eq!(interp.eval_const_usize(&size).unwrap(), 34);
+    }
+
+    #[test]
+    fn eval_const_usize_sees_defined_consts() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.define_const("N".to_string(), ComptimeValue::I64(16));
+        let ident = Expr { id: NodeId(0), kind: ExprKind::Ident("N".to_string()), span: Span::new(0, 0) };
+        assert_eq!(interp.eval_const_usize(&method(ident, "mul", int(2))).unwrap(), 32);
+    }
+
+    #[test]
+    fn eval_const_usize_rejects_negative() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_const_usize(&method(int(2), "sub", int(5)));
+        assert!(matches!(r, Err(ComptimeError::NegativeSize(-3))), "{:?}", r);
+    }
+
+    #[test]
+    fn eval_const_usize_rejects_unknown_name() {
+        let mut interp = ComptimeInterpreter::new();
+        let ident = Expr { id: NodeId(0), kind: ExprKind::Ident("n".to_string()), span: Span::new(0, 0) };
+        let r = interp.eval_const_usize(&ident);
+        assert!(matches!(r, Err(ComptimeError::UndefinedVariable(_))), "{:?}", r);
+    }
+
+    #[test]
+    fn int_rem_by_zero_errors() {
+        let interp = ComptimeInterpreter::new();
+        let r = interp.call_primitive_method(&ComptimeValue::I32(5), "rem", &[ComptimeValue::I32(0)]);
+        assert!(matches!(r, Err(ComptimeError::DivisionByZero)), "5 % 0: {:?}", r);
+    }
+
+    #[test]
+    fn float_rem_by_zero_errors() {
+        let interp = ComptimeInterpreter::new();
+        let r = interp.call_primitive_method(&ComptimeValue::F64(5.0), "rem", &[ComptimeValue::F64(0.0)]);
+        assert!(matches!(r, Err(ComptimeError::DivisionByZero)), "5.0 % 0.0: {:?}", r);
+        let r = interp.call_primitive_method(&ComptimeValue::F32(5.0), "rem", &[ComptimeValue::F32(-0.0)]);
+        assert!(matches!(r, Err(ComptimeError::DivisionByZero)), "5.0 % -0.0: {:?}", r);
+    }
+
+    #[test]
+    fn float_div_by_zero_errors() {
+        let interp = ComptimeInterpreter::new();
+        let r = interp.call_primitive_method(&ComptimeValue::F64(5.0), "div", &[ComptimeValue::F64(0.0)]);
+        assert!(matches!(r, Err(ComptimeError::DivisionByZero)), "5.0 / 0.0: {:?}", r);
+    }
+
+    #[test]
+    fn float_rem_by_nonzero_evaluates() {
+        let interp = ComptimeInterpreter::new();
+        let r = interp.call_primitive_method(&ComptimeValue::F64(5.5), "rem", &[ComptimeValue::F64(2.0)]);
+        assert!(matches!(r, Ok(ComptimeValue::F64(v)) if v == 1.5), "5.5 % 2.0: {:?}", r);
+    }
+
+    fn expr(kind: ExprKind) -> Expr {
+        Expr { id: NodeId(0), kind, span: Span::new(0, 0) }
+    }
+
+    fn call(name: &str, arg: Expr) -> Expr {
+        expr(ExprKind::Call {
+            func: Box::new(expr(ExprKind::Ident(name.to_string()))),
+            args: vec![CallArg { name: None, mode: ArgMode::Default, expr: arg }],
+        })
+    }
+
+    fn unwrap(inner: Expr) -> Expr {
+        expr(ExprKind::Unwrap { expr: Box::new(inner), message: None })
+    }
+
+    fn try_(inner: Expr) -> Expr {
+        expr(ExprKind::Try { expr: Box::new(inner), else_clause: None })
+    }
+
+    /// `const x = <init>` followed by `return 99`.
+    fn const_then_return(init: Expr) -> Vec<Stmt> {
+        let span = Span::new(0, 0);
+        vec![
+            Stmt {
+                id: NodeId(0),
+                kind: StmtKind::Const { name: "x".to_string(), name_span: span, ty: None, init },
+                span,
+            },
+            Stmt { id: NodeId(0), kind: StmtKind::Return(Some(int(99))), span },
+        ]
+    }
+
+    #[test]
+    fn unwrap_some_yields_payload() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&unwrap(call("Some", int(7))));
+        assert!(matches!(r, Ok(ComptimeValue::I64(7))), "{:?}", r);
+    }
+
+    #[test]
+    fn unwrap_none_panics() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&unwrap(expr(ExprKind::None)));
+        assert!(matches!(r, Err(ComptimeError::Panic(_))), "{:?}", r);
+    }
+
+    #[test]
+    fn try_ok_continues_with_payload() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_block_to_value(&const_then_return(try_(call("Ok", int(3)))));
+        assert!(matches!(r, Ok(ComptimeValue::I64(99))), "{:?}", r);
+    }
+
+    #[test]
+    fn try_err_returns_the_error() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_block_to_value(&const_then_return(try_(call("Err", int(3)))));
+        let expected = ComptimeValue::wrapper("Result", "Err", Some(ComptimeValue::I64(3)));
+        assert!(matches!(&r, Ok(v) if *v == expected), "{:?}", r);
+    }
+
+    #[test]
+    fn loop_expression_yields_break_value() {
+        // const x = loop { break 42 }; return x
+        let span = Span::new(0, 0);
+        let stmt = |kind| Stmt { id: NodeId(0), kind, span };
+        let lp = expr(ExprKind::Loop {
+            label: None,
+            body: vec![stmt(StmtKind::Break { label: None, value: Some(int(42)) })],
+        });
+        let body = vec![
+            stmt(StmtKind::Const { name: "x".to_string(), name_span: span, ty: None, init: lp }),
+            stmt(StmtKind::Return(Some(ident("x")))),
+        ];
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_block_to_value(&body);
+        assert!(matches!(r, Ok(ComptimeValue::I64(42))), "{:?}", r);
+    }
+
+    /// `const n = <scrutinee> is Some(v) else { return -1 }` then `return (v, n)`.
+    fn guard_some(scrutinee: Expr) -> Vec<Stmt> {
+        let span = Span::new(0, 0);
+        let stmt = |kind| Stmt { id: NodeId(0), kind, span };
+        let guard = expr(ExprKind::GuardPattern {
+            expr: Box::new(scrutinee),
+            pattern: Pattern::Constructor { name: "Some".to_string(), fields: vec![Pattern::Ident("v".to_string())] },
+            else_branch: Box::new(expr(ExprKind::Block(vec![stmt(StmtKind::Return(Some(int(-1))))]))),
+        });
+        vec![
+            stmt(StmtKind::Const { name: "n".to_string(), name_span: span, ty: None, init: guard }),
+            stmt(StmtKind::Return(Some(expr(ExprKind::Tuple(vec![ident("v"), ident("n")]))))),
+        ]
+    }
+
+    #[test]
+    fn guard_pattern_binding_visible_after_guard() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_block_to_value(&guard_some(call("Some", int(7))));
+        assert_eq!(r.unwrap(), ComptimeValue::Tuple(vec![ComptimeValue::I64(7), ComptimeValue::I64(7)]));
+        let r = interp.eval_block_to_value(&guard_some(expr(ExprKind::None)));
+        assert_eq!(r.unwrap(), ComptimeValue::I64(-1));
+    }
+
+    fn point(x: i64, y: i64) -> ComptimeValue {
+        let mut fields = HashMap::new();
+        fields.insert("x".to_string(), ComptimeValue::I64(x));
+        fields.insert("y".to_string(), ComptimeValue::I64(y));
+        ComptimeValue::Struct { name: "Point".to_string(), fields }
+    }
+
+    fn str_val(s: &str) -> ComptimeValue {
+        ComptimeValue::String(s.to_string())
+    }
+
+    #[test]
+    fn map_insert_duplicate_key_overwrites_in_place() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("m".to_string(), ComptimeValue::Map(Vec::new()));
+        for (k, v) in [("a", 1), ("b", 2)] {
+            interp.call_mutating_vec_method("m", "insert", &[str_val(k), ComptimeValue::I64(v)]).unwrap();
+        }
+        let old = interp.call_mutating_vec_method("m", "insert", &[str_val("a"), ComptimeValue::I64(10)]);
+        assert_eq!(old.unwrap(), ComptimeValue::wrapper("Option", "Some", Some(ComptimeValue::I64(1))));
+        let expected = vec![
+            (str_val("a"), ComptimeValue::I64(10)),
+            (str_val("b"), ComptimeValue::I64(2)),
+        ];
+        assert!(matches!(interp.env.get("m"), Some(ComptimeValue::Map(e)) if *e == expected));
+    }
+
+    #[test]
+    fn map_contains_key_compares_struct_keys_by_value() {
+        let interp = ComptimeInterpreter::new();
+        let map = ComptimeValue::Map(vec![(point(1, 2), str_val("here"))]);
+        let hit = interp.call_primitive_method(&map, "contains_key", &[point(1, 2)]);
+        assert_eq!(hit.unwrap(), ComptimeValue::Bool(true));
+        let miss = interp.call_primitive_method(&map, "contains_key", &[point(2, 1)]);
+        assert_eq!(miss.unwrap(), ComptimeValue::Bool(false));
+        let got = interp.call_primitive_method(&map, "get", &[point(1, 2)]);
+        assert_eq!(got.unwrap(), ComptimeValue::wrapper("Option", "Some", Some(str_val("here"))));
+    }
+
+    #[test]
+    fn map_closure_key_errors() {
+        let interp = ComptimeInterpreter::new();
+        let map = ComptimeValue::Map(Vec::new());
+        let closure = ComptimeValue::Closure { params: vec![], body: Box::new(int(0)), captures: vec![] };
+        let r = interp.call_primitive_method(&map, "contains_key", &[closure]);
+        assert!(matches!(r, Err(ComptimeError::NotSupported(_))), "{:?}", r);
+    }
+
+    #[test]
+    fn map_equality_ignores_insertion_order() {
+        let interp = ComptimeInterpreter::new();
+        let a = ComptimeValue::Map(vec![(str_val("x"), ComptimeValue::I64(1)), (str_val("y"), ComptimeValue::I64(2))]);
+        let b = ComptimeValue::Map(vec![(str_val("y"), ComptimeValue::I64(2)), (str_val("x"), ComptimeValue::I64(1))]);
+        assert_eq!(interp.call_primitive_method(&a, "eq", &[b]).unwrap(), ComptimeValue::Bool(true));
+        let arr = ComptimeValue::Array(vec![ComptimeValue::I64(1)]);
+        assert_eq!(interp.call_primitive_method(&arr, "eq", &[arr.clone()]).unwrap(), ComptimeValue::Bool(true));
+    }
+
+    /// Collects `print` output for assertions.
+    #[derive(Clone, Default)]
+    struct SharedBuf(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+    impl std::io::Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn str_lit(s: &str) -> Expr {
+        expr(ExprKind::String(s.to_string()))
+    }
+
+    #[test]
+    fn println_is_silent_by_default() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&call("println", str_lit("hidden")));
+        assert_eq!(r.unwrap(), ComptimeValue::Unit);
+    }
+
+    #[test]
+    fn println_writes_when_prints_enabled() {
+        let buf = SharedBuf::default();
+        let mut interp = ComptimeInterpreter::new();
+        interp.emit_prints_to(Box::new(buf.clone()));
+        interp.eval_expr(&call("println", str_lit("size"))).unwrap();
+        interp.eval_expr(&call("println", int(4))).unwrap();
+        interp.eval_expr(&call("print", str_lit("done"))).unwrap();
+        assert_eq!(String::from_utf8(buf.0.lock().unwrap().clone()).unwrap(), "size\n4\ndone");
+    }
+
+    fn ident(name: &str) -> Expr {
+        expr(ExprKind::Ident(name.to_string()))
+    }
+
+    /// Defines `f` as a one-parameter closure over the current scopes.
+    fn define_closure(interp: &mut ComptimeInterpreter, param: &str, body: Expr) {
+        let captures = interp.env.scopes.clone();
+        interp.env.define("f".to_string(), ComptimeValue::Closure {
+            params: vec![param.to_string()],
+            body: Box::new(body),
+            captures,
+        });
+    }
+
+    #[test]
+    fn closure_mutating_captured_vec_errors() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("v".to_string(), ComptimeValue::Array(Vec::new()));
+        define_closure(&mut interp, "x", method(ident("v"), "push", ident("x")));
+        let r = interp.eval_expr(&call("f", int(1)));
+        assert!(matches!(r, Err(ComptimeError::CapturedCollectionMutation(ref n)) if n == "v"), "{:?}", r);
+        assert!(r.unwrap_err().is_hard());
+        assert_eq!(interp.env.get("v"), Some(&ComptimeValue::Array(Vec::new())));
+    }
+
+    #[test]
+    fn closure_mutating_its_parameter_is_allowed() {
+        let mut interp = ComptimeInterpreter::new();
+        define_closure(&mut interp, "w", method(ident("w"), "push", int(1)));
+        let r = interp.eval_expr(&call("f", expr(ExprKind::Array(Vec::new()))));
+        assert_eq!(r.unwrap(), ComptimeValue::Unit);
+    }
+
+    #[test]
+    fn value_nested_past_depth_limit_errors() {
+        let mut interp = ComptimeInterpreter::new();
+        let mut deep = ComptimeValue::I64(0);
+        for _ in 0..MAX_VALUE_DEPTH {
+            deep = ComptimeValue::Array(vec![deep]);
+        }
+        interp.env.define("deep".to_string(), deep);
+        let r = interp.eval_expr(&expr(ExprKind::Tuple(vec![ident("deep"), int(1)])));
+        assert!(matches!(r, Err(ComptimeError::ValueTooDeep(MAX_VALUE_DEPTH))), "{:?}", r);
+
+        let r = interp.eval_expr(&call("Some", ident("deep")));
+        assert!(matches!(r, Err(ComptimeError::ValueTooDeep(_))), "{:?}", r);
+    }
+
+    #[test]
+    fn construction_counts_toward_memory_limit() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.set_memory_limit(4 * std::mem::size_of::<ComptimeValue>());
+        let small = expr(ExprKind::Array(vec![int(1), int(2)]));
+        assert!(interp.eval_expr(&small).is_ok());
+        // Charges accumulate within one evaluation
+        let r = interp.eval_expr(&expr(ExprKind::Array(vec![int(1), int(2), int(3)])));
+        assert!(matches!(r, Err(ComptimeError::MemoryLimitExceeded)), "{:?}", r);
+
+        interp.reset_branch_count();
+        assert!(interp.eval_expr(&small).is_ok());
+    }
+
+    fn point_decl() -> Vec<Decl> {
+        let field = |name: &str, visibility| Field {
+            name: name.to_string(),
+            name_span: Span::new(0, 0),
+            ty: "i32".to_string(),
+            visibility,
+        };
+        vec![Decl {
+            id: NodeId(0),
+            kind: DeclKind::Struct(StructDecl {
+                name: "Point".to_string(),
+                type_params: vec![],
+                fields: vec![field("x", FieldVisibility::Public), field("y", FieldVisibility::Package)],
+                methods: vec![],
+                is_pub: true,
+                attrs: vec![],
+                doc: None,
+            }),
+            span: Span::new(0, 0),
+        }]
+    }
+
+    fn point_lit(fields: &[(&str, i64)]) -> Expr {
+        expr(ExprKind::StructLit {
+            name: "Point".to_string(),
+            fields: fields
+                .iter()
+                .map(|(n, v)| rask_ast::expr::FieldInit { name: n.to_string(), value: int(*v) })
+                .collect(),
+            spread: None,
+        })
+    }
+
+    #[test]
+    fn struct_literal_with_misspelled_field_fails_at_construction() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.register_types(&point_decl());
+        let r = interp.eval_expr(&point_lit(&[("x", 1), ("yy", 2)]));
+        assert!(
+            matches!(&r, Err(ComptimeError::NoSuchField { field, .. }) if field == "yy"),
+            "{:?}", r
+        );
+    }
+
+    #[test]
+    fn struct_literal_missing_declared_field_fails() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.register_types(&point_decl());
+        let r = interp.eval_expr(&point_lit(&[("x", 1)]));
+        assert!(
+            matches!(&r, Err(ComptimeError::MissingField { field, .. }) if field == "y"),
+            "{:?}", r
+        );
+        assert!(interp.eval_expr(&point_lit(&[("x", 1), ("y", 2)])).is_ok());
+    }
+
+    #[test]
+    fn unknown_enum_variant_fails_at_construction() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.enum_variants.insert("Color".to_string(), vec!["Red".to_string(), "Green".to_string()]);
+        let field = |v: &str| expr(ExprKind::Field { object: Box::new(ident("Color")), field: v.to_string() });
+        assert_eq!(
+            interp.eval_expr(&field("Red")).unwrap(),
+            ComptimeValue::Enum { name: "Color".to_string(), variant: "Red".to_string(), data: None }
+        );
+        let r = interp.eval_expr(&field("Blue"));
+        assert!(
+            matches!(&r, Err(ComptimeError::UnknownVariant { variant, .. }) if variant == "Blue"),
+            "{:?}", r
+        );
+    }
+
+    fn vec_from(arg: Expr) -> Expr {
+        expr(ExprKind::MethodCall {
+            object: Box::new(ident("Vec")),
+            method: "from".to_string(),
+            type_args: None,
+            args: vec![CallArg { name: None, mode: ArgMode::Default, expr: arg }],
+        })
+    }
+
+    #[test]
+    fn vec_from_homogeneous_tuple() {
+        let mut interp = ComptimeInterpreter::new();
+        let tuple = expr(ExprKind::Tuple(vec![int(1), int(2), int(3)]));
+        assert_eq!(
+            interp.eval_expr(&vec_from(tuple)).unwrap(),
+            ComptimeValue::Array(vec![ComptimeValue::I64(1), ComptimeValue::I64(2), ComptimeValue::I64(3)])
+        );
+    }
+
+    #[test]
+    fn vec_from_heterogeneous_tuple_is_type_mismatch() {
+        let mut interp = ComptimeInterpreter::new();
+        let tuple = expr(ExprKind::Tuple(vec![int(1), expr(ExprKind::Bool(true))]));
+        let r = interp.eval_expr(&vec_from(tuple));
+        assert!(matches!(r, Err(ComptimeError::TypeMismatch { .. })), "{:?}", r);
+    }
+
+    #[test]
+    fn vec_from_range_materializes() {
+        let mut interp = ComptimeInterpreter::new();
+        let range = expr(ExprKind::Range {
+            start: Some(Box::new(int(0))),
+            end: Some(Box::new(int(5))),
+            inclusive: false,
+        });
+        assert_eq!(
+            interp.eval_expr(&vec_from(range)).unwrap(),
+            ComptimeValue::Array((0..5).map(ComptimeValue::I64).collect())
+        );
+    }
+
+    #[test]
+    fn char_classification_at_comptime() {
+        let interp = ComptimeInterpreter::new();
+        let call = |c: char, m: &str| interp.call_primitive_method(&ComptimeValue::Char(c), m, &[]).unwrap();
+        assert_eq!(call('7', "is_digit"), ComptimeValue::Bool(true));
+        assert_eq!(call('x', "is_digit"), ComptimeValue::Bool(false));
+        assert_eq!(call('x', "is_alphabetic"), ComptimeValue::Bool(true));
+        assert_eq!(call('\t', "is_whitespace"), ComptimeValue::Bool(true));
+        assert_eq!(call('q', "to_uppercase"), ComptimeValue::Char('Q'));
+        assert_eq!(call('Q', "to_lowercase"), ComptimeValue::Char('q'));
+    }
+
+    #[test]
+    fn char_to_digit_returns_option() {
+        let interp = ComptimeInterpreter::new();
+        let digit = |c: char, radix: i64| {
+            interp.call_primitive_method(&ComptimeValue::Char(c), "to_digit", &[ComptimeValue::I64(radix)])
+        };
+        assert_eq!(
+            digit('7', 10).unwrap(),
+            ComptimeValue::wrapper("Option", "Some", Some(ComptimeValue::U32(7)))
+        );
+        assert_eq!(
+            digit('f', 16).unwrap(),
+            ComptimeValue::wrapper("Option", "Some", Some(ComptimeValue::U32(15)))
+        );
+        assert_eq!(digit('g', 16).unwrap(), ComptimeValue::wrapper("Option", "None", None));
+        assert!(matches!(digit('1', 99), Err(ComptimeError::Panic(_))));
+    }
+
+    #[test]
+    fn payload_variant_constructor_checks_variant() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.enum_variants.insert("Shape".to_string(), vec!["Circle".to_string()]);
+        let ctor = |v: &str| method(ident("Shape"), v, int(2));
+        assert_eq!(
+            interp.eval_expr(&ctor("Circle")).unwrap(),
+            ComptimeValue::Enum {
+                name: "Shape".to_string(),
+                variant: "Circle".to_string(),
+                data: Some(Box::new(ComptimeValue::I64(2))),
+            }
+        );
+        let r = interp.eval_expr(&ctor("Square"));
+        assert!(matches!(r, Err(ComptimeError::UnknownVariant { .. })), "{:?}", r);
+    }
+
+    fn reflect(method: &str, ty: &str, args: Vec<Expr>) -> Expr {
+        expr(ExprKind::MethodCall {
+            object: Box::new(ident("reflect")),
+            method: method.to_string(),
+            type_args: Some(vec![ty.to_string()]),
+            args: args.into_iter().map(|e| CallArg { name: None, mode: ArgMode::Default, expr: e }).collect(),
+        })
+    }
+
+    #[test]
+    fn reflect_fields_lists_declared_fields() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.register_types(&point_decl());
+        let ComptimeValue::Array(infos) = interp.eval_expr(&reflect("fields", "Point", vec![])).unwrap() else {
+            panic!("expected an array of FieldInfo");
+        };
+        let field = |info: &ComptimeValue, key: &str| match info {
+            ComptimeValue::Struct { fields, .. } => fields[key].clone(),
+            other => panic!("expected FieldInfo, got {:?}", other),
+        };
+        let names: Vec<_> = infos.iter().map(|i| field(i, "name")).collect();
+        assert_eq!(names, vec![ComptimeValue::String("x".into()), ComptimeValue::String("y".into())]);
+        assert_eq!(field(&infos[0], "type_name"), ComptimeValue::String("i32".into()));
+        assert_eq!(field(&infos[0], "is_public"), ComptimeValue::Bool(true));
+        assert_eq!(field(&infos[1], "is_public"), ComptimeValue::Bool(false));
+    }
+
+    #[test]
+    fn reflect_name_and_has_field() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.register_types(&point_decl());
+        assert_eq!(
+            interp.eval_expr(&reflect("name_of", "Point", vec![])).unwrap(),
+            ComptimeValue::String("Point".into())
+        );
+        let has = |interp: &mut ComptimeInterpreter, name: &str| {
+            interp.eval_expr(&reflect("has_field", "Point", vec![expr(ExprKind::String(name.into()))])).unwrap()
+        };
+        assert_eq!(has(&mut interp, "y"), ComptimeValue::Bool(true));
+        assert_eq!(has(&mut interp, "z"), ComptimeValue::Bool(false));
+        let r = interp.eval_expr(&reflect("fields", "Unknown", vec![]));
+        assert!(matches!(r, Err(ComptimeError::NotAStruct(_))), "{:?}", r);
+    }
+
+    #[test]
+    fn serialize_honors_target_endianness() {
+        let arr = ComptimeValue::Array(vec![ComptimeValue::U32(0x0102_0304), ComptimeValue::I16(-2)]);
+        assert_eq!(
+            arr.serialize(Endian::Little),
+            Some(vec![0x04, 0x03, 0x02, 0x01, 0xfe, 0xff])
+        );
+        assert_eq!(
+            arr.serialize(Endian::Big),
+            Some(vec![0x01, 0x02, 0x03, 0x04, 0xff, 0xfe])
+        );
+        let cfg = |target: &str| CfgConfig::from_target(target, "debug", vec![]).endian();
+        assert_eq!(cfg("x86_64-linux-gnu"), Endian::Little);
+        assert_eq!(cfg("s390x-linux-gnu"), Endian::Big);
+        assert_eq!(cfg("aarch64_be-linux-gnu"), Endian::Big);
+    }
+
+    #[test]
+    fn struct_value_fields_follow_declaration_order() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.register_types(&point_decl());
+        let call = expr(ExprKind::MethodCall {
+            object: Box::new(point_lit(&[("y", 2), ("x", 1)])),
+            method: "fields".to_string(),
+            type_args: None,
+            args: vec![],
+        });
+        let pair = |name: &str, v: i64| {
+            ComptimeValue::Tuple(vec![ComptimeValue::String(name.into()), ComptimeValue::I64(v)])
+        };
+        assert_eq!(
+            interp.eval_expr(&call).unwrap(),
+            ComptimeValue::Array(vec![pair("x", 1), pair("y", 2)])
+        );
+    }
+
+    fn call2(name: &str, a: Expr, b: Expr) -> Expr {
+        expr(ExprKind::Call {
+            func: Box::new(expr(ExprKind::Ident(name.to_string()))),
+            args: vec![
+                CallArg { name: None, mode: ArgMode::Default, expr: a },
+                CallArg { name: None, mode: ArgMode::Default, expr: b },
+            ],
+        })
+    }
+
+    #[test]
+    fn numeric_builtins_evaluate_at_comptime() {
+        let mut interp = ComptimeInterpreter::new();
+        assert_eq!(interp.eval_expr(&call2("pow", int(2), int(10))).unwrap(), ComptimeValue::I64(1024));
+        assert_eq!(interp.eval_expr(&call2("max", int(3), int(7))).unwrap(), ComptimeValue::I64(7));
+        assert_eq!(interp.eval_expr(&call2("min", int(3), int(7))).unwrap(), ComptimeValue::I64(3));
+        assert_eq!(interp.eval_expr(&call("abs", int(-4))).unwrap(), ComptimeValue::I64(4));
+        // Method form agrees with the free function
+        assert_eq!(interp.eval_expr(&method(int(3), "max", int(7))).unwrap(), ComptimeValue::I64(7));
+    }
+
+    #[test]
+    fn float_pow_and_max() {
+        let interp = ComptimeInterpreter::new();
+        let r = interp.call_primitive_method(&ComptimeValue::F64(2.0), "pow", &[ComptimeValue::I64(-1)]);
+        assert_eq!(r.unwrap(), ComptimeValue::F64(0.5));
+        let r = interp.call_primitive_method(&ComptimeValue::F64(1.5), "max", &[ComptimeValue::F64(2.5)]);
+        assert_eq!(r.unwrap(), ComptimeValue::F64(2.5));
+    }
+
+    #[test]
+    fn integer_pow_overflow_is_error() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&call2("pow", int(2), int(63)));
+        assert!(matches!(r, Err(ComptimeError::IntegerOverflow(_))), "2 ** 63: {:?}", r);
+        let r = interp.call_primitive_method(&ComptimeValue::U8(2), "pow", &[ComptimeValue::I64(8)]);
+        assert!(matches!(r, Err(ComptimeError::IntegerOverflow(_))), "2u8 ** 8: {:?}", r);
+        let r = interp.call_primitive_method(&ComptimeValue::I8(-128), "abs", &[]);
+        assert!(matches!(r, Err(ComptimeError::IntegerOverflow(_))), "abs(-128i8): {:?}", r);
+    }
+
+    #[test]
+    fn integer_pow_negative_exponent_is_error() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&call2("pow", int(2), int(-1)));
+        assert!(matches!(r, Err(ComptimeError::NegativeExponent(-1))), "{:?}", r);
+        assert!(r.unwrap_err().is_hard());
+    }
+
+    fn ints(vals: &[i64]) -> ComptimeValue {
+        ComptimeValue::Array(vals.iter().map(|v| ComptimeValue::I64(*v)).collect())
+    }
+
+    #[test]
+    fn sort_and_reverse_integer_array() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("v".to_string(), ints(&[3, -1, 2, 2, 0]));
+        interp.call_mutating_vec_method("v", "sort", &[]).unwrap();
+        assert_eq!(interp.env.get("v"), Some(&ints(&[-1, 0, 2, 2, 3])));
+        interp.call_mutating_vec_method("v", "reverse", &[]).unwrap();
+        assert_eq!(interp.env.get("v"), Some(&ints(&[3, 2, 2, 0, -1])));
+    }
+
+    #[test]
+    fn sort_rejects_incomparable_elements() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("v".to_string(), ComptimeValue::Array(vec![ComptimeValue::I64(1), point(0, 0)]));
+        let r = interp.call_mutating_vec_method("v", "sort", &[]);
+        assert!(matches!(r, Err(ComptimeError::TypeMismatch { .. })), "{:?}", r);
+    }
+
+    #[test]
+    fn map_applies_closure_to_each_element() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("v".to_string(), ints(&[1, 2, 3]));
+        define_closure(&mut interp, "x", method(ident("x"), "mul", int(10)));
+        let r = interp.eval_expr(&method(ident("v"), "map", ident("f")));
+        assert_eq!(r.unwrap(), ints(&[10, 20, 30]));
+        // The source array is untouched
+        assert_eq!(interp.env.get("v"), Some(&ints(&[1, 2, 3])));
+    }
+
+    fn range(start: i64, end: i64, inclusive: bool) -> Expr {
+        expr(ExprKind::Range {
+            start: Some(Box::new(int(start))),
+            end: Some(Box::new(int(end))),
+            inclusive,
+        })
+    }
+
+    #[test]
+    fn ascending_range_materializes() {
+        let mut interp = ComptimeInterpreter::new();
+        assert_eq!(interp.eval_expr(&range(2, 6, false)).unwrap(), ints(&[2, 3, 4, 5]));
+        assert_eq!(interp.eval_expr(&range(2, 6, true)).unwrap(), ints(&[2, 3, 4, 5, 6]));
+    }
+
+    #[test]
+    fn backwards_range_is_empty() {
+        // RV2: `10..0` is empty, not a reverse range; `.rev()` counts down
+        let mut interp = ComptimeInterpreter::new();
+        assert_eq!(interp.eval_expr(&range(10, 0, false)).unwrap(), ints(&[]));
+        let rev = expr(ExprKind::MethodCall {
+            object: Box::new(range(0, 4, false)),
+            method: "rev".to_string(),
+            type_args: None,
+            args: vec![],
+        });
+        assert_eq!(interp.eval_expr(&rev).unwrap(), ints(&[3, 2, 1, 0]));
+    }
+
+    #[test]
+    fn stepped_range_follows_spec_table() {
+        let mut interp = ComptimeInterpreter::new();
+        let mut stepped = |start, end, inclusive, step| {
+            interp.eval_expr(&method(range(start, end, inclusive), "step", int(step)))
+        };
+        assert_eq!(stepped(0, 10, false, 3).unwrap(), ints(&[0, 3, 6, 9]));
+        assert_eq!(stepped(0, 10, true, 3).unwrap(), ints(&[0, 3, 6, 9]));
+        assert_eq!(stepped(10, 0, false, -2).unwrap(), ints(&[10, 8, 6, 4, 2]));
+        assert_eq!(stepped(10, 0, true, -2).unwrap(), ints(&[10, 8, 6, 4, 2, 0]));
+        // Direction mismatch is empty
+        assert_eq!(stepped(0, 10, false, -1).unwrap(), ints(&[]));
+        let r = stepped(0, 10, false, 0);
+        assert!(matches!(r, Err(ComptimeError::ZeroStep)), "{:?}", r);
+        assert!(r.unwrap_err().is_hard());
+    }
+
+    #[test]
+    fn string_access_goes_through_char_at_and_byte_at() {
+        let interp = ComptimeInterpreter::new();
+        let s = str_val("héllo");
+        let some = |v| ComptimeValue::wrapper("Option", "Some", Some(v));
+        let char_at = interp.call_primitive_method(&s, "char_at", &[ComptimeValue::I64(1)]);
+        assert_eq!(char_at.unwrap(), some(ComptimeValue::Char('é')));
+        // Byte 1 is the first byte of the two-byte `é`.
+        let byte_at = interp.call_primitive_method(&s, "byte_at", &[ComptimeValue::I64(1)]);
+        assert_eq!(byte_at.unwrap(), some(ComptimeValue::U8(0xC3)));
+        let past_end = interp.call_primitive_method(&s, "char_at", &[ComptimeValue::I64(5)]);
+        assert_eq!(past_end.unwrap(), ComptimeValue::wrapper("Option", "None", None));
+    }
+
+    #[test]
+    fn parse_int_and_parse_float_return_results() {
+        let mut interp = ComptimeInterpreter::new();
+        let ok = |v| ComptimeValue::wrapper("Result", "Ok", Some(v));
+        let parsed = interp.eval_expr(&method(str_lit("42"), "parse_int", int(10)));
+        assert_eq!(parsed.unwrap(), ok(ComptimeValue::I64(42)));
+        let hex = interp.eval_expr(&method(str_lit("ff"), "parse_int", int(16)));
+        assert_eq!(hex.unwrap(), ok(ComptimeValue::I64(255)));
+        let float = interp.call_primitive_method(&str_val(" 2.5 "), "parse_float", &[]);
+        assert_eq!(float.unwrap(), ok(ComptimeValue::F64(2.5)));
+    }
+
+    #[test]
+    fn malformed_parse_int_is_an_err_value() {
+        let mut interp = ComptimeInterpreter::new();
+        let parsed = interp.eval_expr(&method(str_lit("4x"), "parse_int", int(10)));
+        let err = ComptimeValue::String("invalid integer `4x`".to_string());
+        assert_eq!(parsed.unwrap(), ComptimeValue::wrapper("Result", "Err", Some(err)));
+        let r = interp.call_primitive_method(&str_val("42"), "parse_int", &[ComptimeValue::I64(1)]);
+        assert!(matches!(r, Err(ComptimeError::Panic(ref m)) if m.contains("radix")), "{:?}", r);
+    }
+
+    #[test]
+    fn first_and_last_are_optional() {
+        let interp = ComptimeInterpreter::new();
+        let some = |v| ComptimeValue::wrapper("Option", "Some", Some(v));
+        let none = ComptimeValue::wrapper("Option", "None", None);
+        let arr = ComptimeValue::Array(vec![ComptimeValue::I64(3), ComptimeValue::I64(5)]);
+        assert_eq!(interp.call_primitive_method(&arr, "first", &[]).unwrap(), some(ComptimeValue::I64(3)));
+        assert_eq!(interp.call_primitive_method(&arr, "last", &[]).unwrap(), some(ComptimeValue::I64(5)));
+        let empty = ComptimeValue::Array(vec![]);
+        assert_eq!(interp.call_primitive_method(&empty, "first", &[]).unwrap(), none);
+        assert_eq!(interp.call_primitive_method(&empty, "last", &[]).unwrap(), none);
+    }
+
+    #[test]
+    fn slice_is_bounds_checked() {
+        let interp = ComptimeInterpreter::new();
+        let arr = ComptimeValue::Array(vec![
+            ComptimeValue::I64(1), ComptimeValue::I64(2), ComptimeValue::I64(3),
+        ]);
+        let slice = |start, end| {
+            interp.call_primitive_method(&arr, "slice", &[ComptimeValue::I64(start), ComptimeValue::I64(end)])
+        };
+        let mid = slice(1, 3).unwrap();
+        assert_eq!(mid, ComptimeValue::Array(vec![ComptimeValue::I64(2), ComptimeValue::I64(3)]));
+        assert_eq!(slice(3, 3).unwrap(), ComptimeValue::Array(vec![]));
+        let r = slice(1, 4);
+        assert!(matches!(r, Err(ComptimeError::SliceOutOfBounds { start: 1, end: 4, len: 3 })), "{:?}", r);
+        let r = slice(2, 1);
+        assert!(matches!(r, Err(ComptimeError::SliceOutOfBounds { .. })), "{:?}", r);
+    }
+
+    #[test]
+    fn string_position_index_is_not_supported() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("s".to_string(), str_val("hi"));
+        let r = interp.eval_expr(&expr(ExprKind::Index {
+            object: Box::new(ident("s")),
+            index: Box::new(int(0)),
+        }));
+        assert!(matches!(r, Err(ComptimeError::NotSupported(ref m)) if m.contains("char_at")), "{:?}", r);
+    }
+
+    #[test]
+    fn integer_bound_to_float_annotation_is_a_float() {
+        let mut interp = ComptimeInterpreter::new();
+        let span = Span::new(0, 0);
+        let annotated = |ty: &str| Stmt {
+            id: NodeId(0),
+            kind: StmtKind::Const { name: "x".to_string(), name_span: span, ty: Some(ty.to_string()), init: int(5) },
+            span,
+        };
+        interp.eval_stmt(&annotated("f64")).unwrap();
+        assert_eq!(interp.env.get("x"), Some(&ComptimeValue::F64(5.0)));
+        interp.eval_stmt(&annotated("f32")).unwrap();
+        assert_eq!(interp.env.get("x"), Some(&ComptimeValue::F32(5.0)));
+        interp.eval_stmt(&annotated("i64")).unwrap();
+        assert_eq!(interp.env.get("x"), Some(&ComptimeValue::I64(5)));
+    }
+
+    #[test]
+    fn method_on_println_result_names_unit() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&method(call("println", str_lit("hi")), "len", int(0)));
+        let err = r.unwrap_err();
+        assert!(matches!(err, ComptimeError::MethodOnUnit(ref m) if m == "len"), "{:?}", err);
+        assert_eq!(err.to_string(), "cannot call method `len` on `()`");
+    }
+
+    #[test]
+    fn method_on_none_says_value_may_be_none() {
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("x".to_string(), ComptimeValue::wrapper("Option", "None", None));
+        let err = interp.eval_expr(&method(ident("x"), "len", int(0))).unwrap_err();
+        assert_eq!(err.to_string(), "cannot call method `len` on a null value; the value may be None");
+        // `x == none` still compares
+        let eq = interp.eval_expr(&method(ident("x"), "eq", expr(ExprKind::None)));
+        assert_eq!(eq.unwrap(), ComptimeValue::Bool(true));
+    }
+
+    /// `comptime func <name>(n: i64) { <body> }`
+    fn comptime_fn(name: &str, body: Vec<Stmt>) -> Decl {
+        let span = Span::new(0, 0);
+        Decl {
+            id: NodeId(0),
+            kind: DeclKind::Fn(FnDecl {
+                name: name.to_string(),
+                type_params: vec![],
+                params: vec![rask_ast::decl::Param {
+                    name: "n".to_string(),
+                    name_span: span,
+                    ty: "i64".to_string(),
+                    is_take: false,
+                    is_mutate: false,
+                    default: None,
+                }],
+                ret_ty: None,
+                context_clauses: vec![],
+                body,
+                is_pub: false,
+                is_private: false,
+                is_comptime: true,
+                is_unsafe: false,
+                abi: None,
+                attrs: vec![],
+                doc: None,
+                span,
+            }),
+            span,
+        }
+    }
+
+    #[test]
+    fn nested_comptime_uses_same_quota_as_inlined_body() {
+        let span = Span::new(0, 0);
+        let stmt = |kind| Stmt { id: NodeId(0), kind, span };
+        let upto_n = || expr(ExprKind::Range {
+            start: Some(Box::new(int(0))),
+            end: Some(Box::new(ident("n"))),
+            inclusive: false,
+        });
+        let plain_for = || stmt(StmtKind::For {
+            label: None,
+            binding: ForBinding::Single("i".to_string()),
+            mutate: false,
+            iter: upto_n(),
+            body: vec![],
+        });
+        let comptime_for = || stmt(StmtKind::ComptimeFor {
+            binding: ForBinding::Single("j".to_string()),
+            iter: upto_n(),
+            body: vec![],
+        });
+        // comptime func nested(n) { comptime { for ..; comptime { comptime for .. } } }
+        let nested = comptime_fn("nested", vec![stmt(StmtKind::Comptime(vec![
+            plain_for(),
+            stmt(StmtKind::Expr(expr(ExprKind::Comptime { body: vec![comptime_for()] }))),
+        ]))]);
+        // comptime func inlined(n) { for ..; for .. }
+        let inlined = comptime_fn("inlined", vec![plain_for(), plain_for()]);
+
+        let mut interp = ComptimeInterpreter::new();
+        interp.register_functions(&[nested, inlined]);
+        let mut used = Vec::new();
+        for name in ["nested", "inlined"] {
+            interp.reset_branch_count();
+            interp.eval_expr(&call(name, int(10))).unwrap();
+            used.push(interp.env.branch_count);
+        }
+        // One branch for the call plus ten per loop.
+        assert_eq!(used, vec![21, 21]);
+
+        // And both hit the quota at the same size.
+        let mut interp = ComptimeInterpreter::with_quota(40);
+        interp.register_functions(&[
+            comptime_fn("nested", vec![stmt(StmtKind::Comptime(vec![comptime_for(), comptime_for()]))]),
+            comptime_fn("inlined", vec![plain_for(), plain_for()]),
+        ]);
+        for name in ["nested", "inlined"] {
+            interp.reset_branch_count();
+            let err = interp.eval_expr(&call(name, int(20))).unwrap_err();
+            assert!(matches!(err, ComptimeError::BranchQuotaExceeded(40)), "{}: {:?}", name, err);
+        }
+    }
+
+    #[test]
+    fn map_from_pairs_keeps_first_insertion_order() {
+        // Map.from([("b", 2), ["a", 1], ("b", 20)])
+        let pair = |k: &str, v: i64| expr(ExprKind::Tuple(vec![str_lit(k), int(v)]));
+        let pairs = expr(ExprKind::Array(vec![
+            pair("b", 2),
+            expr(ExprKind::Array(vec![str_lit("a"), int(1)])),
+            pair("b", 20),
+        ]));
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&method(ident("Map"), "from", pairs));
+        let expected = vec![
+            (str_val("b"), ComptimeValue::I64(20)),
+            (str_val("a"), ComptimeValue::I64(1)),
+        ];
+        assert!(matches!(&r, Ok(ComptimeValue::Map(e)) if *e == expected), "{:?}", r);
+    }
+
+    #[test]
+    fn map_from_rejects_non_pairs() {
+        let pairs = expr(ExprKind::Array(vec![expr(ExprKind::Tuple(vec![int(1), int(2), int(3)]))]));
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&method(ident("Map"), "from", pairs));
+        assert!(matches!(r, Err(ComptimeError::TypeMismatch { .. })), "{:?}", r);
+    }
+
+    #[test]
+    fn map_new_then_inserts_builds_entries_in_order() {
+        // mut m = Map.new(); m.insert("y", 2); m.insert("x", 1); return m
+        let span = Span::new(0, 0);
+        let stmt = |kind| Stmt { id: NodeId(0), kind, span };
+        let map_new = expr(ExprKind::MethodCall {
+            object: Box::new(ident("Map")),
+            method: "new".to_string(),
+            type_args: None,
+            args: vec![],
+        });
+        let insert = |k: &str, v: i64| {
+            let mut call = method(ident("m"), "insert", str_lit(k));
+            if let ExprKind::MethodCall { args, .. } = &mut call.kind {
+                args.push(CallArg { name: None, mode: ArgMode::Default, expr: int(v) });
+            }
+            stmt(StmtKind::Expr(call))
+        };
+        let body = vec![
+            stmt(StmtKind::Mut { name: "m".to_string(), name_span: span, ty: None, init: map_new }),
+            insert("y", 2),
+            insert("x", 1),
+            stmt(StmtKind::Return(Some(ident("m")))),
+        ];
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_block_to_value(&body);
+        let expected = vec![
+            (str_val("y"), ComptimeValue::I64(2)),
+            (str_val("x"), ComptimeValue::I64(1)),
+        ];
+        assert!(matches!(&r, Ok(ComptimeValue::Map(e)) if *e == expected), "{:?}", r);
+    }
+
+    #[test]
+    fn array_repeat_evaluates_at_comptime() {
+        let mut interp = ComptimeInterpreter::new();
+        let r = interp.eval_expr(&expr(ExprKind::ArrayRepeat {
+            value: Box::new(int(7)),
+            count: Box::new(int(3)),
+        }));
+        assert_eq!(r.unwrap(), ComptimeValue::Array(vec![ComptimeValue::I64(7); 3]));
+        let neg = interp.eval_expr(&expr(ExprKind::ArrayRepeat {
+            value: Box::new(int(0)),
+            count: Box::new(int(-1)),
+        }));
+        assert!(matches!(neg, Err(ComptimeError::NegativeSize(-1))), "{:?}", neg);
+    }
+
+    /// `match <scrutinee> { 0..=9 => 1, 10..=99 => 2, 100 => 3, _ => 4 }`
+    fn classify(scrutinee: Expr) -> Expr {
+        let span = Span::new(0, 0);
+        let arm = |pattern, result| MatchArm { pattern, guard: None, body: Box::new(int(result)), span };
+        let range = |lo, hi| Pattern::Range { start: Box::new(int(lo)), end: Box::new(int(hi)) };
+        expr(ExprKind::Match {
+            scrutinee: Box::new(scrutinee),
+            arms: vec![
+                arm(range(0, 9), 1),
+                arm(range(10, 99), 2),
+                arm(Pattern::Literal(Box::new(int(100))), 3),
+                arm(Pattern::Wildcard, 4),
+            ],
+        })
+    }
+
+    #[test]
+    fn match_on_integer_ranges_includes_both_bounds() {
+        let mut interp = ComptimeInterpreter::new();
+        for (n, expected) in [(-1, 4), (0, 1), (9, 1), (10, 2), (99, 2), (100, 3), (101, 4)] {
+            let r = interp.eval_expr(&classify(int(n)));
+            assert_eq!(r.unwrap(), ComptimeValue::I64(expected), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn range_pattern_compares_across_integer_widths() {
+        let mut interp = ComptimeInterpreter::new();
+        for (n, expected) in [(9u8, 1), (10, 2), (100, 3), (255, 4)] {
+            interp.env.define("b".to_string(), ComptimeValue::U8(n));
+            let r = interp.eval_expr(&classify(ident("b")));
+            assert_eq!(r.unwrap(), ComptimeValue::I64(expected), "b = {}u8", n);
+        }
+    }
+
+    #[test]
+    fn char_range_pattern_at_comptime() {
+        let span = Span::new(0, 0);
+        let ch = |c| expr(ExprKind::Char(c));
+        let letters = Pattern::Range { start: Box::new(ch('a')), end: Box::new(ch('z')) };
+        let is_lower = |c| expr(ExprKind::Match {
+            scrutinee: Box::new(ch(c)),
+            arms: vec![
+                MatchArm { pattern: letters.clone(), guard: None, body: Box::new(expr(ExprKind::Bool(true))), span },
+                MatchArm { pattern: Pattern::Wildcard, guard: None, body: Box::new(expr(ExprKind::Bool(false))), span },
+            ],
+        });
+        let mut interp = ComptimeInterpreter::new();
+        for (c, expected) in [('a', true), ('z', true), ('`', false), ('{', false)] {
+            assert_eq!(interp.eval_expr(&is_lower(c)).unwrap(), ComptimeValue::Bool(expected), "{:?}", c);
+        }
+    }
+
+    #[test]
+    fn string_literal_patterns_match_at_comptime() {
+        let span = Span::new(0, 0);
+        let arm = |pattern, result| MatchArm { pattern, guard: None, body: Box::new(int(result)), span };
+        let lit = |s: &str| Pattern::Literal(Box::new(str_lit(s)));
+        let mut interp = ComptimeInterpreter::new();
+        for (cmd, expected) in [("run", 1), ("build", 2), ("b", 2), ("Run", 0), ("", 0)] {
+            let m = expr(ExprKind::Match {
+                scrutinee: Box::new(str_lit(cmd)),
+                arms: vec![
+                    arm(lit("run"), 1),
+                    arm(Pattern::Or(vec![lit("build"), lit("b")]), 2),
+                    arm(Pattern::Wildcard, 0),
+                ],
+            });
+            assert_eq!(interp.eval_expr(&m).unwrap(), ComptimeValue::I64(expected), "cmd = {:?}", cmd);
+        }
+    }
+
+    fn target_is(target: &str) -> ComptimeInterpreter {
+        let mut interp = ComptimeInterpreter::new();
+        interp.define_const("TARGET".to_string(), str_val(target));
+        interp
+    }
+
+    #[test]
+    fn if_selects_config_value_without_evaluating_untaken_branch() {
+        // if TARGET == "wasm" { panic("no wasm") } else { 2 }
+        let mode = expr(ExprKind::If {
+            cond: Box::new(method(ident("TARGET"), "eq", str_lit("wasm"))),
+            then_branch: Box::new(call("panic", str_lit("no wasm"))),
+            else_branch: Some(Box::new(int(2))),
+            else_binding: None,
+        });
+        assert_eq!(target_is("native").eval_expr(&mode).unwrap(), ComptimeValue::I64(2));
+        let r = target_is("wasm").eval_expr(&mode);
+        assert!(matches!(&r, Err(ComptimeError::Panic(msg)) if msg == "no wasm"), "{:?}", r);
+    }
+
+    #[test]
+    fn match_skips_bodies_of_arms_not_taken() {
+        // match TARGET { "wasm" => 1 / 0, _ => 2 }
+        let mode = match_on(ident("TARGET"), vec![
+            (Pattern::Literal(Box::new(str_lit("wasm"))), method(int(1), "div", int(0))),
+            (Pattern::Wildcard, int(2)),
+        ]);
+        assert_eq!(target_is("native").eval_expr(&mode).unwrap(), ComptimeValue::I64(2));
+        let r = target_is("wasm").eval_expr(&mode);
+        assert!(matches!(r, Err(ComptimeError::DivisionByZero)), "{:?}", r);
+    }
+
+    fn ctor(enum_name: &str, variant: &str, args: Vec<Expr>) -> Expr {
+        expr(ExprKind::MethodCall {
+            object: Box::new(ident(enum_name)),
+            method: variant.to_string(),
+            type_args: None,
+            args: args.into_iter().map(|e| CallArg { name: None, mode: ArgMode::Default, expr: e }).collect(),
+        })
+    }
+
+    fn match_on(scrutinee: Expr, arms: Vec<(Pattern, Expr)>) -> Expr {
+        let span = Span::new(0, 0);
+        expr(ExprKind::Match {
+            scrutinee: Box::new(scrutinee),
+            arms: arms
+                .into_iter()
+                .map(|(pattern, body)| MatchArm { pattern, guard: None, body: Box::new(body), span })
+                .collect(),
+        })
+    }
+
+    fn ctor_pat(name: &str, fields: &[&str]) -> Pattern {
+        Pattern::Constructor {
+            name: name.to_string(),
+            fields: fields.iter().map(|f| Pattern::Ident(f.to_string())).collect(),
+        }
+    }
+
+    #[test]
+    fn two_field_variant_round_trips_through_match() {
+        let mut interp = ComptimeInterpreter::new();
+        interp
+            .enum_variants
+            .insert("Shape".to_string(), vec!["Circle".to_string(), "Rect".to_string()]);
+        let rect = ctor("Shape", "Rect", vec![int(3), int(4)]);
+        assert_eq!(
+            interp.eval_expr(&rect).unwrap(),
+            ComptimeValue::Enum {
+                name: "Shape".to_string(),
+                variant: "Rect".to_string(),
+                data: Some(Box::new(ComptimeValue::Tuple(vec![ComptimeValue::I64(3), ComptimeValue::I64(4)]))),
+            }
+        );
+        // match Shape.Rect(3, 4) { Shape.Circle(r) => r, Shape.Rect(w, h) => w * h }
+        let area = match_on(
+            rect.clone(),
+            vec![
+                (ctor_pat("Shape.Circle", &["r"]), ident("r")),
+                (ctor_pat("Shape.Rect", &["w", "h"]), method(ident("w"), "mul", ident("h"))),
+            ],
+        );
+        assert_eq!(interp.eval_expr(&area).unwrap(), ComptimeValue::I64(12));
+        // Bare variant names match the same payload.
+        let height = match_on(rect, vec![(ctor_pat("Circle", &["r"]), ident("r")), (ctor_pat("Rect", &["w", "h"]), ident("h"))]);
+        assert_eq!(interp.eval_expr(&height).unwrap(), ComptimeValue::I64(4));
+    }
+
+    #[test]
+    fn option_variants_construct_and_match_without_registration() {
+        let mut interp = ComptimeInterpreter::new();
+        let unwrap_or_zero = |value| {
+            match_on(value, vec![(ctor_pat("Some", &["n"]), ident("n")), (Pattern::Ident("None".to_string()), int(0))])
+        };
+        let some = unwrap_or_zero(ctor("Option", "Some", vec![int(5)]));
+        assert_eq!(interp.eval_expr(&some).unwrap(), ComptimeValue::I64(5));
+        let none = unwrap_or_zero(expr(ExprKind::Field { object: Box::new(ident("Option")), field: "None".to_string() }));
+        assert_eq!(interp.eval_expr(&none).unwrap(), ComptimeValue::I64(0));
+    }
+
+    #[test]
+    fn unit_variant_pattern_matches_tag_not_binding() {
+        let mut interp = ComptimeInterpreter::new();
+        interp
+            .enum_variants
+            .insert("Color".to_string(), vec!["Red".to_string(), "Green".to_string()]);
+        let green = expr(ExprKind::Field { object: Box::new(ident("Color")), field: "Green".to_string() });
+        for red in ["Red", "Color.Red"] {
+            let m = match_on(
+                green.clone(),
+                vec![(Pattern::Ident(red.to_string()), int(1)), (Pattern::Ident("Color.Green".to_string()), int(2))],
+            );
+            assert_eq!(interp.eval_expr(&m).unwrap(), ComptimeValue::I64(2), "first arm = {}", red);
+        }
+    }
+
+    // ── Error locations ───────────────────────────────────────────────
+
+    fn at(kind: ExprKind, start: usize, end: usize) -> Expr {
+        Expr { id: NodeId(0), kind, span: Span::new(start, end) }
+    }
+
+    #[test]
+    fn not_supported_error_points_at_the_offending_expression() {
+        // const x = 1
+        // [1, &x]
+        let body = vec![
+            Stmt {
+                id: NodeId(0),
+                kind: StmtKind::Const { name: "x".to_string(), name_span: Span::new(6, 7), ty: None, init: int(1) },
+                span: Span::new(0, 11),
+            },
+            Stmt {
+                id: NodeId(0),
+                kind: StmtKind::Expr(at(ExprKind::Array(vec![
+                    at(ExprKind::Int(1, None), 13, 14),
+                    at(ExprKind::Unary {
+                        op: UnaryOp::Ref,
+                        operand: Box::new(at(ExprKind::Ident("x".to_string()), 17, 18)),
+                    }, 16, 18),
+                ]), 12, 19)),
+                span: Span::new(12, 19),
+            },
+        ];
+        let mut interp = ComptimeInterpreter::new();
+        let err = interp.eval_block_to_value(&body).unwrap_err();
+        assert!(matches!(err, ComptimeError::NotSupported(_)), "got: {:?}", err);
+        assert_eq!(interp.error_span(), Some(Span::new(16, 18)));
+
+        let located = interp.locate(err, Span::new(0, 19));
+        assert_eq!(located.span, Span::new(16, 18));
+        assert_eq!(located.to_string(), "not supported at comptime: references at comptime");
+    }
+
+    #[test]
+    fn error_span_does_not_outlive_the_failed_evaluation() {
+        let mut interp = ComptimeInterpreter::new();
+        let bad = at(ExprKind::Ident("missing".to_string()), 4, 11);
+        assert!(interp.eval_expr(&bad).is_err());
+        assert_eq!(interp.error_span(), Some(Span::new(4, 11)));
+
+        assert!(interp.eval_expr(&int(2)).is_ok());
+        assert_eq!(interp.error_span(), None);
+        let located = interp.locate(ComptimeError::DivisionByZero, Span::new(0, 3));
+        assert_eq!(located.span, Span::new(0, 3));
+    }
+
+    fn call_all(name: &str, args: Vec<Expr>) -> Expr {
+        expr(ExprKind::Call {
+            func: Box::new(ident(name)),
+            args: args.into_iter().map(|e| CallArg { name: None, mode: ArgMode::Default, expr: e }).collect(),
+        })
+    }
+
+    #[test]
+    fn assert_eq_passes_on_equal_values() {
+        let mut interp = ComptimeInterpreter::new();
+        assert_eq!(interp.eval_expr(&call_all("assert_eq", vec![int(3), int(3)])).unwrap(), ComptimeValue::Unit);
+        let same = call_all("assert_eq", vec![point_lit(&[("x", 1), ("y", 2)]), point_lit(&[("x", 1), ("y", 2)])]);
+        assert_eq!(interp.eval_expr(&same).unwrap(), ComptimeValue::Unit);
+        assert_eq!(interp.eval_expr(&call_all("assert_ne", vec![int(3), int(5)])).unwrap(), ComptimeValue::Unit);
+    }
+
+    #[test]
+    fn assert_eq_failure_renders_both_values() {
+        let mut interp = ComptimeInterpreter::new();
+        let err = interp.eval_expr(&call_all("assert_eq", vec![int(3), int(5)])).unwrap_err();
+        assert!(matches!(&err, ComptimeError::Panic(msg) if msg == "assert_eq failed: left = 3, right = 5"), "{:?}", err);
+
+        let err = interp.eval_expr(&call_all("assert_ne", vec![str_lit("a"), str_lit("a")])).unwrap_err();
+        assert!(matches!(&err, ComptimeError::Panic(msg) if msg == "assert_ne failed: left = a, right = a"), "{:?}", err);
+
+        let labelled = call_all("assert_eq", vec![int(1), int(2), str_lit("table size")]);
+        let err = interp.eval_expr(&labelled).unwrap_err();
+        assert!(matches!(&err, ComptimeError::Panic(msg) if msg == "table size: left = 1, right = 2"), "{:?}", err);
+    }
+
+    fn cast(value: Expr, ty: &str) -> Expr {
+        expr(ExprKind::Cast { expr: Box::new(value), ty: ty.to_string() })
+    }
+
+    fn typed(v: i64, suffix: rask_ast::token::IntSuffix) -> Expr {
+        expr(ExprKind::Int(v, Some(suffix)))
+    }
+
+    #[test]
+    fn integer_casts_widen_and_narrow_like_as() {
+        use rask_ast::token::IntSuffix;
+        let mut interp = ComptimeInterpreter::new();
+        let mut eval = |e: Expr| interp.eval_expr(&e).unwrap();
+        // Widening keeps the value; signed sources sign-extend.
+        assert_eq!(eval(cast(typed(-5, IntSuffix::I8), "i32")), ComptimeValue::I32(-5));
+        assert_eq!(eval(cast(typed(200, IntSuffix::U8), "u64")), ComptimeValue::U64(200));
+        assert_eq!(eval(cast(typed(-1, IntSuffix::I16), "i64")), ComptimeValue::I64(-1));
+        // Narrowing keeps the low bits.
+        assert_eq!(eval(cast(typed(300, IntSuffix::I32), "i8")), ComptimeValue::I8(44));
+        assert_eq!(eval(cast(typed(70_000, IntSuffix::U32), "u16")), ComptimeValue::U16(4464));
+        assert_eq!(eval(cast(typed(-129, IntSuffix::I64), "i8")), ComptimeValue::I8(127));
+    }
+
+    #[test]
+    fn integer_casts_reinterpret_across_signedness() {
+        use rask_ast::token::IntSuffix;
+        let mut interp = ComptimeInterpreter::new();
+        let mut eval = |e: Expr| interp.eval_expr(&e).unwrap();
+        assert_eq!(eval(cast(typed(-1, IntSuffix::I8), "u8")), ComptimeValue::U8(255));
+        assert_eq!(eval(cast(typed(-1, IntSuffix::I32), "u64")), ComptimeValue::U64(u64::MAX));
+        assert_eq!(eval(cast(typed(200, IntSuffix::U8), "i8")), ComptimeValue::I8(-56));
+        assert_eq!(eval(cast(typed(40_000, IntSuffix::U16), "i16")), ComptimeValue::I16(-25_536));
+        assert_eq!(eval(cast(int(-2), "u16")), ComptimeValue::U16(65_534));
+    }
+
+    #[test]
+    fn int_width_table_matches_as_legality() {
+        let w = |name| IntWidth::of(name).unwrap();
+        assert_eq!(w("u128"), IntWidth { bits: 128, signed: false });
+        assert_eq!(IntWidth::of("usize"), None);
+        assert!(w("u8").widens_losslessly_to(w("i16")));
+        assert!(w("i8").widens_losslessly_to(w("i64")));
+        assert!(!w("i8").widens_losslessly_to(w("u64")));
+        assert!(!w("i32").widens_losslessly_to(w("i32")));
+        assert_eq!(w("i8").wrap(255), -1);
+        assert_eq!(w("u8").wrap(-1), 255);
+    }
+
+    #[test]
+    fn enumerate_pairs_indices_with_elements() {
+        let interp = ComptimeInterpreter::new();
+        let pair = |i: i64, v: ComptimeValue| ComptimeValue::Tuple(vec![ComptimeValue::I64(i), v]);
+        let items = ComptimeValue::Array(vec![ComptimeValue::I64(10), ComptimeValue::I64(20)]);
+        assert_eq!(
+            interp.call_primitive_method(&items, "enumerate", &[]).unwrap(),
+            ComptimeValue::Array(vec![pair(0, ComptimeValue::I64(10)), pair(1, ComptimeValue::I64(20))]),
+        );
+        let word = ComptimeValue::String("hé".to_string());
+        assert_eq!(
+            interp.call_primitive_method(&word, "enumerate", &[]).unwrap(),
+            ComptimeValue::Array(vec![pair(0, ComptimeValue::Char('h')), pair(1, ComptimeValue::Char('é'))]),
+        );
+        let empty = ComptimeValue::Array(vec![]);
+        assert_eq!(interp.call_primitive_method(&empty, "enumerate", &[]).unwrap(), ComptimeValue::Array(vec![]));
+    }
+
+    #[test]
+    fn for_destructures_enumerate_pairs() {
+        let span = Span::new(0, 0);
+        let stmt = |kind| Stmt { id: NodeId(0), kind, span };
+        // for (i, x) in items.enumerate() { total = total + i * x }, desugared
+        let body = stmt(StmtKind::Assign {
+            target: ident("total"),
+            value: method(ident("total"), "add", method(ident("i"), "mul", ident("x"))),
+        });
+        let enumerate = expr(ExprKind::MethodCall {
+            object: Box::new(ident("items")),
+            method: "enumerate".to_string(),
+            type_args: None,
+            args: vec![],
+        });
+        let for_stmt = stmt(StmtKind::For {
+            label: None,
+            binding: ForBinding::Tuple(vec!["i".to_string(), "x".to_string()]),
+            mutate: false,
+            iter: enumerate,
+            body: vec![body],
+        });
+
+        let mut interp = ComptimeInterpreter::new();
+        interp.env.define("items".to_string(), ints(&[5, 6, 7]));
+        interp.env.define("total".to_string(), ComptimeValue::I64(0));
+        interp.eval_block(&[for_stmt]).unwrap();
+        // 0*5 + 1*6 + 2*7
+        assert_eq!(interp.env.get("total").cloned(), Some(ComptimeValue::I64(20)));
+    }
+
+    #[test]
+    fn continue_inside_if_skips_to_next_iteration() {
+        let span = Span::new(0, 0);
+        let stmt = |kind| Stmt { id: NodeId(0), kind, span };
+        // for x in items { if x == 2 { continue } total = total + x }, desugared
+        let body = || vec![
+            stmt(StmtKind::Expr(expr(ExprKind::If {
+                cond: Box::new(method(ident("x"), "eq", int(2))),
+                then_branch: Box::new(expr(ExprKind::Block(vec![stmt(StmtKind::Continue(None))]))),
+                else_branch: None,
+                else_binding: None,
+            }))),
+            stmt(StmtKind::Assign {
+                target: ident("total"),
+                value: method(ident("total"), "add", ident("x")),
+            }),
+        ];
+        let plain_for = stmt(StmtKind::For {
+            label: None,
+            binding: ForBinding::Single("x".to_string()),
+            mutate: false,
+            iter: ident("items"),
+            body: body(),
+        });
+        let comptime_for = stmt(StmtKind::ComptimeFor {
+            binding: ForBinding::Single("x".to_string()),
+            iter: ident("items"),
+            body: body(),
+        });
+
+        for loop_stmt in [plain_for, comptime_for] {
+            let mut interp = ComptimeInterpreter::new();
+            interp.env.define("items".to_string(), ints(&[1, 2, 3, 4]));
+            interp.env.define("total".to_string(), ComptimeValue::I64(0));
+            interp.eval_block(&[loop_stmt]).unwrap();
+            assert_eq!(interp.env.get("total").cloned(), Some(ComptimeValue::I64(8)));
+        }
+    }
+
+    #[test]
+    fn panic_reports_the_comptime_call_chain() {
+        let stmt = |e: Expr| Stmt { id: NodeId(0), span: e.span, kind: StmtKind::Expr(e) };
+        let call_at = |name: &str, arg: Expr, start, end| at(ExprKind::Call {
+            func: Box::new(ident(name)),
+            args: vec![CallArg { name: None, mode: ArgMode::Default, expr: arg }],
+        }, start, end);
+        // outer(n) { inner(n) }   inner(n) { panic("too deep") }
+        let panic = at(ExprKind::Call {
+            func: Box::new(ident("panic")),
+            args: vec![CallArg { name: None, mode: ArgMode::Default, expr: str_lit("too deep") }],
+        }, 40, 56);
+        let mut interp = ComptimeInterpreter::new();
+        interp.register_functions(&[
+            comptime_fn("outer", vec![stmt(call_at("inner", ident("n"), 20, 28))]),
+            comptime_fn("inner", vec![stmt(panic)]),
+        ]);
+
+        let err = interp.eval_expr(&call_at("outer", int(1), 0, 8)).unwrap_err();
+        let located = interp.locate(err, Span::new(0, 8));
+        assert!(matches!(&located.error, ComptimeError::Panic(msg) if msg == "too deep"), "{:?}", located.error);
+        assert_eq!(located.span, Span::new(40, 56));
+        assert_eq!(located.frames, vec![
+            ComptimeFrame { function: "outer".to_string(), call_site: Span::new(0, 8) },
+            ComptimeFrame { function: "inner".to_string(), call_site: Span::new(20, 28) },
+        ]);
+
+        // A later success leaves no stale frames behind.
+        interp.eval_expr(&int(1)).unwrap();
+        assert!(interp.locate(ComptimeError::DivisionByZero, Span::new(0, 1)).frames.is_empty());
+    }
+
+    fn comptime_const(name: &str, value: Expr) -> Decl {
+        let span = value.span;
+        Decl {
+            id: NodeId(0),
+            kind: DeclKind::Const(rask_ast::decl::ConstDecl {
+                name: name.to_string(),
+                ty: None,
+                init: at(ExprKind::Comptime { body: vec![stmt(value)] }, span.start, span.end),
+                is_pub: false,
+                doc: None,
+            }),
+            span,
+        }
+    }
+
+    fn stmt(e: Expr) -> Stmt {
+        Stmt { id: NodeId(0), span: e.span, kind: StmtKind::Expr(e) }
+    }
+
+    #[test]
+    fn eval_program_returns_every_global_and_error() {
+        // const WIDTH = comptime { 6 * 7 }
+        // const AREA = comptime { WIDTH * WIDTH }
+        // func check() { comptime { panic("bad config") } }
+        let mut check = comptime_fn("check", vec![]);
+        if let DeclKind::Fn(f) = &mut check.kind {
+            f.is_comptime = false;
+            f.body = vec![Stmt {
+                id: NodeId(0),
+                span: Span::new(30, 50),
+                kind: StmtKind::Comptime(vec![stmt(at(ExprKind::Call {
+                    func: Box::new(ident("panic")),
+                    args: vec![CallArg { name: None, mode: ArgMode::Default, expr: str_lit("bad config") }],
+                }, 40, 50))]),
+            }];
+        }
+        let decls = vec![
+            comptime_const("WIDTH", at(method(int(6), "mul", int(7)).kind, 0, 10)),
+            comptime_const("AREA", at(method(ident("WIDTH"), "mul", ident("WIDTH")).kind, 10, 20)),
+            check,
+        ];
+
+        let (globals, errors) = eval_comptime(&decls);
+        let folded: Vec<_> = globals.iter().map(|g| (g.name.as_str(), g.value.clone(), g.span)).collect();
+        assert_eq!(folded, vec![
+            ("WIDTH", ComptimeValue::I64(42), Span::new(0, 10)),
+            ("AREA", ComptimeValue::I64(1764), Span::new(10, 20)),
+        ]);
+        assert_eq!(errors.len(), 1, "{:?}", errors);
+        assert!(matches!(&errors[0].error, ComptimeError::Panic(msg) if msg == "bad config"), "{:?}", errors);
+        assert_eq!(errors[0].span, Span::new(40, 50));
+    }
 }
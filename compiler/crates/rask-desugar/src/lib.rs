@@ -7,6 +7,10 @@
 //! - `a == b` → `a.eq(b)`
 //! - etc.
 //!
+//! Tuple struct construction and patterns become their named-field forms:
+//! - `Meters(x)` → `Meters { 0: x }`
+//! - `Meters(m) => ...` → `Meters { 0: m } => ...`
+//!
 //! Default argument desugaring fills in missing call arguments from
 //! parameter defaults and resolves named arguments to positional form.
 //!
@@ -16,13 +20,14 @@ mod defaults;
 pub use defaults::{desugar_default_args, is_valid_default_expr};
 
 use rask_ast::decl::{Decl, DeclKind, FnDecl, Param, StructDecl, EnumDecl, TraitDecl, ImplDecl};
-use rask_ast::expr::{ArgMode, BinOp, CallArg, Expr, ExprKind, MatchArm, Pattern, UnaryOp};
+use rask_ast::expr::{ArgMode, BinOp, CallArg, Expr, ExprKind, FieldInit, MatchArm, Pattern, UnaryOp};
 use rask_ast::stmt::{Stmt, StmtKind};
 use rask_ast::{NodeId, Span};
+use std::collections::HashSet;
 
 /// Desugar all operators in a list of declarations.
 pub fn desugar(decls: &mut [Decl]) {
-    let mut desugarer = Desugarer::new(1_000_000);
+    let mut desugarer = Desugarer::new(1_000_000, decls);
     for decl in decls {
         desugarer.desugar_decl(decl);
     }
@@ -30,7 +35,7 @@ pub fn desugar(decls: &mut [Decl]) {
 
 /// Desugar with a custom starting NodeId to avoid collisions.
 pub fn desugar_with_start_id(decls: &mut [Decl], start_id: u32) {
-    let mut desugarer = Desugarer::new(start_id);
+    let mut desugarer = Desugarer::new(start_id, decls);
     for decl in decls {
         desugarer.desugar_decl(decl);
     }
@@ -45,7 +50,7 @@ pub struct DesugarError {
 
 /// Desugar all operators, returning any ER26 coverage errors.
 pub fn desugar_with_diagnostics(decls: &mut [Decl]) -> Vec<DesugarError> {
-    let mut desugarer = Desugarer::new(1_000_000);
+    let mut desugarer = Desugarer::new(1_000_000, decls);
     for decl in decls {
         desugarer.desugar_decl(decl);
     }
@@ -56,11 +61,22 @@ pub fn desugar_with_diagnostics(decls: &mut [Decl]) -> Vec<DesugarError> {
 struct Desugarer {
     next_id: u32,
     errors: Vec<DesugarError>,
+    /// Names of tuple structs (`struct Meters(f64)`), generics stripped.
+    tuple_structs: HashSet<String>,
 }
 
 impl Desugarer {
-    fn new(start_id: u32) -> Self {
-        Self { next_id: start_id, errors: Vec::new() }
+    fn new(start_id: u32, decls: &[Decl]) -> Self {
+        let tuple_structs = decls
+            .iter()
+            .filter_map(|d| match &d.kind {
+                DeclKind::Struct(s) if s.is_tuple() => {
+                    Some(s.name.split('<').next().unwrap_or(&s.name).to_string())
+                }
+                _ => None,
+            })
+            .collect();
+        Self { next_id: start_id, errors: Vec::new(), tuple_structs }
     }
 
     fn fresh_id(&mut self) -> NodeId {
@@ -189,6 +205,7 @@ impl Desugarer {
                 pattern,
                 guard: None,
                 body: Box::new(body_expr),
+                span: sp,
             });
         }
 
@@ -293,7 +310,8 @@ impl Desugarer {
                     self.desugar_stmt(s);
                 }
             }
-            StmtKind::WhileLet { expr, body, .. } => {
+            StmtKind::WhileLet { pattern, expr, body } => {
+                self.desugar_pattern(pattern);
                 self.desugar_expr(expr);
                 for s in body {
                     self.desugar_stmt(s);
@@ -387,10 +405,11 @@ impl Desugarer {
             }
             ExprKind::IfLet {
                 expr,
+                pattern,
                 then_branch,
                 else_branch,
-                ..
             } => {
+                self.desugar_pattern(pattern);
                 self.desugar_expr(expr);
                 self.desugar_expr(then_branch);
                 if let Some(e) = else_branch {
@@ -413,13 +432,15 @@ impl Desugarer {
             ExprKind::Unwrap { expr: e, message: _ } => self.desugar_expr(e),
             ExprKind::GuardPattern {
                 expr,
+                pattern,
                 else_branch,
-                ..
             } => {
+                self.desugar_pattern(pattern);
                 self.desugar_expr(expr);
                 self.desugar_expr(else_branch);
             }
-            ExprKind::IsPattern { expr, .. } => {
+            ExprKind::IsPattern { expr, pattern } => {
+                self.desugar_pattern(pattern);
                 self.desugar_expr(expr);
             }
             ExprKind::NullCoalesce { value, default } => {
@@ -546,6 +567,9 @@ impl Desugarer {
                         operand: Box::new(eq_call),
                     };
                 } else {
+                    // Fresh id marks the call as synthesized, so the checker
+                    // can report `a < b` errors as comparisons, not method calls.
+                    expr.id = self.fresh_id();
                     expr.kind = ExprKind::MethodCall {
                         object: Box::new(left_expr),
                         method: method.to_string(),
@@ -573,6 +597,21 @@ impl Desugarer {
         }
         // Not and Ref remain as unary
 
+        // Tuple struct construction: `Meters(x)` → `Meters { 0: x }`
+        if let ExprKind::Call { func, args } = &mut expr.kind {
+            if let ExprKind::Ident(name) = &func.kind {
+                if self.tuple_structs.contains(name) {
+                    let name = name.clone();
+                    let fields = std::mem::take(args)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, arg)| FieldInit { name: i.to_string(), value: arg.expr })
+                        .collect();
+                    expr.kind = ExprKind::StructLit { name, fields, spread: None };
+                }
+            }
+        }
+
         // Desugar StringInterp: segments → "lit".concat(expr.to_string()).concat("lit")...
         if let ExprKind::StringInterp(segments) = &expr.kind {
             let segments = segments.clone();
@@ -724,11 +763,47 @@ impl Desugarer {
     }
 
     fn desugar_match_arm(&mut self, arm: &mut MatchArm) {
+        self.desugar_pattern(&mut arm.pattern);
         if let Some(guard) = &mut arm.guard {
             self.desugar_expr(guard);
         }
         self.desugar_expr(&mut arm.body);
     }
+
+    /// Tuple struct patterns: `Meters(m)` → `Meters { 0: m }`.
+    fn desugar_pattern(&self, pattern: &mut Pattern) {
+        match pattern {
+            Pattern::Constructor { name, fields } => {
+                for field in fields.iter_mut() {
+                    self.desugar_pattern(field);
+                }
+                if self.tuple_structs.contains(name.as_str()) {
+                    let name = std::mem::take(name);
+                    let fields = std::mem::take(fields)
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, p)| (i.to_string(), p))
+                        .collect();
+                    *pattern = Pattern::Struct { name, fields, rest: false };
+                }
+            }
+            Pattern::Struct { fields, .. } => {
+                for (_, field) in fields {
+                    self.desugar_pattern(field);
+                }
+            }
+            Pattern::Tuple(pats) | Pattern::Or(pats) => {
+                for p in pats {
+                    self.desugar_pattern(p);
+                }
+            }
+            Pattern::Wildcard
+            | Pattern::Ident(_)
+            | Pattern::Literal(_)
+            | Pattern::Range { .. }
+            | Pattern::TypePat { .. } => {}
+        }
+    }
 }
 
 /// What a variant's @message resolves to.
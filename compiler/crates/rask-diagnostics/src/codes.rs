@@ -194,6 +194,78 @@ impl Default for ErrorCodeRegistry {
                 "E0357" => ("single-letter type name", Type,
                     "Single uppercase letters are reserved for type parameters. A struct, enum, trait, or union named `T` would be shadowed by the type-parameter convention in every signature.",
                     "struct T { }  // error: reserved for type parameters\n// fix: struct Token { }"),
+                "E0358" => ("comptime-only function called at runtime", Type,
+                    "A `comptime func` can only be called at compile time. Calling it from ordinary runtime code has nothing to run — wrap the call in `comptime` or remove the `comptime` marker.",
+                    "comptime func table_size() -> usize { return 256 }\n\nfunc main() {\n    const n = table_size()           // error: runtime call\n    const m = comptime table_size()  // ok\n}"),
+                "E0359" => ("runtime-only function called at compile time", Type,
+                    "A function that performs I/O, spawns tasks, uses `unsafe`, or calls foreign code — directly or through a callee — cannot run inside the compiler. Comptime positions may only call comptime-evaluable functions.",
+                    "func load() -> string {\n    return try fs.read_file(\"config.txt\")\n}\n\nconst CONFIG = comptime load()  // error: `load` uses the `fs` module"),
+                "E0360" => ("constant evaluation failed", Type,
+                    "An array length must be evaluated at compile time. The comptime engine couldn't evaluate this expression — it may divide by zero, overflow, or call something that isn't comptime-evaluable.",
+                    "const N = 4\nconst a = [0; N * 2]      // ok: folds to 8\nconst b = [0; 10 / (N - 4)]  // error: division by zero"),
+                "E0361" => ("array length not constant", Type,
+                    "The length in `[value; count]` must be known at compile time because it is part of the array's type. A runtime variable can't size an array — use `Vec` for runtime-sized data.",
+                    "func make(n: usize) {\n    const a = [0; n]  // error: n is a runtime value\n    const v = Vec<i32>.new()  // ok: grows at runtime\n}"),
+                "E0362" => ("negative array length", Type,
+                    "An array length evaluated to a negative number. Lengths are element counts and must be zero or more.",
+                    "const a = [0; 2 - 5]  // error: evaluates to -3"),
+                "E0363" => ("value cannot be interpolated", Type,
+                    "String interpolation calls `to_string()` on each `{expr}`. Primitives and enums have one; structs must define `to_string` (Displayable) or `message` (the error bridge).",
+                    "struct Point { x: i32, y: i32 }\n\nfunc main() {\n    const p = Point { x: 1, y: 2 }\n    println(\"at {p}\")  // error: Point has no to_string\n}"),
+                "E0364" => ("incomparable types", Type,
+                    "`==`, `<`, and the other comparison operators need both operands to be the same kind of value. An integer never equals a string; convert one side first.",
+                    "func main() {\n    const n: i32 = 1\n    const s = \"1\"\n    if n == s { }  // error: cannot compare i32 with string\n}"),
+                "E0365" => ("comptime panic", Type,
+                    "A `panic`, failing `assert`, `todo`, or `unreachable` ran during compile-time evaluation. The build stops with the panic message — this is how static assertions are written.",
+                    "const SIZE = comptime { 3 }\ncomptime {\n    assert(SIZE % 2 == 0)  // error: comptime panic: assertion failed\n}"),
+                "E0366" => ("cannot infer type parameter", Type,
+                    "A generic function's type parameter must be pinned down by the call: by an argument, by the type the result flows into, or explicitly with `name<T>(...)`. A parameter that appears only in the return type of a call whose result is unused has nothing to infer from.",
+                    "func make<T>() -> Vec<T> { return Vec.new() }\n\nfunc main() {\n    make()  // error: cannot infer type parameter `T`\n    make<i32>()  // ok\n}"),
+                "E0367" => ("unknown context type", Type,
+                    "A `using` clause on a function signature threads a context as a hidden parameter. Only `Pool<T>` and `Allocator` can be threaded this way; any other name is a typo or a type that isn't a context.",
+                    "struct Player { hp: i32 }\n\nfunc damage(h: Handle<Player>) using Poool<Player> {  // error: not a context type\n    h.hp -= 1\n}"),
+                "E0368" => ("invalid main signature", Type,
+                    "The runtime calls `main` with either nothing or the command-line arguments, so `main` must take no parameters or a single `[]string`. The arguments exclude the program path.",
+                    "func main(count: i32) {  // error: invalid main signature\n}\n\nfunc main(args: []string) {  // ok\n    for a in args { println(a) }\n}"),
+                "E0369" => ("integer literal out of range", Type,
+                    "A type suffix fixes a literal's type, so the value must fit in it. Literals without a suffix take their type from context instead.",
+                    "const a = 300u8   // error: out of range for `u8`\nconst b = -129i8  // error: out of range for `i8`\nconst c = 255u8   // ok"),
+                "E0370" => ("ambiguous method call", Type,
+                    "Two or more traits implemented by the type define a method with this name, and the type has no inherent method of its own to prefer.",
+                    "trait A { func reset(mutate self) }\ntrait B { func reset(mutate self) }\n// c implements both\nc.reset()      // error: ambiguous\nA.reset(c)     // ok"),
+                "E0371" => ("comptime value does not fit its type", Type,
+                    "A comptime initializer evaluated to a value the declared type can't hold. Comptime arithmetic on unsuffixed literals isn't narrowed, so the check happens on the result.",
+                    "comptime func compute() -> u8 { return 200 + 100 }\nconst MASK: u8 = compute()   // error: 300 is out of range for `u8`"),
+                "E0372" => ("unknown ABI", Type,
+                    "`extern` blocks and exported functions name a calling convention. Only conventions the code generator can emit are accepted: `\"C\"` for C interop and `\"Rask\"` for the native convention.",
+                    "extern \"Cdecl\" func puts(s: *u8) -> i32   // error: did you mean \"C\"?"),
+                "E0373" => ("collection elements don't convert to a trait object", Type,
+                    "A single value converts to `any Trait` when passed where one is expected, but a collection doesn't: `Vec<Point>` stores points inline while `Vec<any Show>` stores a box and vtable per element. Convert each element instead.",
+                    "func show_all(items: Vec<any Show>) { }\n\nconst points: Vec<Point> = Vec.new()\nshow_all(points)   // error: elements can't convert in place"),
+                "E0374" => ("branches have incompatible types", Type,
+                    "An `if`/`else` or `match` used as a value produces one type, so every branch must evaluate to it. Branches that diverge (`return`, `panic`) don't count.",
+                    "func label(c: bool) -> i32 {\n    return if c { 1 } else { \"x\" }   // error: `i32` and `string`\n}"),
+                "E0375" => ("float literal where an integer is expected", Type,
+                    "An unsuffixed integer literal adopts a float type from context (`5` becomes `5.0`), but an unsuffixed float literal never becomes an integer — not even `5.0`. Write the integer literal.",
+                    "const x: f64 = 5     // ok: 5.0\nconst y: i32 = 5.0   // error: write `5`"),
+                "E0376" => ("enum discriminant out of range", Type,
+                    "Every discriminant, explicit or positional, must fit the enum's representation: its backing type, or u16 when the enum is auto-sized. Negative values need a signed backing type.",
+                    "enum Flag: u8 {\n    Low = 0,\n    High = 300,   // error: 300 doesn't fit u8\n}"),
+                "E0377" => ("unknown context parameter", Type,
+                    "A `using` block passes an argument its context's constructor doesn't take. `Multitasking` and `ThreadPool` accept an optional worker count (`workers`) and task bound (`tasks`).",
+                    "using ThreadPool(threads: 4) {\n    // error: ThreadPool has no parameter `threads`\n}"),
+                "E0378" => ("match needs a catch-all arm", Type,
+                    "Literal arms can't cover every string, so a match on `string` must end in an unguarded `_` or name arm. A guarded catch-all doesn't count — its guard can fail.",
+                    "func kind(cmd: string) -> i32 {\n    return match cmd {\n        \"run\" => 1,\n        \"build\" => 2,\n    }  // error: no `_` arm\n}"),
+                "E0379" => ("loop breaks with incompatible types", Type,
+                    "A `loop` used as a value takes its type from `break value`, so every break that targets it must produce the same type. A bare `break` produces `()`.",
+                    "const found = loop {\n    if done { break 1 }\n    if failed { break \"none\" }   // error: `i32` and `string`\n}"),
+                "E0380" => ("pool spawn outside thread pool block", Type,
+                    "`ThreadPool.spawn` submits work to the pool installed by `using ThreadPool { ... }`. Without an enclosing block there is no pool to run it.",
+                    "func main() {\n    ThreadPool.spawn(own || { crunch() })  // error: no pool\n\n    // Fix:\n    using ThreadPool {\n        ThreadPool.spawn(own || { crunch() }).detach()\n    }\n}"),
+                "E0381" => ("borrow captured into a spawned task", Type,
+                    "A spawned task — `spawn`, `Thread.spawn`, or `ThreadPool.spawn` — can outlive the scope that started it, so it can't hold a borrow from that scope. Capture owned values, Copy values, or a `Shared<T>` handle instead.",
+                    "func total(v: Vec<i32>) {\n    using ThreadPool {\n        ThreadPool.spawn(own || { v.len() })  // error: `v` is a borrowed parameter\n    }\n}\n\n// Fix: take ownership\nfunc total(take v: Vec<i32>) { ... }"),
                 "E0355" => ("error type mismatch in try", Type,
                     "`try` propagates the inner error to the enclosing function, so both must use the same error type. If the error types differ, transform with `try expr else |e| OuterErr::from(e)`.",
                     "struct IoError { msg: string }\nstruct ParseError { msg: string }\n\nfunc inner() -> i32 or ParseError { return 42 }\nfunc outer() -> i32 or IoError {\n    const x = try inner()  // error: ParseError != IoError\n    return x\n}"),
@@ -246,6 +318,9 @@ impl Default for ErrorCodeRegistry {
                 "E0813" => ("use after maybe-move", Ownership,
                     "A value moved on some paths but not all (e.g. one `if` branch) was used after the paths merged. The spec treats maybe-moved as moved (O3) — move on every path, or keep the use inside the branch that still owns the value.",
                     "const v = Vec.new()\nif c { take(own v) }\nv.len()  // error: v may have been moved"),
+                "E0820" => ("resource copied", Ownership,
+                    "A @resource value was used after an assignment, field store, or `own` argument already moved it. Resources are linear and never copied implicitly — give the value a single owner and borrow it where it's only read.",
+                    "@resource\nstruct File { fd: i32 }\nconst a = File { fd: 1 }\nconst b = a\nconst c = a  // error: File cannot be copied"),
                 "E0817" => ("invalid `as` cast", Type,
                     "`as` permits only lossless widening (CV1). Narrowing, sign reinterpretation, float↔int, int→char, and int↔bool are compile errors — use the explicit conversion forms (`truncate to`, `saturate to`, `try convert to`, `float to int`) or `char.from_u32`.",
                     "const x: i8 = big as i8  // error: use `big truncate to i8`"),
@@ -253,7 +328,7 @@ impl Default for ErrorCodeRegistry {
                     "A CV5–CV10 conversion form was applied to the wrong source/target kind — e.g. `float to int` on an integer, or `truncate to` producing a non-integer.",
                     "const x = n float to int i32  // error if n is already an integer"),
                 "E0819" => ("index type mismatch", Type,
-                    "An index expression `c[i]` used the wrong index type. Vec, arrays, slices, and strings are position-indexed by an integer; `Map<K,V>` is indexed by `K`; `Pool<T>` is indexed by `Handle<T>`. Range indexing (slicing) only works on Vec, arrays, slices, and strings.",
+                    "An index expression `c[i]` used the wrong index type. Vec, arrays, and slices are position-indexed by an integer; strings are not indexed by position at all — use `s.char_at(i)` or `s.byte_at(i)`; `Map<K,V>` is indexed by `K`; `Pool<T>` is indexed by `Handle<T>`. Range indexing (slicing) only works on Vec, arrays, slices, and strings.",
                     "const s = \"hi\"\nv[s]  // error: index a Vec with an integer, not a string"),
             },
         }
@@ -66,13 +66,13 @@ impl ToDiagnostic for rask_resolve::ResolveError {
                 .with_why("all symbols must be defined before use — Rask requires explicit imports"),
 
             DuplicateDefinition { name, previous } => {
-                Diagnostic::error(format!("duplicate definition: `{}`", name))
+                Diagnostic::error(format!("the name `{}` is defined multiple times", name))
                     .with_code("E0201")
                     .with_primary(self.span, "redefined here")
                     .with_secondary(*previous, "previously defined here")
-                    .with_help("rename one of the definitions")
+                    .with_help("rename or remove one of the definitions")
                     .with_fix("rename one of the definitions")
-                    .with_why("each name can only be defined once in a scope")
+                    .with_why("a module-level name can only be defined once — methods on different types don't collide")
             }
 
             InvalidBreak { label } => {
@@ -122,13 +122,14 @@ impl ToDiagnostic for rask_resolve::ResolveError {
                     .with_why("imported packages must exist in the project or be declared as dependencies")
             }
 
-            NotVisible { name } => {
-                Diagnostic::error(format!("`{}` is not public", name))
+            NotVisible { name, package } => {
+                Diagnostic::error(format!("`{}` is private to package `{}`", name, package))
                     .with_code("E0203")
-                    .with_primary(self.span, "not visible from this scope")
+                    .with_primary(self.span, "not visible from this package")
+                    .with_note(format!("`{}` is declared without `public` in package `{}`", name, package))
                     .with_help("mark the item as `public` to make it accessible")
-                    .with_fix(format!("mark `{}` as `public`, or access it from the defining module", name))
-                    .with_why("items are private by default — only `public` items are accessible from other modules")
+                    .with_fix(format!("mark `{}` as `public` in package `{}`", name, package))
+                    .with_why("items are package-visible by default — only `public` items are accessible from other packages")
             }
 
             ShadowsImport { name } => {
@@ -218,6 +219,33 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_help(format!("change this to type `{}`", expected))
             }
 
+            IncompatibleBranches { first, second, first_span, second_span, expected } => {
+                let diag = Diagnostic::error(format!(
+                    "branches have incompatible types `{}` and `{}`",
+                    first, second
+                ))
+                    .with_code("E0374")
+                    .with_primary(*second_span, format!("this is `{}`", second))
+                    .with_secondary(*first_span, format!("this is `{}`", first))
+                    .with_why("an `if` or `match` used as a value has one type, so every branch must produce it");
+                match expected {
+                    Some(ty) => diag
+                        .with_note(format!("the surrounding context expects `{}`", ty))
+                        .with_help(format!("make every branch produce `{}`", ty)),
+                    None => diag.with_help("convert one branch so both produce the same type"),
+                }
+            }
+
+            IncompatibleBreaks { first, second, first_span, second_span } => Diagnostic::error(format!(
+                "loop breaks with both `{}` and `{}`",
+                first, second
+            ))
+                .with_code("E0379")
+                .with_primary(*second_span, format!("this breaks with `{}`", second))
+                .with_secondary(*first_span, format!("first break produces `{}`", first))
+                .with_why("a `loop` used as a value has one type, so every `break` must produce it")
+                .with_help("convert one break value so both produce the same type"),
+
             Undefined(name) => Diagnostic::error(format!("undefined type: `{}`", name))
                 .with_code("E0309")
                 .with_primary(Span::new(0, 0), "type not found")
@@ -281,6 +309,39 @@ impl ToDiagnostic for rask_types::TypeError {
                 .with_why("method calls are resolved at compile time against the type's extend blocks")
             }
 
+            AmbiguousMethod { method, traits, span } => {
+                let names: Vec<String> = traits.iter().map(|t| format!("`{}`", t)).collect();
+                let listed = match names.split_last() {
+                    Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+                    _ => names.join(""),
+                };
+                let first = traits.first().map(String::as_str).unwrap_or("Trait");
+                Diagnostic::error(format!(
+                    "ambiguous method `{}`: found in traits {}; disambiguate with `{}.{}(x)`",
+                    method, listed, first, method
+                ))
+                .with_code("E0370")
+                .with_primary(*span, format!("`{}` is provided by more than one trait", method))
+                .with_help(format!("name the trait: `{}.{}(x)`", first, method))
+                .with_why("with no inherent method to prefer, picking one trait's method would depend on declaration order")
+            }
+
+            IncomparableTypes { left, right, span } => {
+                Diagnostic::error(format!("cannot compare `{}` with `{}`", left, right))
+                    .with_code("E0364")
+                    .with_primary(*span, format!("`{}` on the left, `{}` on the right", left, right))
+                    .with_help("convert one side so both operands have the same type")
+                    .with_why("comparison operators are defined between values of the same type; there is no implicit conversion")
+            }
+
+            NotInterpolable { ty, span } => {
+                Diagnostic::error(format!("value of type `{}` cannot be interpolated", ty))
+                    .with_code("E0363")
+                    .with_primary(*span, format!("`{}` has no `to_string`", ty))
+                    .with_help(format!("implement `to_string` for `{}`: `extend {} with Displayable {{ ... }}`", ty, ty))
+                    .with_why("interpolation calls `to_string()` on each value, and structs opt in to display [std.fmt/D3, D4]")
+            }
+
             InfiniteType { span, .. } => {
                 Diagnostic::error("infinite type detected")
                     .with_code("E0314")
@@ -290,6 +351,15 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_why("a type cannot contain itself without indirection")
             }
 
+            CannotInferTypeParam { param, function, span } => {
+                Diagnostic::error(format!("cannot infer type parameter `{}`", param))
+                    .with_code("E0366")
+                    .with_primary(*span, format!("`{}` is not determined by this call to `{}`", param, function))
+                    .with_help(format!("name it explicitly: `{}<...>(...)`, or annotate the binding the result goes into", function))
+                    .with_fix(format!("call `{}<...>(...)` with explicit type arguments", function))
+                    .with_why("type parameters are solved from argument and result types; one that appears in neither has no single answer")
+            }
+
             CannotInfer { span } => Diagnostic::error("cannot infer type")
                 .with_code("E0315")
                 .with_primary(*span, "type annotation needed")
@@ -432,6 +502,14 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_why("string slices are temporary views into the string's buffer — storing them would create a dangling reference when the source is freed")
             }
 
+            ReturnsLocalBorrow { name, span } => {
+                Diagnostic::error(format!("returns a borrow of local `{}` which does not live long enough", name))
+                    .with_code("E0804")
+                    .with_primary(*span, format!("view into `{}` escapes the function", name))
+                    .with_help("return an owned copy instead, or take the source as a parameter")
+                    .with_why(format!("`{}` is destroyed when the function returns — a view into it would dangle (mem.borrowing/S3)", name))
+            }
+
             VolatileViewStored { source_var, view_var, source_span, store_span } => {
                 Diagnostic::error(format!("cannot hold view from growable source `{}`", source_var))
                     .with_code("E0322")
@@ -509,6 +587,13 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_primary(*span, "unsafe operation outside unsafe block")
             }
 
+            UnnecessaryUnsafe { span } => {
+                Diagnostic::warning("unnecessary `unsafe` block")
+                    .with_code("W0907")
+                    .with_primary(*span, "no unsafe operations in this block")
+                    .with_help("remove the `unsafe` wrapper")
+            }
+
             TraitObjectSelfReturn { trait_name, method, span } => {
                 Diagnostic::error(format!("method `{}` returns Self — cannot be called through `any {}`", method, trait_name))
                     .with_code("E0332")
@@ -523,6 +608,16 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_help("generic methods can't be dispatched dynamically: each instantiation needs its own code, but a trait object erases the concrete type. Call it on the concrete type instead (TR3)")
             }
 
+            TraitObjectElements { found, trait_name, span } => {
+                Diagnostic::error(format!("`{}` elements can't convert to `any {}` in place", found, trait_name))
+                    .with_code("E0373")
+                    .with_primary(*span, format!("collection of `{}` where one of `any {}` is expected", found, trait_name))
+                    .with_help(format!("build the collection from converted elements: `x as any {}`", trait_name))
+                    .with_why("each `any` element is its own heap box and vtable pointer, so a collection of concrete values has a different layout (TR7)")
+            }
+
+            TraitImpl(err) => err.to_diagnostic(),
+
             TraitNotSatisfied { ty, trait_name, span } => {
                 Diagnostic::error(format!("`{}` does not implement `{}`", ty, trait_name))
                     .with_code("E0333")
@@ -587,6 +682,14 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_why("match expressions must cover all possible values")
             }
 
+            MissingCatchAll { ty, span } => {
+                Diagnostic::error(format!("match on `{}` needs a catch-all arm", ty))
+                    .with_code("E0378")
+                    .with_primary(*span, format!("`{}` values can't all be listed", ty))
+                    .with_help("add a `_ => ...` arm, or bind the value with a name")
+                    .with_why("match expressions must cover all possible values")
+            }
+
             UndefinedName { name, span } => {
                 Diagnostic::error(format!("undefined name `{}`", name))
                     .with_code("E0341")
@@ -596,13 +699,78 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_why("all names must be defined or imported before use")
             }
 
-            UnknownContext { name, span } => {
-                Diagnostic::error(format!("unknown context `{}` in `using` block", name))
+            UnknownContext { name, suggestion, span } => {
+                let diag = Diagnostic::error(format!("unknown context `{}` in `using` block", name))
                     .with_code("E0342")
                     .with_primary(*span, "not a recognized context")
-                    .with_help("valid contexts are: `Multitasking`, `ThreadPool`")
-                    .with_fix("replace with a valid context name")
-                    .with_why("`using` blocks require a known runtime context to initialize")
+                    .with_why("`using` blocks require a known runtime context to initialize");
+                if let Some(s) = suggestion {
+                    diag.with_fix(format!("did you mean `{}`?", s))
+                        .with_help(format!("did you mean `{}`?", s))
+                } else {
+                    diag.with_help("valid contexts are: `Multitasking`, `ThreadPool`")
+                        .with_fix("replace with a valid context name")
+                }
+            }
+
+            UnknownContextArg { ctx, arg, params, span } => {
+                let accepted = params.iter()
+                    .map(|p| format!("`{}`", p))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Diagnostic::error(format!("`{}` has no parameter `{}`", ctx, arg))
+                    .with_code("E0377")
+                    .with_primary(*span, "unknown parameter")
+                    .with_help(format!("`{}` accepts: {}", ctx, accepted))
+            }
+
+            IntLiteralOutOfRange { value, ty, span } => {
+                Diagnostic::error(format!("literal `{}` out of range for `{}`", value, ty))
+                    .with_code("E0369")
+                    .with_primary(*span, format!("doesn't fit in `{}`", ty))
+                    .with_help("use a wider suffix, or drop the suffix and let the type be inferred")
+            }
+
+            FloatLiteralForInteger { expected, span } => {
+                Diagnostic::error(format!("expected `{}`, found float literal", expected))
+                    .with_code("E0375")
+                    .with_primary(*span, format!("float literal where `{}` is expected", expected))
+                    .with_why("an integer literal can stand in for a float, but a float literal never silently becomes an integer [type.primitives/L6]")
+                    .with_help("write an integer literal — `5`, not `5.0`")
+            }
+
+            UnknownAbi { abi, supported, suggestion, span } => {
+                let listed: Vec<String> = supported.iter().map(|a| format!("\"{}\"", a)).collect();
+                let diag = Diagnostic::error(format!("unknown ABI `{}`", abi))
+                    .with_code("E0372")
+                    .with_primary(*span, "unsupported calling convention")
+                    .with_why(format!("supported ABIs: {} [struct.c-interop/CI2]", listed.join(", ")));
+                if let Some(s) = suggestion {
+                    diag.with_fix(format!("did you mean `\"{}\"`?", s))
+                        .with_help(format!("did you mean `\"{}\"`?", s))
+                } else {
+                    diag.with_help(format!("use one of {}", listed.join(", ")))
+                }
+            }
+
+            InvalidMainSignature { found, span } => {
+                Diagnostic::error("invalid `main` signature")
+                    .with_code("E0368")
+                    .with_primary(*span, format!("`main({})` can't be called by the runtime", found))
+                    .with_help("declare `func main()` or `func main(args: []string)`")
+            }
+
+            UnknownContextType { ty, suggestion, span } => {
+                let diag = Diagnostic::error(format!("`{}` is not a context type", ty))
+                    .with_code("E0367")
+                    .with_primary(*span, "unknown context type")
+                    .with_why("`using` clauses thread a hidden parameter; only `Pool<T>` and `Allocator` can be passed this way [mem.context/CC1]");
+                if let Some(s) = suggestion {
+                    diag.with_fix(format!("did you mean `{}`?", s))
+                        .with_help(format!("did you mean `{}`?", s))
+                } else {
+                    diag.with_help("use `using Pool<T>` or `using Allocator`")
+                }
             }
 
             SignatureRuntimeContext { ctx, span } => {
@@ -626,6 +794,71 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_why("spawn() requires an active runtime slot installed by `using Multitasking { }` [conc.async/CC1]")
             }
 
+            PoolSpawnOutsideBlock { span } => {
+                Diagnostic::error("`ThreadPool.spawn` must be inside a `using ThreadPool { ... }` block")
+                    .with_code("E0380")
+                    .with_primary(*span, "pool task spawned here without a pool")
+                    .with_help("wrap this code in `using ThreadPool { ... }`")
+                    .with_why("pool tasks run on the worker threads installed by `using ThreadPool { }` [conc.async/CC4]")
+            }
+
+            BorrowCapturedIntoTask { name, span } => {
+                Diagnostic::error(format!(
+                    "cannot capture borrow `{}` into a spawned task; move it or wrap in Shared",
+                    name
+                ))
+                    .with_code("E0381")
+                    .with_primary(*span, "borrowed value captured here")
+                    .with_help(format!(
+                        "take ownership of `{}` (e.g. a `take` parameter or a clone) before spawning, \
+                         or share it as `Shared<T>`",
+                        name
+                    ))
+                    .with_why("the task can outlive this scope, and nothing keeps the borrow's source alive [conc.async/CC5]")
+            }
+
+            ConstEval { message, span } => {
+                Diagnostic::error(format!("cannot evaluate constant: {}", message))
+                    .with_code("E0360")
+                    .with_primary(*span, "must be known at compile time")
+                    .with_help("use literals, constant globals, and `comptime func` calls here")
+                    .with_why("array lengths are part of the type, so the compiler evaluates them [ctrl.comptime/CT56]")
+            }
+
+            ArrayLengthNotConstant { name, span } => {
+                Diagnostic::error("array length must be a constant")
+                    .with_code("E0361")
+                    .with_primary(*span, format!("`{}` is a runtime value", name))
+                    .with_help("use a constant global or a comptime expression, or a `Vec` for runtime-sized data")
+                    .with_why("an array's length is part of its type and fixes its layout, so it must be known at compile time")
+            }
+
+            NegativeArrayLength { value, span } => {
+                Diagnostic::error("array length cannot be negative")
+                    .with_code("E0362")
+                    .with_primary(*span, format!("evaluates to {}", value))
+                    .with_why("array lengths are `usize` element counts")
+            }
+
+            ComptimeOnlyCall { name, span } => {
+                Diagnostic::error(format!("cannot call comptime-only function `{}` at runtime", name))
+                    .with_code("E0358")
+                    .with_primary(*span, "called from runtime code")
+                    .with_help(format!(
+                        "evaluate it at compile time with `comptime {}(...)`, or drop `comptime` from its declaration",
+                        name
+                    ))
+                    .with_why("a `comptime func` only exists inside the compiler — there is no runtime code to call [ctrl.comptime/CT3]")
+            }
+
+            RuntimeOnlyCallAtComptime { name, reason, span } => {
+                Diagnostic::error(format!("cannot call runtime-only function `{}` at compile time", name))
+                    .with_code("E0359")
+                    .with_primary(*span, format!("`{}` {}", name, reason))
+                    .with_help("move this call out of the comptime position, or compute the value at runtime")
+                    .with_why("comptime code cannot do I/O, spawn tasks, or call foreign code [ctrl.comptime/CT6, CT7]")
+            }
+
             CyclicTypeAlias { cycle, span } => {
                 Diagnostic::error(format!("cyclic type alias: {}", cycle))
                     .with_code("E0343")
@@ -673,6 +906,42 @@ impl ToDiagnostic for rask_types::TypeError {
                 .with_why("Copy types (primitives, small values) are cleaned up automatically — `discard` is only meaningful for heap-allocated or move-only types")
             }
 
+            UnreachableArm { span, shadowed_by } => {
+                Diagnostic::warning("unreachable match arm")
+                    .with_code("W0303")
+                    .with_primary(*span, "this arm can never match")
+                    .with_secondary(*shadowed_by, "already matched here")
+                    .with_help("remove the arm, or move it before the broader pattern")
+            }
+
+            MutateTemporary { method, span } => {
+                Diagnostic::warning(format!("`mutate self` method `{}` called on a temporary has no effect", method))
+                    .with_code("W0304")
+                    .with_primary(*span, "the mutated value is discarded")
+                    .with_help("bind the value first, then call the method: `mut p = ...` followed by `p.method()`")
+            }
+
+            RedundantOptionalOp { op, found, span } => {
+                let (label, help) = if op == "??" {
+                    ("the value is always present, so the default is never used", "remove `??` and the default")
+                } else {
+                    ("the value is always present", "remove the operator")
+                };
+                Diagnostic::warning(format!("redundant `{}` on non-optional `{}`", op, found))
+                    .with_code("W0305")
+                    .with_primary(*span, label)
+                    .with_help(help)
+                    .with_why("`??`, `?.`, `!`, and `try` unwrap `T?` — on a plain `T` they do nothing [type.optionals/OPT3]")
+            }
+
+            FieldMethodCollision { ty, name, field_span, method_span } => {
+                Diagnostic::warning(format!("`{}` has both a field and a method named `{}`", ty, name))
+                    .with_code("W0306")
+                    .with_primary(*method_span, "method declared here")
+                    .with_secondary(*field_span, "field with the same name")
+                    .with_help(format!("`x.{0}` reads the field and `x.{0}()` calls the method — rename one of them", name))
+            }
+
             DiscardResourceType { name, ty, span } => {
                 Diagnostic::error(format!(
                     "cannot `discard` resource `{}` of type `{}`",
@@ -754,6 +1023,20 @@ impl ToDiagnostic for rask_types::TypeError {
                     .with_primary(*span, format!("both `{}` and `{}` have value {}", first, second, value))
                     .with_why("each variant must have a unique discriminant value [type.enums/E15]")
             }
+            DiscriminantOutOfRange { enum_name, variant, value, backing_type, span } => {
+                let diag = Diagnostic::error(format!("discriminant {} on `{}.{}` is out of range", value, enum_name, variant))
+                    .with_code("E0376");
+                match backing_type {
+                    Some(bt) => diag
+                        .with_primary(*span, format!("`{}` does not fit in `{}`", value, bt))
+                        .with_help(format!("pick a value that fits `{}`, or widen the backing type", bt))
+                        .with_why("discriminant values must fit the backing type [type.enums/E15]"),
+                    None => diag
+                        .with_primary(*span, format!("`{}` does not fit the default `u16` tag", value))
+                        .with_help(format!("declare a backing type wide enough, e.g. `enum {}: i64 {{ ... }}`", enum_name))
+                        .with_why("without a backing type, discriminants are auto-sized up to u16 [type.enums/E2]"),
+                }
+            }
             ResultNotDisjoint { ty, span } => {
                 Diagnostic::error(format!("`T or E` needs distinct types — both sides are `{}`", ty))
                     .with_code("E0343")
@@ -925,6 +1208,11 @@ impl ToDiagnostic for rask_types::TypeError {
                         "range indexing produces a slice — only Vec, arrays, slices, and strings support it [std.collections/V1]".to_string(),
                         None,
                     ),
+                    K::StringPosition => (
+                        format!("cannot index `{}` by position", container),
+                        "a string is UTF-8, so position `i` could mean a byte or a char — name the unit explicitly [std.strings/S9]".to_string(),
+                        Some("use `s.char_at(i)` for a `char?` or `s.byte_at(i)` for a `u8?`".to_string()),
+                    ),
                 };
                 let mut diag = Diagnostic::error(label.clone())
                     .with_code("E0819")
@@ -980,18 +1268,47 @@ impl ToDiagnostic for rask_types::TraitError {
             .with_fix(format!("add `func {}(...)` in `extend {} : {}`", method, ty, trait_name))
             .with_why("trait implementations must provide all required methods"),
 
+            MissingMethods {
+                ty,
+                trait_name,
+                missing,
+                span,
+            } => {
+                let names = missing.iter().map(|m| format!("`{}`", m)).collect::<Vec<_>>().join(", ");
+                Diagnostic::error(format!(
+                    "type `{}` does not implement all methods of trait `{}`: missing {}",
+                    ty, trait_name, names
+                ))
+                .with_code("E0701")
+                .with_primary(*span, format!("missing {}", names))
+                .with_help(format!("add the missing methods to `extend {} with {}`", ty, trait_name))
+                .with_fix(format!("add {} to `extend {} with {}`", names, ty, trait_name))
+                .with_why("trait implementations must provide all required methods")
+            }
+
             SignatureMismatch {
+                trait_name,
                 method,
                 expected,
                 found,
                 span,
+                trait_span,
                 ..
-            } => Diagnostic::error(format!("method `{}` has wrong signature", method))
+            } => {
+                let diag = Diagnostic::error(format!(
+                    "method `{}` has signature `{}` but trait `{}` requires `{}`",
+                    method, found, trait_name, expected
+                ))
                 .with_code("E0702")
-                .with_primary(*span, format!("expected `{}`, found `{}`", expected, found))
+                .with_primary(*span, format!("expected `{}`", expected))
                 .with_help(format!("change `{}` signature to match the trait", method))
-                .with_fix(format!("change `{}` signature to match the trait", method))
-                .with_why("trait method signatures are contracts — implementations must match exactly"),
+                .with_fix(format!("change `{}` signature to `{}`", method, expected))
+                .with_why("trait method signatures are contracts — implementations must match exactly");
+                match trait_span {
+                    Some(ts) => diag.with_secondary(*ts, format!("`{}` declared here", method)),
+                    None => diag,
+                }
+            }
 
             UnknownTrait(name) => Diagnostic::error(format!("unknown trait: `{}`", name))
                 .with_code("E0703")
@@ -1139,6 +1456,21 @@ impl ToDiagnostic for rask_ownership::OwnershipError {
                 .with_why("borrowed parameters can only be read — the caller retains ownership")
             }
 
+            ResourceCopied { name, type_name, moved_at } => {
+                Diagnostic::error(format!(
+                    "`{}` is a linear resource and cannot be copied; move it or borrow it",
+                    type_name
+                ))
+                .with_code("E0820")
+                .with_primary(self.span, format!("`{}` used again here", name))
+                .with_secondary(*moved_at, "resource moved here")
+                .with_help(format!(
+                    "pass `{}` to exactly one owner, or borrow it where it's only read",
+                    name
+                ))
+                .with_why("resources must be consumed exactly once — a second use would be a copy")
+            }
+
             ResourceAlreadyConsumed { name, consumed_at } => {
                 Diagnostic::error(format!("resource `{}` already consumed", name))
                     .with_code("E0807")
@@ -1355,6 +1687,43 @@ impl ToDiagnostic for rask_ownership::OwnershipError {
     }
 }
 
+// ============================================================================
+// Comptime Errors
+// ============================================================================
+
+impl ToDiagnostic for rask_comptime::ComptimeDiagnostic {
+    fn to_diagnostic(&self) -> Diagnostic {
+        use rask_comptime::ComptimeError;
+
+        let message = self.error.to_string();
+        let diag = match &self.error {
+            ComptimeError::Panic(_) => Diagnostic::error(message)
+                .with_code("E0365")
+                .with_primary(self.span, "panicked during compile-time evaluation")
+                .with_why("a panic or failed assert at comptime fails the build"),
+            ComptimeError::DivisionByZero => Diagnostic::error(message)
+                .with_code("R0001")
+                .with_primary(self.span, "evaluated here")
+                .with_why("division by zero is undefined"),
+            ComptimeError::IntegerOverflow(_) | ComptimeError::NegativeExponent(_) => {
+                Diagnostic::error(message)
+                    .with_code("R0010")
+                    .with_primary(self.span, "evaluated here")
+                    .with_why("comptime overflow is a compile error (type.overflow/CT1)")
+            }
+            ComptimeError::NotSupported(_) => Diagnostic::error(message)
+                .with_primary(self.span, "not supported at comptime")
+                .with_help("move this to runtime code, or rewrite it with constructs comptime can evaluate"),
+            _ => Diagnostic::error(message)
+                .with_primary(self.span, "evaluated here"),
+        };
+        // Innermost call first, so the labels read outward from the failure.
+        self.frames.iter().rev().fold(diag, |diag, frame| {
+            diag.with_secondary(frame.call_site, format!("in comptime `{}`, called here", frame.function))
+        })
+    }
+}
+
 // ============================================================================
 // Runtime Errors
 // ============================================================================
@@ -1363,7 +1732,7 @@ impl ToDiagnostic for rask_interp::RuntimeDiagnostic {
     fn to_diagnostic(&self) -> Diagnostic {
         use rask_interp::RuntimeError;
 
-        match &self.error {
+        let diagnostic = match &self.error {
             RuntimeError::DivisionByZero => {
                 Diagnostic::error("division by zero")
                     .with_code("R0001")
@@ -1382,9 +1751,9 @@ impl ToDiagnostic for rask_interp::RuntimeDiagnostic {
             }
 
             RuntimeError::IndexOutOfBounds { index, len } => {
-                Diagnostic::error(format!("index {} out of bounds", index))
+                Diagnostic::error(format!("index {} out of bounds: length is {}", index, len))
                     .with_code("R0002")
-                    .with_primary(self.span, format!("length is {}", len))
+                    .with_primary(self.span, "indexed here")
                     .with_help("check the index is within bounds before accessing")
                     .with_fix("add a bounds check: `if index < collection.len() { ... }`")
                     .with_why("accessing an out-of-bounds index is unsafe")
@@ -1489,8 +1858,8 @@ impl ToDiagnostic for rask_interp::RuntimeDiagnostic {
                     .with_why("check detected a test failure")
             }
 
-            RuntimeError::UnwrapError => {
-                Diagnostic::error("unwrap failed: value was None")
+            RuntimeError::UnwrapError { message } => {
+                Diagnostic::error(message)
                     .with_code("R0016")
                     .with_primary(self.span, "unwrap failed here")
                     .with_help("use pattern matching or `??` to handle None safely")
@@ -1504,6 +1873,13 @@ impl ToDiagnostic for rask_interp::RuntimeDiagnostic {
                     .with_primary(self.span, "error occurred here")
             }
 
+            RuntimeError::TestTimedOut(ms) => {
+                Diagnostic::error(format!("test timed out after {} ms", ms))
+                    .with_code("R0018")
+                    .with_primary(self.span, "still running here when the time ran out")
+                    .with_help("raise the budget with `@test(timeout_ms = N)` if the test is just slow")
+            }
+
 
             // Control flow and special cases - no diagnostic
             RuntimeError::Exit(_)
@@ -1517,6 +1893,27 @@ impl ToDiagnostic for rask_interp::RuntimeDiagnostic {
                 Diagnostic::error(format!("{}", self.error))
                     .with_primary(self.span, "control flow")
             }
+        };
+
+        if self.backtrace.is_empty() {
+            diagnostic
+        } else {
+            diagnostic.with_note(render_backtrace(&self.backtrace, self.omitted_frames))
         }
     }
 }
+
+/// Render Rask frames innermost first, one per line.
+fn render_backtrace(frames: &[rask_interp::CallFrame], omitted: usize) -> String {
+    let mut out = String::from("backtrace (most recent call first):");
+    for (i, frame) in frames.iter().enumerate() {
+        match &frame.location {
+            Some(loc) => out.push_str(&format!("\n  {}: {} (called at {})", i, frame.function, loc)),
+            None => out.push_str(&format!("\n  {}: {}", i, frame.function)),
+        }
+    }
+    if omitted > 0 {
+        out.push_str(&format!("\n  ... {} more frame{}", omitted, if omitted == 1 { "" } else { "s" }));
+    }
+    out
+}
@@ -6,6 +6,21 @@
 //! (line/col), and actionable repair suggestions.
 //!
 //! Use `--format json` with any rask command to get this output.
+//!
+//! The shape is versioned by `schema_version`. Field names and nesting only
+//! change together with a bump of [`SCHEMA_VERSION`]; the tests at the bottom
+//! of this file pin the current shape.
+//!
+//! ```text
+//! { schema_version, file, success, phase, error_count, warning_count,
+//!   diagnostics: [{ severity, code?, category?, message,
+//!                   location?: { line, column, byte_offset, source_line },
+//!                   labels: [{ role, message?, start, end, source_line }],
+//!                   notes?, help?, fix?, why?,
+//!                   suggestion?: { span: { start, end }, replacement, result_line } }] }
+//! ```
+//!
+//! `start`/`end` are `{ line, column, byte_offset }`; lines and columns are 1-based.
 
 use serde::Serialize;
 
@@ -13,11 +28,15 @@ use rask_ast::LineMap;
 
 use crate::{codes::ErrorCodeRegistry, Diagnostic, LabelStyle};
 
+/// Current report schema. History:
+/// v2 added `fix`/`why`; v3 renamed the top-level `version` to `schema_version`.
+pub const SCHEMA_VERSION: u32 = 3;
+
 /// A complete JSON diagnostic report for a compilation run.
 #[derive(Debug, Serialize)]
 pub struct DiagnosticReport {
-    /// Schema version for forward compatibility (v2: added fix/why fields).
-    pub version: u32,
+    /// Report shape version — see [`SCHEMA_VERSION`].
+    pub schema_version: u32,
     /// The file that was compiled.
     pub file: String,
     /// Whether compilation succeeded (no errors).
@@ -144,7 +163,7 @@ pub fn to_json_report(
         .collect();
 
     DiagnosticReport {
-        version: 2,
+        schema_version: SCHEMA_VERSION,
         file: file.to_string(),
         success: error_count == 0,
         phase: phase.to_string(),
@@ -258,3 +277,107 @@ fn to_json_diagnostic(
 pub fn to_json_string(report: &DiagnosticReport) -> String {
     serde_json::to_string_pretty(report).unwrap_or_else(|e| format!("{{\"error\": \"{}\"}}", e))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rask_ast::Span;
+    use serde::Deserialize;
+
+    // Independent mirror of the documented schema. `deny_unknown_fields`
+    // makes additions fail here too, so every shape change is deliberate.
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Report {
+        schema_version: u32,
+        file: String,
+        success: bool,
+        phase: String,
+        diagnostics: Vec<Diag>,
+        error_count: usize,
+        warning_count: usize,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Diag {
+        severity: String,
+        code: Option<String>,
+        category: Option<String>,
+        message: String,
+        location: Option<Location>,
+        labels: Vec<Label>,
+        #[serde(default)]
+        notes: Vec<String>,
+        help: Option<String>,
+        suggestion: Option<serde_json::Value>,
+        fix: Option<String>,
+        why: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Location {
+        line: usize,
+        column: usize,
+        byte_offset: usize,
+        source_line: String,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Label {
+        role: String,
+        message: Option<String>,
+        start: Pos,
+        end: Pos,
+        source_line: String,
+    }
+
+    #[derive(Deserialize)]
+    #[serde(deny_unknown_fields)]
+    struct Pos {
+        line: usize,
+        column: usize,
+        byte_offset: usize,
+    }
+
+    #[test]
+    fn report_round_trips_through_documented_schema() {
+        let source = "const x = 1\nconst y = z\n";
+        let diag = Diagnostic::error("undefined symbol `z`")
+            .with_code("E0200")
+            .with_primary(Span::new(22, 23), "not found")
+            .with_help("declare `z` first")
+            .with_fix("add `const z = 0`")
+            .with_why("names must be defined before use");
+        let report = to_json_report(&[diag], source, "main.rk", "resolve");
+        let parsed: Report = serde_json::from_str(&to_json_string(&report)).expect("schema drifted");
+
+        assert_eq!(parsed.schema_version, SCHEMA_VERSION);
+        assert_eq!(parsed.file, "main.rk");
+        assert!(!parsed.success);
+        assert_eq!(parsed.phase, "resolve");
+        assert_eq!((parsed.error_count, parsed.warning_count), (1, 0));
+
+        let d = &parsed.diagnostics[0];
+        assert_eq!(d.severity, "error");
+        assert_eq!(d.code.as_deref(), Some("E0200"));
+        assert_eq!(d.message, "undefined symbol `z`");
+        assert_eq!(d.help.as_deref(), Some("declare `z` first"));
+        assert_eq!(d.fix.as_deref(), Some("add `const z = 0`"));
+        assert_eq!(d.category.as_deref(), Some("Resolution"));
+        assert!(d.why.is_some() && d.notes.is_empty() && d.suggestion.is_none());
+
+        let loc = d.location.as_ref().unwrap();
+        assert_eq!((loc.line, loc.column, loc.byte_offset), (2, 11, 22));
+        assert_eq!(loc.source_line, "const y = z");
+
+        let label = &d.labels[0];
+        assert_eq!(label.role, "primary");
+        assert_eq!(label.message.as_deref(), Some("not found"));
+        assert_eq!((label.start.line, label.start.column), (2, 11));
+        assert_eq!(label.end.byte_offset, 23);
+        assert_eq!(label.source_line, "const y = z");
+    }
+}
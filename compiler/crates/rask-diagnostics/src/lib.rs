@@ -112,6 +112,20 @@ impl Diagnostic {
         }
     }
 
+    /// Informational — never fails a build.
+    pub fn note(message: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Note,
+            code: None,
+            message: message.into(),
+            labels: Vec::new(),
+            notes: Vec::new(),
+            help: None,
+            fix: None,
+            why: None,
+        }
+    }
+
     pub fn with_code(mut self, code: impl Into<String>) -> Self {
         self.code = Some(ErrorCode(code.into()));
         self
@@ -5,9 +5,14 @@
 //! EF4: Functions with `using frozen Pool<T>` that perform Grow or Shrink
 //! effects get a compile error.
 //!
+//! EF7: A function with `using frozen Pool<T>` can't satisfy a callee that
+//! declares `using Pool<T>` — propagation would hand it a mutable pool.
+//!
 //! FL1: Public functions with `using Pool<T>` (not frozen) that only Access
 //! get a warning suggesting they add `frozen`.
 
+use std::collections::HashMap;
+
 use rask_ast::decl::{Decl, DeclKind, FnDecl};
 
 use crate::EffectMap;
@@ -28,33 +33,86 @@ pub struct FrozenDiagnostic {
 /// Check frozen context violations (EF4) and missing-frozen lint (FL1).
 pub fn check(decls: &[Decl], effects: &EffectMap) -> Vec<FrozenDiagnostic> {
     let mut results = Vec::new();
+    let fns = qualified_fns(decls);
+
+    // Pool types each function takes through a mutable `using` clause.
+    let mutable_pools: HashMap<&str, Vec<&str>> = fns
+        .iter()
+        .map(|(qname, f)| {
+            let pools = f
+                .context_clauses
+                .iter()
+                .filter(|c| !c.is_frozen && is_pool_context(&c.ty))
+                .map(|c| c.ty.as_str())
+                .collect();
+            (qname.as_str(), pools)
+        })
+        .collect();
+
+    for (qname, f) in &fns {
+        check_fn(f, qname, effects, &mut results);
+        check_frozen_propagation(f, &mutable_pools, &mut results);
+    }
 
+    results
+}
+
+/// Every function and method with its "name" or "Type.method" qualified name.
+fn qualified_fns(decls: &[Decl]) -> Vec<(String, &FnDecl)> {
+    let mut fns = Vec::new();
     for decl in decls {
         match &decl.kind {
-            DeclKind::Fn(f) => check_fn(f, &f.name, effects, &mut results),
+            DeclKind::Fn(f) => fns.push((f.name.clone(), f)),
             DeclKind::Struct(s) => {
-                for m in &s.methods {
-                    let qname = format!("{}.{}", s.name, m.name);
-                    check_fn(m, &qname, effects, &mut results);
-                }
+                fns.extend(s.methods.iter().map(|m| (format!("{}.{}", s.name, m.name), m)));
             }
             DeclKind::Enum(e) => {
-                for m in &e.methods {
-                    let qname = format!("{}.{}", e.name, m.name);
-                    check_fn(m, &qname, effects, &mut results);
-                }
+                fns.extend(e.methods.iter().map(|m| (format!("{}.{}", e.name, m.name), m)));
             }
             DeclKind::Impl(i) => {
-                for m in &i.methods {
-                    let qname = format!("{}.{}", i.target_ty, m.name);
-                    check_fn(m, &qname, effects, &mut results);
-                }
+                fns.extend(i.methods.iter().map(|m| (format!("{}.{}", i.target_ty, m.name), m)));
             }
             _ => {}
         }
     }
+    fns
+}
 
-    results
+/// EF7: calls from a frozen context into a callee needing the same pool
+/// mutably. A pool parameter of that type takes precedence over the
+/// `using` clause at the call site (CC4), so its presence skips the check.
+fn check_frozen_propagation(
+    f: &FnDecl,
+    mutable_pools: &HashMap<&str, Vec<&str>>,
+    results: &mut Vec<FrozenDiagnostic>,
+) {
+    let frozen: Vec<_> = f
+        .context_clauses
+        .iter()
+        .filter(|c| c.is_frozen && is_pool_context(&c.ty))
+        .filter(|c| !f.params.iter().any(|p| p.ty == c.ty))
+        .collect();
+    if frozen.is_empty() {
+        return;
+    }
+
+    let mut callees: Vec<_> = crate::infer::direct_callees(&f.body).into_iter().collect();
+    callees.sort();
+    for callee in callees {
+        let Some(needs) = mutable_pools.get(callee.as_str()) else { continue };
+        for clause in frozen.iter().filter(|c| needs.contains(&c.ty.as_str())) {
+            results.push(FrozenDiagnostic {
+                code: "comp.advanced/EF7",
+                message: format!(
+                    "`{}` needs a mutable `{}`, but `{}` only has it frozen",
+                    callee, clause.ty, f.name,
+                ),
+                span: f.span,
+                clause_span: Some(clause.span),
+                is_error: true,
+            });
+        }
+    }
 }
 
 fn check_fn(
@@ -146,6 +204,17 @@ mod tests {
         }
     }
 
+    fn call(name: &str) -> Expr {
+        Expr {
+            id: NodeId(0),
+            kind: ExprKind::Call {
+                func: Box::new(ident(name)),
+                args: vec![],
+            },
+            span: sp(),
+        }
+    }
+
     fn expr_stmt(e: Expr) -> Stmt {
         Stmt {
             id: NodeId(0),
@@ -171,6 +240,7 @@ mod tests {
                     name: None,
                     ty: "Pool<Entity>".into(),
                     is_frozen: frozen,
+                    span: Span::new(0, 0),
                 }],
                 body,
                 is_pub,
@@ -292,4 +362,29 @@ mod tests {
         let diags = check(&decls, &effects);
         assert!(diags.is_empty(), "No FL1 when function actually mutates");
     }
+
+    /// EF7: frozen caller propagating into a callee that needs the pool mutable
+    #[test]
+    fn ef7_frozen_caller_of_mutable_callee() {
+        let decls = vec![
+            make_fn_with_clause("render", false, true, vec![expr_stmt(call("heal"))]),
+            make_fn_with_clause("heal", false, false, vec![]),
+        ];
+        let diags = check(&decls, &HashMap::new());
+        assert_eq!(diags.len(), 1, "{:?}", diags);
+        assert_eq!(diags[0].code, "comp.advanced/EF7");
+        assert!(diags[0].is_error);
+        assert!(diags[0].message.contains("`heal` needs a mutable `Pool<Entity>`"));
+    }
+
+    /// Frozen caller of a frozen callee → no error
+    #[test]
+    fn frozen_caller_of_frozen_callee_is_ok() {
+        let decls = vec![
+            make_fn_with_clause("render", false, true, vec![expr_stmt(call("peek"))]),
+            make_fn_with_clause("peek", false, true, vec![]),
+        ];
+        let diags = check(&decls, &HashMap::new());
+        assert!(diags.is_empty(), "{:?}", diags);
+    }
 }
@@ -227,6 +227,14 @@ impl InferPass {
 
 // ── Body classification ──────────────────────────────────────────────
 
+/// Names of the functions a body calls directly, in "name" or "Type.method"
+/// form — the same edges the effect call graph uses.
+pub(crate) fn direct_callees(stmts: &[Stmt]) -> HashSet<String> {
+    let mut callees = HashSet::new();
+    classify_body(stmts, &mut Effects::default(), &mut callees);
+    callees
+}
+
 /// Walk a function body, collecting direct effects and callee names.
 fn classify_body(stmts: &[Stmt], effects: &mut Effects, callees: &mut HashSet<String>) {
     for stmt in stmts {
@@ -302,9 +310,11 @@ fn classify_expr(expr: &Expr, effects: &mut Effects, callees: &mut HashSet<Strin
                 callees.insert(qname);
             }
             // Also record bare method name
-            let direct = sources::classify_call(method);
-            effects.union(direct);
-            callees.insert(method.clone());
+            if !matches!(&object.kind, ExprKind::Ident(t) if sources::is_thread_spawn(t, method)) {
+                let direct = sources::classify_call(method);
+                effects.union(direct);
+                callees.insert(method.clone());
+            }
 
             classify_expr(object, effects, callees);
             for arg in args {
@@ -38,6 +38,13 @@ pub fn classify_call(callee: &str) -> Effects {
     Effects::default()
 }
 
+/// `Thread.spawn` and `ThreadPool.spawn` hand a closure to an OS thread.
+/// Unlike a green `spawn` they do no I/O themselves, so the bare `spawn`
+/// classification doesn't apply to them.
+pub fn is_thread_spawn(receiver: &str, method: &str) -> bool {
+    method == "spawn" && matches!(receiver, "Thread" | "ThreadPool")
+}
+
 fn is_io_source(callee: &str) -> bool {
     matches!(callee,
         // fs module
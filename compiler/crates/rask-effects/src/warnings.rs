@@ -164,7 +164,9 @@ impl<'a> WarnContext<'a> {
                     let qname = format!("{}.{}", type_name, method);
                     self.maybe_warn_io_call(&qname, expr.span, warnings);
                 }
-                self.maybe_warn_io_call(method, expr.span, warnings);
+                if !matches!(&object.kind, ExprKind::Ident(t) if crate::sources::is_thread_spawn(t, method)) {
+                    self.maybe_warn_io_call(method, expr.span, warnings);
+                }
                 self.check_expr(object, warnings);
                 for arg in args {
                     self.check_expr(&arg.expr, warnings);
@@ -490,6 +492,33 @@ mod tests {
         assert!(warnings[0].message.contains("println"));
     }
 
+    #[test]
+    fn cw1_not_raised_for_thread_pool_spawn_itself() {
+        // using ThreadPool { ThreadPool.spawn(...) }
+        let spawn = Expr {
+            id: NodeId(0),
+            kind: ExprKind::MethodCall {
+                object: Box::new(ident("ThreadPool")),
+                method: "spawn".into(),
+                type_args: None,
+                args: vec![],
+            },
+            span: sp(),
+        };
+        let body = vec![expr_stmt(Expr {
+            id: NodeId(0),
+            kind: ExprKind::UsingBlock {
+                name: "ThreadPool".into(),
+                args: vec![],
+                body: vec![expr_stmt(spawn)],
+            },
+            span: sp(),
+        })];
+        let decls = vec![make_fn("fan_out", body)];
+        let warnings = detect(&decls, &HashMap::new());
+        assert!(warnings.is_empty(), "warnings: {:?}", warnings.iter().map(|w| &w.message).collect::<Vec<_>>());
+    }
+
     #[test]
     fn cw2_io_in_loop_without_multitasking() {
         // for x in items { println() } — in a concurrent program
@@ -3,6 +3,8 @@
 pub struct FormatConfig {
     pub indent_width: usize,
     pub max_line_width: usize,
+    /// Sort names inside grouped imports; `false` keeps source order.
+    pub sort_imports: bool,
 }
 
 impl Default for FormatConfig {
@@ -10,6 +12,7 @@ impl Default for FormatConfig {
         Self {
             indent_width: 4,
             max_line_width: 100,
+            sort_imports: true,
         }
     }
 }
@@ -69,6 +69,12 @@ mod tests {
         assert!(output.contains("x: i32"), "should format fields with spacing: {}", output);
     }
 
+    #[test]
+    fn formats_tuple_struct_declaration() {
+        let output = format_source("struct Meters( f64 )\n");
+        assert!(output.contains("struct Meters(f64)"), "should keep positional form: {}", output);
+    }
+
     #[test]
     fn formats_enum_declaration() {
         let input = "enum Color{Red\nGreen\nBlue}";
@@ -121,4 +127,48 @@ func process(items: Vec<i32>) -> i32 {
         let twice = format_source(&output);
         assert_eq!(output, twice, "multiline function should be idempotent");
     }
+
+    #[test]
+    fn grouped_import_names_sorted() {
+        let output = format_source("import json.{encode, decode}\n");
+        assert_eq!(output, "import json.{decode, encode}\n");
+    }
+
+    #[test]
+    fn grouped_import_aliases_single_spaced() {
+        let output = format_source("import num.{Wrapping   as   W,Saturating}\n");
+        assert_eq!(output, "import num.{Saturating, Wrapping as W}\n");
+    }
+
+    #[test]
+    fn grouped_import_order_preserved_when_sorting_disabled() {
+        let config = FormatConfig { sort_imports: false, ..FormatConfig::default() };
+        let output = format_source_with_config("import json.{encode, decode}\n", &config);
+        assert_eq!(output, "import json.{encode, decode}\n");
+    }
+
+    #[test]
+    fn grouped_import_wraps_past_max_width() {
+        let config = FormatConfig { max_line_width: 30, ..FormatConfig::default() };
+        let input = "import collections.{Vector, HashMap, Deque}\n";
+        let output = format_source_with_config(input, &config);
+        assert_eq!(
+            output,
+            "import collections.{\n    Deque,\n    HashMap,\n    Vector,\n}\n"
+        );
+        let twice = format_source_with_config(&output, &config);
+        assert_eq!(output, twice, "wrapped group should be idempotent");
+    }
+
+    #[test]
+    fn flattened_imports_regrouped_by_source_group() {
+        // The parser flattens each group into one decl per name; only names
+        // that shared braces in the source are printed back together.
+        let input = "import fs\nimport json.{encode, decode}\nimport num.{Wrapping}\nimport num.Saturating\nimport thread.{ThreadPool,\n    Thread}\n";
+        let output = format_source(input);
+        assert_eq!(
+            output,
+            "import fs\nimport json.{decode, encode}\nimport num.{Wrapping}\nimport num.Saturating\nimport thread.{Thread, ThreadPool}\n"
+        );
+    }
 }
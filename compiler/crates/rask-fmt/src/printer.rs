@@ -241,8 +241,11 @@ impl<'a> Printer<'a> {
         let mut is_first = true;
         let mut prev_was_import = false;
 
-        for decl in decls {
+        let mut i = 0;
+        while i < decls.len() {
+            let decl = &decls[i];
             let is_import = matches!(decl.kind, DeclKind::Import(_));
+            let group_len = self.grouped_import_len(&decls[i..]);
 
             // Emit comments before this decl (with blank lines from source)
             let comments = self.emit_comments_before(decl.span.start, !is_first);
@@ -261,7 +264,13 @@ impl<'a> Printer<'a> {
                 // Already handled above
             }
 
-            self.format_decl(decl);
+            if group_len > 0 {
+                self.format_import_group(&decls[i..i + group_len]);
+                i += group_len;
+            } else {
+                self.format_decl(decl);
+                i += 1;
+            }
             if !self.output.ends_with('\n') {
                 self.emit_newline();
             }
@@ -271,6 +280,92 @@ impl<'a> Printer<'a> {
         }
     }
 
+    /// The parser expands `import pkg.{A, B}` into one `Import` decl per
+    /// name, all ending where the group ends. Returns how many decls at the
+    /// front of `decls` came from one source group (0 for a plain import),
+    /// so the group can be printed back as a group.
+    fn grouped_import_len(&self, decls: &[Decl]) -> usize {
+        let Some(DeclKind::Import(first)) = decls.first().map(|d| &d.kind) else {
+            return 0;
+        };
+        if first.is_glob || first.path.len() < 2 {
+            return 0;
+        }
+        let span = decls[0].span;
+        let text = self.source.get(span.start..span.end).unwrap_or("");
+        let code = text.split("//").next().unwrap_or("");
+        if !code.contains('{') {
+            return 0;
+        }
+        let prefix = &first.path[..first.path.len() - 1];
+        decls
+            .iter()
+            .take_while(|d| {
+                d.span.end == span.end
+                    && matches!(&d.kind, DeclKind::Import(imp)
+                        if imp.path.len() == first.path.len()
+                            && imp.path.starts_with(prefix)
+                            && imp.is_lazy == first.is_lazy
+                            && !imp.is_glob)
+            })
+            .count()
+    }
+
+    /// Print a re-grouped import: names sorted unless configured otherwise,
+    /// one line if it fits, else one name per line with trailing commas.
+    fn format_import_group(&mut self, decls: &[Decl]) {
+        let imports: Vec<&ImportDecl> = decls
+            .iter()
+            .filter_map(|d| match &d.kind {
+                DeclKind::Import(imp) => Some(imp),
+                _ => None,
+            })
+            .collect();
+        let first = imports[0];
+
+        let mut items: Vec<String> = imports
+            .iter()
+            .map(|imp| {
+                let name = imp.path.last().cloned().unwrap_or_default();
+                match &imp.alias {
+                    Some(alias) => format!("{} as {}", name, alias),
+                    None => name,
+                }
+            })
+            .collect();
+        if self.config.sort_imports {
+            items.sort();
+        }
+
+        let mut head = String::from("import ");
+        if first.is_lazy {
+            head.push_str("lazy ");
+        }
+        head.push_str(&first.path[..first.path.len() - 1].join("."));
+        head.push_str(".{");
+
+        let one_line = format!("{}{}}}", head, items.join(", "));
+        let indent = self.indent * self.config.indent_width;
+        self.emit_indent();
+        if indent + one_line.len() <= self.config.max_line_width {
+            self.emit(&one_line);
+            return;
+        }
+
+        self.emit(&head);
+        self.emit_newline();
+        self.indent += 1;
+        for item in &items {
+            self.emit_indent();
+            self.emit(item);
+            self.emit(",");
+            self.emit_newline();
+        }
+        self.indent -= 1;
+        self.emit_indent();
+        self.emit("}");
+    }
+
     // --- Declarations ---
 
     fn format_decl(&mut self, decl: &Decl) {
@@ -454,6 +549,25 @@ impl<'a> Printer<'a> {
             self.emit(">");
         }
 
+        if s.is_tuple() {
+            // Tuple struct: struct Meters(f64)
+            self.emit("(");
+            for (i, field) in s.fields.iter().enumerate() {
+                if i > 0 {
+                    self.emit(", ");
+                }
+                match field.visibility {
+                    FieldVisibility::Private => self.emit("private "),
+                    FieldVisibility::Public => self.emit("public "),
+                    FieldVisibility::Package => {},
+                }
+                let ty = self.format_type(&field.ty);
+                self.emit(&ty);
+            }
+            self.emit(")");
+            return;
+        }
+
         let source_is_multiline = self.source_text(span).contains('\n');
         let has_methods = !s.methods.is_empty();
 
@@ -79,6 +79,20 @@ impl Interpreter {
             }
             "is_empty" => Ok(Value::Bool(v.lock().unwrap().is_empty())),
             "clear" => { v.lock().unwrap().clear(); Ok(Value::Unit) }
+            // Growth is amortized: when a push exceeds capacity the backing
+            // store at least doubles, so capacity never shrinks on its own.
+            "capacity" => Ok(Value::int(v.lock().unwrap().capacity() as i64)),
+            "reserve" => {
+                let additional = self.expect_int(&args, 0)?;
+                if additional < 0 {
+                    return Err(RuntimeError::TypeError(format!(
+                        "reserve: additional capacity cannot be negative (got {})",
+                        additional
+                    )));
+                }
+                v.lock().unwrap().reserve(additional as usize);
+                Ok(Value::Unit)
+            }
             "iter" => {
                 let state = IteratorState::Vec {
                     items: Arc::clone(v),
@@ -1289,8 +1303,14 @@ impl Interpreter {
                 Ok(Value::Vec(Arc::new(Mutex::new(Vec::new()))))
             }
             (TypeConstructorKind::Vec, "with_capacity") => {
-                let cap = self.expect_int(&args, 0)? as usize;
-                Ok(Value::Vec(Arc::new(Mutex::new(Vec::with_capacity(cap)))))
+                let cap = self.expect_int(&args, 0)?;
+                if cap < 0 {
+                    return Err(RuntimeError::TypeError(format!(
+                        "with_capacity: capacity cannot be negative (got {})",
+                        cap
+                    )));
+                }
+                Ok(Value::Vec(Arc::new(Mutex::new(Vec::with_capacity(cap as usize)))))
             }
             (TypeConstructorKind::Vec, "from") => {
                 // Vec.from(array) — copy array elements into new Vec
@@ -1602,3 +1622,91 @@ impl Interpreter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::interp::Interpreter;
+    use crate::value::{TypeConstructorKind, Value};
+
+    fn capacity(interp: &mut Interpreter, v: &Arc<Mutex<Vec<Value>>>) -> i64 {
+        match interp.call_vec_method(v, "capacity", vec![]) {
+            Ok(Value::Int(n, _)) => n,
+            other => panic!("expected int capacity, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn vec_capacity_grows_monotonically() {
+        let (mut interp, _) = Interpreter::with_captured_output();
+        let v = Arc::new(Mutex::new(Vec::new()));
+        let mut last = capacity(&mut interp, &v);
+        for i in 0..100 {
+            interp.call_vec_method(&v, "push", vec![Value::int(i)]).unwrap();
+            let cap = capacity(&mut interp, &v);
+            assert!(cap >= last, "capacity shrank from {} to {}", last, cap);
+            assert!(cap > i, "capacity {} below length {}", cap, i + 1);
+            last = cap;
+        }
+    }
+
+    #[test]
+    fn vec_with_capacity_reports_at_least_n() {
+        let (mut interp, _) = Interpreter::with_captured_output();
+        let value = interp
+            .call_type_constructor_method(&TypeConstructorKind::Vec, None, "with_capacity", vec![Value::int(32)])
+            .unwrap();
+        let v = match value {
+            Value::Vec(v) => v,
+            other => panic!("expected Vec, got {:?}", other),
+        };
+        assert!(capacity(&mut interp, &v) >= 32);
+    }
+
+    #[test]
+    fn vec_reserve_makes_room_for_additional() {
+        let (mut interp, _) = Interpreter::with_captured_output();
+        let v = Arc::new(Mutex::new(vec![Value::int(1), Value::int(2)]));
+        interp.call_vec_method(&v, "reserve", vec![Value::int(10)]).unwrap();
+        assert!(capacity(&mut interp, &v) >= 12);
+        assert!(interp.call_vec_method(&v, "reserve", vec![Value::int(-1)]).is_err());
+    }
+
+    fn map_keys(interp: &mut Interpreter, m: &Arc<Mutex<Vec<(Value, Value)>>>) -> Vec<String> {
+        match interp.call_map_method(m, "keys", vec![]) {
+            Ok(Value::Vec(keys)) => keys.lock().unwrap().iter().map(|k| k.to_string()).collect(),
+            other => panic!("expected Vec of keys, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn map_iterates_in_insertion_order() {
+        let (mut interp, _) = Interpreter::with_captured_output();
+        let m = Arc::new(Mutex::new(Vec::new()));
+        for k in ["pear", "apple", "fig"] {
+            interp.call_map_method(&m, "insert", vec![Value::String(Arc::new(Mutex::new(k.to_string()))), Value::int(1)]).unwrap();
+        }
+        assert_eq!(map_keys(&mut interp, &m), ["pear", "apple", "fig"]);
+        // A second iteration sees the same order.
+        assert_eq!(map_keys(&mut interp, &m), ["pear", "apple", "fig"]);
+    }
+
+    #[test]
+    fn map_duplicate_insert_keeps_position() {
+        let (mut interp, _) = Interpreter::with_captured_output();
+        let m = Arc::new(Mutex::new(Vec::new()));
+        for (k, v) in [(3, 30), (1, 10), (3, 31), (2, 20)] {
+            interp.call_map_method(&m, "insert", vec![Value::int(k), Value::int(v)]).unwrap();
+        }
+        assert_eq!(map_keys(&mut interp, &m), ["3", "1", "2"]);
+        assert_eq!(interp.call_map_method(&m, "len", vec![]).unwrap().to_string(), "3");
+        match interp.call_map_method(&m, "get", vec![Value::int(3)]).unwrap() {
+            Value::Enum { variant, fields, .. } => {
+                assert_eq!(variant, "Some");
+                assert_eq!(fields[0].to_string(), "31");
+            }
+            other => panic!("expected Option, got {:?}", other),
+        }
+    }
+}
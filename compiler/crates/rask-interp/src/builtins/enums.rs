@@ -155,7 +155,9 @@ impl Interpreter {
             },
             "unwrap" => match variant {
                 "Some" => Ok(fields.first().cloned().unwrap_or(Value::Unit)),
-                "None" => Err(RuntimeError::Panic("called unwrap on None".to_string())),
+                "None" => Err(RuntimeError::UnwrapError {
+                    message: "unwrap failed: value was None".to_string(),
+                }),
                 _ => Err(RuntimeError::TypeError("expected Option.Some or Option.None variant".to_string())),
             },
             "filter" => match variant {
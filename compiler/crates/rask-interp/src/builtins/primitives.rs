@@ -275,6 +275,26 @@ impl Interpreter {
             "to_uppercase" => Ok(Value::Char(c.to_uppercase().next().unwrap_or(c))),
             "to_lowercase" => Ok(Value::Char(c.to_lowercase().next().unwrap_or(c))),
             "len_utf8" => Ok(Value::int(c.len_utf8() as i64)),
+            "to_digit" => {
+                let radix = self.expect_int(args, 0)?;
+                if !(2..=36).contains(&radix) {
+                    return Err(RuntimeError::Panic(format!("to_digit: radix {} out of range 2..=36", radix)));
+                }
+                Ok(match c.to_digit(radix as u32) {
+                    Some(d) => Value::Enum {
+                        name: "Option".to_string(),
+                        variant: "Some".to_string(),
+                        fields: vec![Value::int(d as i64)],
+                        variant_index: 0, origin: None,
+                    },
+                    None => Value::Enum {
+                        name: "Option".to_string(),
+                        variant: "None".to_string(),
+                        fields: vec![],
+                        variant_index: 0, origin: None,
+                    },
+                })
+            }
             "to_string" => Ok(Value::String(Arc::new(Mutex::new(c.to_string())))),
             "eq" => { let other = self.expect_char(args, 0)?; Ok(Value::Bool(c == other)) }
             "compare" => { let other = self.expect_char(args, 0)?; Ok(ordering_value(c.cmp(&other))) }
@@ -108,6 +108,12 @@ impl Interpreter {
                     .collect();
                 Ok(Value::Vec(Arc::new(Mutex::new(pairs))))
             }
+            "enumerate" => {
+                let pairs: Vec<Value> = s.lock().unwrap().chars().enumerate()
+                    .map(|(i, c)| Value::Vec(Arc::new(Mutex::new(vec![Value::int(i as i64), Value::Char(c)]))))
+                    .collect();
+                Ok(Value::Vec(Arc::new(Mutex::new(pairs))))
+            }
             "bytes" => {
                 let bytes: Vec<Value> = s.lock().unwrap().bytes()
                     .map(|b| Value::int(b as i64))
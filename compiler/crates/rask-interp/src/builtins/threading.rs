@@ -27,6 +27,7 @@ impl Interpreter {
             "join" => {
                 self.consume_handle(handle);
                 let jh = handle.handle.lock().unwrap().take();
+                let _wait = crate::tasks::blocked();
                 match jh {
                     Some(jh) => match jh.join() {
                         // Thread succeeded - return Ok(value)
@@ -103,6 +104,7 @@ impl Interpreter {
                 self.consume_handle(handle);
                 // Try receiver first (pool-submitted tasks)
                 let rx = handle.receiver.lock().unwrap().take();
+                let _wait = crate::tasks::blocked();
                 if let Some(rx) = rx {
                     return match rx.recv() {
                         Ok(Ok(val)) => Ok(Value::Enum {
@@ -247,6 +249,7 @@ impl Interpreter {
             "send" => {
                 let val = args.into_iter().next().unwrap_or(Value::Unit);
                 let tx = tx.lock().unwrap();
+                let _wait = crate::tasks::blocked();
                 match tx.send(val) {
                     Ok(()) => Ok(Value::Enum {
                         name: "Result".to_string(),
@@ -307,6 +310,12 @@ impl Interpreter {
                     variant_index: 0, origin: None,
                 })
             }
+            "clone" => {
+                // A separate handle, so closing one producer leaves the others
+                // open. The channel closes once every handle is closed or dropped.
+                let tx = tx.lock().unwrap().clone();
+                Ok(Value::Sender(Arc::new(Mutex::new(tx))))
+            }
             _ => Err(RuntimeError::NoSuchMethod {
                 ty: "Sender".to_string(),
                 method: method.to_string(),
@@ -323,6 +332,7 @@ impl Interpreter {
         match method {
             "recv" => {
                 let rx = rx.lock().unwrap();
+                let _wait = crate::tasks::blocked();
                 match rx.recv() {
                     Ok(val) => Ok(Value::Enum {
                         name: "Result".to_string(),
@@ -385,3 +395,168 @@ impl Interpreter {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::interp::Interpreter;
+
+    fn run_output(src: &str) -> String {
+        let lexed = rask_lexer::Lexer::new(src).tokenize();
+        assert!(lexed.is_ok(), "lex errors: {:?}", lexed.errors);
+        let parsed = rask_parser::Parser::new(lexed.tokens).parse();
+        assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+        let (mut interp, output) = Interpreter::with_captured_output();
+        if let Err(diag) = interp.run(&parsed.decls) {
+            panic!("runtime error: {:?}", diag.error);
+        }
+        let out = output.lock().unwrap().clone();
+        out
+    }
+
+    #[test]
+    fn panic_under_mutex_releases_without_poisoning() {
+        // ctrl.panic/LK1–LK2: no poison flag — the next locker succeeds and
+        // sees the writes made before the panic. Poisoning was rejected in
+        // favor of opt-in staged access (conc.sync/ST1–ST4).
+        let out = run_output("\
+import thread.Thread
+
+func main() {
+    const m = Mutex.new(0)
+    const worker = m.clone()
+    const h = Thread.spawn(|| {
+        with worker as v {
+            v = 7
+            panic(\"boom\")
+        }
+    })
+    match h.join() {
+        Ok(_) => println(\"joined\"),
+        Err(e) => println(\"panicked\"),
+    }
+    with m as v {
+        println(v)
+    }
+}
+");
+        assert_eq!(out, "panicked\n7\n");
+    }
+
+    #[test]
+    fn receiver_drains_then_reports_closed() {
+        let out = run_output("\
+func main() {
+    const (tx, rx) = Channel<i32>.buffered(3)
+    tx.send(1)
+    tx.send(2)
+    tx.send(3)
+    tx.close()
+    for msg in rx {
+        println(msg)
+    }
+    match rx.recv() {
+        Ok(v) => println(\"unexpected\"),
+        Err(e) => println(e),
+    }
+}
+");
+        assert_eq!(out, "1\n2\n3\nchannel closed\n");
+    }
+
+    #[test]
+    fn channel_stays_open_while_a_cloned_sender_lives() {
+        let out = run_output("\
+func main() {
+    const (tx, rx) = Channel<i32>.buffered(4)
+    const tx2 = tx.clone()
+    tx.send(1)
+    tx.close()
+    tx2.send(2)
+    match rx.recv() {
+        Ok(v) => println(v),
+        Err(e) => println(e),
+    }
+    match rx.recv() {
+        Ok(v) => println(v),
+        Err(e) => println(e),
+    }
+    tx2.close()
+    match rx.recv() {
+        Ok(v) => println(v),
+        Err(e) => println(e),
+    }
+}
+");
+        assert_eq!(out, "1\n2\nchannel closed\n");
+    }
+
+    #[test]
+    fn select_prefers_ready_channel_over_default() {
+        let out = run_output("\
+func main() {
+    const (tx, rx) = Channel<i32>.buffered(1)
+    tx.send(5)
+    const r = select {
+        rx -> v: v,
+        _: 0,
+    }
+    println(r)
+}
+");
+        assert_eq!(out, "5\n");
+    }
+
+    #[test]
+    fn select_takes_default_when_nothing_ready() {
+        let out = run_output("\
+func main() {
+    const (tx, rx) = Channel<i32>.buffered(1)
+    const r = select {
+        rx -> v: v,
+        _: 0,
+    }
+    println(r)
+}
+");
+        assert_eq!(out, "0\n");
+    }
+
+    #[test]
+    fn select_timer_arm_times_out() {
+        // conc.select: a timeout is a receive arm on Timer.after
+        let out = run_output("\
+import time
+
+func main() {
+    const (tx, rx) = Channel<i32>.buffered(1)
+    const r = select {
+        rx -> v: \"value\",
+        time.Timer.after(time.Duration.from_millis(20)) -> _: \"timeout\",
+    }
+    println(r)
+}
+");
+        assert_eq!(out, "timeout\n");
+    }
+
+    #[test]
+    fn select_on_all_closed_channels_panics() {
+        let src = "\
+func main() {
+    const (tx, rx) = Channel<i32>.buffered(1)
+    tx.close()
+    const r = select {
+        rx -> v: v,
+    }
+}
+";
+        let parsed = rask_parser::Parser::new(rask_lexer::Lexer::new(src).tokenize().tokens).parse();
+        let (mut interp, _) = Interpreter::with_captured_output();
+        let diag = interp.run(&parsed.decls).expect_err("select should panic");
+        assert!(
+            matches!(&diag.error, crate::interp::RuntimeError::Panic(m) if m == "select: all channels closed"),
+            "{:?}",
+            diag.error
+        );
+    }
+}
@@ -8,10 +8,50 @@ use rask_ast::Span;
 
 use crate::value::Value;
 
-use super::{Interpreter, RuntimeDiagnostic, RuntimeError};
+use super::{CallFrame, Interpreter, RuntimeDiagnostic, RuntimeError};
 
 impl Interpreter {
-    pub(crate) fn call_function(&mut self, func: &FnDecl, mut args: Vec<Value>) -> Result<Value, RuntimeDiagnostic> {
+    /// Call a user function, tracking its frame for panic backtraces.
+    pub(crate) fn call_function(&mut self, func: &FnDecl, args: Vec<Value>) -> Result<Value, RuntimeDiagnostic> {
+        let call_site = std::mem::replace(&mut self.pending_call_site, Span::new(0, 0));
+        self.call_stack.push(CallFrame {
+            function: func.name.clone(),
+            call_site,
+            location: None,
+        });
+
+        let result = self.call_function_frame(func, args);
+
+        match &result {
+            // Innermost frame sees the panic first; keep that snapshot.
+            Err(diag) if diag.error.is_panic() && self.panic_backtrace.is_none() => {
+                self.panic_backtrace = Some(self.capture_backtrace());
+            }
+            // A completed call means any earlier panic was contained.
+            Ok(_) => self.panic_backtrace = None,
+            _ => {}
+        }
+
+        self.call_stack.pop();
+        result
+    }
+
+    /// Snapshot the call stack innermost first, skipping compiler-generated frames.
+    fn capture_backtrace(&self) -> Vec<CallFrame> {
+        self.call_stack
+            .iter()
+            .rev()
+            .filter(|frame| !frame.is_generated())
+            .map(|frame| {
+                let has_site = frame.call_site != Span::new(0, 0);
+                let location = (has_site && self.source_info.is_some())
+                    .then(|| self.origin_string(frame.call_site).to_string());
+                CallFrame { location, ..frame.clone() }
+            })
+            .collect()
+    }
+
+    fn call_function_frame(&mut self, func: &FnDecl, mut args: Vec<Value>) -> Result<Value, RuntimeDiagnostic> {
         // Fill in default values for missing trailing arguments
         if args.len() < func.params.len() {
             for i in args.len()..func.params.len() {
@@ -141,6 +181,13 @@ impl Interpreter {
 
     /// Runs ensure blocks in LIFO order on block exit.
     pub(super) fn exec_stmts(&mut self, stmts: &[Stmt]) -> Result<Value, RuntimeDiagnostic> {
+        // Dropping the deadline on expiry lets ensure blocks still run while
+        // the timeout unwinds the test.
+        if self.test_deadline.as_mut().is_some_and(|d| d.expired()) {
+            let timeout_ms = self.test_deadline.take().map_or(0, |d| d.timeout_ms);
+            let span = stmts.first().map_or(Span::new(0, 0), |s| s.span);
+            return Err(RuntimeDiagnostic::new(RuntimeError::TestTimedOut(timeout_ms), span));
+        }
         let mut last_value = Value::Unit;
         let mut ensures: Vec<&Stmt> = Vec::new();
         let mut exit_error: Option<RuntimeDiagnostic> = None;
@@ -361,3 +408,143 @@ fn value_matches_any_type(value: &Value, names: &[String]) -> bool {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use crate::interp::{Interpreter, RuntimeError};
+
+    fn run(src: &str) -> Result<crate::value::Value, crate::interp::RuntimeDiagnostic> {
+        let lexed = rask_lexer::Lexer::new(src).tokenize();
+        assert!(lexed.is_ok(), "lex errors: {:?}", lexed.errors);
+        let parsed = rask_parser::Parser::new(lexed.tokens).parse();
+        assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+        let (mut interp, _) = Interpreter::with_captured_output();
+        interp.set_source_info("test.rk", src);
+        interp.run(&parsed.decls)
+    }
+
+    #[test]
+    fn panic_reports_rask_frames_innermost_first() {
+        let src = "\
+func inner() {
+    panic(\"boom\")
+}
+
+func middle() {
+    inner()
+}
+
+func outer() {
+    middle()
+}
+
+func main() {
+    outer()
+}
+";
+        let diag = run(src).expect_err("program should panic");
+        assert!(matches!(diag.error, RuntimeError::Panic(_)));
+        let names: Vec<&str> = diag.backtrace.iter().map(|f| f.function.as_str()).collect();
+        assert_eq!(names, vec!["inner", "middle", "outer", "main"]);
+        assert_eq!(diag.backtrace[0].location.as_deref(), Some("test.rk:6"));
+        assert_eq!(diag.backtrace[3].location, None);
+    }
+
+    #[test]
+    fn trim_backtrace_keeps_innermost_frames() {
+        let src = "\
+func inner() {
+    panic(\"boom\")
+}
+
+func main() {
+    inner()
+}
+";
+        let mut diag = run(src).expect_err("program should panic");
+        diag.trim_backtrace(1);
+        assert_eq!(diag.backtrace.len(), 1);
+        assert_eq!(diag.backtrace[0].function, "inner");
+        assert_eq!(diag.omitted_frames, 1);
+    }
+
+    #[test]
+    fn out_of_bounds_index_reports_index_and_length() {
+        let src = "\
+func main() {
+    const v = Vec.from([1, 2, 3])
+    const x = v[5]
+}
+";
+        let diag = run(src).expect_err("index should be out of bounds");
+        assert!(
+            matches!(diag.error, RuntimeError::IndexOutOfBounds { index: 5, len: 3 }),
+            "{:?}",
+            diag.error
+        );
+        assert_eq!(diag.error.to_string(), "index 5 out of bounds: length is 3");
+        assert_eq!(&src[diag.span.start..diag.span.end], "v[5]");
+    }
+
+    #[test]
+    fn unwrap_on_none_reports_message() {
+        let src = "\
+func main() {
+    const a: i32? = none
+    const b = a!
+    const c: i32? = none
+    const d = c! \"no value\"
+}
+";
+        let diag = run(src).expect_err("unwrap should fail");
+        assert!(
+            matches!(&diag.error, RuntimeError::UnwrapError { message } if message == "unwrap failed: value was None"),
+            "{:?}",
+            diag.error
+        );
+        assert_eq!(&src[diag.span.start..diag.span.end], "a!");
+
+        let custom = src.replace("const b = a!\n", "");
+        let diag = run(&custom).expect_err("unwrap should fail");
+        assert!(
+            matches!(&diag.error, RuntimeError::UnwrapError { message } if message == "no value"),
+            "{:?}",
+            diag.error
+        );
+    }
+
+    fn run_with_args(src: &str, args: &[&str]) -> String {
+        let parsed = rask_parser::Parser::new(rask_lexer::Lexer::new(src).tokenize().tokens).parse();
+        assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+        let (mut interp, output) = Interpreter::with_captured_output();
+        interp.cli_args = args.iter().map(|a| a.to_string()).collect();
+        interp.run(&parsed.decls).expect("program should run");
+        let out = output.lock().unwrap().clone();
+        out
+    }
+
+    #[test]
+    fn main_receives_args_after_program_path() {
+        let src = "\
+func main(args: []string) {
+    for a in args {
+        println(a)
+    }
+}
+";
+        assert_eq!(run_with_args(src, &["prog.rk", "one", "two"]), "one\ntwo\n");
+    }
+
+    #[test]
+    fn zero_arg_main_ignores_cli_args() {
+        let src = "func main() {\n    println(\"hi\")\n}\n";
+        assert_eq!(run_with_args(src, &["prog.rk", "ignored"]), "hi\n");
+    }
+
+    #[test]
+    fn non_panic_error_has_no_backtrace() {
+        let diag = run("func helper() {}\n").expect_err("no entry point");
+        assert!(matches!(diag.error, RuntimeError::NoEntryPoint));
+        assert!(diag.backtrace.is_empty());
+    }
+}
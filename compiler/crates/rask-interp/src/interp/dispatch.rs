@@ -215,6 +215,23 @@ impl Interpreter {
                     ))
                 }
             }
+            BuiltinKind::AssertNe => {
+                if args.len() < 2 {
+                    return Err(RuntimeError::ArityMismatch { expected: 2, got: args.len() });
+                }
+                if !Self::value_eq(&args[0], &args[1]) {
+                    Ok(Value::Unit)
+                } else {
+                    let msg = if args.len() > 2 {
+                        format!("{}", args[2])
+                    } else {
+                        "assert_ne failed".to_string()
+                    };
+                    Err(RuntimeError::AssertionFailed(
+                        format!("{}\n  both:     {}", msg, args[0])
+                    ))
+                }
+            }
             BuiltinKind::Skip => {
                 let reason = args
                     .first()
@@ -4,7 +4,8 @@
 use indexmap::IndexMap;
 use std::sync::{Arc, Mutex, RwLock, mpsc};
 
-use rask_ast::expr::{BinOp, Expr, ExprKind, UnaryOp};
+use rask_ast::expr::{BinOp, CallArg, Expr, ExprKind, UnaryOp};
+use rask_ast::Span;
 
 use crate::value::{ModuleKind, PoolTask, ThreadHandleInner, ThreadPoolInner, TypeConstructorKind, Value};
 
@@ -21,6 +22,11 @@ const SPAWN_NO_RUNTIME_MSG: &str =
      \n\
      Install a `using Multitasking { ... }` block that encloses the call.";
 
+/// `none` — the value that short-circuits `?.` and selects the `??` default.
+fn is_option_none(value: &Value) -> bool {
+    matches!(value, Value::Enum { name, variant, .. } if name == "Option" && variant == "None")
+}
+
 /// Set origin on an error value (the inner payload of Err). Only sets if not already set (ER15).
 fn set_error_origin(val: Value, origin: &Arc<str>) -> Value {
     match val {
@@ -277,6 +283,10 @@ impl Interpreter {
             ExprKind::Call { func, args } => {
                 if let ExprKind::OptionalField { object, field } = &func.kind {
                     let obj_val = self.eval_expr(object)?;
+                    // OPT10: `none?.m(x)` short-circuits before `x` is evaluated.
+                    if is_option_none(&obj_val) {
+                        return Ok(obj_val);
+                    }
                     let arg_vals: Vec<Value> = args
                         .iter()
                         .map(|a| self.eval_expr(&a.expr))
@@ -301,29 +311,16 @@ impl Interpreter {
                                 }
                                 _ => {}
                             }
-                        } else if name == "Option" {
-                            match variant.as_str() {
-                                "Some" => {
-                                    let inner = fields.first().cloned().unwrap_or(Value::Unit);
-                                    let result = self.call_method(inner, field, arg_vals)
-                                        .map_err(|e| RuntimeDiagnostic::new(e, expr.span))?;
-                                    return Ok(Value::Enum {
-                                        name: "Option".to_string(),
-                                        variant: "Some".to_string(),
-                                        fields: vec![result],
-                                        variant_index: 0, origin: None,
-                                    });
-                                }
-                                "None" => {
-                                    return Ok(Value::Enum {
-                                        name: "Option".to_string(),
-                                        variant: "None".to_string(),
-                                        fields: vec![],
-                                        variant_index: 0, origin: None,
-                                    });
-                                }
-                                _ => {}
-                            }
+                        } else if name == "Option" && variant == "Some" {
+                            let inner = fields.first().cloned().unwrap_or(Value::Unit);
+                            let result = self.call_method(inner, field, arg_vals)
+                                .map_err(|e| RuntimeDiagnostic::new(e, expr.span))?;
+                            return Ok(Value::Enum {
+                                name: "Option".to_string(),
+                                variant: "Some".to_string(),
+                                fields: vec![result],
+                                variant_index: 0, origin: None,
+                            });
                         }
                     }
 
@@ -337,6 +334,7 @@ impl Interpreter {
                     .map(|a| self.eval_expr(&a.expr))
                     .collect::<Result<_, _>>()?;
 
+                self.pending_call_site = expr.span;
                 self.call_value(func_val, arg_vals)
                     .map_err(|e| RuntimeDiagnostic::new(e, expr.span))
             }
@@ -474,6 +472,7 @@ impl Interpreter {
                                     .iter()
                                     .map(|a| self.eval_expr(&a.expr))
                                     .collect::<Result<_, _>>()?;
+                                self.pending_call_site = expr.span;
                                 return self.call_function(method_fn, arg_vals);
                             }
                         }
@@ -515,6 +514,7 @@ impl Interpreter {
                 if let Value::Package(pkg_name) = &receiver {
                     let prefixed = format!("{}${}", pkg_name, method);
                     if let Some(func) = self.functions.get(&prefixed).cloned() {
+                        self.pending_call_site = expr.span;
                         return self.call_function(&func, arg_vals);
                     }
                     return Err(RuntimeDiagnostic::new(
@@ -533,6 +533,7 @@ impl Interpreter {
                     return result.map_err(|e| RuntimeDiagnostic::new(e, expr.span));
                 }
 
+                self.pending_call_site = expr.span;
                 self.call_method(receiver, method, arg_vals)
                     .map_err(|e| RuntimeDiagnostic::new(e, expr.span))
             }
@@ -820,6 +821,7 @@ impl Interpreter {
                         match field.as_str() {
                             "Instant" => Ok(Value::Type("Instant".to_string())),
                             "Duration" => Ok(Value::Type("Duration".to_string())),
+                            "Timer" => Ok(Value::Type("Timer".to_string())),
                             _ => Err(RuntimeDiagnostic::new(
                                 RuntimeError::TypeError(format!(
                                     "time module has no member '{}'",
@@ -991,56 +993,42 @@ impl Interpreter {
 
             ExprKind::OptionalField { object, field } => {
                 let obj_val = self.eval_expr(object)?;
-                match obj_val {
-                    Value::Enum { variant, fields, .. } if variant == "Some" => {
-                        let inner = fields.into_iter().next().unwrap_or(Value::Unit);
-                        // Access field on the inner value
-                        let field_val = match inner {
-                            Value::Struct(ref s) => {
-                                s.lock().unwrap().fields.get(field).cloned().unwrap_or(Value::Unit)
-                            }
-                            _ => {
-                                return Err(RuntimeDiagnostic::new(
-                                    RuntimeError::TypeError(format!(
-                                        "cannot access field '{}' on {}",
-                                        field, inner.type_name()
-                                    )),
-                                    expr.span,
-                                ));
-                            }
-                        };
-                        // If the field is already an Option, return it directly
-                        if let Value::Enum { ref name, .. } = field_val {
-                            if name == "Option" {
-                                return Ok(field_val);
-                            }
-                        }
-                        // Wrap in Some
-                        Ok(Value::Enum {
-                            name: "Option".to_string(),
-                            variant: "Some".to_string(),
-                            fields: vec![field_val],
-                            variant_index: 0, origin: None,
-                        })
-                    }
-                    Value::Enum { variant, .. } if variant == "None" => {
-                        Ok(Value::Enum {
-                            name: "Option".to_string(),
-                            variant: "None".to_string(),
-                            fields: vec![],
-                            variant_index: 0, origin: None,
-                        })
+                // A present `T?` may be held as a bare `T` (struct fields and
+                // locals aren't wrapped), the same as `??` accepts below.
+                let inner = match obj_val {
+                    ref v if is_option_none(v) => return Ok(obj_val),
+                    Value::Enum { name, variant, fields, .. } if name == "Option" && variant == "Some" => {
+                        fields.into_iter().next().unwrap_or(Value::Unit)
+                    }
+                    other => other,
+                };
+                let field_val = match inner {
+                    Value::Struct(ref s) => {
+                        s.lock().unwrap().fields.get(field).cloned().unwrap_or(Value::Unit)
                     }
                     _ => {
-                        Err(RuntimeDiagnostic::new(
+                        return Err(RuntimeDiagnostic::new(
                             RuntimeError::TypeError(format!(
-                                "?. requires Option type, got {}",
-                                obj_val.type_name()
+                                "cannot access field '{}' on {}",
+                                field, inner.type_name()
                             )),
                             expr.span,
-                        ))
+                        ));
+                    }
+                };
+                // If the field is already an Option, return it directly
+                if let Value::Enum { ref name, .. } = field_val {
+                    if name == "Option" {
+                        return Ok(field_val);
                     }
                 }
+                // Wrap in Some
+                Ok(Value::Enum {
+                    name: "Option".to_string(),
+                    variant: "Some".to_string(),
+                    fields: vec![field_val],
+                    variant_index: 0, origin: None,
+                })
             }
 
             ExprKind::Index { object, index } => {
@@ -1054,10 +1042,7 @@ impl Interpreter {
                         match vec.get(idx).cloned() {
                             Some(val) => Ok(val),
                             None => Err(RuntimeDiagnostic::new(
-                                RuntimeError::Panic(format!(
-                                    "index out of bounds: index is {} but length is {}",
-                                    i, vec.len()
-                                )),
+                                RuntimeError::IndexOutOfBounds { index: *i, len: vec.len() },
                                 expr.span,
                             )),
                         }
@@ -1075,19 +1060,14 @@ impl Interpreter {
                         let slice: Vec<Value> = vec[start_idx..end_idx].to_vec();
                         Ok(Value::Vec(Arc::new(Mutex::new(slice))))
                     }
-                    (Value::String(s), Value::Int(i, _)) => {
-                        let str_val = s.lock().unwrap();
-                        match str_val.chars().nth(*i as usize) {
-                            Some(c) => Ok(Value::Char(c)),
-                            None => Err(RuntimeDiagnostic::new(
-                                RuntimeError::Panic(format!(
-                                    "string index out of bounds: index is {} but length is {}",
-                                    i, str_val.chars().count()
-                                )),
-                                expr.span,
-                            )),
-                        }
-                    }
+                    // std.strings/S9: the checker rejects `s[i]`; stay consistent
+                    // for unchecked programs instead of guessing chars.
+                    (Value::String(_), Value::Int(..)) => Err(RuntimeDiagnostic::new(
+                        RuntimeError::TypeError(
+                            "cannot index string by position; use char_at or byte_at".to_string(),
+                        ),
+                        expr.span,
+                    )),
                     (Value::String(s), Value::Range { start, end, inclusive }) => {
                         let str_val = s.lock().unwrap();
                         let len = str_val.len() as i64;
@@ -1365,27 +1345,13 @@ impl Interpreter {
                         variant, fields, ..
                     } => match variant.as_str() {
                         "Some" => Ok(fields.first().cloned().unwrap_or(Value::Unit)),
-                        "None" => {
-                            if let Some(msg) = message {
-                                Err(RuntimeDiagnostic::new(
-                                    RuntimeError::Panic(msg.clone()),
-                                    expr.span
-                                ))
-                            } else {
-                                Err(RuntimeDiagnostic::new(RuntimeError::UnwrapError, expr.span))
-                            }
+                        "None" | "Err" => {
+                            let message = message.clone().unwrap_or_else(|| {
+                                format!("unwrap failed: value was {}", variant)
+                            });
+                            Err(RuntimeDiagnostic::new(RuntimeError::UnwrapError { message }, expr.span))
                         }
                         "Ok" => Ok(fields.first().cloned().unwrap_or(Value::Unit)),
-                        "Err" => {
-                            if let Some(msg) = message {
-                                Err(RuntimeDiagnostic::new(
-                                    RuntimeError::Panic(msg.clone()),
-                                    expr.span
-                                ))
-                            } else {
-                                Err(RuntimeDiagnostic::new(RuntimeError::UnwrapError, expr.span))
-                            }
-                        }
                         _ => Err(RuntimeDiagnostic::new(
                             RuntimeError::TypeError(format!(
                                 "! operator requires Option or Result, got {}",
@@ -1493,11 +1459,7 @@ impl Interpreter {
                     {
                         Ok(fields.first().cloned().unwrap_or(Value::Unit))
                     }
-                    Value::Enum { name, variant, .. }
-                        if name == "Option" && variant == "None" =>
-                    {
-                        self.eval_expr(default)
-                    }
+                    v if is_option_none(v) => self.eval_expr(default),
                     // T or none (Result.Ok / Result.Err with none payload)
                     Value::Enum { name, variant, fields, .. }
                         if name == "Result" && variant == "Ok" =>
@@ -1605,14 +1567,7 @@ impl Interpreter {
             ExprKind::UsingBlock { name, args, body }
                 if name == "ThreadPool" || name == "threading" =>
             {
-                let num_threads = if args.is_empty() {
-                    std::thread::available_parallelism()
-                        .map(|n| n.get())
-                        .unwrap_or(4)
-                } else {
-                    self.eval_expr(&args[0].expr)?.as_int()
-                        .map_err(|e| RuntimeDiagnostic::new(RuntimeError::TypeError(e), expr.span))? as usize
-                };
+                let (num_threads, max_tasks) = self.runtime_config(args, expr.span)?;
 
                 let (tx, rx) = mpsc::channel::<PoolTask>();
                 let rx = Arc::new(Mutex::new(rx));
@@ -1620,24 +1575,15 @@ impl Interpreter {
 
                 for _ in 0..num_threads {
                     let rx = Arc::clone(&rx);
-                    workers.push(std::thread::spawn(move || {
-                        loop {
-                            let task = {
-                                let rx = rx.lock().unwrap();
-                                rx.recv()
-                            };
-                            match task {
-                                Ok(task) => (task.work)(),
-                                Err(_) => break,
-                            }
-                        }
-                    }));
+                    workers.push(std::thread::spawn(move || crate::value::run_pool_worker(rx)));
                 }
 
                 let pool = Arc::new(ThreadPoolInner {
                     sender: Mutex::new(Some(tx)),
                     workers: Mutex::new(Vec::new()),
                     size: num_threads,
+                    in_flight: Arc::new((Mutex::new(0), std::sync::Condvar::new())),
+                    max_tasks,
                 });
 
                 self.env.push_scope();
@@ -1671,16 +1617,12 @@ impl Interpreter {
             {
                 use crate::value::{MultitaskingRuntime, ACTIVE_RUNTIME};
 
-                let num_workers = if args.is_empty() {
-                    std::thread::available_parallelism()
-                        .map(|n| n.get())
-                        .unwrap_or(4)
-                } else {
-                    self.eval_expr(&args[0].expr)?.as_int()
-                        .map_err(|e| RuntimeDiagnostic::new(RuntimeError::TypeError(e), expr.span))? as usize
+                let (num_workers, max_tasks) = self.runtime_config(args, expr.span)?;
+                let runtime = match max_tasks {
+                    Some(max) => MultitaskingRuntime::new(num_workers).with_max_tasks(max),
+                    None => MultitaskingRuntime::new(num_workers),
                 };
-
-                let runtime = Arc::new(MultitaskingRuntime::new(num_workers));
+                let runtime = Arc::new(runtime);
 
                 // C1: exactly one active block per process — nested blocks panic at runtime
                 {
@@ -1705,7 +1647,7 @@ impl Interpreter {
                     match self.exec_stmt(stmt) {
                         Ok(val) => result = val,
                         Err(e) => {
-                            runtime.shutdown();
+                            runtime.abort();
                             *ACTIVE_RUNTIME.write().unwrap() = None;
                             self.env.pop_scope();
                             return Err(e);
@@ -1715,7 +1657,7 @@ impl Interpreter {
 
                 // Check for unconsumed handles (conc.async/H1)
                 if let Err(msg) = self.resource_tracker.check_scope_exit(scope_depth) {
-                    runtime.shutdown();
+                    runtime.abort();
                     *ACTIVE_RUNTIME.write().unwrap() = None;
                     self.env.pop_scope();
                     return Err(RuntimeDiagnostic::new(
@@ -1751,8 +1693,10 @@ impl Interpreter {
 
                 // Submit to the multitasking thread pool
                 let (result_tx, result_rx) = std::sync::mpsc::channel();
+                let registration = crate::tasks::register(None);
                 let task = PoolTask {
                     work: Box::new(move || {
+                        let _task = registration.enter();
                         let mut interp = child;
                         let mut result = Value::Unit;
                         for stmt in &body {
@@ -1768,12 +1712,7 @@ impl Interpreter {
                     }),
                 };
 
-                {
-                    let sender = rt.sender.lock().unwrap();
-                    if let Some(tx) = sender.as_ref() {
-                        let _ = tx.send(task);
-                    }
-                }
+                rt.submit(task);
 
                 let handle_inner = Arc::new(ThreadHandleInner {
                     handle: Mutex::new(None),
@@ -2160,9 +2099,9 @@ impl Interpreter {
                                         // Execute this arm's body with binding
                                         self.env.push_scope();
                                         self.env.define(binding.clone(), val);
-                                        let result = self.eval_expr(&arms[entry.arm_idx].body)?;
+                                        let result = self.eval_expr(&arms[entry.arm_idx].body);
                                         self.env.pop_scope();
-                                        return Ok(result);
+                                        return result;
                                     }
                                     Err(mpsc::TryRecvError::Empty) => {
                                         all_closed = false;
@@ -2192,23 +2131,21 @@ impl Interpreter {
                         }
                     }
 
-                    // All channels closed (CL1)
-                    if all_closed && default_idx.is_none() {
-                        return Ok(Value::Enum {
-                            name: "Result".to_string(),
-                            variant: "Err".to_string(),
-                            fields: vec![Value::String(Arc::new(Mutex::new(
-                                "all channels closed".to_string(),
-                            )))],
-                            variant_index: 0, origin: None,
-                        });
-                    }
-
-                    // Default arm fires if nothing ready (A3)
+                    // Default arm fires if nothing is ready right now (A3),
+                    // whether the rest are empty or closed.
                     if let Some(idx) = default_idx {
                         return self.eval_expr(&arms[idx].body);
                     }
 
+                    // All channels closed (CL1): nothing can ever fire. Same
+                    // panic as rask_select_wait in the compiled runtime.
+                    if all_closed {
+                        return Err(RuntimeDiagnostic::new(
+                            RuntimeError::Panic("select: all channels closed".to_string()),
+                            expr.span,
+                        ));
+                    }
+
                     // Backoff
                     std::thread::sleep(std::time::Duration::from_micros(backoff_us));
                     backoff_us = (backoff_us * 2).min(max_backoff_us);
@@ -2218,5 +2155,169 @@ impl Interpreter {
             _ => Ok(Value::Unit),
         }
     }
+
+    /// Worker count and task bound from a `using Multitasking(...)` or
+    /// `using ThreadPool(...)` config: `workers` then `tasks`, positional or
+    /// named. Workers default to the CPU count; no `tasks` (or 0) means
+    /// unbounded.
+    fn runtime_config(
+        &mut self,
+        args: &[CallArg],
+        span: Span,
+    ) -> Result<(usize, Option<usize>), RuntimeDiagnostic> {
+        let mut workers = None;
+        let mut max_tasks = None;
+        for (i, arg) in args.iter().enumerate() {
+            let n = self.eval_expr(&arg.expr)?.as_int()
+                .map_err(|e| RuntimeDiagnostic::new(RuntimeError::TypeError(e), span))?;
+            match arg.name.as_deref() {
+                Some("tasks") => max_tasks = Some(n),
+                Some(_) => workers = Some(n),
+                None if i == 0 => workers = Some(n),
+                None => max_tasks = Some(n),
+            }
+        }
+        if let Some(n) = max_tasks.filter(|n| *n < 0) {
+            return Err(RuntimeDiagnostic::new(
+                RuntimeError::Panic(format!("runtime `tasks` bound cannot be negative, got {}", n)),
+                span,
+            ));
+        }
+        let workers = match workers {
+            Some(n) => n as usize,
+            None => std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(4),
+        };
+        Ok((workers, max_tasks.filter(|n| *n > 0).map(|n| n as usize)))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::interp::Interpreter;
+
+    fn run_output(src: &str) -> String {
+        let parsed = rask_parser::Parser::new(rask_lexer::Lexer::new(src).tokenize().tokens).parse();
+        assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+        let (mut interp, output) = Interpreter::with_captured_output();
+        interp.run(&parsed.decls).expect("program should run");
+        let out = output.lock().unwrap().clone();
+        out
+    }
+
+    #[test]
+    fn null_coalesce_evaluates_default_only_when_absent() {
+        let src = "\
+func fallback() -> i32 {
+    println(\"fallback ran\")
+    return 0
+}
+
+func main() {
+    const present: i32? = 5
+    const absent: i32? = none
+    println(present ?? fallback())
+    println(absent ?? fallback())
+}
+";
+        assert_eq!(run_output(src), "5\nfallback ran\n0\n");
+    }
+
+    #[test]
+    fn optional_chain_short_circuits_on_first_none() {
+        let src = "\
+struct Profile {
+    name: string
+}
+
+extend Profile {
+    func tagged(self, tag: string) -> string {
+        return self.name.concat(tag)
+    }
+}
+
+struct User {
+    profile: Profile?
 }
 
+func tag() -> string {
+    println(\"tag ran\")
+    return \"!\"
+}
+
+func main() {
+    const ada: User? = User { profile: Profile { name: \"ada\" } }
+    const anon: User? = User { profile: none }
+    const nobody: User? = none
+    println(ada?.profile?.name ?? \"guest\")
+    println(anon?.profile?.name ?? \"guest\")
+    println(nobody?.profile?.name ?? \"guest\")
+    println(nobody?.profile?.tagged(tag()) ?? \"skipped\")
+    println(ada?.profile?.tagged(tag()) ?? \"skipped\")
+}
+";
+        assert_eq!(run_output(src), "ada\nguest\nguest\nskipped\ntag ran\nada!\n");
+    }
+
+    #[test]
+    fn detached_task_finishes_before_multitasking_block_exits() {
+        let src = "\
+import async.spawn
+import time
+
+func main() {
+    using Multitasking {
+        spawn(|| {
+            time.sleep(time.Duration.from_millis(50))
+            println(\"late task\")
+        }).detach()
+    }
+    println(\"after block\")
+}
+";
+        assert_eq!(run_output(src), "late task\nafter block\n");
+    }
+
+    #[test]
+    fn spawn_inside_a_task_is_admitted_past_the_task_bound() {
+        let src = "\
+import async.spawn
+
+func main() {
+    using Multitasking(workers: 2, tasks: 1) {
+        const outer = spawn(|| {
+            const inner = spawn(|| { println(\"inner\") })
+            try inner.join()
+            println(\"outer\")
+        })
+        try outer.join()
+    }
+    println(\"done\")
+}
+";
+        assert_eq!(run_output(src), "inner\nouter\ndone\n");
+    }
+
+    #[test]
+    fn enumerate_yields_index_element_pairs() {
+        let src = "\
+func main() {
+    const v = Vec.from([10, 20])
+    for (i, x) in v.enumerate() {
+        println(i + x)
+    }
+    const a = [7, 8]
+    for (i, x) in a.enumerate() {
+        println(i * 100 + x)
+    }
+    for (i, c) in \"hé!\".enumerate() {
+        println(i)
+        println(c)
+    }
+}
+";
+        assert_eq!(run_output(src), "10\n21\n7\n108\n0\nh\n1\né\n2\n!\n");
+    }
+}
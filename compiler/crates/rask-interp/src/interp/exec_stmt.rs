@@ -539,6 +539,33 @@ impl Interpreter {
                         }
                         Ok(Value::Unit)
                     }
+                    // CH3: receive until every sender is closed or dropped and
+                    // the buffer is drained.
+                    Value::Receiver(rx) => {
+                        loop {
+                            let next = rx.lock().unwrap().recv();
+                            let Ok(item) = next else { break };
+                            self.env.push_scope();
+                            self.define_for_binding(binding, item);
+                            match self.exec_stmts(body) {
+                                Ok(_) => {}
+                                Err(diag) if matches!(diag.error, RuntimeError::Break(_)) => {
+                                    self.env.pop_scope();
+                                    break;
+                                }
+                                Err(diag) if matches!(diag.error, RuntimeError::Continue) => {
+                                    self.env.pop_scope();
+                                    continue;
+                                }
+                                Err(e) => {
+                                    self.env.pop_scope();
+                                    return Err(e);
+                                }
+                            }
+                            self.env.pop_scope();
+                        }
+                        Ok(Value::Unit)
+                    }
                     _ => Err(RuntimeDiagnostic::new(
                         RuntimeError::TypeError(format!(
                             "cannot iterate over {}",
@@ -636,6 +663,10 @@ impl Interpreter {
 /// shaped like the annotation. For Result, picks Ok vs Err by the value's type.
 fn auto_wrap_for_annotation(value: Value, ty: &str) -> Value {
     let ty = ty.trim();
+    // L6: an integer literal bound to a float annotation is that float.
+    if let (Value::Int(n, _), "f32" | "f64") = (&value, ty) {
+        return Value::Float(*n as f64);
+    }
     if ty.ends_with('?') && !ty.starts_with('(') {
         // T? annotation
         if matches!(&value, Value::Enum { name, .. } if name == "Option") {
@@ -94,6 +94,63 @@ pub struct Interpreter {
     /// recover integer widths for overflow checking (type.overflow). Empty
     /// when types weren't supplied (e.g. comptime pre-check paths).
     pub(crate) node_types: HashMap<rask_ast::NodeId, rask_types::Type>,
+    /// Active Rask call frames, outermost first.
+    pub(crate) call_stack: Vec<CallFrame>,
+    /// Span of the call expression about to enter `call_function`.
+    pub(crate) pending_call_site: Span,
+    /// Frames captured where the current panic was raised, innermost first.
+    pub(crate) panic_backtrace: Option<Vec<CallFrame>>,
+    /// Time budget of the test being run, if any.
+    pub(crate) test_deadline: Option<TestDeadline>,
+    /// Budget for tests without `@test(timeout_ms = N)`.
+    pub default_test_timeout_ms: u64,
+}
+
+/// Default per-test time budget.
+pub const DEFAULT_TEST_TIMEOUT_MS: u64 = 10_000;
+
+/// A running test's time budget. Blocks count as steps and the clock is
+/// only read every `CHECK_EVERY` steps, so the hot path is a counter bump.
+/// Every loop iteration and call runs a block, so a hung test can't dodge it.
+#[derive(Debug)]
+pub(crate) struct TestDeadline {
+    at: std::time::Instant,
+    timeout_ms: u64,
+    steps: u32,
+}
+
+impl TestDeadline {
+    const CHECK_EVERY: u32 = 1024;
+
+    fn new(timeout_ms: u64) -> Self {
+        Self {
+            at: std::time::Instant::now() + std::time::Duration::from_millis(timeout_ms),
+            timeout_ms,
+            steps: 0,
+        }
+    }
+
+    fn expired(&mut self) -> bool {
+        self.steps = self.steps.wrapping_add(1);
+        self.steps.is_multiple_of(Self::CHECK_EVERY) && std::time::Instant::now() >= self.at
+    }
+}
+
+/// One Rask-level frame in a panic backtrace.
+#[derive(Debug, Clone)]
+pub struct CallFrame {
+    pub function: String,
+    /// Where the function was called from. Empty for the entry point.
+    pub call_site: Span,
+    /// `file.rk:line` of the call site, when source info is available.
+    pub location: Option<String>,
+}
+
+impl CallFrame {
+    /// Frames for functions the compiler synthesized (`__`-prefixed).
+    pub fn is_generated(&self) -> bool {
+        self.function.starts_with("__")
+    }
 }
 
 /// Source location info for computing error origins (ER15).
@@ -119,6 +176,11 @@ impl Interpreter {
             source_info: None,
             binary_structs: HashMap::new(),
             node_types: HashMap::new(),
+            call_stack: Vec::new(),
+            pending_call_site: Span::new(0, 0),
+            panic_backtrace: None,
+            test_deadline: None,
+            default_test_timeout_ms: DEFAULT_TEST_TIMEOUT_MS,
         }
     }
 
@@ -137,6 +199,11 @@ impl Interpreter {
             node_types: HashMap::new(),
             build_state: None,
             source_info: None,
+            call_stack: Vec::new(),
+            pending_call_site: Span::new(0, 0),
+            panic_backtrace: None,
+            test_deadline: None,
+            default_test_timeout_ms: DEFAULT_TEST_TIMEOUT_MS,
         }
     }
 
@@ -157,6 +224,11 @@ impl Interpreter {
             source_info: None,
             binary_structs: HashMap::new(),
             node_types: HashMap::new(),
+            call_stack: Vec::new(),
+            pending_call_site: Span::new(0, 0),
+            panic_backtrace: None,
+            test_deadline: None,
+            default_test_timeout_ms: DEFAULT_TEST_TIMEOUT_MS,
         };
         (interp, buffer)
     }
@@ -212,6 +284,7 @@ impl Interpreter {
         child.struct_decls = self.struct_decls.clone();
         child.methods = self.methods.clone();
         child.node_types = self.node_types.clone();
+        child.output_buffer = self.output_buffer.clone();
         for (name, value) in captured_vars {
             child.env.define(name, value);
         }
@@ -268,6 +341,7 @@ impl Interpreter {
 
     /// Spawn an async task from a closure (spawn() in using Multitasking).
     /// In interpreter: uses OS thread but returns TaskHandle for type distinction.
+    /// A string argument (`name: "..."`) names the task in the registry (TN1).
     pub(crate) fn spawn_async_task(&mut self, args: Vec<Value>) -> Result<Value, RuntimeError> {
         use crate::value::ThreadHandleInner;
 
@@ -278,14 +352,19 @@ impl Interpreter {
         }
 
         // Check for active runtime slot (CC3 fallback)
-        if crate::value::ACTIVE_RUNTIME.read().unwrap().is_none() {
+        let Some(runtime) = crate::value::ACTIVE_RUNTIME.read().unwrap().clone() else {
             return Err(RuntimeError::Panic(
                 "RUNTIME PANIC: spawn() called with no active `using Multitasking` scope\n\
                  Install a `using Multitasking { ... }` block that encloses the call.".to_string(),
             ));
-        }
+        };
 
-        let closure = &args[0];
+        // Named arguments arrive positionally; the name may precede the closure.
+        let name = args.iter().find_map(|a| match a {
+            Value::String(s) => Some(s.lock().unwrap().clone()),
+            _ => None,
+        });
+        let closure = args.iter().find(|a| !matches!(a, Value::String(_))).unwrap_or(&args[0]);
         match closure {
             Value::Closure {
                 params,
@@ -301,8 +380,13 @@ impl Interpreter {
                 let body = body.clone();
                 let captured = captured_env.clone();
                 let child = self.spawn_child(captured);
+                let registration = crate::tasks::register(name);
+                // Block exit drains this task even if it's detached (C4).
+                let outstanding = runtime.track();
 
                 let join_handle = std::thread::spawn(move || {
+                    let _outstanding = outstanding;
+                    let _task = registration.enter();
                     let mut interp = child;
                     match interp.eval_expr(&body).map_err(|diag| diag.error) {
                         Ok(val) => Ok(val),
@@ -369,9 +453,12 @@ impl Interpreter {
                 let child = self.spawn_child(captured);
 
                 let (result_tx, result_rx) = mpsc::sync_channel::<Result<Value, String>>(1);
+                // A full pool blocks the spawner here (C8).
+                let slot = pool.admit();
 
                 let task = PoolTask {
                     work: Box::new(move || {
+                        let _slot = slot;
                         let mut interp = child;
                         match interp.eval_expr(&body).map_err(|diag| diag.error) {
                             Ok(val) => {
@@ -501,12 +588,31 @@ impl Interpreter {
             .map_err(|e| RuntimeDiagnostic::new(e, Span::new(0, 0)))?;
 
         if let Some(entry) = registered.entry_fn {
-            self.call_function(&entry, vec![])
+            self.panic_backtrace = None;
+            let args = if entry.params.is_empty() {
+                vec![]
+            } else {
+                vec![self.main_args()]
+            };
+            self.call_function(&entry, args)
+                .map_err(|diag| self.attach_backtrace(diag))
         } else {
             Err(RuntimeDiagnostic::new(RuntimeError::NoEntryPoint, Span::new(0, 0)))
         }
     }
 
+    /// `main(args: []string)` receives the user arguments only; the program
+    /// path at `cli_args[0]` stays reachable through `cli.args()`.
+    fn main_args(&self) -> Value {
+        let args: Vec<Value> = self
+            .cli_args
+            .iter()
+            .skip(1)
+            .map(|s| Value::String(Arc::new(Mutex::new(s.clone()))))
+            .collect();
+        Value::Vec(Arc::new(Mutex::new(args)))
+    }
+
     /// Run a build script: register declarations, find `func build(ctx)`,
     /// call it with the BuildContext value. Sets `build_state` so method
     /// dispatch can accumulate link flags and other state.
@@ -537,6 +643,14 @@ impl Interpreter {
         }
     }
 
+    /// Attach the captured Rask backtrace to a panicking diagnostic.
+    fn attach_backtrace(&mut self, diag: RuntimeDiagnostic) -> RuntimeDiagnostic {
+        match self.panic_backtrace.take() {
+            Some(frames) if diag.error.is_panic() => diag.with_backtrace(frames),
+            _ => diag,
+        }
+    }
+
     /// Take the build state after a build script finishes.
     pub fn take_build_state(&mut self) -> Option<crate::build_context::BuildState> {
         self.build_state.take()
@@ -606,6 +720,22 @@ impl Interpreter {
     }
 }
 
+impl RuntimeError {
+    /// Errors that kill the task (ctrl.panic), as opposed to control flow
+    /// or test bookkeeping.
+    pub fn is_panic(&self) -> bool {
+        matches!(
+            self,
+            RuntimeError::Panic(_)
+                | RuntimeError::UnwrapError { .. }
+                | RuntimeError::AssertionFailed(_)
+                | RuntimeError::IntegerOverflow(_)
+                | RuntimeError::DivisionByZero
+                | RuntimeError::IndexOutOfBounds { .. }
+        )
+    }
+}
+
 impl Default for Interpreter {
     fn default() -> Self {
         Self::new()
@@ -639,7 +769,7 @@ pub enum RuntimeError {
     #[error("no field `{field}` on type `{ty}`")]
     NoSuchField { ty: String, field: String },
 
-    #[error("index {index} out of bounds (length is {len})")]
+    #[error("index {index} out of bounds: length is {len}")]
     IndexOutOfBounds { index: i64, len: usize },
 
     #[error("resource is closed; cannot {operation} a closed {resource_type}")]
@@ -677,9 +807,9 @@ pub enum RuntimeError {
     #[error("try error")]
     TryError(Value),
 
-    /// Unwrap on None panics
-    #[error("unwrap failed: value was None")]
-    UnwrapError,
+    /// Unwrap on None/Err panics; `message` is the `!` message if one was given
+    #[error("{message}")]
+    UnwrapError { message: String },
 
     /// Assertion failed (assert expr) — stops test immediately
     #[error("assertion failed: {0}")]
@@ -696,6 +826,10 @@ pub enum RuntimeError {
     /// Test expects failure via expect_fail()
     #[error("expect_fail")]
     TestExpectFail,
+
+    /// Test ran past its time budget — always a failure, even under expect_fail
+    #[error("test timed out after {0} ms")]
+    TestTimedOut(u64),
 }
 
 /// Runtime error with source location for diagnostic display.
@@ -703,11 +837,27 @@ pub enum RuntimeError {
 pub struct RuntimeDiagnostic {
     pub error: RuntimeError,
     pub span: Span,
+    /// Rask call frames at the panic site, innermost first. Empty for
+    /// non-panic errors.
+    pub backtrace: Vec<CallFrame>,
+    /// Outer frames dropped by `trim_backtrace`.
+    pub omitted_frames: usize,
 }
 
 impl RuntimeDiagnostic {
     pub fn new(error: RuntimeError, span: Span) -> Self {
-        Self { error, span }
+        Self { error, span, backtrace: Vec::new(), omitted_frames: 0 }
+    }
+
+    pub fn with_backtrace(mut self, frames: Vec<CallFrame>) -> Self {
+        self.backtrace = frames;
+        self
+    }
+
+    /// Keep only the innermost `max` frames.
+    pub fn trim_backtrace(&mut self, max: usize) {
+        self.omitted_frames += self.backtrace.len().saturating_sub(max);
+        self.backtrace.truncate(max);
     }
 }
 
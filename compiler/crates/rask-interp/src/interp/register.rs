@@ -8,7 +8,7 @@ use rask_ast::Span;
 
 use crate::value::{BuiltinKind, ModuleKind, TypeConstructorKind, Value};
 
-use super::{Interpreter, RegisteredProgram, RuntimeError, TestResult, BenchmarkResult};
+use super::{Interpreter, RegisteredProgram, RuntimeError, TestDeadline, TestResult, BenchmarkResult};
 
 /// Strip generic type parameters from a type name.
 /// "Box<T>" → "Box", "SpscRingBuffer<T, N>" → "SpscRingBuffer", "Point" → "Point"
@@ -59,6 +59,9 @@ impl Interpreter {
             (ModuleKind::Time, "Duration") => {
                 self.env.define(alias.to_string(), Value::Type("Duration".to_string()));
             }
+            (ModuleKind::Time, "Timer") => {
+                self.env.define(alias.to_string(), Value::Type("Timer".to_string()));
+            }
             (ModuleKind::Path, "Path") => {
                 self.env.define(alias.to_string(), Value::Type("Path".to_string()));
             }
@@ -72,7 +75,7 @@ impl Interpreter {
     fn register_glob_companions(env: &mut crate::env::Environment, module: ModuleKind) {
         let types: &[&str] = match module {
             ModuleKind::Http => &["Request", "Response", "Method", "Headers", "HttpServer", "Responder", "HttpClient"],
-            ModuleKind::Time => &["Instant", "Duration"],
+            ModuleKind::Time => &["Instant", "Duration", "Timer"],
             ModuleKind::Path => &["Path"],
             ModuleKind::Fs => &["File", "Metadata"],
             ModuleKind::Io => &["Stdin", "Stdout", "Stderr", "Buffer", "IoError"],
@@ -104,7 +107,7 @@ impl Interpreter {
                         }
                         entry_fn = Some(f.clone());
                     }
-                    if f.attrs.iter().any(|a| a == "test") {
+                    if f.is_test() {
                         test_fns.push(f.clone());
                     }
                     let fn_name = strip_generics(&f.name).to_string();
@@ -239,6 +242,8 @@ impl Interpreter {
             .define("clamp".to_string(), Value::Builtin(BuiltinKind::Clamp));
         self.env
             .define("assert_eq".to_string(), Value::Builtin(BuiltinKind::AssertEq));
+        self.env
+            .define("assert_ne".to_string(), Value::Builtin(BuiltinKind::AssertNe));
         self.env
             .define("skip".to_string(), Value::Builtin(BuiltinKind::Skip));
         self.env
@@ -408,6 +413,12 @@ impl Interpreter {
         })
     }
 
+    /// Arm the time budget for one test; `None` takes the default.
+    fn start_test_clock(&mut self, timeout_ms: Option<u64>) {
+        let timeout_ms = timeout_ms.unwrap_or(self.default_test_timeout_ms);
+        self.test_deadline = Some(TestDeadline::new(timeout_ms));
+    }
+
     /// Run a single test block with isolation and check-continuation.
     pub(super) fn run_single_test(&mut self, name: &str, body: &[Stmt]) -> TestResult {
         let start = std::time::Instant::now();
@@ -415,6 +426,9 @@ impl Interpreter {
         let mut ensures: Vec<&Stmt> = Vec::new();
         let mut skipped: Option<String> = None;
         let mut expect_fail = false;
+        let mut timed_out = false;
+
+        self.start_test_clock(None);
 
         self.env.push_scope();
 
@@ -448,6 +462,7 @@ impl Interpreter {
                         break;
                     }
                     Err(e) => {
+                        timed_out = matches!(e.error, RuntimeError::TestTimedOut(_));
                         errors.push(format!("{}", e));
                         break;
                     }
@@ -455,11 +470,12 @@ impl Interpreter {
             }
         }
 
+        self.test_deadline = None;
         self.run_ensures(&ensures, false);
         self.env.pop_scope();
 
-        // T13: expect_fail inverts pass/fail
-        let passed = if expect_fail {
+        // T13: expect_fail inverts pass/fail — but a hung test never passes
+        let passed = if expect_fail && !timed_out {
             !errors.is_empty()
         } else {
             errors.is_empty()
@@ -484,7 +500,11 @@ impl Interpreter {
         let mut skipped: Option<String> = None;
         let mut expect_fail = false;
 
-        match self.call_function(func, vec![]) {
+        self.start_test_clock(func.test_timeout_ms());
+        let result = self.call_function(func, vec![]);
+        self.test_deadline = None;
+
+        match result {
             Ok(_) => {}
             Err(diag) if matches!(&diag.error, RuntimeError::Return(_)) => {}
             Err(diag) if matches!(&diag.error, RuntimeError::CheckFailed(_) | RuntimeError::AssertionFailed(_)) => {
@@ -580,3 +600,54 @@ impl Interpreter {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use crate::interp::{Interpreter, TestResult};
+
+    fn run_tests(src: &str) -> Vec<TestResult> {
+        let lexed = rask_lexer::Lexer::new(src).tokenize();
+        assert!(lexed.is_ok(), "lex errors: {:?}", lexed.errors);
+        let parsed = rask_parser::Parser::new(lexed.tokens).parse();
+        assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+        let (mut interp, _) = Interpreter::with_captured_output();
+        interp.run_tests(&parsed.decls, None)
+    }
+
+    #[test]
+    fn test_finishing_within_timeout_passes() {
+        let results = run_tests("\
+@test(timeout_ms = 5000)
+func quick() {
+    assert 1 + 1 == 2
+}
+");
+        assert_eq!(results.len(), 1);
+        assert!(results[0].passed, "errors: {:?}", results[0].errors);
+    }
+
+    #[test]
+    fn hung_test_times_out_and_suite_continues() {
+        let results = run_tests("\
+@test(timeout_ms = 50)
+func spins() {
+    loop {}
+}
+
+@test
+func after() {
+    assert 2 * 2 == 4
+}
+");
+        assert_eq!(results.len(), 2);
+        let spins = results.iter().find(|r| r.name == "spins").unwrap();
+        assert!(!spins.passed);
+        assert!(
+            spins.errors.iter().any(|e| e.contains("timed out after 50 ms")),
+            "errors: {:?}",
+            spins.errors
+        );
+        let after = results.iter().find(|r| r.name == "after").unwrap();
+        assert!(after.passed, "errors: {:?}", after.errors);
+    }
+}
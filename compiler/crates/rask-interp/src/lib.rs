@@ -10,9 +10,10 @@ mod interp;
 mod builtins;
 mod stdlib;
 pub mod build_context;
+pub mod tasks;
 
 pub use build_context::BuildState;
-pub use interp::{BenchmarkResult, Interpreter, RuntimeDiagnostic, RuntimeError, SourceInfo, TestResult};
+pub use interp::{BenchmarkResult, CallFrame, Interpreter, RuntimeDiagnostic, RuntimeError, SourceInfo, TestResult};
 
 #[cfg(test)]
 mod drift;
@@ -2,7 +2,7 @@
 //! Async module - green task spawning.
 
 use crate::interp::{Interpreter, RuntimeError};
-use crate::value::{ThreadHandleInner, Value, ACTIVE_RUNTIME};
+use crate::value::{ThreadHandleInner, Value};
 use std::sync::{Arc, Mutex};
 
 impl Interpreter {
@@ -13,65 +13,8 @@ impl Interpreter {
         args: Vec<Value>,
     ) -> Result<Value, RuntimeError> {
         match method {
-            "spawn" => {
-                // spawn(|| {}) - green task spawner
-                if args.is_empty() {
-                    return Err(RuntimeError::TypeError(
-                        "spawn requires a closure argument".to_string(),
-                    ));
-                }
-
-                // Extract closure
-                let closure = &args[0];
-                match closure {
-                    Value::Closure {
-                        params,
-                        body,
-                        captured_env,
-                    } => {
-                        if ACTIVE_RUNTIME.read().unwrap().is_none() {
-                            return Err(RuntimeError::Panic(
-                                "RUNTIME PANIC: spawn() called with no active `using Multitasking` scope\n\
-                                 Install a `using Multitasking { ... }` block that encloses the call.".to_string(),
-                            ));
-                        }
-
-                        if !params.is_empty() {
-                            return Err(RuntimeError::TypeError(
-                                "spawn closure must take no parameters".to_string(),
-                            ));
-                        }
-
-                        let body = body.clone();
-                        let captured = captured_env.clone();
-                        let child = self.spawn_child(captured);
-
-                        let join_handle = std::thread::spawn(move || {
-                            let mut interp = child;
-                            match interp.eval_expr(&body).map_err(|diag| diag.error) {
-                                Ok(val) => Ok(val),
-                                Err(RuntimeError::Return(val)) => Ok(val),
-                                Err(e) => Err(format!("{}", e)),
-                            }
-                        });
-
-                        let handle_inner = Arc::new(ThreadHandleInner {
-                            handle: Mutex::new(Some(join_handle)),
-                            receiver: Mutex::new(None),
-                        });
-
-                        // Register for affine tracking (conc.async/H1)
-                        let ptr = Arc::as_ptr(&handle_inner) as usize;
-                        self.resource_tracker.register_handle(ptr, "TaskHandle", self.env.scope_depth());
-
-                        Ok(Value::TaskHandle(handle_inner))
-                    }
-                    _ => Err(RuntimeError::TypeError(format!(
-                        "spawn expects a closure, got {}",
-                        closure.type_name()
-                    ))),
-                }
-            }
+            // spawn(|| {}) - green task spawner
+            "spawn" => self.spawn_async_task(args),
             "join_all" => {
                 // join_all(handles) — wait for all task handles, return Vec of results
                 if args.is_empty() {
@@ -147,9 +90,10 @@ impl Interpreter {
                 unreachable!()
             }
             "cancelled" => {
-                // Phase A: cooperative cancellation not yet implemented with OS threads.
-                // Always returns false — tasks must use other mechanisms to check.
-                Ok(Value::Bool(false))
+                // Cooperative cancellation: only shutdown's grace-period
+                // expiry sets it. `h.cancel()` has no per-task token yet.
+                let runtime = crate::value::ACTIVE_RUNTIME.read().unwrap().clone();
+                Ok(Value::Bool(runtime.is_some_and(|rt| rt.is_cancelled())))
             }
             _ => Err(RuntimeError::NoSuchMethod {
                 ty: "async".to_string(),
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+//! Live task registry (conc.async/TN1–TN3).
+//!
+//! Every `spawn()`ed task is listed here from spawn until it finishes, with
+//! its optional name and whether it is running or blocked in `join`/`recv`/
+//! `send`. Debug dumps read it to show what a stuck program is waiting on.
+//!
+//! The map lock is taken only on spawn, finish, and snapshot; state changes
+//! are a single atomic store on the task's own entry.
+
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
+
+/// What a live task is doing right now.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaskState {
+    Running,
+    /// Waiting in a blocking `join`, `recv`, or `send`.
+    Blocked,
+}
+
+impl fmt::Display for TaskState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TaskState::Running => write!(f, "running"),
+            TaskState::Blocked => write!(f, "blocked"),
+        }
+    }
+}
+
+/// A snapshot of one live task.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskInfo {
+    pub id: u64,
+    pub name: Option<String>,
+    pub state: TaskState,
+}
+
+struct TaskEntry {
+    name: Option<String>,
+    blocked: AtomicBool,
+}
+
+static NEXT_TASK_ID: AtomicU64 = AtomicU64::new(1);
+
+static REGISTRY: LazyLock<Mutex<BTreeMap<u64, Arc<TaskEntry>>>> =
+    LazyLock::new(|| Mutex::new(BTreeMap::new()));
+
+thread_local! {
+    /// The task running on this thread, if any.
+    static CURRENT: RefCell<Option<Arc<TaskEntry>>> = const { RefCell::new(None) };
+}
+
+/// A task's slot in the registry. Created on the spawning thread so the task
+/// is listed before it is scheduled; removed when dropped.
+pub(crate) struct TaskRegistration {
+    id: u64,
+    entry: Arc<TaskEntry>,
+}
+
+/// Register a new task and return its slot.
+pub(crate) fn register(name: Option<String>) -> TaskRegistration {
+    let id = NEXT_TASK_ID.fetch_add(1, Ordering::Relaxed);
+    let entry = Arc::new(TaskEntry { name, blocked: AtomicBool::new(false) });
+    REGISTRY.lock().unwrap().insert(id, Arc::clone(&entry));
+    TaskRegistration { id, entry }
+}
+
+impl TaskRegistration {
+    /// Mark this thread as running the task until the returned guard drops.
+    /// Dropping the guard also removes the task from the registry.
+    pub(crate) fn enter(self) -> RunningTask {
+        CURRENT.with(|c| *c.borrow_mut() = Some(Arc::clone(&self.entry)));
+        RunningTask { _registration: self }
+    }
+}
+
+impl Drop for TaskRegistration {
+    fn drop(&mut self) {
+        REGISTRY.lock().unwrap().remove(&self.id);
+    }
+}
+
+/// Held by a task's thread for the lifetime of the task body.
+pub(crate) struct RunningTask {
+    _registration: TaskRegistration,
+}
+
+impl Drop for RunningTask {
+    fn drop(&mut self) {
+        CURRENT.with(|c| *c.borrow_mut() = None);
+    }
+}
+
+/// True on a thread that is running a spawned task.
+pub(crate) fn in_task() -> bool {
+    CURRENT.with(|c| c.borrow().is_some())
+}
+
+/// Marks the current task blocked until dropped. No-op off a task thread.
+pub(crate) struct BlockedGuard(Option<Arc<TaskEntry>>);
+
+/// Call before a blocking wait; the task reads as blocked while the guard lives.
+pub(crate) fn blocked() -> BlockedGuard {
+    let entry = CURRENT.with(|c| c.borrow().clone());
+    if let Some(e) = &entry {
+        e.blocked.store(true, Ordering::Relaxed);
+    }
+    BlockedGuard(entry)
+}
+
+impl Drop for BlockedGuard {
+    fn drop(&mut self) {
+        if let Some(e) = &self.0 {
+            e.blocked.store(false, Ordering::Relaxed);
+        }
+    }
+}
+
+/// Every live task, oldest first.
+pub fn snapshot() -> Vec<TaskInfo> {
+    REGISTRY
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|(id, e)| TaskInfo {
+            id: *id,
+            name: e.name.clone(),
+            state: if e.blocked.load(Ordering::Relaxed) { TaskState::Blocked } else { TaskState::Running },
+        })
+        .collect()
+}
+
+/// Human-readable task list, one line per task:
+/// `task 3 "worker": blocked`.
+pub fn dump() -> String {
+    let mut out = String::new();
+    for t in snapshot() {
+        match &t.name {
+            Some(name) => out.push_str(&format!("task {} {:?}: {}\n", t.id, name, t.state)),
+            None => out.push_str(&format!("task {}: {}\n", t.id, t.state)),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interp::Interpreter;
+    use std::time::{Duration, Instant};
+
+    fn find(name: &str) -> Option<TaskInfo> {
+        snapshot().into_iter().find(|t| t.name.as_deref() == Some(name))
+    }
+
+    /// Poll until `pred` holds, failing after a few seconds.
+    fn wait_for(what: &str, pred: impl Fn() -> bool) {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !pred() {
+            assert!(Instant::now() < deadline, "timed out waiting for {}\n{}", what, dump());
+            std::thread::sleep(Duration::from_millis(5));
+        }
+    }
+
+    #[test]
+    fn registration_tracks_state_and_is_removed_on_finish() {
+        let reg = register(Some("registry-unit".to_string()));
+        let id = reg.id;
+        let worker = std::thread::spawn(move || {
+            let _task = reg.enter();
+            let _wait = blocked();
+            assert_eq!(find("registry-unit").unwrap().state, TaskState::Blocked);
+            drop(_wait);
+            assert_eq!(find("registry-unit").unwrap().state, TaskState::Running);
+        });
+        worker.join().unwrap();
+        assert!(snapshot().iter().all(|t| t.id != id), "finished task still listed");
+    }
+
+    #[test]
+    fn blocked_outside_a_task_is_a_no_op() {
+        let _wait = blocked();
+        assert!(find("registry-outside").is_none());
+    }
+
+    #[test]
+    fn named_spawns_are_listed_with_their_states() {
+        let src = "\
+import async.spawn
+import time
+
+func main() {
+    using Multitasking {
+        const (tx, rx) = Channel<i32>.unbuffered()
+        const waiter = spawn(|| {
+            const v = try rx.recv()
+            return v
+        }, name: \"registry-waiter\")
+        const spinner = spawn(|| {
+            mut i = 0
+            while i < 60 {
+                time.sleep(time.Duration.from_millis(10))
+                i += 1
+            }
+        }, name: \"registry-spinner\")
+        time.sleep(time.Duration.from_millis(500))
+        try tx.send(7)
+        const got = try waiter.join()
+        try spinner.join()
+        println(got)
+    }
+}
+";
+        let lexed = rask_lexer::Lexer::new(src).tokenize();
+        assert!(lexed.is_ok(), "lex errors: {:?}", lexed.errors);
+        let parsed = rask_parser::Parser::new(lexed.tokens).parse();
+        assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+        let program = std::thread::spawn(move || {
+            let (mut interp, out) = Interpreter::with_captured_output();
+            interp.run(&parsed.decls).map(|_| out)
+        });
+
+        wait_for("blocked waiter", || {
+            find("registry-waiter").is_some_and(|t| t.state == TaskState::Blocked)
+        });
+        wait_for("running spinner", || {
+            find("registry-spinner").is_some_and(|t| t.state == TaskState::Running)
+        });
+        assert!(dump().contains("\"registry-waiter\": blocked"), "{}", dump());
+
+        let out = program.join().unwrap().expect("program should run");
+        assert_eq!(*out.lock().unwrap(), "7\n");
+        assert!(find("registry-waiter").is_none(), "finished task still listed");
+        assert!(find("registry-spinner").is_none(), "finished task still listed");
+    }
+}
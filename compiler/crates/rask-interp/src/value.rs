@@ -5,9 +5,10 @@ use std::collections::HashMap;
 use indexmap::IndexMap;
 use std::fmt;
 use std::fs::File as StdFile;
-use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::{mpsc, Arc, Mutex, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{mpsc, Arc, Condvar, Mutex, RwLock};
 use std::sync::LazyLock;
+use std::time::Duration;
 
 use rask_ast::expr::Expr;
 
@@ -247,6 +248,7 @@ pub enum BuiltinKind {
     Max,   // generic max(a, b) — prelude
     Clamp, // generic clamp(value, lo, hi) — prelude
     AssertEq,   // assert_eq(got, expected) — pretty-print diff on failure
+    AssertNe,   // assert_ne(got, other) — fail when the two are equal
     Skip,       // skip("reason") — skip rest of test
     ExpectFail, // expect_fail() — invert pass/fail
 }
@@ -313,6 +315,49 @@ pub struct ThreadPoolInner {
     pub sender: Mutex<Option<mpsc::Sender<PoolTask>>>,
     pub workers: Mutex<Vec<std::thread::JoinHandle<()>>>,
     pub size: usize,
+    /// Submitted tasks that haven't finished, for the `tasks` bound.
+    pub in_flight: Arc<(Mutex<usize>, Condvar)>,
+    pub max_tasks: Option<usize>,
+}
+
+impl ThreadPoolInner {
+    /// Take a slot for a new task, waiting while the pool is full (C8).
+    pub fn admit(&self) -> OutstandingTask {
+        admit_task(&self.in_flight, self.max_tasks)
+    }
+}
+
+thread_local! {
+    /// Set on pool worker threads, so a task spawning from inside the pool
+    /// never waits on a slot only its own pool can free.
+    static ON_POOL_WORKER: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Worker loop shared by both pools: run queued tasks until the channel closes.
+pub fn run_pool_worker(rx: Arc<Mutex<mpsc::Receiver<PoolTask>>>) {
+    ON_POOL_WORKER.with(|w| w.set(true));
+    loop {
+        let task = rx.lock().unwrap().recv();
+        match task {
+            Ok(task) => (task.work)(),
+            Err(_) => break, // Channel closed
+        }
+    }
+}
+
+/// Count a new in-flight task (conc.async/C8). With a bound, a spawner
+/// outside any task waits until one finishes; a spawn from inside a task is
+/// admitted over the bound, since waiting there could deadlock the pool.
+fn admit_task(in_flight: &Arc<(Mutex<usize>, Condvar)>, max_tasks: Option<usize>) -> OutstandingTask {
+    let (count, done) = &**in_flight;
+    let mut n = count.lock().unwrap();
+    if let Some(max) = max_tasks {
+        if !ON_POOL_WORKER.with(|w| w.get()) && !crate::tasks::in_task() {
+            n = done.wait_while(n, |n| *n >= max).unwrap();
+        }
+    }
+    *n += 1;
+    OutstandingTask(Arc::clone(in_flight))
 }
 
 impl fmt::Debug for ThreadPoolInner {
@@ -321,47 +366,145 @@ impl fmt::Debug for ThreadPoolInner {
     }
 }
 
+/// How long block exit waits for outstanding tasks before cancelling them
+/// (conc.async/C4). `RASK_SHUTDOWN_GRACE_MS` overrides the default.
+pub fn shutdown_grace() -> Duration {
+    std::env::var("RASK_SHUTDOWN_GRACE_MS")
+        .ok()
+        .and_then(|ms| ms.parse().ok())
+        .map(Duration::from_millis)
+        .unwrap_or(Duration::from_secs(5))
+}
+
 /// Multitasking runtime — bounded thread pool for spawn() tasks.
 pub struct MultitaskingRuntime {
     pub workers: usize,
     pub sender: Mutex<Option<mpsc::Sender<PoolTask>>>,
     pub pool_threads: Mutex<Vec<std::thread::JoinHandle<()>>>,
+    /// Submitted tasks that haven't finished, with a condvar signalled as
+    /// each one does.
+    outstanding: Arc<(Mutex<usize>, Condvar)>,
+    /// Most tasks in flight at once, queued or running; `None` is unbounded.
+    max_tasks: Option<usize>,
+    /// Set once the shutdown grace period runs out; `cancelled()` reads it.
+    cancelled: Arc<AtomicBool>,
+    grace: Duration,
 }
 
 impl MultitaskingRuntime {
     pub fn new(workers: usize) -> Self {
+        Self::with_grace(workers, shutdown_grace())
+    }
+
+    pub fn with_grace(workers: usize, grace: Duration) -> Self {
         let (tx, rx) = mpsc::channel::<PoolTask>();
         let rx = Arc::new(Mutex::new(rx));
 
         let mut threads = Vec::with_capacity(workers);
         for _ in 0..workers {
             let rx = Arc::clone(&rx);
-            threads.push(std::thread::spawn(move || {
-                loop {
-                    let task = rx.lock().unwrap().recv();
-                    match task {
-                        Ok(task) => (task.work)(),
-                        Err(_) => break, // Channel closed
-                    }
-                }
-            }));
+            threads.push(std::thread::spawn(move || run_pool_worker(rx)));
         }
 
         Self {
             workers,
             sender: Mutex::new(Some(tx)),
             pool_threads: Mutex::new(threads),
+            outstanding: Arc::new((Mutex::new(0), Condvar::new())),
+            max_tasks: None,
+            cancelled: Arc::new(AtomicBool::new(false)),
+            grace,
         }
     }
 
-    /// Shut down the pool: drop sender, join all workers.
+    /// Bound the tasks in flight (conc.async/C8): past `max_tasks`, spawning
+    /// blocks until one finishes.
+    pub fn with_max_tasks(mut self, max_tasks: usize) -> Self {
+        self.max_tasks = Some(max_tasks);
+        self
+    }
+
+    /// Count a task as outstanding until the returned guard drops. Tasks on
+    /// their own thread (`spawn()`) hold one so shutdown can drain them.
+    /// Waits for a free slot when the runtime is bounded.
+    pub fn track(&self) -> OutstandingTask {
+        admit_task(&self.outstanding, self.max_tasks)
+    }
+
+    /// Queue a task. A task still queued when shutdown cancels is dropped
+    /// without running.
+    pub fn submit(&self, task: PoolTask) {
+        // Wait for a slot before taking the sender, so a blocked spawner
+        // doesn't hold up shutdown.
+        let outstanding = self.track();
+        let sender = self.sender.lock().unwrap();
+        let Some(tx) = sender.as_ref() else { return };
+        let cancelled = Arc::clone(&self.cancelled);
+        let work = task.work;
+        let _ = tx.send(PoolTask {
+            work: Box::new(move || {
+                let _outstanding = outstanding;
+                if !cancelled.load(Ordering::Acquire) {
+                    work();
+                }
+            }),
+        });
+    }
+
+    /// True once shutdown has given up waiting and asked tasks to stop.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Shut down the pool (conc.async/C4): wait up to the grace period for
+    /// outstanding tasks, detached ones included, then abort the rest.
     pub fn shutdown(&self) {
+        if self.wait_outstanding() > 0 {
+            self.abort();
+        } else {
+            self.join_workers();
+        }
+    }
+
+    /// Cancel outstanding tasks and shut down without draining. Queued tasks
+    /// are dropped; running ones see `cancelled()` turn true and get one
+    /// grace period to return. A task on its own thread that still hasn't
+    /// returned is abandoned.
+    pub fn abort(&self) {
+        self.cancelled.store(true, Ordering::Release);
+        self.wait_outstanding();
+        self.join_workers();
+    }
+
+    fn join_workers(&self) {
         *self.sender.lock().unwrap() = None;
         let mut threads = self.pool_threads.lock().unwrap();
         for t in threads.drain(..) {
             let _ = t.join();
         }
     }
+
+    /// Wait up to the grace period for outstanding tasks; returns how many
+    /// are left.
+    fn wait_outstanding(&self) -> usize {
+        let (count, done) = &*self.outstanding;
+        let (remaining, _) = done
+            .wait_timeout_while(count.lock().unwrap(), self.grace, |n| *n > 0)
+            .unwrap();
+        *remaining
+    }
+}
+
+/// An outstanding task's slot in its runtime's count; dropping it marks the
+/// task finished.
+pub struct OutstandingTask(Arc<(Mutex<usize>, Condvar)>);
+
+impl Drop for OutstandingTask {
+    fn drop(&mut self) {
+        let (count, done) = &*self.0;
+        *count.lock().unwrap() -= 1;
+        done.notify_all();
+    }
 }
 
 impl fmt::Debug for MultitaskingRuntime {
@@ -1069,3 +1212,95 @@ impl fmt::Display for Value {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    #[test]
+    fn shutdown_waits_for_a_slow_task_within_the_grace_period() {
+        let rt = MultitaskingRuntime::with_grace(2, Duration::from_secs(5));
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let task_log = Arc::clone(&log);
+        rt.submit(PoolTask {
+            work: Box::new(move || {
+                std::thread::sleep(Duration::from_millis(50));
+                task_log.lock().unwrap().push("done");
+            }),
+        });
+        rt.shutdown();
+        assert_eq!(*log.lock().unwrap(), vec!["done"]);
+        assert!(!rt.is_cancelled());
+    }
+
+    #[test]
+    fn shutdown_cancels_tasks_still_running_after_the_grace_period() {
+        let rt = Arc::new(MultitaskingRuntime::with_grace(1, Duration::from_millis(50)));
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let (task_rt, task_log) = (Arc::clone(&rt), Arc::clone(&log));
+        rt.submit(PoolTask {
+            work: Box::new(move || {
+                while !task_rt.is_cancelled() {
+                    std::thread::sleep(Duration::from_millis(5));
+                }
+                task_log.lock().unwrap().push("cancelled");
+            }),
+        });
+        // Queued behind the looping task on the only worker: never starts.
+        let queued_log = Arc::clone(&log);
+        rt.submit(PoolTask { work: Box::new(move || queued_log.lock().unwrap().push("queued")) });
+
+        let start = Instant::now();
+        rt.shutdown();
+        assert!(start.elapsed() < Duration::from_secs(5), "shutdown waited {:?}", start.elapsed());
+        assert_eq!(*log.lock().unwrap(), vec!["cancelled"]);
+    }
+
+    #[test]
+    fn bounded_runtime_never_has_more_tasks_in_flight_than_the_bound() {
+        let rt = MultitaskingRuntime::with_grace(4, Duration::from_secs(5)).with_max_tasks(2);
+        let running = Arc::new(Mutex::new((0usize, 0usize))); // (now, peak)
+        for _ in 0..6 {
+            let running = Arc::clone(&running);
+            rt.submit(PoolTask {
+                work: Box::new(move || {
+                    {
+                        let mut r = running.lock().unwrap();
+                        r.0 += 1;
+                        r.1 = r.1.max(r.0);
+                    }
+                    std::thread::sleep(Duration::from_millis(20));
+                    running.lock().unwrap().0 -= 1;
+                }),
+            });
+        }
+        rt.shutdown();
+        let (now, peak) = *running.lock().unwrap();
+        assert_eq!(now, 0);
+        assert!(peak <= 2, "{} tasks ran at once with a bound of 2", peak);
+    }
+
+    #[test]
+    fn spawning_into_a_full_runtime_blocks_until_a_task_finishes() {
+        let rt = Arc::new(MultitaskingRuntime::with_grace(2, Duration::from_secs(5)).with_max_tasks(1));
+        let (release, gate) = mpsc::channel::<()>();
+        rt.submit(PoolTask { work: Box::new(move || { let _ = gate.recv(); }) });
+
+        let (submitted_tx, submitted) = mpsc::channel();
+        let spawner_rt = Arc::clone(&rt);
+        let spawner = std::thread::spawn(move || {
+            spawner_rt.submit(PoolTask { work: Box::new(|| {}) });
+            let _ = submitted_tx.send(());
+        });
+        assert!(
+            submitted.recv_timeout(Duration::from_millis(50)).is_err(),
+            "second spawn went through while the pool was full"
+        );
+
+        release.send(()).unwrap();
+        submitted.recv_timeout(Duration::from_secs(5)).expect("spawn should unblock once a slot frees");
+        spawner.join().unwrap();
+        rt.shutdown();
+    }
+}
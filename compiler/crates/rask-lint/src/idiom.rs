@@ -4,6 +4,7 @@
 //! - unwrap-production: Flag .unwrap() outside test blocks
 //! - missing-ensure: Flag @resource creation without ensure
 //! - ensure-ordering: Flag ensure registration order that doesn't match acquisition order
+//! - ensure-no-resource: Flag ensure bodies that don't touch any resource binding
 
 use rask_ast::decl::*;
 use rask_ast::expr::{Expr, ExprKind};
@@ -455,6 +456,216 @@ fn extract_ensure_receiver(body: &[Stmt]) -> Option<String> {
     }
 }
 
+/// Type-name fragments that suggest a binding owns something needing cleanup.
+const RESOURCE_HINTS: &[&str] = &[
+    "File", "Socket", "Conn", "Stream", "Listener", "Handle", "Lock", "Guard",
+    "Reader", "Writer", "Transaction", "Session", "Pool", "Sender", "Receiver",
+];
+
+/// idiom/ensure-no-resource: Flag `ensure` bodies that don't operate on any
+/// resource in scope. An ensure that closes the wrong variable (or only logs)
+/// compiles fine and cleans up nothing; ownership can't see it because the
+/// real resource may still be consumed elsewhere.
+///
+/// Conservative: a binding counts as a resource if its type is `@resource`
+/// or its type name suggests one. When nothing in scope looks like a
+/// resource, only bodies that reference no local binding at all are flagged.
+pub fn check_ensure_no_resource(decls: &[Decl], source: &str) -> Vec<LintDiagnostic> {
+    let resource_types: Vec<String> = decls
+        .iter()
+        .filter_map(|d| match &d.kind {
+            DeclKind::Struct(s) if s.attrs.iter().any(|a| a == "resource") => Some(s.name.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut diags = Vec::new();
+    let mut check_fn = |f: &FnDecl| {
+        let scope: Vec<(String, bool)> = f
+            .params
+            .iter()
+            .map(|p| (p.name.clone(), is_resource_type(&p.ty, &resource_types)))
+            .collect();
+        check_ensure_resource_in_block(&f.body, scope, &resource_types, source, &mut diags);
+    };
+
+    for decl in decls {
+        match &decl.kind {
+            DeclKind::Fn(f) => check_fn(f),
+            DeclKind::Struct(s) => s.methods.iter().for_each(&mut check_fn),
+            DeclKind::Enum(e) => e.methods.iter().for_each(&mut check_fn),
+            DeclKind::Impl(imp) => imp.methods.iter().for_each(&mut check_fn),
+            _ => {}
+        }
+    }
+
+    diags
+}
+
+fn check_ensure_resource_in_block(
+    stmts: &[Stmt],
+    mut scope: Vec<(String, bool)>,
+    resource_types: &[String],
+    source: &str,
+    diags: &mut Vec<LintDiagnostic>,
+) {
+    for stmt in stmts {
+        match &stmt.kind {
+            StmtKind::Const { name, ty, init, .. } | StmtKind::Mut { name, ty, init, .. } => {
+                let is_resource = ty
+                    .as_deref()
+                    .map(str::to_string)
+                    .or_else(|| init_type_name(init))
+                    .is_some_and(|t| is_resource_type(&t, resource_types));
+                scope.push((name.clone(), is_resource));
+            }
+            StmtKind::MutTuple { patterns, .. } | StmtKind::ConstTuple { patterns, .. } => {
+                for name in rask_ast::stmt::tuple_pats_flat_names(patterns) {
+                    scope.push((name.to_string(), false));
+                }
+            }
+            StmtKind::Ensure { body, .. } => {
+                let mut used = Vec::new();
+                for s in body {
+                    collect_stmt_idents(s, &mut used);
+                }
+                let touched: Vec<&(String, bool)> =
+                    scope.iter().filter(|(n, _)| used.contains(n)).collect();
+                let any_resource = scope.iter().any(|(_, r)| *r);
+                let flagged = if touched.iter().any(|(_, r)| *r) {
+                    false
+                } else {
+                    any_resource || touched.is_empty()
+                };
+                if flagged {
+                    let (line, col) = util::line_col(source, stmt.span.start);
+                    let source_line = util::get_source_line(source, line);
+                    diags.push(LintDiagnostic {
+                        rule: "idiom/ensure-no-resource".to_string(),
+                        severity: Severity::Warning,
+                        message: "ensure block doesn't appear to consume any resource".to_string(),
+                        location: LintLocation {
+                            line,
+                            column: col,
+                            source_line,
+                        },
+                        fix: "release the resource this ensure guards, e.g. `ensure file.close()`"
+                            .to_string(),
+                    });
+                }
+            }
+            StmtKind::While { body, .. }
+            | StmtKind::WhileLet { body, .. }
+            | StmtKind::Loop { body, .. } => {
+                check_ensure_resource_in_block(body, scope.clone(), resource_types, source, diags);
+            }
+            StmtKind::For { binding, body, .. } => {
+                let mut inner = scope.clone();
+                inner.extend(binding.names().into_iter().map(|n| (n.to_string(), false)));
+                check_ensure_resource_in_block(body, inner, resource_types, source, diags);
+            }
+            StmtKind::Expr(expr) => match &expr.kind {
+                ExprKind::Block(body)
+                | ExprKind::UsingBlock { body, .. }
+                | ExprKind::Unsafe { body }
+                | ExprKind::Loop { body, .. } => {
+                    check_ensure_resource_in_block(body, scope.clone(), resource_types, source, diags);
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+fn is_resource_type(ty: &str, resource_types: &[String]) -> bool {
+    let base = ty.split('<').next().unwrap_or(ty).trim();
+    resource_types.iter().any(|r| r == base) || RESOURCE_HINTS.iter().any(|h| base.contains(h))
+}
+
+/// Best-effort type name of an initializer: `Foo { .. }`, `Foo.open(..)`,
+/// optionally behind `try` or `!`.
+fn init_type_name(expr: &Expr) -> Option<String> {
+    match &expr.kind {
+        ExprKind::StructLit { name, .. } => Some(name.clone()),
+        ExprKind::MethodCall { object, .. } => match &object.kind {
+            ExprKind::Ident(name) if name.starts_with(char::is_uppercase) => Some(name.clone()),
+            _ => None,
+        },
+        ExprKind::Call { func, .. } => match &func.kind {
+            ExprKind::Field { object, .. } => match &object.kind {
+                ExprKind::Ident(name) if name.starts_with(char::is_uppercase) => Some(name.clone()),
+                _ => None,
+            },
+            _ => None,
+        },
+        ExprKind::Try { expr: inner, .. } | ExprKind::Unwrap { expr: inner, .. } => {
+            init_type_name(inner)
+        }
+        _ => None,
+    }
+}
+
+fn collect_stmt_idents(stmt: &Stmt, out: &mut Vec<String>) {
+    match &stmt.kind {
+        StmtKind::Expr(e) | StmtKind::Return(Some(e)) => collect_expr_idents(e, out),
+        StmtKind::Mut { init, .. } | StmtKind::Const { init, .. } => collect_expr_idents(init, out),
+        StmtKind::Assign { target, value } => {
+            collect_expr_idents(target, out);
+            collect_expr_idents(value, out);
+        }
+        StmtKind::While { cond: e, body } | StmtKind::For { iter: e, body, .. } => {
+            collect_expr_idents(e, out);
+            body.iter().for_each(|s| collect_stmt_idents(s, out));
+        }
+        StmtKind::Loop { body, .. } => body.iter().for_each(|s| collect_stmt_idents(s, out)),
+        _ => {}
+    }
+}
+
+fn collect_expr_idents(expr: &Expr, out: &mut Vec<String>) {
+    match &expr.kind {
+        ExprKind::Ident(name) => out.push(name.clone()),
+        ExprKind::MethodCall { object, args, .. } => {
+            collect_expr_idents(object, out);
+            args.iter().for_each(|a| collect_expr_idents(&a.expr, out));
+        }
+        ExprKind::Call { func, args } => {
+            collect_expr_idents(func, out);
+            args.iter().for_each(|a| collect_expr_idents(&a.expr, out));
+        }
+        ExprKind::Binary { left, right, .. } => {
+            collect_expr_idents(left, out);
+            collect_expr_idents(right, out);
+        }
+        ExprKind::Unary { operand: inner, .. }
+        | ExprKind::Field { object: inner, .. }
+        | ExprKind::OptionalField { object: inner, .. }
+        | ExprKind::Try { expr: inner, .. }
+        | ExprKind::Unwrap { expr: inner, .. }
+        | ExprKind::Cast { expr: inner, .. } => collect_expr_idents(inner, out),
+        ExprKind::Index { object, index } => {
+            collect_expr_idents(object, out);
+            collect_expr_idents(index, out);
+        }
+        ExprKind::If { cond, then_branch, else_branch, .. } => {
+            collect_expr_idents(cond, out);
+            collect_expr_idents(then_branch, out);
+            if let Some(e) = else_branch {
+                collect_expr_idents(e, out);
+            }
+        }
+        ExprKind::Match { scrutinee, arms } => {
+            collect_expr_idents(scrutinee, out);
+            arms.iter().for_each(|arm| collect_expr_idents(&arm.body, out));
+        }
+        ExprKind::Block(stmts) | ExprKind::Unsafe { body: stmts } => {
+            stmts.iter().for_each(|s| collect_stmt_idents(s, out));
+        }
+        _ => {}
+    }
+}
+
 /// idiom/large-unsafe-block: Flag unsafe blocks with too many statements.
 /// Big unsafe blocks defeat the purpose — keep them minimal so each unsafe
 /// operation is visible and auditable (mem.unsafe/U4).
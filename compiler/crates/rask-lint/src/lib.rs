@@ -123,6 +123,178 @@ mod tests {
             "is_* method returning bool should not be flagged");
     }
 
+    // ─── idiom/ensure-no-resource ───────────────────────────
+
+    #[test]
+    fn ensure_that_only_logs_is_flagged() {
+        let report = lint_default(
+            "func process() {\n    const file = try File.open(\"data.txt\")\n    ensure println(\"done\")\n    file.close()\n}"
+        );
+        assert!(has_rule(&report, "idiom/ensure-no-resource"),
+            "ensure that only logs should be flagged");
+    }
+
+    #[test]
+    fn ensure_on_wrong_variable_is_flagged() {
+        let report = lint_default(
+            "func process(count: i32) {\n    const file = try File.open(\"data.txt\")\n    ensure log(count)\n    file.close()\n}"
+        );
+        assert!(has_rule(&report, "idiom/ensure-no-resource"),
+            "ensure that skips the file should be flagged");
+    }
+
+    #[test]
+    fn ensure_closing_resource_is_allowed() {
+        let report = lint_default(
+            "func process() {\n    const file = try File.open(\"data.txt\")\n    ensure file.close()\n}"
+        );
+        assert!(!has_rule(&report, "idiom/ensure-no-resource"),
+            "ensure that closes the file should not be flagged");
+    }
+
+    // ─── style/complexity ───────────────────────────────────
+
+    #[test]
+    fn complexity_flags_deeply_branched_function() {
+        let source = r#"
+func classify(n: i32, flag: bool) -> i32 {
+    if n < 0 && flag {
+        return -1
+    } else if n == 0 || !flag {
+        return 0
+    }
+    for i in 0..n {
+        while flag {
+            if i > 3 { return i }
+        }
+    }
+    match n {
+        1 => return 1,
+        2 => return 2,
+        x if x > 100 => return 100,
+        _ => return 3,
+    }
+}
+"#;
+        let opts = LintOpts { complexity_threshold: 5, ..LintOpts::default() };
+        let report = lint(source, "test.rk", opts);
+        let diag = report.diagnostics.iter()
+            .find(|d| d.rule == "style/complexity")
+            .expect("branchy function should be flagged");
+        assert!(diag.message.contains("complexity 12"), "got: {}", diag.message);
+        assert_eq!(diag.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn complexity_allows_simple_function() {
+        let source = "func add(a: i32, b: i32) -> i32 {\n    if a > b { return a }\n    return a + b\n}";
+        let opts = LintOpts { complexity_threshold: 5, ..LintOpts::default() };
+        let report = lint(source, "test.rk", opts);
+        assert!(!has_rule(&report, "style/complexity"),
+            "simple function should not be flagged");
+    }
+
+    #[test]
+    fn complexity_severity_is_configurable() {
+        let source = "func pick(a: bool, b: bool) -> i32 {\n    if a && b { return 1 }\n    return 0\n}";
+        let opts = LintOpts {
+            complexity_threshold: 1,
+            complexity_severity: Severity::Error,
+            ..LintOpts::default()
+        };
+        let report = lint(source, "test.rk", opts);
+        assert_eq!(report.error_count, 1);
+        assert!(!report.success);
+    }
+
+    // ─── style/missing-docs ─────────────────────────────────
+
+    fn lint_docs(source: &str) -> LintReport {
+        let opts = LintOpts { missing_docs_severity: Some(Severity::Warning), ..LintOpts::default() };
+        lint(source, "test.rk", opts)
+    }
+
+    #[test]
+    fn missing_docs_flags_public_undocumented_function() {
+        let report = lint_docs("public func area(w: i32, h: i32) -> i32 { return w * h }");
+        let diag = report.diagnostics.iter()
+            .find(|d| d.rule == "style/missing-docs")
+            .expect("undocumented public function should be flagged");
+        assert_eq!(diag.message, "public function `area` has no doc comment");
+        assert_eq!(diag.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn missing_docs_allows_documented_function() {
+        let report = lint_docs("/// Area of a rectangle.\npublic func area(w: i32, h: i32) -> i32 { return w * h }");
+        assert!(!has_rule(&report, "style/missing-docs"),
+            "documented public function should not be flagged");
+    }
+
+    #[test]
+    fn missing_docs_ignores_private_function() {
+        let report = lint_docs("func area(w: i32, h: i32) -> i32 { return w * h }");
+        assert!(!has_rule(&report, "style/missing-docs"),
+            "non-public function should not be flagged");
+    }
+
+    #[test]
+    fn missing_docs_flags_public_types_and_methods() {
+        let report = lint_docs(
+            "public struct Point { x: i32 }\nextend Point {\n    public func norm(self) -> i32 { return self.x }\n}"
+        );
+        let messages: Vec<_> = report.diagnostics.iter()
+            .filter(|d| d.rule == "style/missing-docs")
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(messages, [
+            "public struct `Point` has no doc comment",
+            "public method `Point.norm` has no doc comment",
+        ]);
+    }
+
+    #[test]
+    fn missing_docs_is_off_by_default() {
+        let report = lint_default("public func area(w: i32, h: i32) -> i32 { return w * h }");
+        assert!(!has_rule(&report, "style/missing-docs"));
+    }
+
+    // ─── style/public-return-type ───────────────────────────
+
+    #[test]
+    fn public_return_type_flags_inferred_public_return() {
+        let report = lint_default("public func area(w: i32, h: i32) {\n    return w * h\n}");
+        let diag = report.diagnostics.iter()
+            .find(|d| d.rule == "style/public-return-type")
+            .expect("public function returning a value without `->` should be flagged");
+        assert!(diag.message.contains("`area`"), "got: {}", diag.message);
+        assert_eq!(diag.severity, Severity::Error);
+    }
+
+    #[test]
+    fn public_return_type_allows_explicit_return_type() {
+        let report = lint_default("public func area(w: i32, h: i32) -> i32 {\n    return w * h\n}");
+        assert!(!has_rule(&report, "style/public-return-type"));
+    }
+
+    #[test]
+    fn public_return_type_ignores_private_and_unit_functions() {
+        let source = "func area(w: i32, h: i32) {\n    return w * h\n}\n\npublic func log(msg: string) {\n    println(msg)\n}";
+        let report = lint_default(source);
+        assert!(!has_rule(&report, "style/public-return-type"),
+            "got: {:?}", report.diagnostics.iter().map(|d| &d.message).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn public_return_type_severity_is_configurable() {
+        let source = "public func pick(a: bool) {\n    if a { return 1 }\n    return 0\n}";
+        let opts = LintOpts { public_return_type_severity: Severity::Warning, ..LintOpts::default() };
+        let report = lint(source, "test.rk", opts);
+        assert!(has_rule(&report, "style/public-return-type"));
+        assert_eq!(report.error_count, 0);
+        assert!(report.success);
+    }
+
     // ─── Clean code passes without warnings ─────────────────
 
     #[test]
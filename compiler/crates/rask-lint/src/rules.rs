@@ -12,6 +12,12 @@ struct Rule {
     check: fn(&[Decl], &str) -> Vec<LintDiagnostic>,
 }
 
+/// A lint rule whose behavior depends on `LintOpts` (thresholds, severity).
+struct ConfiguredRule {
+    id: &'static str,
+    check: fn(&[Decl], &str, &LintOpts) -> Vec<LintDiagnostic>,
+}
+
 /// All registered rules.
 fn all_rules() -> Vec<Rule> {
     vec![
@@ -28,11 +34,20 @@ fn all_rules() -> Vec<Rule> {
         Rule { id: "idiom/unwrap-production", check: idiom::check_unwrap_production },
         Rule { id: "idiom/missing-ensure", check: idiom::check_missing_ensure },
         Rule { id: "idiom/ensure-ordering", check: idiom::check_ensure_ordering },
+        Rule { id: "idiom/ensure-no-resource", check: idiom::check_ensure_no_resource },
         Rule { id: "idiom/large-unsafe-block", check: idiom::check_large_unsafe_blocks },
         // Style
         Rule { id: "style/snake-case-func", check: style::check_snake_case_func },
         Rule { id: "style/pascal-case-type", check: style::check_pascal_case_type },
-        Rule { id: "style/public-return-type", check: style::check_public_return_type },
+    ]
+}
+
+/// All registered rules that read `LintOpts`.
+fn configured_rules() -> Vec<ConfiguredRule> {
+    vec![
+        ConfiguredRule { id: "style/public-return-type", check: style::check_public_return_type },
+        ConfiguredRule { id: "style/complexity", check: style::check_complexity },
+        ConfiguredRule { id: "style/missing-docs", check: style::check_missing_docs },
     ]
 }
 
@@ -46,6 +61,12 @@ pub fn run_rules(decls: &[Decl], source: &str, opts: &LintOpts) -> Vec<LintDiagn
         }
         results.extend((rule.check)(decls, source));
     }
+    for rule in configured_rules() {
+        if !should_run(rule.id, opts) {
+            continue;
+        }
+        results.extend((rule.check)(decls, source, opts));
+    }
 
     results
 }
@@ -1,7 +1,9 @@
 // SPDX-License-Identifier: (MIT OR Apache-2.0)
-//! Style rules: naming conventions, visibility.
+//! Style rules: naming conventions, visibility, function complexity.
 
 use rask_ast::decl::*;
+use rask_ast::expr::{BinOp, Expr, ExprKind, SelectArmKind};
+use rask_ast::stmt::{Stmt, StmtKind};
 
 use crate::types::*;
 use crate::util;
@@ -93,26 +95,27 @@ pub fn check_pascal_case_type(decls: &[Decl], source: &str) -> Vec<LintDiagnosti
     diags
 }
 
-/// style/public-return-type: Public functions should have explicit return types.
-pub fn check_public_return_type(decls: &[Decl], source: &str) -> Vec<LintDiagnostic> {
+/// style/public-return-type: Public functions that return a value should
+/// say so in the signature. Reported at `LintOpts::public_return_type_severity`.
+pub fn check_public_return_type(decls: &[Decl], source: &str, opts: &LintOpts) -> Vec<LintDiagnostic> {
     let mut diags = Vec::new();
 
     for decl in decls {
         match &decl.kind {
-            DeclKind::Fn(f) => check_fn_return_type(f, source, decl.span, &mut diags),
+            DeclKind::Fn(f) => check_fn_return_type(f, source, decl.span, opts, &mut diags),
             DeclKind::Struct(s) => {
                 for m in &s.methods {
-                    check_fn_return_type(m, source, decl.span, &mut diags);
+                    check_fn_return_type(m, source, decl.span, opts, &mut diags);
                 }
             }
             DeclKind::Enum(e) => {
                 for m in &e.methods {
-                    check_fn_return_type(m, source, decl.span, &mut diags);
+                    check_fn_return_type(m, source, decl.span, opts, &mut diags);
                 }
             }
             DeclKind::Impl(imp) => {
                 for m in &imp.methods {
-                    check_fn_return_type(m, source, decl.span, &mut diags);
+                    check_fn_return_type(m, source, decl.span, opts, &mut diags);
                 }
             }
             _ => {}
@@ -126,18 +129,19 @@ fn check_fn_return_type(
     f: &FnDecl,
     source: &str,
     span: rask_ast::Span,
+    opts: &LintOpts,
     diags: &mut Vec<LintDiagnostic>,
 ) {
     if !f.is_pub || is_suppressed(f, "style/public-return-type") {
         return;
     }
-    // Only flag if no return type AND body is non-empty (not just a declaration)
-    if f.ret_ty.is_none() && !f.body.is_empty() {
+    // A body that never returns a value is a unit function — nothing to annotate
+    if f.ret_ty.is_none() && stmts_return_value(&f.body) {
         let (line, col) = util::line_col(source, span.start);
         let source_line = util::get_source_line(source, line);
         diags.push(LintDiagnostic {
             rule: "style/public-return-type".to_string(),
-            severity: Severity::Error,
+            severity: opts.public_return_type_severity,
             message: format!(
                 "public function `{}` is missing a return type annotation",
                 f.name
@@ -152,6 +156,282 @@ fn check_fn_return_type(
     }
 }
 
+/// Whether any `return <value>` in `stmts` exits the enclosing function.
+/// Closure bodies return from the closure, so they don't count.
+fn stmts_return_value(stmts: &[Stmt]) -> bool {
+    stmts.iter().any(stmt_returns_value)
+}
+
+fn stmt_returns_value(stmt: &Stmt) -> bool {
+    match &stmt.kind {
+        StmtKind::Return(Some(_)) => true,
+        StmtKind::Expr(e) => expr_returns_value(e),
+        StmtKind::Mut { init, .. }
+        | StmtKind::Const { init, .. }
+        | StmtKind::MutTuple { init, .. }
+        | StmtKind::ConstTuple { init, .. } => expr_returns_value(init),
+        StmtKind::While { body, .. }
+        | StmtKind::WhileLet { body, .. }
+        | StmtKind::For { body, .. }
+        | StmtKind::ComptimeFor { body, .. }
+        | StmtKind::Loop { body, .. } => stmts_return_value(body),
+        StmtKind::Ensure { body, else_handler } => {
+            stmts_return_value(body)
+                || else_handler.as_ref().is_some_and(|(_, h)| stmts_return_value(h))
+        }
+        _ => false,
+    }
+}
+
+fn expr_returns_value(expr: &Expr) -> bool {
+    let opt = |e: &Option<Box<Expr>>| e.as_deref().is_some_and(expr_returns_value);
+    match &expr.kind {
+        ExprKind::If { then_branch, else_branch, .. }
+        | ExprKind::IfLet { then_branch, else_branch, .. } => {
+            expr_returns_value(then_branch) || opt(else_branch)
+        }
+        ExprKind::GuardPattern { else_branch, .. } => expr_returns_value(else_branch),
+        ExprKind::Match { arms, .. } => arms.iter().any(|arm| expr_returns_value(&arm.body)),
+        ExprKind::Try { else_clause, .. } => {
+            else_clause.as_ref().is_some_and(|e| expr_returns_value(&e.body))
+        }
+        ExprKind::Block(stmts)
+        | ExprKind::UsingBlock { body: stmts, .. }
+        | ExprKind::WithAs { body: stmts, .. }
+        | ExprKind::Unsafe { body: stmts }
+        | ExprKind::Loop { body: stmts, .. } => stmts_return_value(stmts),
+        _ => false,
+    }
+}
+
+/// style/complexity: Functions whose cyclomatic complexity exceeds
+/// `LintOpts::complexity_threshold` should be split up.
+pub fn check_complexity(decls: &[Decl], source: &str, opts: &LintOpts) -> Vec<LintDiagnostic> {
+    let mut diags = Vec::new();
+
+    for decl in decls {
+        match &decl.kind {
+            DeclKind::Fn(f) => check_fn_complexity(f, None, source, opts, &mut diags),
+            DeclKind::Struct(s) => {
+                for m in &s.methods {
+                    check_fn_complexity(m, Some(&s.name), source, opts, &mut diags);
+                }
+            }
+            DeclKind::Enum(e) => {
+                for m in &e.methods {
+                    check_fn_complexity(m, Some(&e.name), source, opts, &mut diags);
+                }
+            }
+            DeclKind::Impl(imp) => {
+                for m in &imp.methods {
+                    check_fn_complexity(m, Some(&imp.target_ty), source, opts, &mut diags);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    diags
+}
+
+fn check_fn_complexity(
+    f: &FnDecl,
+    owner: Option<&str>,
+    source: &str,
+    opts: &LintOpts,
+    diags: &mut Vec<LintDiagnostic>,
+) {
+    if is_suppressed(f, "style/complexity") {
+        return;
+    }
+    let complexity = 1 + stmts_complexity(&f.body);
+    if complexity <= opts.complexity_threshold {
+        return;
+    }
+    let name = match owner {
+        Some(ty) => format!("{}.{}", strip_generics(ty), f.name),
+        None => f.name.clone(),
+    };
+    let (line, col) = util::line_col(source, f.span.start);
+    let source_line = util::get_source_line(source, line);
+    diags.push(LintDiagnostic {
+        rule: "style/complexity".to_string(),
+        severity: opts.complexity_severity,
+        message: format!(
+            "`{}` has cyclomatic complexity {} (threshold {})",
+            name, complexity, opts.complexity_threshold
+        ),
+        location: LintLocation {
+            line,
+            column: col,
+            source_line,
+        },
+        fix: "extract branches into smaller helper functions".to_string(),
+    });
+}
+
+/// style/missing-docs: Public functions, types, and methods should carry a
+/// doc comment. Off unless `LintOpts::missing_docs_severity` is set.
+pub fn check_missing_docs(decls: &[Decl], source: &str, opts: &LintOpts) -> Vec<LintDiagnostic> {
+    let Some(severity) = opts.missing_docs_severity else {
+        return Vec::new();
+    };
+    let mut diags = Vec::new();
+    let mut report = |kind: &str, name: &str, span: rask_ast::Span| {
+        let (line, col) = util::line_col(source, span.start);
+        let source_line = util::get_source_line(source, line);
+        diags.push(LintDiagnostic {
+            rule: "style/missing-docs".to_string(),
+            severity,
+            message: format!("public {} `{}` has no doc comment", kind, name),
+            location: LintLocation {
+                line,
+                column: col,
+                source_line,
+            },
+            fix: format!("add a `///` comment describing `{}`", name),
+        });
+    };
+
+    for decl in decls {
+        let (kind, name, is_pub, doc, methods) = match &decl.kind {
+            DeclKind::Fn(f) => ("function", f.name.as_str(), f.is_pub, &f.doc, &[][..]),
+            DeclKind::Struct(s) => ("struct", s.name.as_str(), s.is_pub, &s.doc, &s.methods[..]),
+            DeclKind::Enum(e) => ("enum", e.name.as_str(), e.is_pub, &e.doc, &e.methods[..]),
+            DeclKind::Union(u) => ("union", u.name.as_str(), u.is_pub, &u.doc, &[][..]),
+            DeclKind::Trait(t) => ("trait", t.name.as_str(), t.is_pub, &t.doc, &[][..]),
+            DeclKind::Impl(imp) => ("", imp.target_ty.as_str(), false, &None, &imp.methods[..]),
+            _ => continue,
+        };
+        let suppressed = matches!(&decl.kind, DeclKind::Fn(f) if is_suppressed(f, "style/missing-docs"));
+        if is_pub && is_undocumented(doc) && !suppressed {
+            report(kind, name, decl.span);
+        }
+        let owner = strip_generics(name);
+        for m in methods {
+            if m.is_pub && is_undocumented(&m.doc) && !is_suppressed(m, "style/missing-docs") {
+                report("method", &format!("{}.{}", owner, m.name), m.span);
+            }
+        }
+    }
+
+    diags
+}
+
+fn is_undocumented(doc: &Option<String>) -> bool {
+    doc.as_deref().is_none_or(|d| d.trim().is_empty())
+}
+
+/// Decision points in a statement list. Closures count toward the
+/// enclosing function — they're read as part of it.
+fn stmts_complexity(stmts: &[Stmt]) -> usize {
+    stmts.iter().map(stmt_complexity).sum()
+}
+
+fn stmt_complexity(stmt: &Stmt) -> usize {
+    match &stmt.kind {
+        StmtKind::Expr(e) => expr_complexity(e),
+        StmtKind::Mut { init, .. }
+        | StmtKind::Const { init, .. }
+        | StmtKind::MutTuple { init, .. }
+        | StmtKind::ConstTuple { init, .. } => expr_complexity(init),
+        StmtKind::Assign { target, value } => expr_complexity(target) + expr_complexity(value),
+        StmtKind::Return(Some(e)) => expr_complexity(e),
+        StmtKind::Break { value: Some(e), .. } => expr_complexity(e),
+        StmtKind::While { cond, body } => 1 + expr_complexity(cond) + stmts_complexity(body),
+        StmtKind::WhileLet { expr, body, .. } => 1 + expr_complexity(expr) + stmts_complexity(body),
+        StmtKind::For { iter, body, .. } | StmtKind::ComptimeFor { iter, body, .. } => {
+            1 + expr_complexity(iter) + stmts_complexity(body)
+        }
+        StmtKind::Loop { body, .. } | StmtKind::Comptime(body) => stmts_complexity(body),
+        StmtKind::Ensure { body, else_handler } => {
+            stmts_complexity(body)
+                + else_handler.as_ref().map_or(0, |(_, h)| 1 + stmts_complexity(h))
+        }
+        _ => 0,
+    }
+}
+
+fn expr_complexity(expr: &Expr) -> usize {
+    let opt = |e: &Option<Box<Expr>>| e.as_deref().map_or(0, expr_complexity);
+    match &expr.kind {
+        ExprKind::Binary { op, left, right } => {
+            let branch = usize::from(matches!(op, BinOp::And | BinOp::Or));
+            branch + expr_complexity(left) + expr_complexity(right)
+        }
+        // `else if` is a nested If in the else branch, so each link counts once
+        ExprKind::If { cond, then_branch, else_branch, .. } => {
+            1 + expr_complexity(cond) + expr_complexity(then_branch) + opt(else_branch)
+        }
+        ExprKind::IfLet { expr, then_branch, else_branch, .. } => {
+            1 + expr_complexity(expr) + expr_complexity(then_branch) + opt(else_branch)
+        }
+        ExprKind::GuardPattern { expr, else_branch, .. } => {
+            1 + expr_complexity(expr) + expr_complexity(else_branch)
+        }
+        // An n-arm match is n-1 decisions, like an if/else-if chain
+        ExprKind::Match { scrutinee, arms } => {
+            let mut total = arms.len().saturating_sub(1) + expr_complexity(scrutinee);
+            for arm in arms {
+                total += arm.guard.as_deref().map_or(0, |g| 1 + expr_complexity(g));
+                total += expr_complexity(&arm.body);
+            }
+            total
+        }
+        ExprKind::Select { arms, .. } => {
+            let mut total = arms.len().saturating_sub(1);
+            for arm in arms {
+                total += match &arm.kind {
+                    SelectArmKind::Recv { channel, .. } => expr_complexity(channel),
+                    SelectArmKind::Send { channel, value } => {
+                        expr_complexity(channel) + expr_complexity(value)
+                    }
+                    SelectArmKind::Default => 0,
+                };
+                total += expr_complexity(&arm.body);
+            }
+            total
+        }
+        ExprKind::Try { expr, else_clause } => {
+            expr_complexity(expr)
+                + else_clause.as_ref().map_or(0, |e| 1 + expr_complexity(&e.body))
+        }
+        ExprKind::Block(stmts)
+        | ExprKind::UsingBlock { body: stmts, .. }
+        | ExprKind::WithAs { body: stmts, .. }
+        | ExprKind::Spawn { body: stmts }
+        | ExprKind::BlockCall { body: stmts, .. }
+        | ExprKind::Unsafe { body: stmts }
+        | ExprKind::Comptime { body: stmts }
+        | ExprKind::Loop { body: stmts, .. } => stmts_complexity(stmts),
+        ExprKind::Unary { operand, .. } => expr_complexity(operand),
+        ExprKind::Call { func, args } => {
+            expr_complexity(func) + args.iter().map(|a| expr_complexity(&a.expr)).sum::<usize>()
+        }
+        ExprKind::MethodCall { object, args, .. } => {
+            expr_complexity(object) + args.iter().map(|a| expr_complexity(&a.expr)).sum::<usize>()
+        }
+        ExprKind::Field { object, .. } | ExprKind::OptionalField { object, .. } => {
+            expr_complexity(object)
+        }
+        ExprKind::Index { object, index } => expr_complexity(object) + expr_complexity(index),
+        ExprKind::Closure { body, .. } => expr_complexity(body),
+        ExprKind::NullCoalesce { value, default } => {
+            expr_complexity(value) + expr_complexity(default)
+        }
+        ExprKind::IsPattern { expr, .. }
+        | ExprKind::IsPresent { expr, .. }
+        | ExprKind::Unwrap { expr, .. }
+        | ExprKind::Cast { expr, .. }
+        | ExprKind::Convert { expr, .. } => expr_complexity(expr),
+        ExprKind::Array(elems) | ExprKind::Tuple(elems) => elems.iter().map(expr_complexity).sum(),
+        ExprKind::StructLit { fields, spread, .. } => {
+            fields.iter().map(|f| expr_complexity(&f.value)).sum::<usize>() + opt(spread)
+        }
+        _ => 0,
+    }
+}
+
 fn is_suppressed(f: &FnDecl, rule_id: &str) -> bool {
     f.attrs
         .iter()
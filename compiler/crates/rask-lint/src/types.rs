@@ -46,6 +46,14 @@ pub struct LintOpts {
     pub rules: Vec<String>,
     /// Exclude rules matching these patterns
     pub excludes: Vec<String>,
+    /// Highest cyclomatic complexity `style/complexity` accepts
+    pub complexity_threshold: usize,
+    /// Severity reported by `style/complexity`
+    pub complexity_severity: Severity,
+    /// Severity reported by `style/missing-docs`; `None` leaves the rule off
+    pub missing_docs_severity: Option<Severity>,
+    /// Severity reported by `style/public-return-type`
+    pub public_return_type_severity: Severity,
 }
 
 impl Default for LintOpts {
@@ -53,6 +61,10 @@ impl Default for LintOpts {
         Self {
             rules: Vec::new(),
             excludes: Vec::new(),
+            complexity_threshold: 15,
+            complexity_severity: Severity::Warning,
+            missing_docs_severity: None,
+            public_return_type_severity: Severity::Error,
         }
     }
 }
@@ -1,16 +1,20 @@
 // SPDX-License-Identifier: (MIT OR Apache-2.0)
 
-//! Escape analysis for string-typed locals.
+//! Escape analysis for string-typed and aggregate locals.
 //!
 //! Determines which string locals may escape the function scope. A string
 //! escapes when it's returned, stored in a collection passed out, captured
 //! by an escaping closure, or sent cross-task. Non-escaping strings can
 //! skip atomic refcount operations entirely (RE2).
 //!
+//! The same question for structs, enums, tuples, and fixed arrays decides
+//! where codegen puts them: a non-escaping aggregate lives in a stack slot,
+//! an escaping one gets heap storage that outlives the frame.
+//!
 //! See `comp.string-refcount-elision` spec, "Escape Analysis" section.
 
-use crate::{LocalId, MirFunction, MirOperand, MirStmtKind, MirTerminatorKind, MirType};
-use std::collections::HashSet;
+use crate::{LocalId, MirFunction, MirOperand, MirRValue, MirStmtKind, MirTerminatorKind, MirType};
+use std::collections::{HashMap, HashSet};
 
 /// Functions that take ownership of a string argument (the string escapes).
 const ESCAPE_FUNCTIONS: &[&str] = &[
@@ -109,6 +113,125 @@ pub fn escaping_strings(func: &MirFunction) -> HashSet<LocalId> {
     escaped
 }
 
+/// Why an aggregate local can't live in its function's stack frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AggregateEscape {
+    /// Returned from the function.
+    Returned,
+    /// Stored through a pointer or handed to a function that keeps it
+    /// (collections, channels, shared cells, trait boxes).
+    Stored,
+    /// Captured by a heap-allocated closure.
+    Captured,
+    /// Captured by a closure passed to `spawn` — another task reads it.
+    Spawned,
+}
+
+fn is_aggregate(ty: &MirType) -> bool {
+    matches!(ty, MirType::Struct(_) | MirType::Enum(_) | MirType::Tuple(_) | MirType::Array { .. })
+}
+
+/// Returns the aggregate locals (struct, enum, tuple, fixed array) that may
+/// outlive the function, with the first reason found. Parameters are
+/// excluded — their storage belongs to the caller.
+///
+/// Escape flows backwards through copies and references: if `_2 = &_1` or
+/// `_2 = _1` and `_2` escapes, so does `_1`, since codegen may alias the
+/// two to the same storage.
+pub fn escaping_aggregates(func: &MirFunction) -> HashMap<LocalId, AggregateEscape> {
+    let aggregates: HashSet<LocalId> = func.locals.iter()
+        .filter(|l| !l.is_param && is_aggregate(&l.ty))
+        .map(|l| l.id)
+        .collect();
+    if aggregates.is_empty() {
+        return HashMap::new();
+    }
+
+    // Any local may carry an aggregate's address (refs, copies), so collect
+    // the roots for every local and filter to aggregates at the end.
+    let mut roots: HashMap<LocalId, AggregateEscape> = HashMap::new();
+    let mut mark = |id: &LocalId, why: AggregateEscape| {
+        // `spawn` is the more specific reason for a heap closure's captures.
+        let slot = roots.entry(*id).or_insert(why);
+        if why == AggregateEscape::Spawned {
+            *slot = why;
+        }
+    };
+    let mut closure_captures: HashMap<LocalId, Vec<LocalId>> = HashMap::new();
+    let mut sources: HashMap<LocalId, Vec<LocalId>> = HashMap::new();
+
+    for block in &func.blocks {
+        for stmt in &block.statements {
+            if let MirStmtKind::ClosureCreate { dst, captures, .. } = &stmt.kind {
+                closure_captures.insert(*dst, captures.iter().map(|c| c.local_id).collect());
+            }
+        }
+    }
+
+    for block in &func.blocks {
+        for stmt in &block.statements {
+            match &stmt.kind {
+                MirStmtKind::Assign { dst, rvalue: MirRValue::Use(MirOperand::Local(src)) }
+                | MirStmtKind::Assign { dst, rvalue: MirRValue::Ref(src) } => {
+                    sources.entry(*dst).or_default().push(*src);
+                }
+                MirStmtKind::Call { func: fref, args, .. } => {
+                    if fref.name == "spawn" {
+                        for arg in args {
+                            if let MirOperand::Local(id) = arg {
+                                for cap in closure_captures.get(id).into_iter().flatten() {
+                                    mark(cap, AggregateEscape::Spawned);
+                                }
+                            }
+                        }
+                    } else if ESCAPE_FUNCTIONS.iter().any(|name| fref.name.contains(name)) {
+                        for arg in args {
+                            if let MirOperand::Local(id) = arg {
+                                mark(id, AggregateEscape::Stored);
+                            }
+                        }
+                    }
+                }
+                MirStmtKind::Store { value: MirOperand::Local(id), .. }
+                | MirStmtKind::ArrayStore { value: MirOperand::Local(id), .. }
+                | MirStmtKind::TraitBox { value: MirOperand::Local(id), .. } => {
+                    mark(id, AggregateEscape::Stored);
+                }
+                MirStmtKind::ClosureCreate { captures, heap: true, .. } => {
+                    for cap in captures {
+                        mark(&cap.local_id, AggregateEscape::Captured);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        match &block.terminator.kind {
+            MirTerminatorKind::Return { value: Some(MirOperand::Local(id)) }
+            | MirTerminatorKind::CleanupReturn { value: Some(MirOperand::Local(id)), .. } => {
+                mark(id, AggregateEscape::Returned);
+            }
+            _ => {}
+        }
+    }
+
+    // Propagate to whatever each escaping local was copied or borrowed from.
+    let mut escaped = roots.clone();
+    let mut work: Vec<LocalId> = roots.keys().copied().collect();
+    while let Some(id) = work.pop() {
+        let why = escaped[&id];
+        for src in sources.get(&id).into_iter().flatten() {
+            if !escaped.contains_key(src) {
+                escaped.insert(*src, why);
+                work.push(*src);
+            }
+        }
+    }
+
+    escaped.retain(|id, _| aggregates.contains(id));
+    escaped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -259,4 +382,100 @@ mod tests {
         let esc = escaping_strings(&f);
         assert!(esc.is_empty());
     }
+
+    fn struct_local(id: u32, name: &str) -> MirLocal {
+        MirLocal {
+            id: local(id),
+            name: Some(name.into()),
+            ty: MirType::Struct(crate::types::StructLayoutId::new(0, 16, 8)),
+            is_param: false,
+        }
+    }
+
+    fn block(statements: Vec<MirStmt>, ret: Option<u32>) -> MirBlock {
+        MirBlock {
+            id: BlockId(0),
+            statements,
+            terminator: MirTerminator::dummy(MirTerminatorKind::Return {
+                value: ret.map(|id| MirOperand::Local(local(id))),
+            }),
+        }
+    }
+
+    #[test]
+    fn local_only_struct_stays_on_stack() {
+        // p = Point{..}; q = p; print(q.x)
+        let f = make_fn(
+            vec![struct_local(0, "p"), struct_local(1, "q")],
+            vec![block(vec![
+                MirStmt::dummy(MirStmtKind::Assign {
+                    dst: local(1),
+                    rvalue: MirRValue::Use(MirOperand::Local(local(0))),
+                }),
+                MirStmt::dummy(MirStmtKind::Call {
+                    dst: None,
+                    func: FunctionRef::internal("print_point".to_string()),
+                    args: vec![MirOperand::Local(local(1))],
+                }),
+            ], None)],
+        );
+        assert!(escaping_aggregates(&f).is_empty());
+    }
+
+    #[test]
+    fn returned_struct_escapes_through_its_copy() {
+        // q = p; return q
+        let f = make_fn(
+            vec![struct_local(0, "p"), struct_local(1, "q")],
+            vec![block(vec![MirStmt::dummy(MirStmtKind::Assign {
+                dst: local(1),
+                rvalue: MirRValue::Use(MirOperand::Local(local(0))),
+            })], Some(1))],
+        );
+        let esc = escaping_aggregates(&f);
+        assert_eq!(esc.get(&local(0)), Some(&AggregateEscape::Returned));
+        assert_eq!(esc.get(&local(1)), Some(&AggregateEscape::Returned));
+    }
+
+    #[test]
+    fn struct_captured_by_spawned_closure_escapes() {
+        let f = make_fn(
+            vec![struct_local(0, "p"), int_local(1), struct_local(2, "local")],
+            vec![block(vec![
+                MirStmt::dummy(MirStmtKind::ClosureCreate {
+                    dst: local(1),
+                    func_name: "task".to_string(),
+                    captures: vec![crate::ClosureCapture { local_id: local(0), offset: 8, size: 16 }],
+                    heap: true,
+                }),
+                MirStmt::dummy(MirStmtKind::Call {
+                    dst: None,
+                    func: FunctionRef::internal("spawn".to_string()),
+                    args: vec![MirOperand::Local(local(1))],
+                }),
+            ], None)],
+        );
+        let esc = escaping_aggregates(&f);
+        assert_eq!(esc.get(&local(0)), Some(&AggregateEscape::Spawned));
+        assert!(!esc.contains_key(&local(2)));
+    }
+
+    #[test]
+    fn struct_whose_reference_is_stored_escapes() {
+        // r = &p; rask_vec_push(v, r)
+        let f = make_fn(
+            vec![struct_local(0, "p"), int_local(1), int_local(2)],
+            vec![block(vec![
+                MirStmt::dummy(MirStmtKind::Assign { dst: local(1), rvalue: MirRValue::Ref(local(0)) }),
+                MirStmt::dummy(MirStmtKind::Call {
+                    dst: None,
+                    func: FunctionRef::internal("rask_vec_push".to_string()),
+                    args: vec![MirOperand::Local(local(2)), MirOperand::Local(local(1))],
+                }),
+            ], None)],
+        );
+        let esc = escaping_aggregates(&f);
+        assert_eq!(esc.get(&local(0)), Some(&AggregateEscape::Stored));
+        assert!(!esc.contains_key(&local(1)), "non-aggregates are not reported");
+    }
 }
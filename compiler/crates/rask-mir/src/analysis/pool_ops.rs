@@ -3,6 +3,9 @@
 //! Pool operation classification — shared between generation coalescing,
 //! typestate analysis, and future pool-related passes.
 
+use std::collections::{HashMap, HashSet};
+
+use crate::analysis::uses;
 use crate::{LocalId, MirOperand, MirStmt, MirStmtKind};
 
 /// Pool-mutating function names that add elements (Grow effect).
@@ -82,3 +85,40 @@ pub fn pool_remove_target(stmt: &MirStmt) -> Option<(LocalId, LocalId)> {
     }
     None
 }
+
+/// Drop validated `(pool, handle)` checks that `stmt` could invalidate:
+/// structural mutation of the pool (MT1), an unknown call taking it (MT3,
+/// CF4), any closure call, and reassignment of the pool or handle (GC3).
+/// `pool_locals` are the locals any check in the function reads as a pool.
+pub fn invalidate_checks<V>(
+    stmt: &MirStmt,
+    checked: &mut HashMap<(LocalId, LocalId), V>,
+    pool_locals: &HashSet<LocalId>,
+) {
+    if let Some(mutated_pool) = pool_mutation(stmt) {
+        checked.retain(|&(pool, _), _| pool != mutated_pool);
+    }
+
+    if let MirStmtKind::Call { func, args, .. } = &stmt.kind {
+        if !is_pool_mutator(&func.name) && !is_safe_pool_call(&func.name) {
+            for arg in args {
+                if let MirOperand::Local(id) = arg {
+                    if pool_locals.contains(id) {
+                        checked.retain(|&(pool, _), _| pool != *id);
+                    }
+                }
+            }
+        }
+    }
+
+    // Closure calls could capture pool references (conservative)
+    if matches!(&stmt.kind, MirStmtKind::ClosureCall { .. }) {
+        checked.clear();
+    }
+
+    if let Some(assigned) = uses::stmt_def(stmt) {
+        if !matches!(&stmt.kind, MirStmtKind::PoolCheckedAccess { .. }) {
+            checked.retain(|&(pool, handle), _| pool != assigned && handle != assigned);
+        }
+    }
+}
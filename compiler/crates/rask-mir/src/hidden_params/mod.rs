@@ -275,6 +275,7 @@ mod tests {
             name: None,
             ty: "Pool<Player>".to_string(),
             is_frozen: false,
+            span: Span::new(0, 0),
         };
         let req = context_clause_to_req(&cc);
         assert_eq!(req.param_name, "__ctx_pool_Player");
@@ -288,6 +289,7 @@ mod tests {
             name: Some("players".to_string()),
             ty: "Pool<Player>".to_string(),
             is_frozen: false,
+            span: Span::new(0, 0),
         };
         let req = context_clause_to_req(&cc);
         assert_eq!(req.param_name, "__ctx_players");
@@ -34,4 +34,7 @@ pub use rask_ast::expr::ConvertKind;
 pub use stmt::{ClosureCapture, MirStmt, MirStmtKind, MirTerminator, MirTerminatorKind, Span};
 pub use lower::ComptimeGlobalMeta;
 pub use program::MirProgram;
-pub use types::{MirType, StructLayoutId, EnumLayoutId};
+pub use types::{
+    MirType, StructLayoutId, EnumLayoutId, RESULT_ORIGIN_FILE_OFFSET, RESULT_ORIGIN_LINE_OFFSET,
+    RESULT_PAYLOAD_OFFSET,
+};
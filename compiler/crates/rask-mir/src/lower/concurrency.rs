@@ -1,13 +1,13 @@
 // SPDX-License-Identifier: (MIT OR Apache-2.0)
 
-//! Concurrency lowering: Shared read/write blocks, Mutex lock blocks.
+//! Concurrency lowering: Shared read/write blocks, Mutex lock blocks, select.
 
 use super::{LoweringError, MirLowerer, TypedOperand};
 use crate::{
-    stmt::ClosureCapture, types::StructLayoutId, BlockBuilder, FunctionRef,
-    MirOperand, MirStmt, MirStmtKind, MirTerminator, MirTerminatorKind, MirType,
+    stmt::ClosureCapture, types::StructLayoutId, BlockBuilder, FunctionRef, MirConst,
+    MirOperand, MirRValue, MirStmt, MirStmtKind, MirTerminator, MirTerminatorKind, MirType,
 };
-use rask_ast::expr::{Expr, ExprKind};
+use rask_ast::expr::{Expr, ExprKind, SelectArm, SelectArmKind};
 
 impl<'a> MirLowerer<'a> {
     /// Extract the inner type name from a Shared variable expression.
@@ -264,4 +264,167 @@ impl<'a> MirLowerer<'a> {
         }
         8 // scalar default
     }
+
+    /// `select` (conc.select): register every channel arm with the runtime,
+    /// block until one fires — or poll once when there's a default arm (A3) —
+    /// then switch to the winning arm's body.
+    pub(super) fn lower_select(
+        &mut self,
+        arms: &[SelectArm],
+        is_priority: bool,
+    ) -> Result<TypedOperand, LoweringError> {
+        let op_count = arms.iter().filter(|a| !matches!(a.kind, SelectArmKind::Default)).count();
+        let sel = self.builder.alloc_temp(MirType::I64);
+        self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+            dst: Some(sel),
+            func: FunctionRef::internal("rask_select_new".to_string()),
+            args: vec![MirOperand::Constant(MirConst::Int(op_count as i64))],
+        }));
+
+        // Registration order defines the index the runtime hands back.
+        let mut op_arms: Vec<usize> = Vec::new();
+        let mut default_arm = None;
+        for (i, arm) in arms.iter().enumerate() {
+            match &arm.kind {
+                SelectArmKind::Recv { channel, .. } => {
+                    let (rx, _) = self.lower_expr(channel)?;
+                    self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+                        dst: None,
+                        func: FunctionRef::internal("rask_select_add_recv".to_string()),
+                        args: vec![MirOperand::Local(sel), rx],
+                    }));
+                    op_arms.push(i);
+                }
+                SelectArmKind::Send { channel, value } => {
+                    let (tx, _) = self.lower_expr(channel)?;
+                    let (val, _) = self.lower_expr(value)?;
+                    self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+                        dst: None,
+                        func: FunctionRef::internal("rask_select_add_send".to_string()),
+                        args: vec![MirOperand::Local(sel), tx, val],
+                    }));
+                    op_arms.push(i);
+                }
+                SelectArmKind::Default => default_arm = Some(i),
+            }
+        }
+
+        let fired = self.builder.alloc_temp(MirType::I64);
+        let wait_fn = if default_arm.is_some() { "rask_select_poll" } else { "rask_select_wait" };
+        self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+            dst: Some(fired),
+            func: FunctionRef::internal(wait_fn.to_string()),
+            args: vec![
+                MirOperand::Local(sel),
+                MirOperand::Constant(MirConst::Int(is_priority as i64)),
+            ],
+        }));
+
+        let merge_block = self.builder.create_block();
+        let arm_blocks: Vec<_> = arms.iter().map(|_| self.builder.create_block()).collect();
+        let cases = op_arms
+            .iter()
+            .enumerate()
+            .map(|(op, &arm)| (op as u64, arm_blocks[arm]))
+            .collect();
+        // wait never returns -1; poll's -1 means "take the default arm".
+        let fallback = match default_arm {
+            Some(i) => arm_blocks[i],
+            // New blocks start out terminated by Unreachable.
+            None => self.builder.create_block(),
+        };
+        self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Switch {
+            value: MirOperand::Local(fired),
+            cases,
+            default: fallback,
+        }));
+
+        let mut result: Option<(crate::LocalId, MirType)> = None;
+        for (i, arm) in arms.iter().enumerate() {
+            self.builder.switch_to_block(arm_blocks[i]);
+            if let SelectArmKind::Recv { channel, binding } = &arm.kind {
+                let op = op_arms.iter().position(|&a| a == i).unwrap_or(0);
+                let elem_ty = self.channel_elem_type(channel);
+                let local = self.builder.alloc_local(binding.clone(), elem_ty.clone());
+                if is_aggregate(&elem_ty) {
+                    let out_ptr = self.builder.alloc_temp(MirType::Ptr);
+                    self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Assign {
+                        dst: out_ptr,
+                        rvalue: MirRValue::Ref(local),
+                    }));
+                    self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+                        dst: None,
+                        func: FunctionRef::internal("rask_select_take_into".to_string()),
+                        args: vec![
+                            MirOperand::Local(sel),
+                            MirOperand::Constant(MirConst::Int(op as i64)),
+                            MirOperand::Local(out_ptr),
+                        ],
+                    }));
+                } else {
+                    self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+                        dst: Some(local),
+                        func: FunctionRef::internal("rask_select_take_i64".to_string()),
+                        args: vec![
+                            MirOperand::Local(sel),
+                            MirOperand::Constant(MirConst::Int(op as i64)),
+                        ],
+                    }));
+                }
+                self.locals.insert(binding.clone(), (local, elem_ty));
+            }
+            self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+                dst: None,
+                func: FunctionRef::internal("rask_select_free".to_string()),
+                args: vec![MirOperand::Local(sel)],
+            }));
+
+            let (arm_val, arm_ty) = self.lower_expr(&arm.body)?;
+            if result.is_none() && arm_ty != MirType::Void {
+                result = Some((self.builder.alloc_temp(arm_ty.clone()), arm_ty));
+            }
+            if let Some((dst, _)) = &result {
+                if self.builder.current_block_unterminated() {
+                    self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Assign {
+                        dst: *dst,
+                        rvalue: MirRValue::Use(arm_val),
+                    }));
+                }
+            }
+            if self.builder.current_block_unterminated() {
+                self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: merge_block }));
+            }
+        }
+
+        self.builder.switch_to_block(merge_block);
+        Ok(match result {
+            Some((local, ty)) => (MirOperand::Local(local), ty),
+            None => (MirOperand::Constant(MirConst::Int(0)), MirType::Void),
+        })
+    }
+
+    /// Element type of a `Receiver<T>` expression, from the checker's types.
+    fn channel_elem_type(&self, channel: &Expr) -> MirType {
+        self.ctx
+            .lookup_raw_type(channel.id)
+            .and_then(|ty| match ty {
+                rask_types::Type::UnresolvedGeneric { name, args } if name == "Receiver" => {
+                    args.first().and_then(|a| match a {
+                        rask_types::GenericArg::Type(t) => Some(self.ctx.type_to_mir(t)),
+                        _ => None,
+                    })
+                }
+                _ => None,
+            })
+            .unwrap_or(MirType::I64)
+    }
+}
+
+/// Types whose locals hold a pointer to stack storage (see codegen's `Ref`).
+fn is_aggregate(ty: &MirType) -> bool {
+    matches!(
+        ty,
+        MirType::Struct(_) | MirType::Enum(_) | MirType::Array { .. } | MirType::Tuple(_)
+            | MirType::Slice(_) | MirType::Option(_) | MirType::Result { .. } | MirType::Union(_)
+    )
 }
@@ -9,7 +9,7 @@ use super::{
 };
 use crate::{
     operand::MirConst, types::{EnumLayoutId, StructLayoutId},
-    BlockId, FunctionRef, LocalId, MirOperand, MirRValue, MirStmt, MirStmtKind, MirTerminator,
+    FunctionRef, LocalId, MirOperand, MirRValue, MirStmt, MirStmtKind, MirTerminator,
     MirTerminatorKind, MirType,
 };
 use rask_ast::{
@@ -183,12 +183,30 @@ impl<'a> MirLowerer<'a> {
     }
 
     pub(super) fn lower_expr(&mut self, expr: &Expr) -> Result<TypedOperand, LoweringError> {
+        let result = self.lower_expr_kind(expr)?;
+        // C1/C2: the ownership pass says this expression consumes a resource —
+        // cancel its ensure so cleanup doesn't run a second time.
+        if let Some(name) = self.ctx.resource_moves.get(&expr.id) {
+            if let Some(resource_id) = self.meta(name).and_then(|m| m.resource_id) {
+                self.builder.push_stmt(MirStmt::dummy(MirStmtKind::ResourceConsume { resource_id }));
+            }
+        }
+        Ok(result)
+    }
+
+    fn lower_expr_kind(&mut self, expr: &Expr) -> Result<TypedOperand, LoweringError> {
         self.builder.set_span(expr.span);
         match &expr.kind {
             // Literals
             ExprKind::Int(val, suffix) => {
                 // Suffixed literals carry their type explicitly. Unsuffixed
                 // literals follow the type checker's inference (default i32).
+                // L6: an unsuffixed integer literal in float position is that float.
+                if suffix.is_none() {
+                    if let Some(ty @ (MirType::F32 | MirType::F64)) = self.ctx.lookup_node_type(expr.id) {
+                        return Ok((MirOperand::Constant(MirConst::Float(*val as f64)), ty));
+                    }
+                }
                 let ty = match suffix {
                     Some(IntSuffix::I8) => MirType::I8,
                     Some(IntSuffix::I16) => MirType::I16,
@@ -403,7 +421,13 @@ impl<'a> MirLowerer<'a> {
             ExprKind::Call { func, args } => {
                 let mut arg_operands = Vec::new();
                 let mut arg_mir_types = Vec::new();
+                // conc.async/TN1: a task name is interpreter debugging metadata;
+                // the native runtime's spawn takes only the closure.
+                let is_spawn = matches!(&func.kind, ExprKind::Ident(n) if n == "spawn");
                 for a in args {
+                    if is_spawn && a.name.as_deref() == Some("name") {
+                        continue;
+                    }
                     let (op, mir_ty) = self.lower_expr(&a.expr)?;
                     // TR5: implicit trait coercion — emit TraitBox if type checker flagged this arg
                     if let Some(trait_name) = self.ctx.trait_coercions.get(&a.expr.id) {
@@ -2377,9 +2401,16 @@ impl<'a> MirLowerer<'a> {
                     }));
                     // Store fields at their offsets within the payload
                     for field in fields.iter() {
-                        let (val_op, _) = self.lower_expr(&field.value)?;
+                        let (val_op, val_ty) = self.lower_expr(&field.value)?;
                         let vf = variant_fields.iter()
                             .find(|f| f.name == field.name);
+                        let val_op = match vf {
+                            Some(f) => {
+                                let field_ty = self.ctx.resolve_type_str(&format!("{}", f.ty));
+                                self.wrap_some_for(val_op, &val_ty, &field_ty)
+                            }
+                            None => val_op,
+                        };
                         let offset = vf.map(|f| payload_offset + f.offset)
                             .unwrap_or(payload_offset);
                         let store_size = vf.map(|f| f.size);
@@ -2392,10 +2423,18 @@ impl<'a> MirLowerer<'a> {
                     }
                 } else {
                 for field in fields.iter() {
-                    let (val_op, _) = self.lower_expr(&field.value)?;
+                    let (val_op, val_ty) = self.lower_expr(&field.value)?;
                     // Look up field offset and size from layout
                     let field_layout = layout
                         .and_then(|sl| sl.fields.iter().find(|f| f.name == field.name));
+                    // `profile: Profile { .. }` into a `Profile?` field wraps as Some.
+                    let val_op = match field_layout {
+                        Some(f) => {
+                            let field_ty = self.ctx.resolve_type_str(&format!("{}", f.ty));
+                            self.wrap_some_for(val_op, &val_ty, &field_ty)
+                        }
+                        None => val_op,
+                    };
                     let offset = field_layout.map(|f| f.offset).unwrap_or(0);
                     let store_size = field_layout.map(|f| f.size);
                     self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Store {
@@ -2629,7 +2668,7 @@ impl<'a> MirLowerer<'a> {
             }
 
             // Unwrap (postfix !) - panic on None/Err
-            ExprKind::Unwrap { expr: inner, message: _ } => {
+            ExprKind::Unwrap { expr: inner, message } => {
                 let is_niche = self.is_niche_option_expr(inner);
                 let (val, _inner_ty) = self.lower_expr(inner)?;
                 let tag_local = self.emit_option_tag(&val, is_niche);
@@ -2644,10 +2683,14 @@ impl<'a> MirLowerer<'a> {
 
                 self.builder.switch_to_block(panic_block);
 
+                // The user's message rides along; codegen reports it with the
+                // source location instead of the generic unwrap text.
                 self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
                     dst: None,
                     func: FunctionRef::internal("panic_unwrap".to_string()),
-                    args: vec![],
+                    args: message.iter()
+                        .map(|m| MirOperand::Constant(MirConst::String(m.clone())))
+                        .collect(),
                 }));
                 self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Unreachable));
 
@@ -2769,11 +2812,10 @@ impl<'a> MirLowerer<'a> {
                 // Resolve the payload struct's layout to find the field's
                 // index, type, and offset. Required for the Some-branch
                 // load and to size the Option<field_ty> result.
-                // Peel off any extra Option layers — the type checker's flatten
-                // logic only fires when constraints have already resolved at
-                // OptionalField time, so chained `?.` can store an
-                // `Option<Option<T>>` raw type for the inner expression. We
-                // want the bare T to look up the field on.
+                // Peel off any extra Option layers. The checker flattens `?.`
+                // chains to a single `T?`, but a receiver typed from a
+                // `T??` field still arrives nested. We want the bare T to
+                // look up the field on.
                 let mut payload_ty = self.extract_payload_type(object)
                     .unwrap_or(MirType::I64);
                 while let MirType::Option(inner) = payload_ty {
@@ -2952,17 +2994,23 @@ impl<'a> MirLowerer<'a> {
                 if name == "Multitasking" || name == "MultiTasking" || name == "multitasking"
                     || name == "ThreadPool" || name == "threadpool"
                 {
-                    // Extract worker count from args, default to 0 (auto-detect)
-                    let worker_count = if let Some(arg) = args.first() {
+                    // Worker count then task bound, positional or named;
+                    // 0 means auto-detect workers / unbounded tasks.
+                    let mut worker_count = MirOperand::Constant(crate::operand::MirConst::Int(0));
+                    let mut max_tasks = MirOperand::Constant(crate::operand::MirConst::Int(0));
+                    for (i, arg) in args.iter().enumerate() {
                         let (op, _ty) = self.lower_expr(&arg.expr)?;
-                        op
-                    } else {
-                        MirOperand::Constant(crate::operand::MirConst::Int(0))
-                    };
+                        match arg.name.as_deref() {
+                            Some("tasks") => max_tasks = op,
+                            Some(_) => worker_count = op,
+                            None if i == 0 => worker_count = op,
+                            None => max_tasks = op,
+                        }
+                    }
                     self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
                         dst: None,
                         func: FunctionRef::internal("rask_runtime_init".to_string()),
-                        args: vec![worker_count],
+                        args: vec![worker_count, max_tasks],
                     }));
                     let result = self.lower_block(body);
                     self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
@@ -3261,34 +3309,7 @@ impl<'a> MirLowerer<'a> {
             }
 
             // Select (channel multiplexing)
-            ExprKind::Select { arms, .. } => {
-                let merge_block = self.builder.create_block();
-                let arm_blocks: Vec<BlockId> = arms.iter().map(|_| self.builder.create_block()).collect();
-
-                if let Some(&first) = arm_blocks.first() {
-                    self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: first }));
-                } else {
-                    self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: merge_block }));
-                }
-
-                let mut result_ty = MirType::Void;
-                let result_local = self.builder.alloc_temp(MirType::I32);
-                for (i, arm) in arms.iter().enumerate() {
-                    self.builder.switch_to_block(arm_blocks[i]);
-                    let (arm_val, arm_ty) = self.lower_expr(&arm.body)?;
-                    if i == 0 {
-                        result_ty = arm_ty;
-                    }
-                    self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Assign {
-                        dst: result_local,
-                        rvalue: MirRValue::Use(arm_val),
-                    }));
-                    self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: merge_block }));
-                }
-
-                self.builder.switch_to_block(merge_block);
-                Ok((MirOperand::Local(result_local), result_ty))
-            }
+            ExprKind::Select { arms, is_priority } => self.lower_select(arms, *is_priority),
 
             // Assert
             ExprKind::Assert { condition, message } => {
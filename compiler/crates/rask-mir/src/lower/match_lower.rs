@@ -157,6 +157,9 @@ impl<'a> MirLowerer<'a> {
                 Pattern::Struct { name, .. } => {
                     if let Some(tag) = self.resolve_pattern_tag(name) {
                         cases.push((tag, arm_blocks[i]));
+                    } else if !has_tag && matches!(scrutinee_ty, MirType::Struct(_)) {
+                        // Plain struct destructure can't fail
+                        default_block = arm_blocks[i];
                     } else {
                         cases.push((i as u64, arm_blocks[i]));
                     }
@@ -185,7 +188,9 @@ impl<'a> MirLowerer<'a> {
         }));
 
         let mut result_ty = MirType::Void;
-        let result_local = self.builder.alloc_temp(MirType::I64);
+        // Typed from the first arm that yields a value (like if-lowering),
+        // so float and struct results aren't squeezed through an i64.
+        let mut result_local = None;
         for (i, arm) in arms.iter().enumerate() {
             self.builder.switch_to_block(arm_blocks[i]);
 
@@ -348,6 +353,8 @@ impl<'a> MirLowerer<'a> {
                         }
                     }
                 }
+            } else if let (Pattern::Struct { fields, .. }, MirType::Struct(sid)) = (&arm.pattern, &scrutinee_ty) {
+                self.bind_struct_pattern_fields(&scrutinee_op, sid.id, fields);
             }
 
             if let Some(guard_expr) = &arm.guard {
@@ -368,12 +375,16 @@ impl<'a> MirLowerer<'a> {
 
             let (body_val, arm_ty) = self.lower_expr(&arm.body)?;
             if i == 0 {
-                result_ty = arm_ty;
+                result_ty = arm_ty.clone();
             }
 
             if self.builder.current_block_unterminated() {
+                let dst = *result_local.get_or_insert_with(|| {
+                    let ty = if matches!(arm_ty, MirType::Void) { MirType::I64 } else { arm_ty };
+                    self.builder.alloc_temp(ty)
+                });
                 self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Assign {
-                    dst: result_local,
+                    dst,
                     rvalue: MirRValue::Use(body_val),
                 }));
                 self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto {
@@ -383,10 +394,51 @@ impl<'a> MirLowerer<'a> {
         }
 
         self.builder.switch_to_block(merge_block);
+        let result_local = result_local.unwrap_or_else(|| self.builder.alloc_temp(MirType::I64));
         Ok((MirOperand::Local(result_local), result_ty))
     }
 
-    /// Lower match on strings: emit chain of string_eq comparisons.
+    /// Bind `Point { x, y }` / `Meters(m)` field patterns on a plain struct
+    /// scrutinee. Tuple struct fields are named `0`, `1`, ... like any other.
+    fn bind_struct_pattern_fields(
+        &mut self,
+        scrutinee_op: &MirOperand,
+        layout_id: u32,
+        fields: &[(String, rask_ast::expr::Pattern)],
+    ) {
+        use rask_ast::expr::Pattern;
+
+        let Some(layout) = self.ctx.struct_layouts.get(layout_id as usize) else { return };
+        for (field_name, field_pat) in fields {
+            let Pattern::Ident(binding) = field_pat else { continue };
+            let Some((field_idx, fl)) = layout.fields.iter()
+                .enumerate()
+                .find(|(_, f)| f.name == *field_name)
+            else {
+                continue;
+            };
+            let field_ty = self.ctx.resolve_type_str(&format!("{}", fl.ty));
+            let local = self.builder.alloc_local(binding.clone(), field_ty.clone());
+            self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Assign {
+                dst: local,
+                rvalue: MirRValue::Field {
+                    base: scrutinee_op.clone(),
+                    field_index: field_idx as u32,
+                    byte_offset: Some(fl.offset),
+                    field_size: Some(fl.size),
+                },
+            }));
+            if let Some(p) = self.mir_type_name(&field_ty) {
+                self.meta_mut(binding).type_prefix = Some(p);
+            }
+            self.locals.insert(binding.clone(), (local, field_ty));
+        }
+    }
+
+    /// Lower match on strings. Arms are tested in source order up to the
+    /// first unguarded catch-all. Without guarded catch-alls in that prefix,
+    /// dispatch switches on the byte length first and runs `string_eq` only
+    /// against literals of that length; otherwise it falls back to a chain.
     pub(super) fn lower_string_match(
         &mut self,
         scrutinee_op: MirOperand,
@@ -399,72 +451,77 @@ impl<'a> MirLowerer<'a> {
         let result_local = self.builder.alloc_temp(MirType::I64);
         let mut result_ty = MirType::Void;
 
-        let default_idx = arms.iter().position(|a| {
-            matches!(&a.pattern, Pattern::Wildcard)
-                || matches!(&a.pattern, Pattern::Ident(n) if !n.starts_with('"'))
-        });
+        let is_catch_all = |p: &Pattern| matches!(p, Pattern::Wildcard | Pattern::Ident(_));
+        let default_idx = arms.iter().position(|a| is_catch_all(&a.pattern) && a.guard.is_none());
+        let default_block = default_idx.map(|i| arm_blocks[i]).unwrap_or(merge_block);
+        let tested = &arms[..default_idx.unwrap_or(arms.len())];
+
+        let literals_of = |p: &Pattern| -> Vec<String> {
+            flatten_pattern_alternatives(p).into_iter().filter_map(|alt| match alt {
+                Pattern::Literal(lit) => match &lit.kind {
+                    ExprKind::String(s) => Some(s.clone()),
+                    _ => None,
+                },
+                _ => None,
+            }).collect()
+        };
 
-        let mut string_arms: Vec<(usize, Vec<String>)> = Vec::new();
-        for (i, arm) in arms.iter().enumerate() {
-            match &arm.pattern {
-                Pattern::Literal(lit) => {
-                    if let ExprKind::String(s) = &lit.kind {
-                        string_arms.push((i, vec![s.clone()]));
-                    }
+        if tested.iter().any(|a| is_catch_all(&a.pattern)) {
+            // A guarded catch-all can fire between literals of any length,
+            // so arms must be tried strictly in order.
+            for (i, arm) in tested.iter().enumerate() {
+                if is_catch_all(&arm.pattern) {
+                    let next = self.builder.create_block();
+                    self.emit_string_arm_hit(&scrutinee_op, arm, arm_blocks[i], next)?;
+                    self.builder.switch_to_block(next);
+                    continue;
                 }
-                Pattern::Or(pats) => {
-                    let strs: Vec<String> = pats.iter().filter_map(|p| {
-                        if let Pattern::Literal(lit) = p {
-                            if let ExprKind::String(s) = &lit.kind {
-                                return Some(s.clone());
-                            }
-                        }
-                        None
-                    }).collect();
-                    if !strs.is_empty() {
-                        string_arms.push((i, strs));
-                    }
+                for lit in literals_of(&arm.pattern) {
+                    self.emit_string_literal_test(&scrutinee_op, &lit, arm, arm_blocks[i])?;
+                }
+            }
+        } else {
+            // Literals of different lengths can't both match, so grouping by
+            // length keeps first-match order within each group.
+            let mut by_len: std::collections::BTreeMap<usize, Vec<(usize, String)>> =
+                std::collections::BTreeMap::new();
+            for (i, arm) in tested.iter().enumerate() {
+                for lit in literals_of(&arm.pattern) {
+                    by_len.entry(lit.len()).or_default().push((i, lit));
                 }
-                Pattern::Wildcard | Pattern::Ident(_) => {}
-                _ => {}
             }
-        }
-
-        let default_block = default_idx.map(|i| arm_blocks[i]).unwrap_or(merge_block);
 
-        for (arm_idx, literals) in &string_arms {
-            for (j, lit) in literals.iter().enumerate() {
-                let eq_result = self.builder.alloc_temp(MirType::Bool);
-                self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
-                    dst: Some(eq_result),
-                    func: FunctionRef::internal("string_eq".to_string()),
-                    args: vec![
-                        scrutinee_op.clone(),
-                        MirOperand::Constant(MirConst::String(lit.clone())),
-                    ],
-                }));
-                let next_test = self.builder.create_block();
-                self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Branch {
-                    cond: MirOperand::Local(eq_result),
-                    then_block: arm_blocks[*arm_idx],
-                    else_block: next_test,
-                }));
-                self.builder.switch_to_block(next_test);
-                let _ = j;
+            let len_local = self.builder.alloc_temp(MirType::I64);
+            self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+                dst: Some(len_local),
+                func: FunctionRef::internal("string_len".to_string()),
+                args: vec![scrutinee_op.clone()],
+            }));
+            let groups: Vec<(u64, BlockId, Vec<(usize, String)>)> = by_len.into_iter()
+                .map(|(len, lits)| (len as u64, self.builder.create_block(), lits))
+                .collect();
+            self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Switch {
+                value: MirOperand::Local(len_local),
+                cases: groups.iter().map(|(len, block, _)| (*len, *block)).collect(),
+                default: default_block,
+            }));
+            for (_, block, lits) in &groups {
+                self.builder.switch_to_block(*block);
+                for (i, lit) in lits {
+                    self.emit_string_literal_test(&scrutinee_op, lit, &arms[*i], arm_blocks[*i])?;
+                }
+                self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: default_block }));
             }
         }
-        self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: default_block }));
+        if self.builder.current_block_unterminated() {
+            self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: default_block }));
+        }
 
         for (i, arm) in arms.iter().enumerate() {
             self.builder.switch_to_block(arm_blocks[i]);
 
             if let Pattern::Ident(name) = &arm.pattern {
-                let bind_local = self.builder.alloc_local(name.clone(), MirType::String);
-                self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Assign {
-                    dst: bind_local,
-                    rvalue: MirRValue::Use(scrutinee_op.clone()),
-                }));
-                self.locals.insert(name.clone(), (bind_local, MirType::String));
+                self.bind_string_match(name, &scrutinee_op);
             }
 
             let (body_val, arm_ty) = self.lower_expr(&arm.body)?;
@@ -484,6 +541,74 @@ impl<'a> MirLowerer<'a> {
         Ok((MirOperand::Local(result_local), result_ty))
     }
 
+    /// Compare the scrutinee against one literal: go to `arm_block` on a
+    /// match (and passing guard), otherwise continue in a fresh block.
+    fn emit_string_literal_test(
+        &mut self,
+        scrutinee_op: &MirOperand,
+        lit: &str,
+        arm: &rask_ast::expr::MatchArm,
+        arm_block: BlockId,
+    ) -> Result<(), LoweringError> {
+        let next = self.builder.create_block();
+        let eq_result = self.builder.alloc_temp(MirType::Bool);
+        self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Call {
+            dst: Some(eq_result),
+            func: FunctionRef::internal("string_eq".to_string()),
+            args: vec![
+                scrutinee_op.clone(),
+                MirOperand::Constant(MirConst::String(lit.to_string())),
+            ],
+        }));
+        let hit = self.builder.create_block();
+        self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Branch {
+            cond: MirOperand::Local(eq_result),
+            then_block: hit,
+            else_block: next,
+        }));
+        self.builder.switch_to_block(hit);
+        self.emit_string_arm_hit(scrutinee_op, arm, arm_block, next)?;
+        self.builder.switch_to_block(next);
+        Ok(())
+    }
+
+    /// The pattern matched: bind a catch-all name, check the guard if any,
+    /// and jump to the arm body (or `next` when the guard fails).
+    fn emit_string_arm_hit(
+        &mut self,
+        scrutinee_op: &MirOperand,
+        arm: &rask_ast::expr::MatchArm,
+        arm_block: BlockId,
+        next: BlockId,
+    ) -> Result<(), LoweringError> {
+        use rask_ast::expr::Pattern;
+
+        let Some(guard) = &arm.guard else {
+            self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Goto { target: arm_block }));
+            return Ok(());
+        };
+        if let Pattern::Ident(name) = &arm.pattern {
+            self.bind_string_match(name, scrutinee_op);
+        }
+        let (guard_val, _) = self.lower_expr(guard)?;
+        self.builder.terminate(MirTerminator::dummy(MirTerminatorKind::Branch {
+            cond: guard_val,
+            then_block: arm_block,
+            else_block: next,
+        }));
+        Ok(())
+    }
+
+    /// Bind a catch-all arm's name to the string scrutinee.
+    fn bind_string_match(&mut self, name: &str, scrutinee_op: &MirOperand) {
+        let bind_local = self.builder.alloc_local(name.to_string(), MirType::String);
+        self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Assign {
+            dst: bind_local,
+            rvalue: MirRValue::Use(scrutinee_op.clone()),
+        }));
+        self.locals.insert(name.to_string(), (bind_local, MirType::String));
+    }
+
     /// Lower match with tuple patterns.
     pub(super) fn lower_tuple_match(
         &mut self,
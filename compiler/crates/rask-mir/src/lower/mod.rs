@@ -129,6 +129,9 @@ pub struct MirContext<'a> {
     pub call_rewrites: &'a HashMap<NodeId, String>,
     /// Type names marked with `@resource` — used for resource tracking ops (C1/C2).
     pub resource_types: &'a std::collections::HashSet<String>,
+    /// Ownership results: expression NodeId → resource binding it consumes.
+    /// Lowering emits ResourceConsume there so a pending ensure is cancelled (C1/C2).
+    pub resource_moves: &'a HashMap<NodeId, String>,
 }
 
 impl<'a> MirContext<'a> {
@@ -148,6 +151,8 @@ impl<'a> MirContext<'a> {
             std::sync::LazyLock::new(HashMap::new);
         static EMPTY_RESOURCE_TYPES: std::sync::LazyLock<std::collections::HashSet<String>> =
             std::sync::LazyLock::new(std::collections::HashSet::new);
+        static EMPTY_RESOURCE_MOVES: std::sync::LazyLock<HashMap<NodeId, String>> =
+            std::sync::LazyLock::new(HashMap::new);
         MirContext {
             struct_layouts: &[],
             enum_layouts: &[],
@@ -164,6 +169,7 @@ impl<'a> MirContext<'a> {
             trait_coercions: &EMPTY_COERCIONS,
             call_rewrites: &EMPTY_REWRITES,
             resource_types: &EMPTY_RESOURCE_TYPES,
+            resource_moves: &EMPTY_RESOURCE_MOVES,
         }
     }
 
@@ -564,8 +570,13 @@ impl<'a> MirLowerer<'a> {
     /// For branching ensures (else handler): creates block copies at the exit point.
     /// C1/C2: check if an expression is a consuming method call on an ensure
     /// receiver. If so, emit ResourceConsume to cancel the ensure at cleanup time.
+    /// Fallback for lowering without ownership results — when the ownership
+    /// pass recorded this node, `lower_expr` has already emitted the consume.
     fn check_resource_consume(&mut self, expr: &rask_ast::expr::Expr) {
         use rask_ast::expr::ExprKind;
+        if self.ctx.resource_moves.contains_key(&expr.id) {
+            return;
+        }
         if let ExprKind::MethodCall { object, method, .. } = &expr.kind {
             if let ExprKind::Ident(receiver_name) = &object.kind {
                 // Check if this receiver has a resource_id (registered by an ensure)
@@ -1428,6 +1439,36 @@ impl<'a> MirLowerer<'a> {
         result
     }
 
+    /// Widen a bare `T` into a `T?` slot: build `Some(value)` (tag 0, payload
+    /// bytes at offset 8). Values that are already optional, or that aren't
+    /// the slot's payload type, pass through unchanged.
+    pub(super) fn wrap_some_for(
+        &mut self,
+        value: MirOperand,
+        value_ty: &MirType,
+        slot_ty: &MirType,
+    ) -> MirOperand {
+        let needs_wrap = matches!(slot_ty, MirType::Option(inner)
+            if !matches!(value_ty, MirType::Option(_)) && **inner == *value_ty);
+        if !needs_wrap {
+            return value;
+        }
+        let wrap_local = self.builder.alloc_temp(slot_ty.clone());
+        self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Store {
+            addr: wrap_local,
+            offset: 0,
+            value: MirOperand::Constant(MirConst::Int(0)),
+            store_size: Some(8),
+        }));
+        self.builder.push_stmt(MirStmt::dummy(MirStmtKind::Store {
+            addr: wrap_local,
+            offset: 8,
+            value,
+            store_size: Some(value_ty.size()),
+        }));
+        MirOperand::Local(wrap_local)
+    }
+
     /// Bind pattern payload variables into the current scope.
     ///
     /// After confirming a tag match, extracts payload fields from the
@@ -2709,8 +2750,8 @@ mod tests {
             return_stmt(Some(match_expr(
                 ident_expr("x"),
                 vec![
-                    MatchArm { pattern: Pattern::Literal(Box::new(int_expr(1))), guard: None, body: Box::new(int_expr(10)) },
-                    MatchArm { pattern: Pattern::Literal(Box::new(int_expr(2))), guard: None, body: Box::new(int_expr(20)) },
+                    MatchArm { pattern: Pattern::Literal(Box::new(int_expr(1))), guard: None, body: Box::new(int_expr(10)), span: sp() },
+                    MatchArm { pattern: Pattern::Literal(Box::new(int_expr(2))), guard: None, body: Box::new(int_expr(20)), span: sp() },
                 ],
             ))),
         ]);
@@ -2718,6 +2759,54 @@ mod tests {
         assert!(has_switch(&f));
     }
 
+    #[test]
+    fn lower_string_match_switches_on_length() {
+        let lit = |s: &str| Pattern::Literal(Box::new(string_expr(s)));
+        let decl = make_fn("f", vec![("cmd", "string")], Some("i64"), vec![
+            return_stmt(Some(match_expr(
+                ident_expr("cmd"),
+                vec![
+                    MatchArm { pattern: lit("run"), guard: None, body: Box::new(int_expr(1)), span: sp() },
+                    MatchArm { pattern: Pattern::Or(vec![lit("build"), lit("b")]), guard: None, body: Box::new(int_expr(2)), span: sp() },
+                    MatchArm { pattern: lit("test"), guard: None, body: Box::new(int_expr(3)), span: sp() },
+                    MatchArm { pattern: Pattern::Wildcard, guard: None, body: Box::new(int_expr(0)), span: sp() },
+                ],
+            ))),
+        ]);
+        let f = lower_one(&decl);
+        assert!(find_call(&f, "string_len"));
+        let cases = f.blocks.iter().find_map(|b| match &b.terminator.kind {
+            MirTerminatorKind::Switch { cases, .. } => Some(cases.iter().map(|(len, _)| *len).collect::<Vec<_>>()),
+            _ => None,
+        }).expect("length switch");
+        assert_eq!(cases, vec![1, 3, 4, 5]);
+        let eq_calls = f.blocks.iter()
+            .flat_map(|b| &b.statements)
+            .filter(|s| matches!(&s.kind, MirStmtKind::Call { func, .. } if func.name == "string_eq"))
+            .count();
+        assert_eq!(eq_calls, 4, "one byte comparison per literal");
+    }
+
+    #[test]
+    fn lower_match_result_takes_arm_type() {
+        let decl = make_fn("f", vec![("x", "i32")], Some("f64"), vec![
+            return_stmt(Some(match_expr(
+                ident_expr("x"),
+                vec![
+                    MatchArm { pattern: Pattern::Literal(Box::new(int_expr(1))), guard: None, body: Box::new(float_expr(1.5)), span: sp() },
+                    MatchArm { pattern: Pattern::Wildcard, guard: None, body: Box::new(float_expr(2.5)), span: sp() },
+                ],
+            ))),
+        ]);
+        let f = lower_one(&decl);
+        let ret = f.blocks.iter().find_map(|b| match &b.terminator.kind {
+            MirTerminatorKind::Return { value: Some(MirOperand::Local(id)) } => Some(*id),
+            _ => None,
+        }).expect("match result returned");
+        let local = f.locals.iter().find(|l| l.id == ret).unwrap();
+        assert_eq!(local.ty, MirType::F64);
+    }
+
     #[test]
     fn lower_while_loop_cfg() {
         let decl = make_fn("f", vec![], None, vec![
@@ -2846,6 +2935,46 @@ mod tests {
         assert!(f.locals.iter().any(|l| l.name.as_deref() == Some("err")));
     }
 
+    #[test]
+    fn ensure_cancelled_where_ownership_records_the_move() {
+        // func f(file: File, quit: bool) {
+        //     ensure file.close()
+        //     if quit { return }
+        //     const closed = file.close()   // consumed in a binding, not a bare stmt
+        // }
+        let closing = Expr { id: NodeId(950), ..method_call_expr(ident_expr("file"), "close", vec![]) };
+        let early_return = Expr { id: NodeId(951), kind: ExprKind::Block(vec![return_stmt(None)]), span: sp() };
+        let decl = make_fn("f", vec![("file", "File"), ("quit", "bool")], None, vec![
+            ensure_stmt(vec![expr_stmt(method_call_expr(ident_expr("file"), "close", vec![]))], None),
+            expr_stmt(if_expr(ident_expr("quit"), early_return, None)),
+            const_stmt("closed", None, closing),
+            return_stmt(None),
+        ]);
+        let node_types = HashMap::new();
+        let resource_moves = HashMap::from([(NodeId(950), "file".to_string())]);
+        let ctx = MirContext { resource_moves: &resource_moves, ..MirContext::empty_with_map(&node_types) };
+        let f = MirLowerer::lower_function(&decl, &[decl.clone()], &ctx).expect("lowering failed").remove(0);
+
+        // Normal path: the explicit close is followed by exactly one consume.
+        let consumes: Vec<_> = f.blocks.iter()
+            .flat_map(|b| b.statements.windows(2))
+            .filter(|w| matches!(w[1].kind, MirStmtKind::ResourceConsume { .. }))
+            .collect();
+        assert_eq!(consumes.len(), 1, "{}", f);
+        assert!(matches!(&consumes[0][0].kind, MirStmtKind::Call { func, .. } if func.name.ends_with("close")), "{}", f);
+
+        // Both exits run the cleanup chain, which checks consumption first —
+        // the early return closes the file, the normal exit skips it.
+        let exits: Vec<_> = f.blocks.iter()
+            .filter_map(|b| match &b.terminator.kind {
+                MirTerminatorKind::CleanupReturn { cleanup_chain, .. } => Some(cleanup_chain.len()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(exits, vec![1, 1], "{}", f);
+        assert!(find_call(&f, "rask_resource_is_consumed"));
+    }
+
     #[test]
     fn lower_unwrap_panics_on_err() {
         let decl = make_fn("f", vec![("x", "i32")], Some("i32"), vec![
@@ -2865,6 +2994,30 @@ mod tests {
         assert!(f.blocks.iter().any(|b| matches!(b.terminator.kind, MirTerminatorKind::Unreachable)));
     }
 
+    #[test]
+    fn lower_unwrap_passes_its_message_to_the_panic() {
+        let decl = make_fn("f", vec![("x", "i32")], Some("i32"), vec![
+            return_stmt(Some(Expr {
+                id: NodeId(400),
+                kind: ExprKind::Unwrap {
+                    expr: Box::new(ident_expr("x")),
+                    message: Some("config missing".to_string()),
+                },
+                span: sp(),
+            })),
+        ]);
+        let f = lower_one(&decl);
+        let args: Vec<_> = f.blocks.iter()
+            .flat_map(|b| &b.statements)
+            .filter_map(|s| match &s.kind {
+                MirStmtKind::Call { func, args, .. } if func.name == "panic_unwrap" => Some(args.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(args.len(), 1, "{}", f);
+        assert!(matches!(&args[0][..], [MirOperand::Constant(MirConst::String(m))] if m == "config missing"), "{}", f);
+    }
+
     // ═══════════════════════════════════════════════════════════
     // End-to-end
     // ═══════════════════════════════════════════════════════════
@@ -3032,6 +3185,7 @@ mod tests {
             trait_coercions: &empty_coercions,
             call_rewrites: &empty_rewrites,
             resource_types: &empty_resource_types,
+            resource_moves: &HashMap::new(),
         };
 
         let decl = make_fn("f", vec![], None, vec![
@@ -3088,6 +3242,7 @@ mod tests {
             trait_coercions: &empty_coercions,
             call_rewrites: &empty_rewrites,
             resource_types: &empty_resource_types,
+            resource_moves: &HashMap::new(),
         };
 
         let decl = make_fn("f", vec![], None, vec![
@@ -3100,6 +3255,88 @@ mod tests {
         assert_eq!(count_stores(&f), 1); // tag only
     }
 
+    #[test]
+    fn dense_enum_match_lowers_to_jump_table_switch() {
+        use rask_mono::{EnumLayout, VariantLayout};
+
+        let variants = ["Idle", "Running", "Paused", "Done"];
+        let state_enum = EnumLayout {
+            name: "State".to_string(),
+            size: 1,
+            align: 1,
+            tag_ty: rask_types::Type::U8,
+            tag_offset: 0,
+            variants: variants.iter().enumerate().map(|(tag, name)| VariantLayout {
+                name: name.to_string(),
+                tag: tag as u64,
+                payload_offset: 0,
+                payload_size: 0,
+                fields: vec![],
+            }).collect(),
+        };
+
+        let enum_layouts = vec![state_enum];
+        let node_types = HashMap::new();
+        let comptime_globals = HashMap::new();
+        let extern_funcs = std::collections::HashSet::new();
+        let type_names = HashMap::new();
+        let empty_coercions = HashMap::new();
+        let empty_rewrites = HashMap::new();
+        let empty_resource_types = std::collections::HashSet::new();
+        let ctx = MirContext {
+            struct_layouts: &[],
+            enum_layouts: &enum_layouts,
+            node_types: &node_types,
+            type_names: &type_names,
+            comptime_globals: &comptime_globals,
+            extern_funcs: &extern_funcs,
+            package_modules: &std::collections::HashSet::new(),
+            shared_elem_types: std::cell::RefCell::new(HashMap::new()),
+            line_map: None,
+            source_file: None,
+            comptime_interp: None,
+            trait_methods: HashMap::new(),
+            trait_coercions: &empty_coercions,
+            call_rewrites: &empty_rewrites,
+            resource_types: &empty_resource_types,
+            resource_moves: &HashMap::new(),
+        };
+
+        let arms = variants.iter().enumerate().map(|(i, name)| MatchArm {
+            pattern: Pattern::Ident(format!("State.{}", name)),
+            guard: None,
+            body: Box::new(int_expr(i as i64 * 10)),
+            span: sp(),
+        }).collect();
+        let decl = make_fn("f", vec![("s", "State")], Some("i64"), vec![
+            return_stmt(Some(match_expr(ident_expr("s"), arms))),
+        ]);
+        let f = lower_with_ctx(&decl, &[decl.clone()], &ctx);
+
+        // One switch over all four tags — no per-variant branch chain
+        let switches: Vec<_> = f.blocks.iter()
+            .filter(|b| matches!(b.terminator.kind, MirTerminatorKind::Switch { .. }))
+            .collect();
+        assert_eq!(switches.len(), 1);
+        assert!(!has_branch(&f));
+        assert_eq!(switches[0].terminator.kind.dense_switch_range(), Some((0, 3)));
+    }
+
+    #[test]
+    fn sparse_switch_is_not_dense() {
+        let switch = |values: &[u64]| MirTerminatorKind::Switch {
+            value: MirOperand::Constant(crate::operand::MirConst::Int(0)),
+            cases: values.iter().map(|v| (*v, BlockId(1))).collect(),
+            default: BlockId(0),
+        };
+        assert_eq!(switch(&[2, 3, 5, 4]).dense_switch_range(), Some((2, 5)));
+        // Holes are fine up to half the range
+        assert_eq!(switch(&[0, 1, 2, 7]).dense_switch_range(), Some((0, 7)));
+        assert_eq!(switch(&[0, 10, 20, 30]).dense_switch_range(), None);
+        // Too few cases to beat a compare chain
+        assert_eq!(switch(&[0, 1, 2]).dense_switch_range(), None);
+    }
+
     #[test]
     fn lower_enum_variant_multi_field() {
         // Msg.Pair(1, 2) → store tag + 2 payload fields
@@ -3150,6 +3387,7 @@ mod tests {
             trait_coercions: &empty_coercions,
             call_rewrites: &empty_rewrites,
             resource_types: &empty_resource_types,
+            resource_moves: &HashMap::new(),
         };
 
         let decl = make_fn("f", vec![], None, vec![
@@ -3246,6 +3484,35 @@ mod tests {
         assert!(find_call(&f, "work"));
     }
 
+    #[test]
+    fn lower_using_named_task_bound_passes_both_init_args() {
+        let using_block = Expr {
+            id: NodeId(704),
+            kind: ExprKind::UsingBlock {
+                name: "Multitasking".to_string(),
+                args: vec![CallArg { name: Some("tasks".to_string()), mode: ArgMode::Default, expr: int_expr(8) }],
+                body: vec![],
+            },
+            span: sp(),
+        };
+        let decl = make_fn("main", vec![], None, vec![
+            expr_stmt(using_block),
+            return_stmt(None),
+        ]);
+        let f = lower(&decl, &[decl.clone()]);
+        let init_args = f.blocks.iter()
+            .flat_map(|b| &b.statements)
+            .find_map(|s| match &s.kind {
+                MirStmtKind::Call { func, args, .. } if func.name == "rask_runtime_init" => Some(args.clone()),
+                _ => None,
+            })
+            .expect("missing rask_runtime_init call");
+        assert!(matches!(init_args.as_slice(), [
+            MirOperand::Constant(MirConst::Int(0)),
+            MirOperand::Constant(MirConst::Int(8)),
+        ]), "init args: {:?}", init_args);
+    }
+
     #[test]
     fn lower_using_unknown_no_init() {
         let using_block = Expr {
@@ -3269,4 +3536,77 @@ mod tests {
         assert!(!find_call(&f, "rask_runtime_shutdown"), "Unknown context should not emit shutdown");
         assert!(find_call(&f, "work"));
     }
+
+    fn select_expr(arms: Vec<rask_ast::expr::SelectArm>) -> Expr {
+        Expr { id: NodeId(703), kind: ExprKind::Select { arms, is_priority: false }, span: sp() }
+    }
+
+    fn recv_arm(channel: &str, binding: &str, body: Expr) -> rask_ast::expr::SelectArm {
+        rask_ast::expr::SelectArm {
+            kind: rask_ast::expr::SelectArmKind::Recv {
+                channel: ident_expr(channel),
+                binding: binding.to_string(),
+            },
+            body: Box::new(body),
+        }
+    }
+
+    fn count_calls(f: &MirFunction, func_name: &str) -> usize {
+        f.blocks.iter()
+            .flat_map(|b| &b.statements)
+            .filter(|s| matches!(&s.kind, MirStmtKind::Call { func, .. } if func.name == func_name))
+            .count()
+    }
+
+    #[test]
+    fn lower_select_two_recv_arms_blocks_on_runtime() {
+        let sel = select_expr(vec![
+            recv_arm("rx1", "v", ident_expr("v")),
+            recv_arm("rx2", "w", ident_expr("w")),
+        ]);
+        let decl = make_fn(
+            "main",
+            vec![("rx1", "Receiver<i64>"), ("rx2", "Receiver<i64>")],
+            Some("i64"),
+            vec![return_stmt(Some(sel))],
+        );
+        let f = lower_one(&decl);
+        assert!(find_call(&f, "rask_select_new"));
+        assert_eq!(count_calls(&f, "rask_select_add_recv"), 2);
+        assert!(find_call(&f, "rask_select_wait"));
+        assert!(!find_call(&f, "rask_select_poll"));
+        assert_eq!(count_calls(&f, "rask_select_take_i64"), 2);
+        assert_eq!(count_calls(&f, "rask_select_free"), 2);
+        let switch = f.blocks.iter().find_map(|b| match &b.terminator.kind {
+            MirTerminatorKind::Switch { cases, .. } => Some(cases.len()),
+            _ => None,
+        });
+        assert_eq!(switch, Some(2));
+    }
+
+    #[test]
+    fn lower_select_default_arm_polls() {
+        let default = rask_ast::expr::SelectArm {
+            kind: rask_ast::expr::SelectArmKind::Default,
+            body: Box::new(int_expr(0)),
+        };
+        let sel = select_expr(vec![recv_arm("rx", "v", ident_expr("v")), default]);
+        let decl = make_fn(
+            "main",
+            vec![("rx", "Receiver<i64>")],
+            Some("i64"),
+            vec![return_stmt(Some(sel))],
+        );
+        let f = lower_one(&decl);
+        assert!(find_call(&f, "rask_select_poll"), "default arm must use the non-blocking path");
+        assert!(!find_call(&f, "rask_select_wait"));
+        // Every arm, default included, releases the select.
+        assert_eq!(count_calls(&f, "rask_select_free"), 2);
+        let default_target = f.blocks.iter().find_map(|b| match &b.terminator.kind {
+            MirTerminatorKind::Switch { default, .. } => Some(*default),
+            _ => None,
+        }).expect("select lowers to a switch");
+        let target = f.blocks.iter().find(|b| b.id == default_target).unwrap();
+        assert!(!matches!(target.terminator.kind, MirTerminatorKind::Unreachable));
+    }
 }
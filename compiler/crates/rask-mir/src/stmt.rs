@@ -208,3 +208,29 @@ impl MirTerminator {
         Self { kind, span: Span::new(0, 0) }
     }
 }
+
+impl MirTerminatorKind {
+    /// Fewest cases worth a jump table; below this a compare chain is as fast.
+    pub const MIN_JUMP_TABLE_CASES: usize = 4;
+
+    /// `(lowest, highest)` case value when a `Switch` is dense enough for a
+    /// jump table: at least `MIN_JUMP_TABLE_CASES` distinct values filling
+    /// at least half of their range. Matches over C-like enums land here
+    /// since variant tags are assigned consecutively. Sparse switches return
+    /// None and stay a compare chain.
+    pub fn dense_switch_range(&self) -> Option<(u64, u64)> {
+        let MirTerminatorKind::Switch { cases, .. } = self else { return None };
+        if cases.len() < Self::MIN_JUMP_TABLE_CASES {
+            return None;
+        }
+        let mut values: Vec<u64> = cases.iter().map(|(v, _)| *v).collect();
+        values.sort_unstable();
+        values.dedup();
+        if values.len() != cases.len() {
+            return None;
+        }
+        let (lo, hi) = (values[0], values[values.len() - 1]);
+        let span = (hi - lo).checked_add(1)?;
+        (span <= 2 * values.len() as u64).then_some((lo, hi))
+    }
+}
@@ -184,37 +184,16 @@ fn apply_incoming_checks(
 }
 
 /// Process invalidations from a statement, updating the checked map.
+/// Beyond the shared rules, reassigning a result local loses the check.
 fn process_invalidations(
     stmt: &MirStmt,
     checked: &mut CheckedMap,
     pool_locals: &HashSet<LocalId>,
 ) {
-    if let Some(mutated_pool) = pool_ops::pool_mutation(stmt) {
-        checked.retain(|&(pool, _), _| pool != mutated_pool);
-    }
-
-    if let MirStmtKind::Call { func, args, .. } = &stmt.kind {
-        if !pool_ops::is_pool_mutator(&func.name) && !pool_ops::is_safe_pool_call(&func.name) {
-            for arg in args.iter() {
-                if let MirOperand::Local(id) = arg {
-                    if pool_locals.contains(id) {
-                        let id = *id;
-                        checked.retain(|&(pool, _), _| pool != id);
-                    }
-                }
-            }
-        }
-    }
-
-    if matches!(&stmt.kind, MirStmtKind::ClosureCall { .. }) {
-        checked.clear();
-    }
-
+    pool_ops::invalidate_checks(stmt, checked, pool_locals);
     if let Some(assigned) = uses::stmt_def(stmt) {
         if !matches!(&stmt.kind, MirStmtKind::PoolCheckedAccess { .. }) {
-            checked.retain(|&(pool, handle), &mut dst| {
-                pool != assigned && handle != assigned && dst != assigned
-            });
+            checked.retain(|_, &mut dst| dst != assigned);
         }
     }
 }
@@ -225,38 +204,7 @@ fn coalesce_block(stmts: &mut [MirStmt], pool_locals: &HashSet<LocalId>) {
     let mut checked: HashMap<CheckKey, LocalId> = HashMap::new();
 
     for stmt in stmts.iter_mut() {
-        // Check for pool mutations before processing this statement
-        if let Some(mutated_pool) = pool_ops::pool_mutation(stmt) {
-            checked.retain(|&(pool, _), _| pool != mutated_pool);
-        }
-
-        // Unknown calls with a pool arg invalidate that pool's entries (MT3, CF4)
-        if let MirStmtKind::Call { func, args, .. } = &stmt.kind {
-            if !pool_ops::is_pool_mutator(&func.name) && !pool_ops::is_safe_pool_call(&func.name) {
-                for arg in args.iter() {
-                    if let MirOperand::Local(id) = arg {
-                        if pool_locals.contains(id) {
-                            let id = *id;
-                            checked.retain(|&(pool, _), _| pool != id);
-                        }
-                    }
-                }
-            }
-        }
-
-        // Closure calls could capture pool references (conservative)
-        if matches!(&stmt.kind, MirStmtKind::ClosureCall { .. }) {
-            checked.clear();
-        }
-
-        // Handle reassignment invalidates entries referencing that local (GC3)
-        if let Some(assigned) = uses::stmt_def(stmt) {
-            if !matches!(&stmt.kind, MirStmtKind::PoolCheckedAccess { .. }) {
-                checked.retain(|&(pool, handle), &mut dst| {
-                    pool != assigned && handle != assigned && dst != assigned
-                });
-            }
-        }
+        process_invalidations(stmt, &mut checked, pool_locals);
 
         // Coalesce PoolCheckedAccess
         if let MirStmtKind::PoolCheckedAccess { dst, pool, handle } = &stmt.kind {
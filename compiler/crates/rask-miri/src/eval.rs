@@ -4,7 +4,8 @@
 
 use rask_mir::{
     BlockId, MirBlock, MirConst, MirFunction, MirOperand, MirRValue, MirStmt, MirStmtKind,
-    MirTerminator, MirTerminatorKind,
+    MirTerminator, MirTerminatorKind, MirType, RESULT_ORIGIN_FILE_OFFSET,
+    RESULT_ORIGIN_LINE_OFFSET, RESULT_PAYLOAD_OFFSET,
 };
 
 use crate::intrinsics;
@@ -192,10 +193,34 @@ impl MiriEngine {
                 ..
             } => {
                 let val = self.resolve_operand(value)?;
-                let base = self.stack.current()?.get(*addr)?.clone();
+                let frame = self.stack.current()?;
+                let local_ty = frame.local_type(*addr).cloned();
+                // Aggregates are built in place: the first store into a
+                // struct, tuple, or Option/Result local creates it.
+                let base = match frame.get(*addr) {
+                    Ok(v) => v.clone(),
+                    Err(MiriError::UninitializedLocal(id)) => self
+                        .empty_aggregate(local_ty.as_ref())
+                        .ok_or(MiriError::UninitializedLocal(id))?,
+                    Err(e) => return Err(e),
+                };
 
-                // Store into struct field by byte offset
-                if let MiriValue::Struct { layout_id, mut fields } = base {
+                // Option/Result: tag at offset 0, payload at the payload offset.
+                // A Result's origin words aren't modelled.
+                if let MiriValue::Enum { mut tag, mut payload } = base {
+                    let payload_offset = match local_ty {
+                        Some(MirType::Result { .. }) => RESULT_PAYLOAD_OFFSET,
+                        _ => 8,
+                    };
+                    if *offset == 0 {
+                        tag = val.to_u64().ok_or_else(|| {
+                            MiriError::UnsupportedOperation(format!("non-integer enum tag: {val:?}"))
+                        })?;
+                    } else if *offset == payload_offset {
+                        payload = Some(Box::new(val));
+                    }
+                    self.stack.current_mut()?.set(*addr, MiriValue::Enum { tag, payload });
+                } else if let MiriValue::Struct { layout_id, mut fields } = base {
                     // Find field index from byte offset using layout
                     let field_idx = self.field_index_from_offset(layout_id, *offset);
                     if let Some(idx) = field_idx {
@@ -213,6 +238,20 @@ impl MiriEngine {
                         *addr,
                         MiriValue::Struct { layout_id, fields },
                     );
+                } else if let MiriValue::Tuple(mut fields) = base {
+                    let idx = match &local_ty {
+                        Some(MirType::Tuple(elems)) => tuple_index_from_offset(elems, *offset),
+                        _ => None,
+                    };
+                    match idx {
+                        Some(idx) if idx < fields.len() => fields[idx] = val,
+                        _ => {
+                            return Err(MiriError::UnsupportedOperation(
+                                format!("tuple store at offset {offset} matches no element"),
+                            ))
+                        }
+                    }
+                    self.stack.current_mut()?.set(*addr, MiriValue::Tuple(fields));
                 } else {
                     return Err(MiriError::UnsupportedOperation(
                         format!("Store into non-struct value: {base:?}"),
@@ -342,7 +381,7 @@ impl MiriEngine {
             }
 
             MirRValue::Field {
-                base, field_index, ..
+                base, field_index, byte_offset, ..
             } => {
                 let base_val = self.resolve_operand(base)?;
                 match base_val {
@@ -362,15 +401,18 @@ impl MiriEngine {
                             )
                         })
                     }
-                    // Option/Result — tag is field 0, payload is field 1
-                    MiriValue::Enum { tag, payload, .. } => {
-                        match *field_index {
-                            0 => Ok(MiriValue::U64(tag)),
-                            1 => payload.map(|p| *p).ok_or_else(|| {
+                    // Option/Result — the tag is read with EnumTag; field 0
+                    // is the payload. Origin words read as zero.
+                    MiriValue::Enum { payload, .. } => {
+                        match (*field_index, byte_offset) {
+                            (0, _) => payload.map(|p| *p).ok_or_else(|| {
                                 MiriError::UnsupportedOperation(
                                     "enum has no payload".to_string(),
                                 )
                             }),
+                            (_, Some(RESULT_ORIGIN_FILE_OFFSET | RESULT_ORIGIN_LINE_OFFSET)) => {
+                                Ok(MiriValue::I64(0))
+                            }
                             _ => Err(MiriError::UnsupportedOperation(
                                 format!("invalid enum field index: {field_index}"),
                             )),
@@ -437,6 +479,22 @@ impl MiriEngine {
         }
     }
 
+    /// A blank value for an aggregate local about to be built by stores.
+    fn empty_aggregate(&self, ty: Option<&MirType>) -> Option<MiriValue> {
+        match ty? {
+            MirType::Struct(layout_id) => {
+                let layout = self.struct_layouts.get(layout_id.id as usize)?;
+                Some(MiriValue::Struct {
+                    layout_id: *layout_id,
+                    fields: vec![MiriValue::Unit; layout.fields.len()],
+                })
+            }
+            MirType::Option(_) | MirType::Result { .. } => Some(MiriValue::Enum { tag: 0, payload: None }),
+            MirType::Tuple(elems) => Some(MiriValue::Tuple(vec![MiriValue::Unit; elems.len()])),
+            _ => None,
+        }
+    }
+
     /// Find field index from byte offset using struct layout.
     fn field_index_from_offset(
         &self,
@@ -451,6 +509,21 @@ impl MiriEngine {
     }
 }
 
+/// Element index of a tuple store, laid out the way MIR lowering packs
+/// tuple literals: each element at its alignment, in order.
+fn tuple_index_from_offset(elems: &[MirType], offset: u32) -> Option<usize> {
+    let mut at = 0u32;
+    for (i, elem) in elems.iter().enumerate() {
+        let align = elem.align().max(1);
+        at = (at + align - 1) & !(align - 1);
+        if at == offset {
+            return Some(i);
+        }
+        at += elem.size();
+    }
+    None
+}
+
 /// Convert a MIR constant to a MiriValue.
 fn const_to_value(c: &MirConst) -> MiriValue {
     match c {
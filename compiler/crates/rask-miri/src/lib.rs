@@ -14,6 +14,7 @@ pub mod stdlib;
 use std::collections::HashMap;
 use std::fmt;
 
+use rask_comptime::Endian;
 use rask_mir::{LocalId, MirFunction, StructLayoutId};
 use rask_mono::{StructLayout, EnumLayout};
 
@@ -58,6 +59,9 @@ impl MiriValue {
         match self {
             MiriValue::Bool(b) => Ok(*b),
             MiriValue::I64(v) => Ok(*v != 0),
+            // Enum tags branch directly: non-zero is the `None`/`Err` side.
+            MiriValue::U8(v) => Ok(*v != 0),
+            MiriValue::U64(v) => Ok(*v != 0),
             _ => Err(MiriError::UnsupportedOperation(
                 format!("expected bool, got {self:?}"),
             )),
@@ -119,47 +123,49 @@ impl MiriValue {
         }
     }
 
-    /// Serialize to bytes for embedding in data sections.
-    /// Returns None for types that can't be statically embedded.
-    pub fn serialize(&self) -> Option<Vec<u8>> {
-        match self {
-            MiriValue::Unit => Some(vec![]),
-            MiriValue::Bool(v) => Some(vec![*v as u8]),
-            MiriValue::I8(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::I16(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::I32(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::I64(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::U8(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::U16(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::U32(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::U64(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::F32(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::F64(v) => Some(v.to_le_bytes().to_vec()),
-            MiriValue::Char(v) => Some((*v as u32).to_le_bytes().to_vec()),
-            MiriValue::String(v) => Some(v.as_bytes().to_vec()),
+    /// Serialize to bytes for embedding in data sections, scalars in the
+    /// target's byte order. Returns None for types that can't be statically
+    /// embedded.
+    pub fn serialize(&self, endian: Endian) -> Option<Vec<u8>> {
+        let le = match self {
+            MiriValue::Unit => vec![],
+            MiriValue::Bool(v) => vec![*v as u8],
+            MiriValue::I8(v) => v.to_le_bytes().to_vec(),
+            MiriValue::I16(v) => v.to_le_bytes().to_vec(),
+            MiriValue::I32(v) => v.to_le_bytes().to_vec(),
+            MiriValue::I64(v) => v.to_le_bytes().to_vec(),
+            MiriValue::U8(v) => v.to_le_bytes().to_vec(),
+            MiriValue::U16(v) => v.to_le_bytes().to_vec(),
+            MiriValue::U32(v) => v.to_le_bytes().to_vec(),
+            MiriValue::U64(v) => v.to_le_bytes().to_vec(),
+            MiriValue::F32(v) => v.to_le_bytes().to_vec(),
+            MiriValue::F64(v) => v.to_le_bytes().to_vec(),
+            MiriValue::Char(v) => (*v as u32).to_le_bytes().to_vec(),
+            MiriValue::String(v) => return Some(v.as_bytes().to_vec()),
             MiriValue::Array(elems) => {
                 let mut bytes = Vec::new();
                 for elem in elems {
-                    bytes.extend(elem.serialize()?);
+                    bytes.extend(elem.serialize(endian)?);
                 }
-                Some(bytes)
+                return Some(bytes);
             }
             MiriValue::Tuple(fields) => {
                 let mut bytes = Vec::new();
                 for field in fields {
-                    bytes.extend(field.serialize()?);
+                    bytes.extend(field.serialize(endian)?);
                 }
-                Some(bytes)
+                return Some(bytes);
             }
             MiriValue::Struct { fields, .. } => {
                 let mut bytes = Vec::new();
                 for field in fields {
-                    bytes.extend(field.serialize()?);
+                    bytes.extend(field.serialize(endian)?);
                 }
-                Some(bytes)
+                return Some(bytes);
             }
-            MiriValue::Enum { .. } | MiriValue::FuncPtr(_) => None,
-        }
+            MiriValue::Enum { .. } | MiriValue::FuncPtr(_) => return None,
+        };
+        Some(endian.reorder_le(le))
     }
 
     /// Element count (for arrays/tuples serialized as flat data).
@@ -210,6 +216,8 @@ pub enum MiriError {
     UnsupportedOperation(String),
     StackOverflow,
     Unreachable,
+    /// Program-level panic, e.g. a failed unwrap.
+    Panic(String),
 }
 
 impl MiriError {
@@ -239,6 +247,7 @@ impl fmt::Display for MiriError {
             MiriError::UnsupportedOperation(msg) => write!(f, "{msg}"),
             MiriError::StackOverflow => write!(f, "compile-time evaluation exceeded stack depth"),
             MiriError::Unreachable => write!(f, "reached unreachable code"),
+            MiriError::Panic(msg) => write!(f, "{msg}"),
         }
     }
 }
@@ -715,15 +724,25 @@ mod tests {
 
     #[test]
     fn test_serialization() {
-        assert_eq!(MiriValue::I64(42).serialize(), Some(42i64.to_le_bytes().to_vec()));
-        assert_eq!(MiriValue::Bool(true).serialize(), Some(vec![1]));
-        assert_eq!(MiriValue::F64(3.14).serialize(), Some(3.14f64.to_le_bytes().to_vec()));
+        assert_eq!(MiriValue::I64(42).serialize(Endian::Little), Some(42i64.to_le_bytes().to_vec()));
+        assert_eq!(MiriValue::Bool(true).serialize(Endian::Little), Some(vec![1]));
+        assert_eq!(MiriValue::F64(3.14).serialize(Endian::Little), Some(3.14f64.to_le_bytes().to_vec()));
 
         let arr = MiriValue::Array(vec![MiriValue::I64(1), MiriValue::I64(2), MiriValue::I64(3)]);
-        let bytes = arr.serialize().unwrap();
+        let bytes = arr.serialize(Endian::Little).unwrap();
         assert_eq!(bytes.len(), 24); // 3 × 8 bytes
     }
 
+    #[test]
+    fn test_serialization_big_endian() {
+        let arr = MiriValue::Array(vec![MiriValue::U16(0x0102), MiriValue::U16(0x0304)]);
+        assert_eq!(arr.serialize(Endian::Little), Some(vec![0x02, 0x01, 0x04, 0x03]));
+        assert_eq!(arr.serialize(Endian::Big), Some(vec![0x01, 0x02, 0x03, 0x04]));
+        // Strings are byte sequences, not scalars
+        let s = MiriValue::String("ab".to_string());
+        assert_eq!(s.serialize(Endian::Big), Some(b"ab".to_vec()));
+    }
+
     #[test]
     fn test_switch() {
         // func classify(x: i64) -> i64 {
@@ -58,6 +58,21 @@ impl StdlibProvider for PureStdlib {
                     )),
                 }
             }
+            "string_eq" => {
+                if args.len() < 2 {
+                    return Err(MiriError::UnsupportedOperation(
+                        format!("{name}: expected 2 arguments"),
+                    ));
+                }
+                match (&args[0], &args[1]) {
+                    (MiriValue::String(a), MiriValue::String(b)) => {
+                        Ok(Some(MiriValue::Bool(a.as_bytes() == b.as_bytes())))
+                    }
+                    _ => Err(MiriError::UnsupportedOperation(
+                        format!("{name}: expected string arguments"),
+                    )),
+                }
+            }
             "string_concat" | "string_add" => {
                 if args.len() < 2 {
                     return Err(MiriError::UnsupportedOperation(
@@ -74,6 +89,15 @@ impl StdlibProvider for PureStdlib {
                 }
             }
 
+            // Failed `x!` / `x! "msg"` — the message matches what codegen reports
+            "panic_unwrap" => {
+                let msg = match args.first() {
+                    Some(MiriValue::String(m)) => m.clone(),
+                    _ => "called unwrap on None/Err value".to_string(),
+                };
+                Err(MiriError::Panic(msg))
+            }
+
             // Printing (comptime diagnostic — future @comptime_print)
             "print" | "println" => {
                 Err(MiriError::UnsupportedOperation(
@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+//! Unreachable methods — advisory, read off the reachable set (M1).
+//!
+//! A private inherent method that monomorphization never instantiated is
+//! dead: nothing reachable from `main()` calls it. Public methods are API
+//! and never reported. Trait impls are skipped — operators, derives, and
+//! dynamic dispatch call their methods without a visible call site.
+
+use crate::MonoProgram;
+use rask_ast::decl::{Decl, DeclKind, FnDecl};
+use rask_ast::Span;
+use std::collections::HashSet;
+
+/// Code no path from `main()` reaches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeadCode {
+    /// A private method that is never called.
+    Method { type_name: String, method: String, span: Span },
+    /// An `extend` block whose methods are all private and never called.
+    Impl { type_name: String, method_count: usize, span: Span },
+}
+
+/// Find private inherent methods in `decls` that `mono` never reached.
+pub fn unreachable_methods(decls: &[Decl], mono: &MonoProgram) -> Vec<DeadCode> {
+    // Instances are mangled `Type_method$Args`; any instance counts.
+    let reached: HashSet<&str> = mono
+        .functions
+        .iter()
+        .map(|f| f.name.split('$').next().unwrap_or(&f.name))
+        .collect();
+    let is_dead = |type_name: &str, m: &FnDecl| {
+        if m.is_pub || m.is_comptime || m.is_test() {
+            return false;
+        }
+        let base = type_name.split('<').next().unwrap_or(type_name);
+        !reached.contains(format!("{}_{}", type_name, m.name).as_str())
+            && !reached.contains(format!("{}_{}", base, m.name).as_str())
+    };
+    let dead_methods = |type_name: &str, methods: &[FnDecl], out: &mut Vec<DeadCode>| {
+        for m in methods.iter().filter(|m| is_dead(type_name, m)) {
+            out.push(DeadCode::Method {
+                type_name: type_name.to_string(),
+                method: m.name.clone(),
+                span: m.span,
+            });
+        }
+    };
+
+    let mut dead = Vec::new();
+    for decl in decls {
+        match &decl.kind {
+            DeclKind::Struct(s) => dead_methods(&s.name, &s.methods, &mut dead),
+            DeclKind::Enum(e) => dead_methods(&e.name, &e.methods, &mut dead),
+            DeclKind::Impl(imp) if imp.trait_name.is_none() => {
+                if !imp.methods.is_empty() && imp.methods.iter().all(|m| is_dead(&imp.target_ty, m)) {
+                    dead.push(DeadCode::Impl {
+                        type_name: imp.target_ty.clone(),
+                        method_count: imp.methods.len(),
+                        span: decl.span,
+                    });
+                } else {
+                    dead_methods(&imp.target_ty, &imp.methods, &mut dead);
+                }
+            }
+            _ => {}
+        }
+    }
+    dead
+}
@@ -647,6 +647,7 @@ impl TypeSubstitutor {
             pattern: self.clone_pattern(&arm.pattern),
             guard: arm.guard.as_ref().map(|g| Box::new(self.clone_expr(g))),
             body: Box::new(self.clone_expr(&arm.body)),
+            span: arm.span,
         }
     }
 
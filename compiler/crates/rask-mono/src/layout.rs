@@ -445,24 +445,27 @@ pub fn compute_enum_layout(enum_def: &Decl, type_args: &[Type], cache: &LayoutCa
 
     let subst = build_subst(&enum_decl.type_params, type_args);
 
-    let variant_count = enum_decl.variants.len();
-
-    // E2/E14: Determine discriminant type
+    // E2/E14: Determine discriminant type. Auto-sizing follows the largest
+    // tag, explicit or positional; the checker has rejected values past u16.
+    let max_tag = enum_decl.variants.iter().enumerate()
+        .map(|(i, v)| v.discriminant.unwrap_or(i as i128))
+        .max()
+        .unwrap_or(0);
+    let auto_tag_ty = if max_tag <= u8::MAX as i128 { Type::U8 } else { Type::U16 };
     let tag_ty = if let Some(ref bt) = enum_decl.backing_type {
         match bt.as_str() {
             "u8" => Type::U8,
             "u16" => Type::U16,
             "u32" => Type::U32,
+            "u64" | "usize" => Type::U64,
             "i8" => Type::I8,
             "i16" => Type::I16,
             "i32" => Type::I32,
-            "i64" | "int" => Type::I64,
-            _ => if variant_count <= 256 { Type::U8 } else { Type::U16 },
+            "i64" | "int" | "isize" => Type::I64,
+            _ => auto_tag_ty,
         }
-    } else if variant_count <= 256 {
-        Type::U8
     } else {
-        Type::U16
+        auto_tag_ty
     };
     let (tag_size, tag_align) = type_size_align(&tag_ty, cache);
 
@@ -887,6 +890,20 @@ mod tests {
         assert_eq!(layout.size, 24);
     }
 
+    #[test]
+    fn explicit_discriminants_size_the_tag() {
+        // enum Status { Ok = 200, Moved = 301 } → tags are the values, u16 tag
+        let mut decl = make_enum("Status", vec![("Ok", vec![]), ("Moved", vec![])]);
+        if let DeclKind::Enum(e) = &mut decl.kind {
+            e.variants[0].discriminant = Some(200);
+            e.variants[1].discriminant = Some(301);
+        }
+        let layout = compute_enum_layout(&decl, &[], &empty_cache());
+        assert_eq!(layout.variants[0].tag, 200);
+        assert_eq!(layout.variants[1].tag, 301);
+        assert!(matches!(layout.tag_ty, Type::U16));
+    }
+
     // ── Field reordering (S1/L4) ──────────────────────────────────
 
     fn make_struct_with_attrs(name: &str, fields: Vec<(&str, &str)>, attrs: Vec<&str>) -> Decl {
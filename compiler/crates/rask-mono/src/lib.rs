@@ -7,10 +7,13 @@
 //! - Computed memory layouts for all structs and enums
 //! - Reachability analysis starting from main()
 
+mod dead_code;
 mod instantiate;
 mod layout;
 mod reachability;
 
+pub use dead_code::{unreachable_methods, DeadCode};
+
 pub use instantiate::instantiate_function;
 pub use layout::{
     compute_enum_layout, compute_struct_layout, compute_union_layout, type_size_align, EnumLayout, FieldLayout,
@@ -94,6 +94,15 @@ pub enum OwnershipErrorKind {
         name: String,
     },
 
+    /// Second use of a resource that an earlier assignment, field store, or
+    /// `own` argument already took. Linear values have no implicit copy.
+    #[error("`{type_name}` is a linear resource and cannot be copied; move it or borrow it")]
+    ResourceCopied {
+        name: String,
+        type_name: String,
+        moved_at: Span,
+    },
+
     /// Resource consumed more than once.
     #[error("resource `{name}` already consumed")]
     ResourceAlreadyConsumed {
@@ -19,7 +19,7 @@ use std::collections::{HashMap, HashSet};
 use rask_ast::decl::{Decl, DeclKind, FnDecl};
 use rask_ast::expr::{ArgMode, Expr, ExprKind, Pattern};
 use rask_ast::stmt::{ForBinding, Stmt, StmtKind};
-use rask_ast::Span;
+use rask_ast::{NodeId, Span};
 use rask_types::{Type, TypedProgram};
 
 /// Result of ownership analysis.
@@ -27,6 +27,10 @@ use rask_types::{Type, TypedProgram};
 pub struct OwnershipResult {
     /// Any errors found during analysis.
     pub errors: Vec<OwnershipError>,
+    /// Expression nodes that consume a `@resource` binding, mapped to the
+    /// binding's name. MIR lowering cancels the binding's `ensure` cleanup at
+    /// these points so the cleanup runs exactly once (C1/C2).
+    pub resource_moves: HashMap<NodeId, String>,
 }
 
 impl OwnershipResult {
@@ -80,6 +84,8 @@ pub struct OwnershipChecker<'a> {
     ensure_registered: HashSet<String>,
     /// True when inside an `ensure` body (defer moves).
     in_ensure: bool,
+    /// Consumption points of resource bindings, for `OwnershipResult::resource_moves`.
+    resource_moves: HashMap<NodeId, String>,
     /// Pool type names with frozen context (CC3/PF5: no writes, inserts, removes, clears).
     frozen_contexts: HashSet<String>,
     /// Active `with` block bindings for W2 checking.
@@ -118,6 +124,7 @@ impl<'a> OwnershipChecker<'a> {
             resource_bindings: HashSet::new(),
             ensure_registered: HashSet::new(),
             in_ensure: false,
+            resource_moves: HashMap::new(),
             frozen_contexts: HashSet::new(),
             active_with_bindings: Vec::new(),
             active_for_mutates: Vec::new(),
@@ -137,6 +144,7 @@ impl<'a> OwnershipChecker<'a> {
         }
         OwnershipResult {
             errors: self.errors,
+            resource_moves: self.resource_moves,
         }
     }
 
@@ -659,11 +667,7 @@ impl<'a> OwnershipChecker<'a> {
                                 .map(|ty| self.move_reason(ty))
                                 .unwrap_or_else(|| self.move_reason_for(name));
                             self.errors.push(OwnershipError {
-                                kind: OwnershipErrorKind::UseAfterMove {
-                                    name: name.clone(),
-                                    moved_at: *at,
-                                    reason,
-                                },
+                                kind: Self::use_after_move(name.clone(), *at, reason),
                                 span: expr.span,
                             });
                         }
@@ -743,6 +747,7 @@ impl<'a> OwnershipChecker<'a> {
                                 });
                             }
                             self.bindings.insert(name.clone(), BindingState::Moved { at: arg.expr.span });
+                            self.note_resource_move(name, arg.expr.id);
                         }
                     }
                 }
@@ -786,6 +791,7 @@ impl<'a> OwnershipChecker<'a> {
                                 });
                             }
                             self.bindings.insert(name.clone(), BindingState::Moved { at: arg.expr.span });
+                            self.note_resource_move(name, arg.expr.id);
                         }
                     }
                 }
@@ -876,6 +882,7 @@ impl<'a> OwnershipChecker<'a> {
                 if !self.in_ensure && self.is_take_self_method(object, method) {
                     if let ExprKind::Ident(name) = &object.kind {
                         self.bindings.insert(name.clone(), BindingState::Moved { at: expr.span });
+                        self.note_resource_move(name, expr.id);
                     }
                 }
             }
@@ -1477,43 +1484,15 @@ impl<'a> OwnershipChecker<'a> {
                                 });
                                 return;
                             }
-                            BindingState::Moved { at } => {
-                                let reason = self.move_reason_for(&source_name);
-                                self.errors.push(OwnershipError {
-                                    kind: OwnershipErrorKind::UseAfterMove {
-                                        name: source_name.clone(),
-                                        moved_at: *at,
-                                        reason,
-                                    },
-                                    span,
-                                });
-                                return;
-                            }
-                            BindingState::MaybeMoved { at } => {
-                                let reason = self.move_reason_for(&source_name);
-                                self.errors.push(OwnershipError {
-                                    kind: OwnershipErrorKind::UseAfterMaybeMove {
-                                        name: source_name.clone(),
-                                        moved_at: *at,
-                                        reason,
-                                    },
-                                    span,
-                                });
-                                return;
-                            }
-                            BindingState::Discarded { at } => {
-                                self.errors.push(OwnershipError {
-                                    kind: OwnershipErrorKind::UseAfterDiscard {
-                                        name: source_name.clone(),
-                                        discarded_at: *at,
-                                    },
-                                    span,
-                                });
-                                return;
-                            }
+                            // The source was already checked as an expression;
+                            // its use-after-move/discard is reported there.
+                            BindingState::Moved { .. }
+                            | BindingState::MaybeMoved { .. }
+                            | BindingState::Discarded { .. } => return,
                             BindingState::Owned => {}
                         }
                     }
+                    self.note_resource_move(&source_name, expr.id);
                     self.bindings.insert(source_name, BindingState::Moved { at: span });
                 }
             }
@@ -1622,11 +1601,7 @@ impl<'a> OwnershipChecker<'a> {
                 BindingState::Moved { at } => {
                     let reason = self.move_reason_for(&source_name);
                     self.errors.push(OwnershipError {
-                        kind: OwnershipErrorKind::UseAfterMove {
-                            name: source_name,
-                            moved_at: *at,
-                            reason,
-                        },
+                        kind: Self::use_after_move(source_name, *at, reason),
                         span,
                     });
                 }
@@ -1694,6 +1669,10 @@ impl<'a> OwnershipChecker<'a> {
 
             // User-defined types: need to check size and fields
             Type::Named(type_id) => {
+                // L1: linear types are never implicitly copied, however small
+                if self.program.types.is_transitive_resource_by_id(*type_id) {
+                    return false;
+                }
                 if let Some(def) = self.program.types.get(*type_id) {
                     match def {
                         rask_types::TypeDef::Struct { fields, is_unique, .. } => {
@@ -1786,6 +1765,9 @@ impl<'a> OwnershipChecker<'a> {
     /// Determine why a type is move-only (not Copy).
     fn move_reason(&self, ty: &Type) -> MoveReason {
         let type_name = format!("{}", self.program.types.resolve_type_names(ty));
+        if self.type_is_resource(ty) {
+            return MoveReason::Resource { type_name };
+        }
         match ty {
             // String is Copy (S1) — this branch shouldn't be reached
             Type::String => MoveReason::Unknown,
@@ -1872,11 +1854,27 @@ impl<'a> OwnershipChecker<'a> {
     fn move_reason_for(&self, name: &str) -> MoveReason {
         if let Some(ty) = self.binding_types.get(name) {
             self.move_reason(ty)
+        } else if self.resource_bindings.contains(name) {
+            let type_name = self.param_type_strings.get(name)
+                .cloned()
+                .unwrap_or_else(|| name.to_string());
+            MoveReason::Resource { type_name }
         } else {
             MoveReason::Unknown
         }
     }
 
+    /// The error for reading a moved binding. A moved resource gets its own
+    /// kind: the mistake is an attempted copy, not a stale read.
+    fn use_after_move(name: String, moved_at: Span, reason: MoveReason) -> OwnershipErrorKind {
+        match reason {
+            MoveReason::Resource { type_name } => {
+                OwnershipErrorKind::ResourceCopied { name, type_name, moved_at }
+            }
+            reason => OwnershipErrorKind::UseAfterMove { name, moved_at, reason },
+        }
+    }
+
     /// Release instant borrows that end at the given statement.
     fn release_instant_borrows(&mut self, stmt_id: u32) {
         self.borrows.retain(|b| {
@@ -2410,8 +2408,12 @@ impl<'a> OwnershipChecker<'a> {
     /// Check if a method call uses `take self`.
     fn is_take_self_method(&self, object: &Expr, method_name: &str) -> bool {
         if let Some(ty) = self.program.node_types.get(&object.id) {
+            // Generic receivers (`Pair<A, B>.swap()`) share the base type's methods
             let type_id = match ty {
-                Type::Named(id) => Some(*id),
+                Type::Named(id) | Type::Generic { base: id, .. } => Some(*id),
+                Type::UnresolvedNamed(name) | Type::UnresolvedGeneric { name, .. } => {
+                    self.program.types.get_type_id(name)
+                }
                 _ => None,
             };
             if let Some(id) = type_id {
@@ -2516,6 +2518,14 @@ impl<'a> OwnershipChecker<'a> {
     }
 
     /// At function exit, emit errors for unconsumed @resource bindings.
+    /// Record that `node` consumes `name` if it's a resource binding. Moves
+    /// inside an `ensure` body are deferred, so they don't count.
+    fn note_resource_move(&mut self, name: &str, node: NodeId) {
+        if !self.in_ensure && self.resource_bindings.contains(name) {
+            self.resource_moves.insert(node, name.to_string());
+        }
+    }
+
     fn check_resource_consumption(&mut self, span: Span) {
         let unconsumed: Vec<String> = self.resource_bindings.iter()
             .filter(|name| {
@@ -206,6 +206,18 @@ mod tests {
         assert!(result.is_ok(), "trailing comma should be allowed");
     }
 
+    #[test]
+    fn tuple_struct_fields_are_positional() {
+        let result = parse("struct Meters(f64)\nstruct Pair(i32, public string)\n");
+        assert!(result.is_ok(), "Parse errors: {:?}", result.errors);
+        let DeclKind::Struct(ref s) = result.decls[1].kind else { panic!("expected struct") };
+        assert!(s.is_tuple());
+        let names: Vec<&str> = s.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, ["0", "1"]);
+        assert!(s.fields[1].visibility.is_pub());
+        assert!(!parse("struct Empty()").is_ok(), "tuple struct needs at least one field");
+    }
+
     #[test]
     fn rust_syntax_double_colon() {
         let result = parse("func main() { const x = Result::Ok }");
@@ -590,8 +602,8 @@ mod tests {
 
     #[test]
     fn newline_in_grouping_parens_does_not_continue() {
-        // Grouping parens (expr) do NOT skip newlines — unlike call parens f(args)
-        // (1\n+ 2) fails: after parsing `1`, newline terminates, then expects `)`
+        // A newline inside grouping parens still ends the inner expression:
+        // (1\n+ 2) fails — `+` can't start a continuation, so `)` is expected
         parse_body_err("const x = (1\n+ 2)");
     }
 
@@ -680,6 +692,79 @@ mod tests {
         assert_eq!(stmts.len(), 1);
     }
 
+    #[test]
+    fn newline_trailing_dot_continues() {
+        // Line ending in `.` continues; mixes with leading-dot lines
+        let stmts = parse_body("const b = builder.\nwith_name(n).\nwith_size(4)\n.build()");
+        assert_eq!(stmts.len(), 1);
+        if let StmtKind::Const { ref init, .. } = stmts[0].kind {
+            if let ExprKind::MethodCall { ref method, .. } = init.kind {
+                assert_eq!(method, "build");
+            } else {
+                panic!("expected method call chain");
+            }
+        } else {
+            panic!("expected const with method chain");
+        }
+    }
+
+    #[test]
+    fn newline_trailing_optional_dot_continues() {
+        let stmts = parse_body("const n = user?.\nname");
+        assert_eq!(stmts.len(), 1);
+        if let StmtKind::Const { ref init, .. } = stmts[0].kind {
+            assert!(matches!(init.kind, ExprKind::OptionalField { .. }));
+        } else {
+            panic!("expected optional field access");
+        }
+    }
+
+    #[test]
+    fn newline_binary_split_across_lines() {
+        // Operators at end of line chain across several lines; precedence holds
+        let stmts = parse_body("const total = base *\nscale +\noffset\nfoo()");
+        assert_eq!(stmts.len(), 2);
+        if let StmtKind::Const { ref init, .. } = stmts[0].kind {
+            if let ExprKind::Binary { op: BinOp::Add, ref left, .. } = init.kind {
+                assert!(matches!(left.kind, ExprKind::Binary { op: BinOp::Mul, .. }));
+            } else {
+                panic!("expected add at the root");
+            }
+        } else {
+            panic!("expected const with binary expression");
+        }
+    }
+
+    #[test]
+    fn newline_open_paren_at_end_of_line_continues() {
+        let stmts = parse_body("const x = (\n1 + 2\n) * 3");
+        assert_eq!(stmts.len(), 1);
+        if let StmtKind::Const { ref init, .. } = stmts[0].kind {
+            assert!(matches!(init.kind, ExprKind::Binary { op: BinOp::Mul, .. }));
+        } else {
+            panic!("expected const with binary mul");
+        }
+
+        let stmts = parse_body("const t = (\n1,\n2,\n)");
+        assert_eq!(stmts.len(), 1);
+        if let StmtKind::Const { ref init, .. } = stmts[0].kind {
+            assert!(matches!(init.kind, ExprKind::Tuple(ref elems) if elems.len() == 2));
+        } else {
+            panic!("expected const with tuple");
+        }
+    }
+
+    #[test]
+    fn newline_inside_index_brackets_continues() {
+        let stmts = parse_body("const v = arr[\ni + 1\n]");
+        assert_eq!(stmts.len(), 1);
+        if let StmtKind::Const { ref init, .. } = stmts[0].kind {
+            assert!(matches!(init.kind, ExprKind::Index { .. }));
+        } else {
+            panic!("expected index expression");
+        }
+    }
+
     // ================================================================
     // B. Generic vs comparison disambiguation
     // ================================================================
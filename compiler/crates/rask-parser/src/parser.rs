@@ -1,5 +1,17 @@
 // SPDX-License-Identifier: (MIT OR Apache-2.0)
 //! The parser implementation using Pratt parsing for expressions.
+//!
+//! Statement termination: a newline (or `;`) ends a statement, except where
+//! the expression is visibly unfinished. An expression continues past a
+//! newline when
+//! - the line ends in a binary operator (`a +` / `b`),
+//! - the line ends in an open `(` or `[`, or inside call/array brackets,
+//! - the line ends in `.` or `?.` (`builder.` / `build()`),
+//! - the next line starts with `.`, `?.`, `?`, or `[` (fluent chains), or
+//! - the next line starts with an infix operator that can't begin an
+//!   expression (`&&`, `||`, `==`, `??`, ...). `+`, `-`, `*`, `<`, `>` don't
+//!   qualify — they also parse as prefix operators or generic brackets, so
+//!   `a` / `-b` stays two statements.
 
 use rask_ast::decl::{BenchmarkDecl, CImportDecl, ConstDecl, ContextClause, Decl, DeclKind, DepDecl, EnumDecl, ExternDecl, FeatureDecl, FeatureOption, Field, FieldVisibility, FnDecl, ImplDecl, ImportDecl, PackageDecl, Param, ProfileDecl, StructDecl, TestDecl, TraitDecl, TypeAliasDecl, TypeParam, UnionDecl, Variant};
 use rask_ast::expr::{ArgMode, BinOp, CallArg, ClosureParam, ConvertKind, Expr, ExprKind, FieldInit, MatchArm, Pattern, SelectArm, SelectArmKind, StringSegment, UnaryOp, WithBinding};
@@ -720,6 +732,7 @@ impl Parser {
                         TokenKind::Ident(s) => attr.push_str(s),
                         TokenKind::Int(n, _) => attr.push_str(&n.to_string()),
                         TokenKind::Comma => attr.push_str(", "),
+                        TokenKind::Eq => attr.push_str(" = "),
                         _ => attr.push_str(&format!("{:?}", self.current_kind())),
                     }
                 }
@@ -826,6 +839,7 @@ impl Parser {
         let mut clauses = Vec::new();
         loop {
             self.skip_newlines();
+            let clause_start = self.current().span.start;
 
             // Check for `frozen` modifier
             let is_frozen = if let TokenKind::Ident(ref name) = self.current_kind().clone() {
@@ -856,7 +870,10 @@ impl Parser {
 
             let ty = self.parse_type_name()?;
 
-            clauses.push(ContextClause { name, ty, is_frozen });
+            let clause_end = self.tokens[self.pos.saturating_sub(1)].span.end;
+            let span = self.span(clause_start, clause_end);
+
+            clauses.push(ContextClause { name, ty, is_frozen, span });
 
             if !self.match_token(&TokenKind::Comma) {
                 break;
@@ -1316,6 +1333,44 @@ impl Parser {
             vec![]
         };
 
+        // Tuple struct: `struct Meters(f64)` — positional fields `0`, `1`, ...
+        if self.check(&TokenKind::LParen) {
+            self.advance();
+            self.skip_newlines();
+            let mut fields = Vec::new();
+            while !self.check(&TokenKind::RParen) && !self.at_end() {
+                let visibility = if self.match_token(&TokenKind::Private) {
+                    FieldVisibility::Private
+                } else if self.match_token(&TokenKind::Public) {
+                    FieldVisibility::Public
+                } else {
+                    FieldVisibility::Package
+                };
+                let name_span = self.current().span;
+                let ty = self.parse_type_name()?;
+                fields.push(Field { name: fields.len().to_string(), name_span, ty, visibility });
+                self.skip_newlines();
+                if !self.match_token(&TokenKind::Comma) {
+                    break;
+                }
+                self.skip_newlines();
+            }
+            if fields.is_empty() {
+                return Err(ParseError::expected("a field type", self.current_kind(), self.current().span));
+            }
+            self.expect(&TokenKind::RParen)?;
+            self.expect_terminator()?;
+            return Ok(DeclKind::Struct(StructDecl {
+                name,
+                type_params,
+                fields,
+                methods: vec![],
+                is_pub,
+                attrs,
+                doc,
+            }));
+        }
+
         self.skip_newlines();
         self.expect(&TokenKind::LBrace)?;
         self.skip_newlines();
@@ -2958,14 +3013,19 @@ impl Parser {
         }))
     }
 
+    /// Whether the expression at a newline continues on the next line (see
+    /// the statement-termination rules in the module docs).
+    fn continues_past_newline(&self) -> bool {
+        self.check(&TokenKind::Newline)
+            && (self.peek_past_newlines_is_postfix() || self.peek_past_newlines_is_infix())
+    }
+
     fn parse_expr_bp(&mut self, min_bp: u8) -> Result<Expr, ParseError> {
         let start = self.current().span.start;
         let mut lhs = self.parse_prefix()?;
 
         loop {
-            if self.check(&TokenKind::Newline)
-                && (self.peek_past_newlines_is_postfix() || self.peek_past_newlines_is_infix())
-            {
+            if self.continues_past_newline() {
                 self.skip_newlines();
             }
 
@@ -3536,6 +3596,8 @@ impl Parser {
     fn parse_paren_or_tuple(&mut self) -> Result<Expr, ParseError> {
         let start = self.current().span.start;
         self.expect(&TokenKind::LParen)?;
+        // A line ending in `(` continues; so does a `)` on its own line.
+        self.skip_newlines();
 
         if self.check(&TokenKind::RParen) {
             self.advance();
@@ -3544,12 +3606,16 @@ impl Parser {
         }
 
         let first = self.parse_expr()?;
+        self.skip_newlines();
 
         if self.match_token(&TokenKind::Comma) {
+            self.skip_newlines();
             let mut elements = vec![first];
             while !self.check(&TokenKind::RParen) && !self.at_end() {
                 elements.push(self.parse_expr()?);
+                self.skip_newlines();
                 if !self.match_token(&TokenKind::Comma) { break; }
+                self.skip_newlines();
             }
             self.expect(&TokenKind::RParen)?;
             let end = self.tokens[self.pos - 1].span.end;
@@ -3702,6 +3768,8 @@ impl Parser {
 
             TokenKind::Dot => {
                 self.advance();
+                // Trailing `.` — the member name is on the next line.
+                self.skip_newlines();
 
                 // Dynamic field access: value.(expr) — comptime field name
                 if self.check(&TokenKind::LParen) {
@@ -3787,6 +3855,7 @@ impl Parser {
             // Optional chaining
             TokenKind::QuestionDot => {
                 self.advance();
+                self.skip_newlines();
                 let field = if let TokenKind::Int(n, None) = self.current_kind().clone() {
                     if n < 0 {
                         return Err(ParseError::expected(
@@ -3807,7 +3876,9 @@ impl Parser {
             // Index access
             TokenKind::LBracket => {
                 self.advance();
+                self.skip_newlines();
                 let index = self.parse_expr()?;
+                self.skip_newlines();
                 self.expect(&TokenKind::RBracket)?;
                 let end = self.tokens[self.pos - 1].span.end;
                 Ok(Expr { id: self.next_id(), kind: ExprKind::Index { object: Box::new(lhs), index: Box::new(index) }, span: self.span(start, end) })
@@ -4104,12 +4175,14 @@ impl Parser {
 
         let mut arms = Vec::new();
         while !self.check(&TokenKind::RBrace) && !self.at_end() {
+            let arm_start = self.current().span.start;
             let pattern = self.parse_pattern()?;
             let guard = if self.match_token(&TokenKind::If) {
                 Some(Box::new(self.parse_expr()?))
             } else {
                 None
             };
+            let arm_span = self.span(arm_start, self.tokens[self.pos.saturating_sub(1)].span.end);
 
             self.expect(&TokenKind::FatArrow)?;
             self.skip_newlines();
@@ -4122,7 +4195,7 @@ impl Parser {
                 self.parse_inline_block(start)?
             };
 
-            arms.push(MatchArm { pattern, guard, body: Box::new(body) });
+            arms.push(MatchArm { pattern, guard, body: Box::new(body), span: arm_span });
             self.match_token(&TokenKind::Comma);
             self.skip_newlines();
         }
@@ -55,9 +55,9 @@ impl ResolveError {
         }
     }
 
-    pub fn not_visible(name: String, span: Span) -> Self {
+    pub fn not_visible(name: String, package: String, span: Span) -> Self {
         Self {
-            kind: ResolveErrorKind::NotVisible { name },
+            kind: ResolveErrorKind::NotVisible { name, package },
             span,
         }
     }
@@ -104,7 +104,7 @@ pub enum ResolveErrorKind {
     #[error("undefined symbol: {name}")]
     UndefinedSymbol { name: String },
 
-    #[error("duplicate definition: {name} (previously defined at {previous:?})")]
+    #[error("the name `{name}` is defined multiple times")]
     DuplicateDefinition { name: String, previous: Span },
 
     #[error("break outside of loop{}", label.as_ref().map(|l| format!(" (label: {})", l)).unwrap_or_default())]
@@ -119,8 +119,8 @@ pub enum ResolveErrorKind {
     #[error("unknown package: `{}`", if path.is_empty() { "<empty>".to_string() } else { path.join(".") })]
     UnknownPackage { path: Vec<String> },
 
-    #[error("`{name}` is not public and cannot be accessed from this package")]
-    NotVisible { name: String },
+    #[error("`{name}` is private to package `{package}`")]
+    NotVisible { name: String, package: String },
 
     #[error("cannot define `{name}` because it shadows an imported name; consider using a different name or aliasing the import")]
     ShadowsImport { name: String },
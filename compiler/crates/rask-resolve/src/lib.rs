@@ -27,7 +27,7 @@ pub mod signing;
 
 pub use error::{ResolveError, ResolveErrorKind};
 pub use scope::{Scope, ScopeId, ScopeKind};
-pub use symbol::{Symbol, SymbolId, SymbolKind, SymbolTable, BuiltinFunctionKind};
+pub use symbol::{Symbol, SymbolId, SymbolKind, SymbolTable, BuiltinFunctionKind, BuiltinModuleKind};
 pub use resolver::Resolver;
 pub use package::{Package, PackageId, PackageRegistry, PackageError, SourceFile};
 #[cfg(not(target_arch = "wasm32"))]
@@ -53,6 +53,21 @@ pub struct ResolvedProgram {
     /// Public type declarations from external packages, keyed by package name.
     /// The type checker registers these so cross-package types resolve.
     pub external_decls: HashMap<String, Vec<Decl>>,
+    /// Names each `import` declaration brought into scope, keyed by the
+    /// import's decl id. Tooling maps a use back to its import through this.
+    pub imports: HashMap<NodeId, Vec<ImportBinding>>,
+}
+
+/// A name introduced by an `import`.
+#[derive(Debug, Clone)]
+pub struct ImportBinding {
+    /// Name as visible in the importing file (the alias, if one was given).
+    pub name: String,
+    /// Symbol the name resolves to — the originating declaration for
+    /// package exports.
+    pub symbol: SymbolId,
+    /// Package or stdlib module the name came from (first path segment).
+    pub package: String,
 }
 
 /// Extern function signature extracted from C imports or explicit `extern "C"` decls.
@@ -64,6 +79,13 @@ pub struct CImportExternFunc {
 }
 
 impl ResolvedProgram {
+    /// The import that brought `symbol` into scope, if any.
+    pub fn import_of(&self, symbol: SymbolId) -> Option<(NodeId, &ImportBinding)> {
+        self.imports.iter().find_map(|(&decl_id, bindings)| {
+            bindings.iter().find(|b| b.symbol == symbol).map(|b| (decl_id, b))
+        })
+    }
+
     /// Extract all extern "C" function names and signatures from C import namespaces.
     /// Used by MIR lowering (extern_funcs set) and codegen (extern function declarations).
     pub fn c_import_extern_funcs(&self) -> Vec<CImportExternFunc> {
@@ -11,7 +11,7 @@ use crate::error::ResolveError;
 use crate::scope::{ScopeTree, ScopeKind};
 use crate::symbol::{BuiltinModuleKind, SymbolTable, SymbolId, SymbolKind};
 use crate::package::PackageId;
-use crate::ResolvedProgram;
+use crate::{ImportBinding, ResolvedProgram};
 
 pub struct Resolver {
     symbols: SymbolTable,
@@ -24,10 +24,15 @@ pub struct Resolver {
     package_bindings: HashMap<String, PackageId>,
     imported_symbols: HashSet<String>,
     lazy_imports: HashMap<String, Vec<String>>,
+    /// Names bound by each import declaration (see `ResolvedProgram::imports`).
+    imports: HashMap<NodeId, Vec<ImportBinding>>,
     /// Maps struct/enum base names to their type params (for extend blocks)
     type_param_map: HashMap<String, Vec<TypeParam>>,
     /// Public symbols exported by each external package.
     package_exports: HashMap<PackageId, HashMap<String, SymbolId>>,
+    /// Top-level items each external package declares without `public`,
+    /// so reaching for one reports privacy instead of an undefined name.
+    package_private: HashMap<PackageId, HashSet<String>>,
     /// When true, declarations can shadow builtin names without E0209.
     stdlib_mode: bool,
     /// Symbols defined during stdlib_mode — imports may override these.
@@ -35,6 +40,10 @@ pub struct Resolver {
     /// Compile-time cfg values for dead branch elimination in `comptime if`.
     /// Maps field names (os, arch, env, profile) to their values.
     cfg_values: HashMap<String, String>,
+    /// Module-scope declarations seen so far (name → first span), for
+    /// duplicate detection. Stdlib decls aren't recorded — user code may
+    /// shadow them.
+    top_level_names: HashMap<String, Span>,
 }
 
 impl Resolver {
@@ -49,11 +58,14 @@ impl Resolver {
             package_bindings: HashMap::new(),
             imported_symbols: HashSet::new(),
             lazy_imports: HashMap::new(),
+            imports: HashMap::new(),
             type_param_map: HashMap::new(),
             package_exports: HashMap::new(),
+            package_private: HashMap::new(),
             stdlib_mode: false,
             stdlib_symbols: HashSet::new(),
             cfg_values: HashMap::new(),
+            top_level_names: HashMap::new(),
         };
 
         resolver.register_builtins();
@@ -74,6 +86,7 @@ impl Resolver {
             ("max", BuiltinFunctionKind::Max, None),
             ("clamp", BuiltinFunctionKind::Clamp, None),
             ("assert_eq", BuiltinFunctionKind::AssertEq, None),
+            ("assert_ne", BuiltinFunctionKind::AssertNe, None),
             ("skip", BuiltinFunctionKind::Skip, Some("!")),
             ("expect_fail", BuiltinFunctionKind::ExpectFail, None),
         ];
@@ -484,6 +497,7 @@ impl Resolver {
                 symbols: resolver.symbols,
                 resolutions: resolver.resolutions,
                 external_decls: HashMap::new(),
+                imports: resolver.imports,
             })
         } else {
             Err(resolver.errors)
@@ -508,6 +522,7 @@ impl Resolver {
                 symbols: resolver.symbols,
                 resolutions: resolver.resolutions,
                 external_decls: HashMap::new(),
+                imports: resolver.imports,
             })
         } else {
             Err(resolver.errors)
@@ -611,6 +626,7 @@ impl Resolver {
                 symbols: resolver.symbols,
                 resolutions: resolver.resolutions,
                 external_decls,
+                imports: resolver.imports,
             })
         } else {
             Err(resolver.errors)
@@ -721,15 +737,46 @@ impl Resolver {
             }
         }
 
+        let private = pkg.all_decls()
+            .filter_map(|decl| match &decl.kind {
+                DeclKind::Fn(f) if !f.is_pub => Some(f.name.as_str()),
+                DeclKind::Struct(s) if !s.is_pub => Some(s.name.as_str()),
+                DeclKind::Enum(e) if !e.is_pub => Some(e.name.as_str()),
+                DeclKind::Trait(t) if !t.is_pub => Some(t.name.as_str()),
+                DeclKind::Const(c) if !c.is_pub => Some(c.name.as_str()),
+                DeclKind::TypeAlias(a) if !a.is_pub => Some(a.name.as_str()),
+                _ => None,
+            })
+            .map(|name| Self::base_name(name).to_string())
+            .filter(|name| !exports.contains_key(name))
+            .collect();
+        self.package_private.insert(pkg.id, private);
         self.package_exports.insert(pkg.id, exports);
     }
 
+    /// Report `name` if it's an item of `pkg_id` declared without `public`.
+    fn check_package_visibility(&mut self, pkg_id: PackageId, name: &str, span: Span) -> bool {
+        let is_private = self.package_private.get(&pkg_id)
+            .is_some_and(|private| private.contains(name));
+        if is_private {
+            let package = self.package_bindings.iter()
+                .find(|(_, id)| **id == pkg_id)
+                .map(|(pkg_name, _)| pkg_name.clone())
+                .unwrap_or_default();
+            self.errors.push(ResolveError::not_visible(name.to_string(), package, span));
+        }
+        is_private
+    }
+
     // =========================================================================
     // Pass 1: Declaration Collection
     // =========================================================================
 
     fn collect_declarations(&mut self, decls: &[Decl]) {
         for decl in decls {
+            if let Some(name) = Self::top_level_name(decl) {
+                self.check_duplicate_top_level(name, decl.span);
+            }
             match &decl.kind {
                 DeclKind::Fn(fn_decl) => {
                     self.declare_function(fn_decl, decl.span, fn_decl.is_pub);
@@ -745,7 +792,7 @@ impl Resolver {
                 }
                 DeclKind::Impl(_) => {}
                 DeclKind::Import(import_decl) => {
-                    self.resolve_import(import_decl, decl.span);
+                    self.resolve_import(import_decl, decl.id, decl.span);
                 }
                 DeclKind::Export(export_decl) => {
                     self.resolve_export(export_decl, decl.span);
@@ -805,6 +852,39 @@ impl Resolver {
         }
     }
 
+    /// Name a declaration binds at module scope. Methods live on their type
+    /// and imports have their own collision rules, so neither counts here.
+    fn top_level_name(decl: &Decl) -> Option<&str> {
+        let name = match &decl.kind {
+            DeclKind::Fn(f) => &f.name,
+            DeclKind::Struct(s) => &s.name,
+            DeclKind::Enum(e) => &e.name,
+            DeclKind::Trait(t) => &t.name,
+            DeclKind::Union(u) => &u.name,
+            DeclKind::Const(c) => &c.name,
+            DeclKind::TypeAlias(a) => &a.name,
+            DeclKind::Extern(e) => &e.name,
+            _ => return None,
+        };
+        Some(Self::base_name(name))
+    }
+
+    /// Two module-scope declarations with the same name are an error, not
+    /// last-one-wins. Each later collision reports against the first.
+    fn check_duplicate_top_level(&mut self, name: &str, span: Span) {
+        if self.stdlib_mode {
+            return;
+        }
+        match self.top_level_names.get(name) {
+            Some(&previous) => {
+                self.errors.push(ResolveError::duplicate(name.to_string(), span, previous));
+            }
+            None => {
+                self.top_level_names.insert(name.to_string(), span);
+            }
+        }
+    }
+
     /// Strip generic params from function name: "foo<T: Trait>" → "foo"
     fn base_name(name: &str) -> &str {
         name.split('<').next().unwrap_or(name)
@@ -975,7 +1055,16 @@ impl Resolver {
     // Import Resolution
     // =========================================================================
 
-    fn resolve_import(&mut self, import_decl: &ImportDecl, span: Span) {
+    /// Remember that `decl_id` bound `name` to `symbol`, for tooling.
+    fn record_import(&mut self, decl_id: NodeId, name: &str, symbol: SymbolId, package: &str) {
+        self.imports.entry(decl_id).or_default().push(ImportBinding {
+            name: name.to_string(),
+            symbol,
+            package: package.to_string(),
+        });
+    }
+
+    fn resolve_import(&mut self, import_decl: &ImportDecl, decl_id: NodeId, span: Span) {
         let path = &import_decl.path;
 
         if path.is_empty() {
@@ -1024,6 +1113,7 @@ impl Resolver {
                 if let Err(e) = self.scopes.define(binding_name.clone(), sym_id, span) {
                     self.errors.push(e);
                 }
+                self.record_import(decl_id, &binding_name, sym_id, pkg_name);
                 // Stdlib modules always register companion types/enums into scope.
                 // Module functions are accessed qualified (os.env), but types
                 // (Command, File, Signal) are used unqualified per convention.
@@ -1038,6 +1128,7 @@ impl Resolver {
                                 self.errors.push(e);
                             }
                             self.imported_symbols.insert(name.clone());
+                            self.record_import(decl_id, name, *sym_id, pkg_name);
                         }
                     }
                     return;
@@ -1052,6 +1143,7 @@ impl Resolver {
                 if let Err(e) = self.scopes.define(binding_name.clone(), sym_id, span) {
                     self.errors.push(e);
                 }
+                self.record_import(decl_id, &binding_name, sym_id, pkg_name);
             } else {
                 self.errors.push(ResolveError::unknown_package(path.clone(), span));
                 return;
@@ -1094,6 +1186,7 @@ impl Resolver {
                         if let Err(e) = self.scopes.define(binding_name.clone(), exported_sym, span) {
                             self.errors.push(e);
                         }
+                        self.record_import(decl_id, &binding_name, exported_sym, pkg_name);
                         self.imported_symbols.insert(binding_name.clone());
                         if import_decl.is_lazy {
                             self.lazy_imports.insert(binding_name, path.clone());
@@ -1101,6 +1194,9 @@ impl Resolver {
                         return;
                     }
                 }
+                if self.check_package_visibility(pkg_id, symbol_name, span) {
+                    return;
+                }
             }
 
             // Check if the package is a known stdlib module — if so, the imported
@@ -1131,6 +1227,9 @@ impl Resolver {
                         }
                     }
                 }
+                if let Some(sym_id) = self.scopes.lookup(&binding_name) {
+                    self.record_import(decl_id, &binding_name, sym_id, pkg_name);
+                }
             } else {
                 // Unknown package — create variable binding as fallback
                 let sym_id = self.symbols.insert(
@@ -1143,6 +1242,7 @@ impl Resolver {
                 if let Err(e) = self.scopes.define(binding_name.clone(), sym_id, span) {
                     self.errors.push(e);
                 }
+                self.record_import(decl_id, &binding_name, sym_id, pkg_name);
             }
 
             self.imported_symbols.insert(binding_name.clone());
@@ -1924,10 +2024,13 @@ impl Resolver {
                             if let SymbolKind::ExternalPackage { package_id } = &sym.kind {
                                 let pkg_id = *package_id;
                                 self.resolutions.insert(object.id, sym_id);
-                                if let Some(exports) = self.package_exports.get(&pkg_id) {
-                                    if let Some(&method_sym) = exports.get(method) {
+                                match self.package_exports.get(&pkg_id).and_then(|e| e.get(method)) {
+                                    Some(&method_sym) => {
                                         self.resolutions.insert(expr.id, method_sym);
                                     }
+                                    None => {
+                                        self.check_package_visibility(pkg_id, method, expr.span);
+                                    }
                                 }
                                 for arg in args {
                                     self.resolve_expr(&arg.expr);
@@ -1961,11 +2064,15 @@ impl Resolver {
                             if let SymbolKind::ExternalPackage { package_id } = &sym.kind {
                                 let pkg_id = *package_id;
                                 self.resolutions.insert(object.id, sym_id);
-                                if let Some(exports) = self.package_exports.get(&pkg_id) {
-                                    if let Some(&field_sym) = exports.get(field) {
+                                match self.package_exports.get(&pkg_id).and_then(|e| e.get(field)) {
+                                    Some(&field_sym) => {
                                         self.resolutions.insert(expr.id, field_sym);
                                     }
-                                    // No error for missing field — type checker handles it
+                                    // A missing name that isn't private is left
+                                    // to the type checker
+                                    None => {
+                                        self.check_package_visibility(pkg_id, field, expr.span);
+                                    }
                                 }
                                 return;
                             }
@@ -2139,10 +2246,13 @@ impl Resolver {
                         if let Some(sym) = self.symbols.get(sym_id) {
                             if let SymbolKind::ExternalPackage { package_id } = &sym.kind {
                                 let pkg_id = *package_id;
-                                if let Some(exports) = self.package_exports.get(&pkg_id) {
-                                    if let Some(&struct_sym) = exports.get(parts[1]) {
+                                match self.package_exports.get(&pkg_id).and_then(|e| e.get(parts[1])) {
+                                    Some(&struct_sym) => {
                                         self.resolutions.insert(expr.id, struct_sym);
                                     }
+                                    None => {
+                                        self.check_package_visibility(pkg_id, parts[1], expr.span);
+                                    }
                                 }
                             } else if let SymbolKind::CNamespace { members } = &sym.kind {
                                 if let Some(&member_sym) = members.get(parts[1]) {
@@ -2409,6 +2519,7 @@ impl Default for Resolver {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::ResolveErrorKind;
     use rask_ast::decl::{Decl, DeclKind, ImportDecl};
 
     fn make_import_decl(path: Vec<&str>, alias: Option<&str>, is_glob: bool, is_lazy: bool) -> Decl {
@@ -2497,6 +2608,70 @@ mod tests {
         assert!(result.is_ok(), "Shadowing built-in function should be allowed");
     }
 
+    #[test]
+    fn test_duplicate_function_error() {
+        let mut second = make_fn_decl("foo");
+        second.span = Span::new(20, 30);
+        let decls = vec![make_fn_decl("foo"), second];
+        let errors = Resolver::resolve(&decls).expect_err("duplicate `foo` should fail");
+        assert_eq!(errors.len(), 1);
+        match &errors[0].kind {
+            ResolveErrorKind::DuplicateDefinition { name, previous } => {
+                assert_eq!(name, "foo");
+                assert_eq!(*previous, Span::new(0, 10));
+                assert_eq!(errors[0].span, Span::new(20, 30));
+            }
+            other => panic!("expected DuplicateDefinition, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_duplicate_struct_and_function_error() {
+        use rask_ast::decl::StructDecl;
+        let point = Decl {
+            id: NodeId(0),
+            kind: DeclKind::Struct(StructDecl {
+                name: "Point".to_string(),
+                type_params: vec![],
+                fields: vec![],
+                methods: vec![],
+                is_pub: false,
+                attrs: vec![],
+                doc: None,
+            }),
+            span: Span::new(0, 10),
+        };
+        let decls = vec![point, make_fn_decl("Point")];
+        let errors = Resolver::resolve(&decls).expect_err("`Point` defined twice should fail");
+        assert!(errors.iter().any(|e| matches!(&e.kind,
+            ResolveErrorKind::DuplicateDefinition { name, .. } if name == "Point")));
+    }
+
+    #[test]
+    fn test_same_method_on_different_types_ok() {
+        use rask_ast::decl::StructDecl;
+        let method = |name: &str| match make_fn_decl(name).kind {
+            DeclKind::Fn(f) => f,
+            _ => unreachable!(),
+        };
+        let make_struct = |name: &str| Decl {
+            id: NodeId(0),
+            kind: DeclKind::Struct(StructDecl {
+                name: name.to_string(),
+                type_params: vec![],
+                fields: vec![],
+                methods: vec![method("area")],
+                is_pub: false,
+                attrs: vec![],
+                doc: None,
+            }),
+            span: Span::new(0, 10),
+        };
+        let decls = vec![make_struct("Circle"), make_struct("Square")];
+        let result = Resolver::resolve(&decls);
+        assert!(result.is_ok(), "methods on different types don't collide: {:?}", result.err());
+    }
+
     #[test]
     fn test_builtin_type_shadowing_error() {
         use rask_ast::decl::StructDecl;
@@ -2667,6 +2842,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_import_binding_points_at_origin_symbol() {
+        use crate::PackageRegistry;
+        use std::path::PathBuf;
+
+        let mut registry = PackageRegistry::new();
+        let _lib_pkg = registry.add_package_with_decls(
+            "lib".to_string(),
+            vec!["lib".to_string()],
+            PathBuf::from("/lib"),
+            vec![make_pub_fn_decl("greet")],
+        );
+        let app_pkg = registry.add_package(
+            "app".to_string(),
+            vec!["app".to_string()],
+            PathBuf::from("/app"),
+        );
+
+        let decls = vec![
+            make_import_decl(vec!["lib", "greet"], Some("hello"), false, false),
+            make_fn_decl("main"),
+        ];
+        let resolved = Resolver::resolve_package(&decls, &registry, app_pkg)
+            .expect("selective import should resolve");
+
+        let bindings = resolved.imports.get(&NodeId(0)).expect("import should record its bindings");
+        assert_eq!(bindings.len(), 1);
+        assert_eq!(bindings[0].name, "hello");
+        assert_eq!(bindings[0].package, "lib");
+        let origin = resolved.symbols.get(bindings[0].symbol).expect("origin symbol");
+        assert_eq!(origin.name, "greet");
+        assert!(matches!(origin.kind, SymbolKind::Function { .. }), "got {:?}", origin.kind);
+
+        let (decl_id, binding) = resolved.import_of(bindings[0].symbol).expect("reverse lookup");
+        assert_eq!(decl_id, NodeId(0));
+        assert_eq!(binding.name, "hello");
+    }
+
     #[test]
     fn test_cross_package_private_not_visible() {
         use crate::PackageRegistry;
@@ -2694,10 +2907,44 @@ mod tests {
             make_fn_decl("main"),
         ];
 
-        // This should still resolve (the import falls through to the fallback path)
-        // but the symbol won't be the actual function — it'll be a dummy Variable
-        let result = Resolver::resolve_package(&decls, &registry, app_pkg);
-        assert!(result.is_ok(), "Import of non-public symbol should not error at resolve time");
+        let errors = Resolver::resolve_package(&decls, &registry, app_pkg)
+            .expect_err("importing a non-public symbol should fail");
+        assert_eq!(errors.len(), 1, "got {:?}", errors);
+        assert!(
+            matches!(&errors[0].kind, ResolveErrorKind::NotVisible { name, package }
+                if name == "internal_helper" && package == "lib"),
+            "got {:?}", errors[0].kind
+        );
+        assert_eq!(errors[0].to_string(), "`internal_helper` is private to package `lib`");
+    }
+
+    #[test]
+    fn test_cross_package_qualified_private_access() {
+        use crate::PackageRegistry;
+        use std::path::PathBuf;
+
+        let mut registry = PackageRegistry::new();
+        let _lib_pkg = registry.add_package_with_decls(
+            "lib".to_string(),
+            vec!["lib".to_string()],
+            PathBuf::from("/lib"),
+            vec![make_pub_fn_decl("greet"), make_fn_decl("internal_helper")],
+        );
+        let app_pkg = registry.add_package(
+            "app".to_string(),
+            vec!["app".to_string()],
+            PathBuf::from("/app"),
+        );
+
+        let src = "import lib\nfunc main() {\n    lib.greet()\n    lib.internal_helper()\n}\n";
+        let parsed = rask_parser::Parser::new(rask_lexer::Lexer::new(src).tokenize().tokens).parse();
+        assert!(parsed.is_ok(), "parse errors: {:?}", parsed.errors);
+
+        let errors = Resolver::resolve_package(&parsed.decls, &registry, app_pkg)
+            .expect_err("calling a non-public function should fail");
+        assert_eq!(errors.len(), 1, "only the private call is rejected: {:?}", errors);
+        assert!(matches!(&errors[0].kind, ResolveErrorKind::NotVisible { name, .. } if name == "internal_helper"));
+        assert_eq!(&src[errors[0].span.start..errors[0].span.end], "lib.internal_helper()");
     }
 
     #[test]
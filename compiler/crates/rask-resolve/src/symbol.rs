@@ -169,6 +169,8 @@ pub enum BuiltinFunctionKind {
     Clamp,
     /// assert_eq - compare got/expected with pretty-print diff
     AssertEq,
+    /// assert_ne - fail when got/expected are equal, rendering both
+    AssertNe,
     /// skip - skip rest of test with reason
     Skip,
     /// expect_fail - invert pass/fail for test
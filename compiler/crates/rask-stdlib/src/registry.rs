@@ -105,7 +105,7 @@ const BOOL_METHODS: &[&str] = &["eq"];
 const CHAR_METHODS: &[&str] = &[
     "is_whitespace", "is_ascii", "is_alphabetic", "is_numeric",
     "is_alphanumeric", "is_digit", "is_uppercase", "is_lowercase",
-    "to_uppercase", "to_lowercase", "len_utf8",
+    "to_uppercase", "to_lowercase", "len_utf8", "to_digit",
     "to_string", "eq",
 ];
 
@@ -113,7 +113,7 @@ const STRING_METHODS: &[&str] = &[
     "len", "is_empty", "clone", "starts_with", "ends_with", "contains",
     "push", "push_str", "trim", "trim_start", "trim_end", "trim_bounds",
     "to_string", "to_owned", "to_uppercase", "to_lowercase",
-    "split", "split_whitespace", "chars", "char_indices", "bytes", "lines",
+    "split", "split_whitespace", "chars", "char_indices", "enumerate", "bytes", "lines",
     "replace", "substring", "parse_int", "parse",
     "char_at", "byte_at", "parse_float", "find", "index_of", "rfind",
     "repeat", "reverse", "eq", "ne",
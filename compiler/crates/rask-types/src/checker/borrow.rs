@@ -114,6 +114,42 @@ impl TypeChecker {
         None
     }
 
+    /// Treat locals bound before this point as captures into a task body.
+    /// Returns the enclosing depth for the caller to restore afterwards.
+    pub(super) fn begin_task_capture(&mut self) -> Option<usize> {
+        self.reported_task_captures.clear();
+        self.task_capture_depth.replace(self.local_types.len())
+    }
+
+    /// CC5: a local bound outside a `spawn` body and read inside it is
+    /// captured into a task that may outlive the current frame. Borrowed
+    /// parameters and views can't make that trip unless they're Copy or a
+    /// `Shared` handle.
+    pub(super) fn check_task_capture(&mut self, name: &str, span: Span) {
+        let Some(depth) = self.task_capture_depth else { return };
+        let binding = self.local_types.iter().enumerate().rev()
+            .find_map(|(i, scope)| scope.get(name).map(|(ty, kind)| (i, ty.clone(), *kind)));
+        let Some((scope_idx, ty, kind)) = binding else { return };
+        if scope_idx >= depth || self.reported_task_captures.contains(name) {
+            return;
+        }
+        let is_view = self.persistent_borrows.iter().any(|b| b.view_var == name);
+        if !is_view && !matches!(kind, super::BindingKind::Param) {
+            return;
+        }
+        let ty = self.ctx.apply(&ty);
+        let is_shared = match &ty {
+            Type::Generic { base, .. } => self.types.type_name(*base) == "Shared",
+            Type::UnresolvedGeneric { name, .. } => name == "Shared",
+            _ => false,
+        };
+        if self.is_copy_type(&ty) || is_shared {
+            return;
+        }
+        self.reported_task_captures.insert(name.to_string());
+        self.errors.push(TypeError::BorrowCapturedIntoTask { name: name.to_string(), span });
+    }
+
     /// Check if a local variable is read-only (const binding or default parameter).
     pub(super) fn is_local_read_only(&self, name: &str) -> bool {
         self.lookup_binding_kind(name).map_or(false, |k| k.is_read_only())
@@ -301,33 +337,8 @@ impl TypeChecker {
     /// `const v = Vec.new(); v.push(1)` before constraint solving runs).
     pub(super) fn method_mutates_self(&self, var_name: &str, method_name: &str) -> bool {
         let Some(ty) = self.lookup_local(var_name) else { return false };
-        let resolved = self.resolve_named(&self.ctx.apply(&ty));
-        let type_id = match &resolved {
-            Type::Named(id) => Some(*id),
-            Type::Generic { base, .. } => Some(*base),
-            _ => None,
-        };
-
-        if let Some(id) = type_id {
-            // User-defined types: check the internal method table.
-            let methods = match self.types.get(id) {
-                Some(TypeDef::Struct { methods, .. }) |
-                Some(TypeDef::Enum { methods, .. }) => Some(methods),
-                _ => None,
-            };
-            if let Some(methods) = methods {
-                if let Some(sig) = methods.iter().find(|m| m.name == method_name) {
-                    return matches!(sig.self_param, SelfParam::Mutate);
-                }
-            }
-
-            // Builtin types (Vec, Map, string, ...): check the stdlib stubs.
-            let type_name = self.types.type_name(id);
-            if let Some(stub) = rask_stdlib::lookup_method(&type_name, method_name) {
-                return stub.mutate_self;
-            }
-
-            return false;
+        if let Some(mutates) = self.receiver_method_mutates(&ty, method_name) {
+            return mutates;
         }
 
         // Receiver type unresolved: fall back to the set of method names that
@@ -336,6 +347,33 @@ impl TypeChecker {
         rask_stdlib::any_builtin_method_mutates(method_name)
     }
 
+    /// Whether `method_name` on a receiver of type `ty` is `mutate self`.
+    /// None when the receiver's type isn't resolved to a nominal type yet.
+    pub(super) fn receiver_method_mutates(&self, ty: &Type, method_name: &str) -> Option<bool> {
+        let resolved = self.resolve_named(&self.ctx.apply(ty));
+        let id = match &resolved {
+            Type::Named(id) => *id,
+            Type::Generic { base, .. } => *base,
+            _ => return None,
+        };
+
+        // User-defined types: check the internal method table.
+        let methods = match self.types.get(id) {
+            Some(TypeDef::Struct { methods, .. }) |
+            Some(TypeDef::Enum { methods, .. }) => Some(methods),
+            _ => None,
+        };
+        if let Some(methods) = methods {
+            if let Some(sig) = methods.iter().find(|m| m.name == method_name) {
+                return Some(matches!(sig.self_param, SelfParam::Mutate));
+            }
+        }
+
+        // Builtin types (Vec, Map, string, ...): check the stdlib stubs.
+        let type_name = self.types.type_name(id);
+        Some(rask_stdlib::lookup_method(&type_name, method_name).is_some_and(|stub| stub.mutate_self))
+    }
+
     /// Determine borrow mode for a method call by looking up the method signature.
     /// Falls back to a name-based heuristic for unresolved types.
     pub(super) fn method_borrow_mode(&self, var_name: &str, method_name: &str) -> BorrowMode {
@@ -418,4 +456,36 @@ impl TypeChecker {
             }
         }
     }
+
+    /// S3: a view into a function-local array can't be returned — the local
+    /// dies at return. Views into parameters are fine; the caller owns them.
+    pub(super) fn check_returned_view(&mut self, expr: &Expr) {
+        let source = match Self::detect_view_creation(expr) {
+            Some((source, _)) => source,
+            // `const v = arr[0..2]; return v`
+            None => match &expr.kind {
+                ExprKind::Ident(name) => {
+                    match self.persistent_borrows.iter().rev().find(|b| &b.view_var == name) {
+                        Some(b) => b.source_var.clone(),
+                        None => return,
+                    }
+                }
+                _ => return,
+            },
+        };
+        let is_local = match self.lookup_binding_kind(&source) {
+            Some(super::BindingKind::Param) | None => false,
+            Some(super::BindingKind::Mut) => !self.mutate_params.contains(&source),
+            Some(super::BindingKind::Const) => true,
+        };
+        if !is_local {
+            return;
+        }
+        // Strings are refcounted, so their views don't dangle at return.
+        let Some(source_ty) = self.lookup_local(&source) else { return };
+        if matches!(self.ctx.apply(&source_ty), Type::Array { .. }) {
+            self.errors.push(TypeError::ReturnsLocalBorrow { name: source, span: expr.span });
+        }
+    }
 }
+
@@ -93,8 +93,8 @@ pub(super) fn parse_stub_type(s: &str) -> Type {
             let bytes = inner.as_bytes();
             for (i, b) in bytes.iter().enumerate() {
                 match b {
-                    b'<' => depth += 1,
-                    b'>' => depth -= 1,
+                    b'<' | b'(' => depth += 1,
+                    b'>' | b')' => depth -= 1,
                     b',' if depth == 0 => {
                         args.push(parse_stub_type(inner[start..i].trim()));
                         start = i + 1;
@@ -112,6 +112,20 @@ pub(super) fn parse_stub_type(s: &str) -> Type {
         }
     }
 
+    // Tuples: `(usize, char)`
+    if let Some(inner) = s.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+        if !inner.trim().is_empty() {
+            let mut elems = Vec::new();
+            let mut rest = inner;
+            while let Some((head, tail)) = split_comma(rest) {
+                elems.push(parse_stub_type(head));
+                rest = tail;
+            }
+            elems.push(parse_stub_type(rest));
+            return Type::Tuple(elems);
+        }
+    }
+
     match s {
         "" | "()" | "void" => Type::Unit,
         "none" => Type::None,
@@ -161,8 +175,8 @@ fn split_comma(s: &str) -> Option<(&str, &str)> {
     let mut depth: i32 = 0;
     for (i, b) in s.bytes().enumerate() {
         match b {
-            b'<' => depth += 1,
-            b'>' => depth -= 1,
+            b'<' | b'(' => depth += 1,
+            b'>' | b')' => depth -= 1,
             b',' if depth == 0 => {
                 return Some((s[..i].trim(), s[i + 1..].trim()));
             }
@@ -334,4 +348,14 @@ mod tests {
         let ty = parse_stub_type("Option<i64>");
         assert_eq!(ty, Type::option(Type::I64));
     }
+
+    #[test]
+    fn stub_tuples_parse_inside_generics() {
+        let pair = Type::Tuple(vec![Type::U64, Type::Char]);
+        assert_eq!(parse_stub_type("(usize, char)"), pair);
+        assert_eq!(parse_stub_type("Vec<(usize, char)>"), Type::UnresolvedGeneric {
+            name: "Vec".to_string(),
+            args: vec![crate::types::GenericArg::Type(Box::new(pair))],
+        });
+    }
 }
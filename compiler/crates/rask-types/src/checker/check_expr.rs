@@ -4,6 +4,7 @@
 use rask_ast::expr::{BinOp, CallArg, ConvertKind, Expr, ExprKind, MatchArm, Pattern};
 use rask_ast::stmt::{Stmt, StmtKind};
 use rask_ast::{NodeId, Span};
+use rask_comptime::IntWidth;
 use rask_resolve::{SymbolId, SymbolKind};
 
 use super::type_defs::TypeDef;
@@ -15,6 +16,10 @@ use super::TypeChecker;
 
 use crate::types::{GenericArg, Type};
 
+/// First NodeId the desugarer hands out. Nodes at or above it were
+/// synthesized from sugar (operators, interpolation), not parsed.
+const DESUGAR_ID_BASE: u32 = 1_000_000;
+
 /// Split a type argument string by commas, respecting nested angle brackets.
 /// "Map<string, bool>, i64" → ["Map<string, bool>", "i64"]
 fn split_type_args(s: &str) -> Vec<String> {
@@ -96,11 +101,77 @@ impl TypeChecker {
                 self.node_types.insert(expr.id, ty.clone());
                 return ty;
             }
+            ExprKind::If { .. }
+            | ExprKind::IfLet { .. }
+            | ExprKind::Match { .. }
+            | ExprKind::Block(_)
+            | ExprKind::Array(_) => {
+                self.expected_ty = Some(expected.clone());
+            }
             _ => {}
         }
         self.infer_expr(expr)
     }
 
+    /// Span of the value a branch produces: the tail expression of a block
+    /// branch, so diagnostics point at `"x"` rather than the whole `{ ... }`.
+    fn branch_tail_span(expr: &Expr) -> rask_ast::Span {
+        match &expr.kind {
+            ExprKind::Block(stmts) => match stmts.last().map(|s| &s.kind) {
+                Some(StmtKind::Expr(tail)) => Self::branch_tail_span(tail),
+                _ => expr.span,
+            },
+            _ => expr.span,
+        }
+    }
+
+    /// Infer a branch tail, forwarding the enclosing expected type if any.
+    fn infer_branch(&mut self, expr: &Expr, expected: Option<&Type>) -> Type {
+        match expected {
+            Some(exp) => self.infer_expr_expecting(expr, exp),
+            None => self.infer_expr(expr),
+        }
+    }
+
+    /// `using Ctx(args) { }`: each argument names a constructor parameter
+    /// (or is positional, filling the next one) and counts must be integers.
+    fn check_context_args(&mut self, ctx: &str, params: &[&str], args: &[CallArg], span: Span) {
+        if args.len() > params.len() {
+            self.errors.push(TypeError::ArityMismatch {
+                expected: params.len(),
+                found: args.len(),
+                span,
+            });
+        }
+        for (i, arg) in args.iter().enumerate() {
+            if let Some(arg_name) = &arg.name {
+                if !params.contains(&arg_name.as_str()) {
+                    self.errors.push(TypeError::UnknownContextArg {
+                        ctx: ctx.to_string(),
+                        arg: arg_name.clone(),
+                        params: params.iter().map(|p| p.to_string()).collect(),
+                        span: arg.expr.span,
+                    });
+                }
+            }
+            let ty = self.infer_expr(&arg.expr);
+            if i >= params.len() {
+                continue;
+            }
+            match self.ctx.apply(&ty) {
+                Type::Var(_) => {
+                    self.ctx.add_constraint(TypeConstraint::Equal(Type::I64, ty, arg.expr.span));
+                }
+                t if Self::is_integer_type(&t) || matches!(t, Type::Error) => {}
+                found => self.errors.push(TypeError::Mismatch {
+                    expected: Type::I64,
+                    found,
+                    span: arg.expr.span,
+                }),
+            }
+        }
+    }
+
     fn is_integer_type(ty: &Type) -> bool {
         matches!(ty, Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128
                     | Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128)
@@ -110,12 +181,30 @@ impl TypeChecker {
         matches!(ty, Type::F32 | Type::F64)
     }
 
+    /// Whether a suffixed literal's value is representable in its type.
+    /// 64-bit and wider types hold every value the lexer produces.
+    pub(super) fn int_literal_fits(value: i128, ty: &Type) -> bool {
+        let (min, max) = match ty {
+            Type::I8 => (i8::MIN as i128, i8::MAX as i128),
+            Type::I16 => (i16::MIN as i128, i16::MAX as i128),
+            Type::I32 => (i32::MIN as i128, i32::MAX as i128),
+            Type::U8 => (0, u8::MAX as i128),
+            Type::U16 => (0, u16::MAX as i128),
+            Type::U32 => (0, u32::MAX as i128),
+            Type::U64 | Type::U128 => (0, i128::MAX),
+            _ => return true,
+        };
+        (min..=max).contains(&value)
+    }
+
     pub(super) fn infer_expr(&mut self, expr: &Expr) -> Type {
+        // Taken unconditionally so a hint never leaks into subexpressions.
+        let expected = self.expected_ty.take();
         let ty = match &expr.kind {
             // Literals
-            ExprKind::Int(_, suffix) => {
+            ExprKind::Int(v, suffix) => {
                 use rask_ast::token::IntSuffix;
-                match suffix {
+                let ty = match suffix {
                     Some(IntSuffix::I8) => Type::I8,
                     Some(IntSuffix::I16) => Type::I16,
                     Some(IntSuffix::I32) => Type::I32,
@@ -129,7 +218,23 @@ impl TypeChecker {
                     Some(IntSuffix::U128) => Type::U128,
                     Some(IntSuffix::Usize) => Type::U64,
                     None => self.ctx.fresh_literal_var(LiteralKind::Integer),
+                };
+                if suffix.is_some() {
+                    // `-128i8` arrives as the operand of a desugared `neg`
+                    let value = if self.negated_literal == Some(expr.id) {
+                        -(*v as i128)
+                    } else {
+                        *v as i128
+                    };
+                    if !Self::int_literal_fits(value, &ty) {
+                        self.errors.push(TypeError::IntLiteralOutOfRange {
+                            value,
+                            ty: ty.clone(),
+                            span: expr.span,
+                        });
+                    }
                 }
+                ty
             }
             ExprKind::Float(_, suffix) => {
                 use rask_ast::token::FloatSuffix;
@@ -157,6 +262,7 @@ impl TypeChecker {
                     return Type::Error;
                 }
                 if let Some(ty) = self.lookup_local(name) {
+                    self.check_task_capture(name, expr.span);
                     ty
                 } else if let Some(&sym_id) = self.resolved.resolutions.get(&expr.id) {
                     self.get_symbol_type(sym_id)
@@ -205,7 +311,19 @@ impl TypeChecker {
                 method,
                 args,
                 type_args,
-            } => self.check_method_call(object, method, args, type_args.as_deref(), expr.span),
+            } => {
+                if expr.id.0 >= DESUGAR_ID_BASE {
+                    let key = (expr.span.start, expr.span.end, expr.span.file_id);
+                    match method.as_str() {
+                        "to_string" if args.is_empty() => { self.interp_spans.insert(key); }
+                        "eq" | "lt" | "le" | "gt" | "ge" if args.len() == 1 => {
+                            self.comparison_spans.insert(key);
+                        }
+                        _ => {}
+                    }
+                }
+                self.check_method_call(object, method, args, type_args.as_deref(), expr.span)
+            }
 
             ExprKind::Field { object, field } => self.check_field_access(object, field, expr.span),
 
@@ -235,11 +353,12 @@ impl TypeChecker {
                             *elem.clone()
                         }
                     }
+                    // S9: the scalar case was rejected in `check_index_types`.
                     Type::String => {
                         if is_range {
                             Type::String
                         } else {
-                            Type::Char
+                            Type::Error
                         }
                     }
                     // Vec<T>, Pool<T>, Handle<T> → element from first type arg.
@@ -298,7 +417,7 @@ impl TypeChecker {
                     self.push_scope();
                     self.define_local(var_name.clone(), then_ty.clone());
                 }
-                let then_ty = self.infer_expr(then_branch);
+                let then_ty = self.infer_branch(then_branch, expected.as_ref());
                 if narrowing.is_some() || presence_narrowing.is_some() {
                     self.pop_scope();
                 }
@@ -331,7 +450,7 @@ impl TypeChecker {
                         self.push_scope();
                         self.define_local(name.clone(), err_ty.clone());
                     }
-                    let else_ty = self.infer_expr(else_branch);
+                    let else_ty = self.infer_branch(else_branch, expected.as_ref());
                     if else_narrow.is_some() {
                         self.pop_scope();
                     }
@@ -347,11 +466,13 @@ impl TypeChecker {
                         // don't need to agree. Return unit.
                         Type::Unit
                     } else {
-                        self.ctx.add_constraint(TypeConstraint::Equal(
-                            then_ty.clone(),
-                            else_ty,
-                            expr.span,
-                        ));
+                        self.ctx.add_constraint(TypeConstraint::BranchesAgree {
+                            first: then_ty.clone(),
+                            second: else_ty,
+                            first_span: Self::branch_tail_span(then_branch),
+                            second_span: Self::branch_tail_span(else_branch),
+                            expected: expected.clone(),
+                        });
                         then_ty
                     }
                 } else {
@@ -379,15 +500,17 @@ impl TypeChecker {
                         self.define_local(name, ty);
                     }
                 }
-                let then_ty = self.infer_expr(then_branch);
+                let then_ty = self.infer_branch(then_branch, expected.as_ref());
                 self.pop_scope();
                 if let Some(else_branch) = else_branch {
-                    let else_ty = self.infer_expr(else_branch);
-                    self.ctx.add_constraint(TypeConstraint::Equal(
-                        then_ty.clone(),
-                        else_ty,
-                        expr.span,
-                    ));
+                    let else_ty = self.infer_branch(else_branch, expected.as_ref());
+                    self.ctx.add_constraint(TypeConstraint::BranchesAgree {
+                        first: then_ty.clone(),
+                        second: else_ty,
+                        first_span: Self::branch_tail_span(then_branch),
+                        second_span: Self::branch_tail_span(else_branch),
+                        expected: expected.clone(),
+                    });
                 }
                 then_ty
             }
@@ -450,6 +573,8 @@ impl TypeChecker {
                     self.errors.push(TypeError::MatchOnOption { span: expr.span });
                 }
                 let result_ty = self.ctx.fresh_var();
+                // The first value-producing arm sets the type the rest must match
+                let mut first_arm_span = None;
                 for arm in arms {
                     self.push_scope();
                     let bindings = self.check_pattern(&arm.pattern, &scrutinee_ty, expr.span);
@@ -464,21 +589,37 @@ impl TypeChecker {
                             expr.span,
                         ));
                     }
-                    let arm_ty = self.infer_expr(&arm.body);
+                    let arm_ty = self.infer_branch(&arm.body, expected.as_ref());
                     self.pop_scope();
                     let resolved_arm_ty = self.ctx.apply(&arm_ty);
                     // In statement position, arm types don't need to agree.
                     if !is_stmt && !matches!(resolved_arm_ty, Type::Never) {
-                        self.ctx.add_constraint(TypeConstraint::Equal(
-                            result_ty.clone(),
-                            arm_ty,
-                            expr.span,
-                        ));
+                        let arm_span = Self::branch_tail_span(&arm.body);
+                        match first_arm_span {
+                            None => {
+                                first_arm_span = Some(arm_span);
+                                self.ctx.add_constraint(TypeConstraint::Equal(
+                                    result_ty.clone(),
+                                    arm_ty,
+                                    expr.span,
+                                ));
+                            }
+                            Some(first_span) => {
+                                self.ctx.add_constraint(TypeConstraint::BranchesAgree {
+                                    first: result_ty.clone(),
+                                    second: arm_ty,
+                                    first_span,
+                                    second_span: arm_span,
+                                    expected: expected.clone(),
+                                });
+                            }
+                        }
                     }
                 }
 
                 // Exhaustiveness check for enum scrutinees
                 self.check_match_exhaustiveness(&scrutinee_ty, arms, expr.span);
+                self.check_unreachable_arms(&scrutinee_ty, arms);
 
                 if is_stmt { Type::Unit } else { result_ty }
             }
@@ -495,7 +636,7 @@ impl TypeChecker {
                 }
                 let result = if let Some(last) = stmts.last() {
                     match &last.kind {
-                        StmtKind::Expr(e) => self.infer_expr(e),
+                        StmtKind::Expr(e) => self.infer_branch(e, expected.as_ref()),
                         StmtKind::Return(_) | StmtKind::Break { .. } | StmtKind::Continue(_) => {
                             Type::Never
                         }
@@ -594,16 +735,22 @@ impl TypeChecker {
             }
 
             ExprKind::Array(elements) => {
+                // `[]` takes its element type from context; `[1]` under
+                // `[i64]` types the literal as i64 rather than defaulting.
+                let expected_elem = match expected.as_ref().map(|t| self.ctx.apply(t)) {
+                    Some(Type::Array { elem, .. }) | Some(Type::Slice(elem)) => Some(*elem),
+                    _ => None,
+                };
                 if elements.is_empty() {
-                    let elem_ty = self.ctx.fresh_var();
+                    let elem_ty = expected_elem.unwrap_or_else(|| self.ctx.fresh_var());
                     Type::Array {
                         elem: Box::new(elem_ty),
                         len: 0,
                     }
                 } else {
-                    let first_ty = self.infer_expr(&elements[0]);
+                    let first_ty = self.infer_branch(&elements[0], expected_elem.as_ref());
                     for elem in &elements[1..] {
-                        let elem_ty = self.infer_expr(elem);
+                        let elem_ty = self.infer_branch(elem, expected_elem.as_ref());
                         self.ctx.add_constraint(TypeConstraint::Equal(
                             first_ty.clone(),
                             elem_ty,
@@ -843,6 +990,14 @@ impl TypeChecker {
                             Type::Error
                         }
                     }
+                    _ if else_clause.is_none() && resolved.is_never_optional() => {
+                        self.errors.push(TypeError::RedundantOptionalOp {
+                            op: "try".to_string(),
+                            found: resolved.clone(),
+                            span: expr.span,
+                        });
+                        resolved
+                    }
                     _ => {
                         self.errors.push(TypeError::TryOnNonResult {
                             found: resolved,
@@ -881,6 +1036,14 @@ impl TypeChecker {
                         // Extract the ok type (works for T? and T or E)
                         *ok.clone()
                     }
+                    _ if resolved.is_never_optional() => {
+                        self.errors.push(TypeError::RedundantOptionalOp {
+                            op: "!".to_string(),
+                            found: resolved.clone(),
+                            span: expr.span,
+                        });
+                        resolved
+                    }
                     Type::Var(_) => {
                         // Don't constrain yet - let later context determine if Option or Result
                         self.ctx.fresh_var()
@@ -917,9 +1080,11 @@ impl TypeChecker {
                 self.accumulate_errors = false;
                 let closure_return_type = self.ctx.fresh_var();
                 self.current_return_type = Some(closure_return_type.clone());
+                let outer_loops = std::mem::take(&mut self.loops);
 
                 let inferred_ret = self.infer_expr(body);
 
+                self.loops = outer_loops;
                 self.current_return_type = outer_return_type;
                 self.accumulate_errors = outer_accumulate;
                 self.inferred_errors = outer_inferred_errors;
@@ -998,16 +1163,23 @@ impl TypeChecker {
                 }
             }
 
-            ExprKind::Loop { body, .. } => {
-                for stmt in body {
-                    self.check_stmt(stmt);
+            ExprKind::Loop { label, body } => {
+                let is_stmt = self.in_stmt_expr;
+                self.in_stmt_expr = false;
+                self.push_scope();
+                let first_break = self.check_loop_body(label.clone(), !is_stmt, body);
+                self.pop_scope();
+                // Loop-as-expression gets its type from break values; with
+                // none it never finishes (CF17).
+                match first_break {
+                    Some((ty, _)) => ty,
+                    None => Type::Never,
                 }
-                // Loop-as-expression gets its type from break values
-                self.ctx.fresh_var()
             }
 
             ExprKind::Unsafe { body } => {
                 let was_unsafe = self.in_unsafe;
+                let ops_before = self.unsafe_ops.len();
                 self.in_unsafe = true;
                 for stmt in body {
                     self.check_stmt(stmt);
@@ -1022,19 +1194,33 @@ impl TypeChecker {
                     Type::Unit
                 };
                 self.in_unsafe = was_unsafe;
+                if self.unsafe_ops.len() == ops_before {
+                    self.errors.push(TypeError::UnnecessaryUnsafe { span: expr.span });
+                }
                 result
             }
 
             ExprKind::Comptime { body } => {
-                for stmt in body {
-                    self.check_stmt(stmt);
-                }
-                if let Some(last) = body.last() {
-                    if let StmtKind::Expr(e) = &last.kind {
-                        return self.infer_expr(e);
+                self.comptime_depth += 1;
+                // The trailing expression is inferred once, as the value —
+                // checking it as a statement too would report its errors twice.
+                let result = match body.split_last() {
+                    Some((last, rest)) => {
+                        for stmt in rest {
+                            self.check_stmt(stmt);
+                        }
+                        match &last.kind {
+                            StmtKind::Expr(e) => self.infer_expr(e),
+                            _ => {
+                                self.check_stmt(last);
+                                Type::Unit
+                            }
+                        }
                     }
-                }
-                Type::Unit
+                    None => Type::Unit,
+                };
+                self.comptime_depth -= 1;
+                result
             }
 
             ExprKind::Spawn { body } => {
@@ -1044,12 +1230,15 @@ impl TypeChecker {
                 }
 
                 // Spawn blocks are like anonymous functions - they have their own return type
+                let outer_capture_depth = self.begin_task_capture();
                 let outer_return_type = self.current_return_type.take();
                 let outer_accumulate = self.accumulate_errors;
                 let outer_inferred_errors = std::mem::take(&mut self.inferred_errors);
                 self.accumulate_errors = false;
                 let spawn_return_type = self.ctx.fresh_var();
                 self.current_return_type = Some(spawn_return_type.clone());
+                let outer_loops = std::mem::take(&mut self.loops);
+                self.push_scope();
 
                 // Check all statements except the last (which we infer separately)
                 let last_idx = body.len().saturating_sub(1);
@@ -1082,45 +1271,57 @@ impl TypeChecker {
                     expr.span,
                 ));
 
+                self.pop_scope();
+                self.loops = outer_loops;
                 self.current_return_type = outer_return_type;
                 self.accumulate_errors = outer_accumulate;
                 self.inferred_errors = outer_inferred_errors;
+                self.task_capture_depth = outer_capture_depth;
 
+                // conc.async: `join`/`cancel` on the handle yield `T or JoinError`
                 Type::UnresolvedGeneric {
-                    name: "ThreadHandle".to_string(),
+                    name: "TaskHandle".to_string(),
                     args: vec![GenericArg::Type(Box::new(spawn_return_type))],
                 }
             }
 
             ExprKind::UsingBlock { name, args, body } => {
-                // Validate context name
-                match name.as_str() {
-                    "Multitasking" | "MultiTasking" | "multitasking"
-                    | "ThreadPool" | "threadpool" => {}
-                    _ => {
+                // The context must be one we can construct, with arguments
+                // its constructor accepts.
+                match super::check_fn::runtime_context_params(name) {
+                    Some(params) => self.check_context_args(name, params, args, expr.span),
+                    None => {
                         self.errors.push(TypeError::UnknownContext {
                             name: name.clone(),
+                            suggestion: super::check_fn::closest_runtime_context(name),
                             span: expr.span,
                         });
+                        for arg in args {
+                            self.infer_expr(&arg.expr);
+                        }
                     }
                 }
-                for arg in args {
-                    self.infer_expr(&arg.expr);
-                }
                 // CC1: track nesting depth so spawn() inside this block is allowed
                 let is_multitasking = matches!(
                     name.as_str(),
                     "Multitasking" | "MultiTasking" | "multitasking"
                 );
+                let is_threadpool = matches!(name.as_str(), "ThreadPool" | "threadpool");
                 if is_multitasking {
                     self.multitasking_depth += 1;
                 }
+                if is_threadpool {
+                    self.threadpool_depth += 1;
+                }
                 for stmt in body {
                     self.check_stmt(stmt);
                 }
                 if is_multitasking {
                     self.multitasking_depth -= 1;
                 }
+                if is_threadpool {
+                    self.threadpool_depth -= 1;
+                }
                 // Check if the block ends with a diverging statement (return/break/continue)
                 if let Some(last) = body.last() {
                     match &last.kind {
@@ -1200,10 +1401,14 @@ impl TypeChecker {
             ExprKind::ArrayRepeat { value, count } => {
                 let elem_ty = self.infer_expr(value);
                 self.infer_expr(count);
-                // Extract literal size when available, otherwise use 0 as placeholder
-                let len = match &count.kind {
-                    ExprKind::Int(n, _) => *n as usize,
-                    _ => 0,
+                // The count must fold to a constant; lengths only known at
+                // monomorphization use 0 as placeholder
+                let len = match self.eval_const_usize(count) {
+                    Ok(len) => len.unwrap_or(0),
+                    Err(e) => {
+                        self.errors.push(e);
+                        0
+                    }
                 };
                 Type::Array {
                     elem: Box::new(elem_ty),
@@ -1216,6 +1421,20 @@ impl TypeChecker {
                 let val_ty = self.infer_expr(value);
                 let def_ty = self.infer_expr(default);
                 let resolved_def = self.ctx.apply(&def_ty);
+                let resolved_val = self.ctx.apply(&val_ty);
+                if resolved_val.is_never_optional() {
+                    self.errors.push(TypeError::RedundantOptionalOp {
+                        op: "??".to_string(),
+                        found: resolved_val.clone(),
+                        span: expr.span,
+                    });
+                    if !matches!(resolved_def, Type::Never) {
+                        if let Err(e) = self.unify(&def_ty, &resolved_val, default.span) {
+                            self.errors.push(e);
+                        }
+                    }
+                    return resolved_val;
+                }
                 // OPT13: diverging default (`?? return y`, `?? break`, `?? continue`,
                 // `?? panic(…)`) unwraps the scrutinee and yields the inner type.
                 if matches!(resolved_def, Type::Never) {
@@ -1237,28 +1456,16 @@ impl TypeChecker {
             }
 
             ExprKind::OptionalField { object, field } => {
-                let inferred = self.infer_expr(object);
-                let obj_ty = self.ctx.apply(&inferred);
-                // ?. unwraps Option, accesses field, wraps in Option (flatten if already Option)
-                let inner_ty = match obj_ty.as_option() {
-                    Some(inner) => inner.clone(),
-                    None => obj_ty.clone(),
-                };
-                let field_ty = self.ctx.fresh_var();
-                self.ctx.add_constraint(TypeConstraint::HasField {
-                    ty: inner_ty,
+                let obj_ty = self.infer_expr(object);
+                let result = self.ctx.fresh_var();
+                self.ctx.add_constraint(TypeConstraint::OptionalField {
+                    object: obj_ty,
                     field: field.clone(),
-                    expected: field_ty.clone(),
+                    result: result.clone(),
                     span: expr.span,
                     self_type: self.current_self_type.clone(),
                 });
-                // Flatten: if field is already T?, return T? not (T?)?
-                let resolved_field = self.ctx.apply(&field_ty);
-                if resolved_field.is_option() {
-                    resolved_field
-                } else {
-                    Type::option(field_ty)
-                }
+                result
             }
 
             ExprKind::Select { arms, .. } => {
@@ -1413,18 +1620,43 @@ impl TypeChecker {
 
         // Extern and unsafe function calls require unsafe context
         // Also: CC1 — spawn() must be inside a `using Multitasking { }` block
+        let mut is_spawn = false;
         if let ExprKind::Ident(_) = &func.kind {
             if let Some(&sym_id) = self.resolved.resolutions.get(&func.id) {
                 if let Some(sym) = self.resolved.symbols.get(sym_id) {
                     // CC1: spawn() outside any using Multitasking block
+                    // Without `import async.spawn` the name is the async.rk stub.
+                    is_spawn = matches!(&sym.kind, SymbolKind::Function { ret_ty: Some(ret), .. }
+                        if sym.name == "spawn" && ret.starts_with("TaskHandle"));
                     if matches!(&sym.kind, SymbolKind::BuiltinFunction { builtin }
                         if *builtin == rask_resolve::BuiltinFunctionKind::Spawn)
                     {
+                        is_spawn = true;
                         if self.multitasking_depth == 0 {
                             self.errors.push(TypeError::SpawnOutsideBlock { span });
                         }
                     }
 
+                    // CT3/CT6: calls must match the callee's stage
+                    if let (SymbolKind::Function { .. }, ExprKind::Ident(name)) = (&sym.kind, &func.kind) {
+                        match self.fn_stages.get(name) {
+                            Some(super::staging::FnStage::ComptimeOnly) if self.comptime_depth == 0 => {
+                                self.errors.push(TypeError::ComptimeOnlyCall {
+                                    name: name.clone(),
+                                    span,
+                                });
+                            }
+                            Some(super::staging::FnStage::RuntimeOnly { reason }) if self.comptime_depth > 0 => {
+                                self.errors.push(TypeError::RuntimeOnlyCallAtComptime {
+                                    name: name.clone(),
+                                    reason: reason.clone(),
+                                    span,
+                                });
+                            }
+                            _ => {}
+                        }
+                    }
+
                     let unsafe_category = match &sym.kind {
                         SymbolKind::ExternFunction { .. } => Some(super::UnsafeCategory::ExternCall),
                         SymbolKind::Function { is_unsafe: true, .. } => Some(super::UnsafeCategory::UnsafeFuncCall),
@@ -1447,6 +1679,30 @@ impl TypeChecker {
             }
         }
 
+        // conc.async: `spawn(|| body)` yields TaskHandle<T> for the closure's
+        // result T, so `join`/`cancel` give `T or JoinError`. An optional
+        // `name: string` labels the task in the runtime registry (TN1).
+        let (task_name, closure_args): (Vec<_>, Vec<_>) =
+            args.iter().partition(|a| a.name.as_deref() == Some("name"));
+        if is_spawn && closure_args.len() == 1 && task_name.len() <= 1 {
+            self.infer_expr(func);
+            for a in &task_name {
+                let name_ty = self.infer_expr(&a.expr);
+                self.ctx.add_constraint(TypeConstraint::Equal(Type::String, name_ty, a.expr.span));
+            }
+            let outer_capture_depth = self.begin_task_capture();
+            let closure_ty = self.infer_expr(&closure_args[0].expr);
+            self.task_capture_depth = outer_capture_depth;
+            let inner = match self.ctx.apply(&closure_ty) {
+                Type::Fn { ret, .. } => *ret,
+                _ => self.ctx.fresh_var(),
+            };
+            return Type::UnresolvedGeneric {
+                name: "TaskHandle".to_string(),
+                args: vec![GenericArg::Type(Box::new(inner))],
+            };
+        }
+
         // Call-site annotations (mutate/own) are optional — IDE shows ghost
         // annotations but the compiler doesn't require them (spec decision).
         // Validate when present, but don't error on missing annotations.
@@ -1478,6 +1734,20 @@ impl TypeChecker {
 
         let func_ty = self.infer_expr(func);
 
+        if let (Some(pairs), ExprKind::Ident(name)) = (&generic_subst, &func.kind) {
+            // Explicit `f<T>(...)` binds the fresh vars up front.
+            self.bind_explicit_type_args(name, pairs, span);
+            let base = name.split('<').next().unwrap_or(name).to_string();
+            let comptime = self.resolved.resolutions.get(&func.id)
+                .and_then(|sym_id| self.fn_comptime_params.get(sym_id));
+            let inferable: Vec<(String, Type)> = pairs.iter()
+                .filter(|(p, _)| !comptime.map_or(false, |c| c.contains(p)))
+                .cloned()
+                .collect();
+            let arg_ids = args.iter().map(|a| a.expr.id).collect();
+            self.pending_type_param_checks.push((base, inferable, arg_ids, span));
+        }
+
         // Substitute type param names with fresh vars in the function signature
         let func_ty = if let Some(ref pairs) = generic_subst {
             let subst: std::collections::HashMap<&str, Type> = pairs.iter()
@@ -1508,7 +1778,9 @@ impl TypeChecker {
                 // Propagate expected param types to arguments
                 let ret = *ret.clone();
                 for (param, arg) in params.clone().iter().zip(args.iter()) {
-                    // TR5: record implicit trait coercion for MIR boxing
+                    // TR5: record implicit trait coercion for MIR boxing. The
+                    // parameter is always dispatched dynamically — MIR boxes
+                    // the value and builds the vtable at the call site.
                     if let Type::TraitObject { ref trait_name } = param {
                         let is_explicit_cast = matches!(
                             &arg.expr.kind,
@@ -1516,6 +1788,16 @@ impl TypeChecker {
                         );
                         if !is_explicit_cast {
                             let arg_ty = self.infer_expr(&arg.expr);
+                            if let Type::Named(id) = arg_ty {
+                                if !crate::traits::implements_trait(&self.types, &arg_ty, trait_name) {
+                                    self.errors.push(TypeError::TraitNotSatisfied {
+                                        ty: self.types.type_name(id),
+                                        trait_name: trait_name.clone(),
+                                        span: arg.expr.span,
+                                    });
+                                    continue;
+                                }
+                            }
                             if !matches!(arg_ty, Type::TraitObject { .. } | Type::Error) {
                                 self.trait_coercions.insert(
                                     arg.expr.id,
@@ -1564,7 +1846,7 @@ impl TypeChecker {
     pub(super) fn is_builtin_function(&self, name: &str) -> bool {
         matches!(name, "println" | "print" | "panic" | "todo" | "unreachable"
             | "assert" | "debug" | "format" | "fence" | "compiler_fence"
-            | "assert_eq" | "skip" | "expect_fail")
+            | "assert_eq" | "assert_ne" | "skip" | "expect_fail")
     }
 
     /// Validate that call-site annotations match parameter declarations.
@@ -1720,7 +2002,18 @@ impl TypeChecker {
                 } else {
                     Type::UnresolvedNamed(name.clone())
                 };
+                // CC4/CC5: pool tasks need a pool, and the closure body runs on
+                // another thread — locals it reads are captures into the task.
+                let is_thread_spawn = matches!(base_name, "Thread" | "ThreadPool") && method == "spawn";
+                if base_name == "ThreadPool" && is_thread_spawn && self.threadpool_depth == 0 {
+                    self.errors.push(TypeError::PoolSpawnOutsideBlock { span });
+                }
+                let outer_capture_depth = self.task_capture_depth;
+                if is_thread_spawn {
+                    self.begin_task_capture();
+                }
                 let arg_types: Vec<_> = args.iter().map(|a| self.infer_expr(&a.expr)).collect();
+                self.task_capture_depth = outer_capture_depth;
                 let ret_ty = self.ctx.fresh_var();
                 self.ctx.add_constraint(TypeConstraint::HasMethod {
                     ty: obj_ty,
@@ -1808,7 +2101,11 @@ impl TypeChecker {
             self.push_borrow(var_name.clone(), mode, object.span);
         }
 
+        if method == "neg" && args.is_empty() && matches!(object.kind, ExprKind::Int(_, Some(_))) {
+            self.negated_literal = Some(object.id);
+        }
         let obj_ty_raw = self.infer_expr(object);
+        self.negated_literal = None;
         let obj_ty = self.resolve_named(&obj_ty_raw);
         let arg_types: Vec<_> = args.iter().map(|a| self.infer_expr(&a.expr)).collect();
 
@@ -2288,6 +2585,25 @@ impl TypeChecker {
             return;
         }
 
+        // No set of literals covers every string; only a catch-all does.
+        if matches!(resolved, Type::String) {
+            let is_catch_all = |p: &Pattern| match p {
+                Pattern::Wildcard => true,
+                Pattern::Ident(name) => name.starts_with(|c: char| c.is_lowercase() || c == '_'),
+                _ => false,
+            };
+            let covered = arms.iter().any(|arm| {
+                arm.guard.is_none() && match &arm.pattern {
+                    Pattern::Or(alts) => alts.iter().any(is_catch_all),
+                    p => is_catch_all(p),
+                }
+            });
+            if !covered {
+                self.errors.push(TypeError::MissingCatchAll { ty: "string".to_string(), span });
+            }
+            return;
+        }
+
         // Only check enums for the Named case
         let type_id = match &resolved {
             Type::Named(id) => *id,
@@ -2325,6 +2641,31 @@ impl TypeChecker {
         }
     }
 
+    /// Warn on arms that an earlier unguarded arm already covers — a
+    /// catch-all followed by more arms, or the same literal/variant twice.
+    fn check_unreachable_arms(&mut self, scrutinee_ty: &Type, arms: &[MatchArm]) {
+        let variants: Vec<String> = match self.ctx.apply(scrutinee_ty) {
+            Type::Named(id) => match self.types.get(id) {
+                Some(TypeDef::Enum { variants, .. }) => variants.iter().map(|(n, _)| n.clone()).collect(),
+                _ => Vec::new(),
+            },
+            // ER30: bare names in `T or E` matches are type patterns, not bindings
+            Type::Result { .. } => return,
+            _ => Vec::new(),
+        };
+        for (i, arm) in arms.iter().enumerate() {
+            let shadow = arms[..i]
+                .iter()
+                .find(|prev| prev.guard.is_none() && pattern_subsumes(&prev.pattern, &arm.pattern, &variants));
+            if let Some(prev) = shadow {
+                self.errors.push(TypeError::UnreachableArm {
+                    span: arm.span,
+                    shadowed_by: prev.span,
+                });
+            }
+        }
+    }
+
     fn collect_result_covered(
         &self,
         pattern: &Pattern,
@@ -2696,6 +3037,16 @@ impl TypeChecker {
     /// `validate_pending_index` so a literal index can adapt to the key/element
     /// type after constraint solving.
     fn check_index_types(&mut self, container: &Type, index: &Type, is_range: bool, span: Span) {
+        // std.strings/S9: no `s[i]` — the caller must pick bytes or chars.
+        if matches!(container, Type::String) && !is_range {
+            self.errors.push(TypeError::IndexTypeMismatch {
+                container: container.clone(),
+                found: index.clone(),
+                kind: IndexErrorKind::StringPosition,
+                span,
+            });
+            return;
+        }
         match self.classify_index_container(container) {
             Some(IndexContainer::Sequence) => {
                 // A range index is a valid slice; a scalar index must be integer.
@@ -2770,6 +3121,34 @@ impl TypeChecker {
         }
     }
 
+    /// CT56: evaluate a type-level constant (array length, repeat count)
+    /// with the comptime engine. `Ok(None)` means the value isn't known yet —
+    /// it names a comptime parameter, sits inside comptime code, or calls
+    /// something the engine can't fold here — and is settled at
+    /// monomorphization. Runtime locals, negative values, and hard comptime
+    /// failures are errors.
+    pub(super) fn eval_const_usize(&mut self, expr: &Expr) -> Result<Option<usize>, TypeError> {
+        use rask_comptime::ComptimeError;
+        match self.comptime.eval_const_usize(expr) {
+            Ok(n) => Ok(Some(n)),
+            Err(ComptimeError::NegativeSize(value)) => Err(TypeError::NegativeArrayLength {
+                value,
+                span: expr.span,
+            }),
+            Err(ComptimeError::UndefinedVariable(name)) => {
+                if self.comptime_depth == 0 && self.lookup_local(&name).is_some() {
+                    Err(TypeError::ArrayLengthNotConstant { name, span: expr.span })
+                } else {
+                    Ok(None)
+                }
+            }
+            Err(e) if e.is_hard() || matches!(e, ComptimeError::TypeMismatch { .. }) => {
+                Err(TypeError::ConstEval { message: e.to_string(), span: expr.span })
+            }
+            Err(_) => Ok(None),
+        }
+    }
+
     /// Name of a builtin generic container, matching by TypeId (resolved) or by
     /// spelling (unresolved).
     fn generic_base_name(&self, ty: &Type) -> Option<&'static str> {
@@ -2981,16 +3360,11 @@ enum Prim {
 
 fn prim_of(ty: &Type) -> Option<Prim> {
     Some(match ty {
-        Type::I8 => Prim::Int { bits: 8, signed: true },
-        Type::I16 => Prim::Int { bits: 16, signed: true },
-        Type::I32 => Prim::Int { bits: 32, signed: true },
-        Type::I64 => Prim::Int { bits: 64, signed: true },
-        Type::I128 => Prim::Int { bits: 128, signed: true },
-        Type::U8 => Prim::Int { bits: 8, signed: false },
-        Type::U16 => Prim::Int { bits: 16, signed: false },
-        Type::U32 => Prim::Int { bits: 32, signed: false },
-        Type::U64 => Prim::Int { bits: 64, signed: false },
-        Type::U128 => Prim::Int { bits: 128, signed: false },
+        Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128
+        | Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128 => {
+            let IntWidth { bits, signed } = IntWidth::of(&ty.to_string())?;
+            Prim::Int { bits, signed }
+        }
         Type::F32 => Prim::Float { bits: 32 },
         Type::F64 => Prim::Float { bits: 64 },
         Type::Bool => Prim::Bool,
@@ -3016,7 +3390,7 @@ fn as_cast_is_lossless(src_ty: &Type, tgt_ty: &Type, s: Prim, t: Prim) -> bool {
     }
     match (s, t) {
         (Prim::Int { bits: sb, signed: ss }, Prim::Int { bits: tb, signed: ts }) => {
-            tb > sb && (ss == ts || !ss)
+            IntWidth { bits: sb, signed: ss }.widens_losslessly_to(IntWidth { bits: tb, signed: ts })
         }
         (Prim::Int { .. }, Prim::Float { .. }) => true,
         (Prim::Float { bits: sb }, Prim::Float { bits: tb }) => tb >= sb,
@@ -3041,3 +3415,72 @@ fn classify_invalid_cast(s: Prim, t: Prim) -> InvalidCastClass {
         _ => InvalidCastClass::Other,
     }
 }
+
+/// Whether every value matched by `later` is already matched by `earlier`.
+/// Conservative: anything it can't decide counts as not subsumed.
+fn pattern_subsumes(earlier: &Pattern, later: &Pattern, variants: &[String]) -> bool {
+    let is_catch_all = |p: &Pattern| match p {
+        Pattern::Wildcard => true,
+        // PascalCase names are variants even when the enum isn't known here
+        Pattern::Ident(name) => {
+            name.starts_with(|c: char| c.is_lowercase() || c == '_')
+                && !variants.iter().any(|v| v == variant_name(name))
+        }
+        _ => false,
+    };
+    if is_catch_all(earlier) {
+        return true;
+    }
+    match (earlier, later) {
+        (Pattern::Or(alts), _) => alts.iter().any(|a| pattern_subsumes(a, later, variants)),
+        (_, Pattern::Or(alts)) => alts.iter().all(|a| pattern_subsumes(earlier, a, variants)),
+        (Pattern::Ident(a), Pattern::Ident(b)) => variant_name(a) == variant_name(b),
+        (Pattern::Literal(a), Pattern::Literal(b)) => {
+            matches!((literal_key(a), literal_key(b)), (Some(x), Some(y)) if x == y)
+        }
+        (Pattern::Range { start, end }, Pattern::Literal(lit)) => {
+            match (literal_key(start), literal_key(end), literal_key(lit)) {
+                (Some(LitKey::Int(lo)), Some(LitKey::Int(hi)), Some(LitKey::Int(v))) => lo <= v && v <= hi,
+                (Some(LitKey::Char(lo)), Some(LitKey::Char(hi)), Some(LitKey::Char(v))) => lo <= v && v <= hi,
+                _ => false,
+            }
+        }
+        (Pattern::Constructor { name: a, fields: fa }, Pattern::Constructor { name: b, fields: fb }) => {
+            variant_name(a) == variant_name(b)
+                && fa.len() == fb.len()
+                && fa.iter().zip(fb).all(|(x, y)| pattern_subsumes(x, y, variants))
+        }
+        (Pattern::Tuple(xs), Pattern::Tuple(ys)) => {
+            xs.len() == ys.len() && xs.iter().zip(ys).all(|(x, y)| pattern_subsumes(x, y, variants))
+        }
+        (Pattern::TypePat { ty_name: a, .. }, Pattern::TypePat { ty_name: b, .. }) => a == b,
+        _ => false,
+    }
+}
+
+/// `Enum.Variant` → `Variant`.
+fn variant_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+#[derive(PartialEq)]
+enum LitKey<'a> {
+    Int(i64),
+    Str(&'a str),
+    Char(char),
+    Bool(bool),
+}
+
+fn literal_key(expr: &Expr) -> Option<LitKey<'_>> {
+    match &expr.kind {
+        ExprKind::Int(v, _) => Some(LitKey::Int(*v)),
+        ExprKind::String(s) => Some(LitKey::Str(s)),
+        ExprKind::Char(c) => Some(LitKey::Char(*c)),
+        ExprKind::Bool(b) => Some(LitKey::Bool(*b)),
+        ExprKind::Unary { op: rask_ast::expr::UnaryOp::Neg, operand } => match operand.kind {
+            ExprKind::Int(v, _) => Some(LitKey::Int(-v)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -103,6 +103,25 @@ impl TypeChecker {
                     ctx: cc.ty.clone(),
                     span: f.span,
                 });
+                continue;
+            }
+            // CC1–CC3/AL7: only `Pool<T>` and `Allocator` can be threaded as contexts
+            let base = cc.ty.split('<').next().unwrap_or("").trim();
+            match base {
+                "Allocator" => {}
+                "Pool" => {
+                    if let Ok(ty) = parse_type_string(&cc.ty, &self.types) {
+                        self.validate_signature_names(&ty, &sig_type_params, cc.span);
+                    }
+                }
+                _ => {
+                    let suggestion = closest_context_name(base);
+                    self.errors.push(TypeError::UnknownContextType {
+                        ty: cc.ty.clone(),
+                        suggestion,
+                        span: cc.span,
+                    });
+                }
             }
         }
 
@@ -136,14 +155,21 @@ impl TypeChecker {
             }
         }
 
-        // Reset multitasking depth for each function body
+        // Reset runtime block depths for each function body
         self.multitasking_depth = 0;
+        self.threadpool_depth = 0;
+        // CT60: a comptime func body is itself a comptime position
+        let was_comptime_depth = self.comptime_depth;
+        self.comptime_depth = u32::from(f.is_comptime);
 
         self.push_scope();
         for param in &f.params {
             if param.name == "self" {
                 if let Some(self_ty) = self.current_self_type.clone() {
                     if inferred_self_mutate || param.is_mutate || param.is_take {
+                        if !param.is_take {
+                            self.mutate_params.insert("self".to_string());
+                        }
                         self.define_local("self".to_string(), self_ty.clone());
                     } else {
                         self.define_local_param("self".to_string(), self_ty.clone());
@@ -172,6 +198,9 @@ impl TypeChecker {
             // ER3/ER4: validate nested `T or E` in parameter types.
             self.validate_result_types_in(&ty, param.name_span);
             if param.is_mutate || param.is_take {
+                if param.is_mutate {
+                    self.mutate_params.insert(param.name.clone());
+                }
                 self.define_local(param.name.clone(), ty.clone());
             } else {
                 self.define_local_param(param.name.clone(), ty.clone());
@@ -255,7 +284,9 @@ impl TypeChecker {
 
         self.pop_scope();
         self.current_return_type = None;
+        self.mutate_params.clear();
         self.in_unsafe = was_unsafe;
+        self.comptime_depth = was_comptime_depth;
 
         // ER20: Restore outer accumulation state
         self.accumulate_errors = old_accumulate;
@@ -547,10 +578,39 @@ impl TypeChecker {
     }
 }
 
-fn is_runtime_context(ty: &str) -> bool {
+pub(super) fn is_runtime_context(ty: &str) -> bool {
     matches!(ty, "Multitasking" | "MultiTasking" | "multitasking" | "ThreadPool" | "threadpool")
 }
 
+/// Constructor parameters of a runtime context, or `None` if `name` isn't one.
+/// Both take an optional worker count and task bound:
+/// `using ThreadPool(workers: 4, tasks: 64) { }`.
+pub(super) fn runtime_context_params(name: &str) -> Option<&'static [&'static str]> {
+    is_runtime_context(name).then_some(&["workers", "tasks"][..])
+}
+
+/// Closest runtime context name, for `using` block typos.
+pub(super) fn closest_runtime_context(name: &str) -> Option<String> {
+    let max_dist = (name.len() / 3).max(1);
+    ["Multitasking", "ThreadPool"]
+        .iter()
+        .map(|cand| (edit_distance(&name.to_lowercase(), &cand.to_lowercase()), cand))
+        .filter(|(d, _)| *d <= max_dist)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, cand)| cand.to_string())
+}
+
+/// Closest context-capable type name, for `using` clause typos.
+fn closest_context_name(name: &str) -> Option<String> {
+    let max_dist = (name.len() / 3).max(1);
+    ["Pool", "Allocator"]
+        .iter()
+        .map(|cand| (edit_distance(name, cand), cand))
+        .filter(|(d, _)| *d <= max_dist)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, cand)| cand.to_string())
+}
+
 /// Levenshtein distance, for type-name suggestions.
 fn edit_distance(a: &str, b: &str) -> usize {
     let a: Vec<char> = a.chars().collect();
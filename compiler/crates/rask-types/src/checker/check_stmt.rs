@@ -3,13 +3,23 @@
 
 use rask_ast::expr::{Expr, ExprKind};
 use rask_ast::stmt::{ForBinding, Stmt, StmtKind};
+use rask_ast::Span;
 
 use super::errors::TypeError;
 use super::inference::{TypeConstraint, WrapPosition};
 use super::parse_type::parse_type_string;
 use super::TypeChecker;
 
-use crate::types::Type;
+use crate::types::{GenericArg, Type};
+
+/// A loop the checker is inside of, for matching `break` to its target.
+pub(crate) struct LoopFrame {
+    label: Option<String>,
+    /// Only a `loop` in value position has a type for breaks to agree on.
+    yields_value: bool,
+    /// Type and span of the first `break` that targets this loop.
+    first_break: Option<(Type, Span)>,
+}
 
 impl TypeChecker {
     // ------------------------------------------------------------------------
@@ -23,6 +33,7 @@ impl TypeChecker {
                 self.in_stmt_expr = true;
                 self.infer_expr(expr);
                 self.in_stmt_expr = was;
+                self.defer_temporary_mutation_check(expr);
                 // E5: Bare sync access without chaining is a compile error
                 self.check_bare_sync_access(expr);
                 // ESAD Phase 1: Clear borrows at statement end (semicolon)
@@ -164,6 +175,9 @@ impl TypeChecker {
                 } else {
                     Type::Unit
                 };
+                if let Some(expr) = value {
+                    self.check_returned_view(expr);
+                }
                 if let Some(expected) = &self.current_return_type {
                     // Defer auto-wrap — the solver resolves this after
                     // method/field constraints are solved, so we know if the
@@ -183,16 +197,21 @@ impl TypeChecker {
                 self.ctx
                     .add_constraint(TypeConstraint::Equal(Type::Bool, cond_ty, stmt.span));
                 self.push_scope();
-                for s in body {
-                    self.check_stmt(s);
-                }
+                self.check_loop_body(None, false, body);
                 self.pop_scope();
             }
-            StmtKind::For { binding, iter, body, .. } => {
+            StmtKind::For { label, binding, iter, body, .. } => {
                 let iter_ty = self.infer_expr(iter);
                 self.push_scope();
-                let elem_ty = match &iter_ty {
-                    Type::Array { elem, .. } | Type::Slice(elem) => *elem.clone(),
+                let elem_ty = match self.ctx.apply(&iter_ty) {
+                    Type::Array { elem, .. } | Type::Slice(elem) => *elem,
+                    // CH3: iterating a receiver yields messages until closed
+                    Type::UnresolvedGeneric { name, args } if name == "Receiver" => {
+                        match args.into_iter().next() {
+                            Some(GenericArg::Type(t)) => *t,
+                            _ => self.ctx.fresh_var(),
+                        }
+                    }
                     _ => self.ctx.fresh_var(),
                 };
                 match binding {
@@ -204,15 +223,11 @@ impl TypeChecker {
                         }
                     }
                 }
-                for s in body {
-                    self.check_stmt(s);
-                }
+                self.check_loop_body(label.clone(), false, body);
                 self.pop_scope();
             }
-            StmtKind::Break { value, .. } => {
-                if let Some(v) = value {
-                    self.infer_expr(v);
-                }
+            StmtKind::Break { label, value } => {
+                self.check_break(label.as_deref(), value.as_ref(), stmt.span);
             }
             StmtKind::Continue(_) => {}
             StmtKind::Ensure { body, else_handler } => {
@@ -226,13 +241,23 @@ impl TypeChecker {
                 }
             }
             StmtKind::Comptime(body) => {
+                // CT57: branches of a `comptime if` stay runtime code, so only
+                // a plain comptime block is a comptime position for calls.
+                let is_comptime_if = matches!(body.as_slice(),
+                    [only] if matches!(&only.kind, StmtKind::Expr(e) if matches!(e.kind, ExprKind::If { .. })));
+                let depth = u32::from(!is_comptime_if);
+                self.comptime_depth += depth;
                 for s in body {
                     self.check_stmt(s);
                 }
+                self.comptime_depth -= depth;
             }
             StmtKind::ComptimeFor { binding, iter, body, .. } => {
-                // CT48–CT54: comptime for loop type checking
+                // CT48–CT54: comptime for loop type checking. The iterable is
+                // a comptime position (CT56); the body is residue (CT57).
+                self.comptime_depth += 1;
                 let iter_ty = self.infer_expr(iter);
+                self.comptime_depth -= 1;
                 self.push_scope();
                 let elem_ty = match &iter_ty {
                     Type::Array { elem, .. } | Type::Slice(elem) => *elem.clone(),
@@ -247,9 +272,7 @@ impl TypeChecker {
                         }
                     }
                 }
-                for s in body {
-                    self.check_stmt(s);
-                }
+                self.check_loop_body(None, false, body);
                 self.pop_scope();
             }
             StmtKind::MutTuple { patterns, init } | StmtKind::ConstTuple { patterns, init } => {
@@ -264,16 +287,12 @@ impl TypeChecker {
                 for (name, ty) in bindings {
                     self.define_local(name, ty);
                 }
-                for s in body {
-                    self.check_stmt(s);
-                }
+                self.check_loop_body(None, false, body);
                 self.pop_scope();
             }
-            StmtKind::Loop { body, .. } => {
+            StmtKind::Loop { label, body } => {
                 self.push_scope();
-                for s in body {
-                    self.check_stmt(s);
-                }
+                self.check_loop_body(label.clone(), false, body);
                 self.pop_scope();
             }
             StmtKind::Discard { name, name_span } => {
@@ -303,6 +322,50 @@ impl TypeChecker {
         }
     }
 
+    /// Check a loop body with a frame pushed for `break` to target.
+    /// Returns the first break's type and span, if any break reached it.
+    pub(super) fn check_loop_body(
+        &mut self,
+        label: Option<String>,
+        yields_value: bool,
+        body: &[Stmt],
+    ) -> Option<(Type, Span)> {
+        self.loops.push(LoopFrame { label, yields_value, first_break: None });
+        for s in body {
+            self.check_stmt(s);
+        }
+        self.loops.pop().and_then(|frame| frame.first_break)
+    }
+
+    /// CF16–CF18: a `break` out of a value-position `loop` must agree with
+    /// every other break of that loop; a bare `break` produces `()`.
+    fn check_break(&mut self, label: Option<&str>, value: Option<&Expr>, span: Span) {
+        let (ty, span) = match value {
+            Some(v) => (self.infer_expr(v), v.span),
+            None => (Type::Unit, span),
+        };
+        let target = match label {
+            Some(l) => self.loops.iter_mut().rev().find(|f| f.label.as_deref() == Some(l)),
+            None => self.loops.last_mut(),
+        };
+        let Some(frame) = target else { return };
+        if !frame.yields_value {
+            return;
+        }
+        match &frame.first_break {
+            None => frame.first_break = Some((ty, span)),
+            Some((first, first_span)) => {
+                let constraint = TypeConstraint::BreaksAgree {
+                    first: first.clone(),
+                    second: ty,
+                    first_span: *first_span,
+                    second_span: span,
+                };
+                self.ctx.add_constraint(constraint);
+            }
+        }
+    }
+
     /// Recursively bind tuple destructuring patterns to types.
     /// Handles nested patterns like `(a, (b, c))` matched against `(i32, (i32, i32))`.
     fn bind_tuple_patterns(
@@ -358,7 +421,7 @@ impl TypeChecker {
     }
 
     /// Check if a type is a primitive Copy type (trivially cleaned up).
-    fn is_copy_type(&self, ty: &Type) -> bool {
+    pub(super) fn is_copy_type(&self, ty: &Type) -> bool {
         matches!(
             ty,
             Type::Bool | Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128
@@ -376,6 +439,53 @@ impl TypeChecker {
         }
     }
 
+    // ── Method receivers that are temporaries ───────────────────────────
+    //
+    // `Point { x: 1, y: 2 }.bump()` as a statement mutates a value nobody
+    // can observe afterwards. `take self` on a temporary is fine — the
+    // temporary is simply consumed.
+
+    /// Queue a discarded method call on a temporary receiver. The method's
+    /// self mode is only known once the receiver type is solved.
+    fn defer_temporary_mutation_check(&mut self, expr: &Expr) {
+        let ExprKind::MethodCall { object, method, .. } = &expr.kind else { return };
+        if !self.is_temporary(object) {
+            return;
+        }
+        if let Some(ty) = self.node_types.get(&object.id).cloned() {
+            self.pending_temporary_mutations.push((ty, method.clone(), expr.span));
+        }
+    }
+
+    /// Expressions that produce a fresh value with no binding to observe it.
+    fn is_temporary(&self, expr: &Expr) -> bool {
+        match &expr.kind {
+            ExprKind::StructLit { .. }
+            | ExprKind::Call { .. }
+            | ExprKind::Array(_)
+            | ExprKind::ArrayRepeat { .. }
+            | ExprKind::Tuple(_) => true,
+            // Static constructor: `Point.new(1, 2)`.
+            ExprKind::MethodCall { object, .. } => matches!(
+                &object.kind,
+                ExprKind::Ident(name)
+                    if self.lookup_local(name).is_none() && self.types.get_type_id(name).is_some()
+            ),
+            _ => false,
+        }
+    }
+
+    /// Warn on discarded `mutate self` calls queued by
+    /// `defer_temporary_mutation_check`.
+    pub(super) fn validate_temporary_mutations(&mut self) {
+        let pending = std::mem::take(&mut self.pending_temporary_mutations);
+        for (ty, method, span) in pending {
+            if self.receiver_method_mutates(&ty, &method) == Some(true) {
+                self.errors.push(TypeError::MutateTemporary { method, span });
+            }
+        }
+    }
+
     // ── E5: Sync inline access validation ──────────────────────────────
     //
     // E5/R5/MX3: `.read()/.write()/.lock()` on Shared<T>/Mutex<T> produce
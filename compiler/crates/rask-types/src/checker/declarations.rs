@@ -8,10 +8,14 @@ use super::errors::TypeError;
 use super::inference::TypeConstraint;
 use super::parse_type::parse_type_string;
 use super::TypeChecker;
+use crate::traits::TraitChecker;
 
 use crate::types::Type;
 use rask_ast::Span;
 
+/// ABI strings accepted on `extern` blocks and exported functions.
+const SUPPORTED_ABIS: &[&str] = &["C", "Rask"];
+
 impl TypeChecker {
     // ------------------------------------------------------------------------
     // Pass 1: Declaration Collection
@@ -53,6 +57,13 @@ impl TypeChecker {
                             .find(|s| s.name == base_name && matches!(s.kind, SymbolKind::Function { .. }))
                         {
                             self.fn_type_params.insert(sym.id, type_param_names);
+                            let comptime: Vec<String> = f.type_params.iter()
+                                .filter(|p| p.is_comptime)
+                                .map(|p| p.name.clone())
+                                .collect();
+                            if !comptime.is_empty() {
+                                self.fn_comptime_params.insert(sym.id, comptime);
+                            }
                         }
                     }
                 }
@@ -64,6 +75,7 @@ impl TypeChecker {
                 self.register_impl_methods(i);
             }
         }
+        self.check_field_method_collisions(decls);
         self.propagate_uniqueness();
         self.propagate_resource_linearity();
         self.auto_derive_traits();
@@ -153,12 +165,50 @@ impl TypeChecker {
         }
     }
 
+    /// Warn when a struct has a field and a method of the same name. Both
+    /// stay reachable — `x.len` reads the field, `x.len()` calls the
+    /// method — but the pair reads as one member.
+    fn check_field_method_collisions(&mut self, decls: &[Decl]) {
+        let structs: Vec<&StructDecl> = decls.iter()
+            .filter_map(|d| match &d.kind {
+                DeclKind::Struct(s) if !s.is_tuple() => Some(s),
+                _ => None,
+            })
+            .collect();
+        for s in structs {
+            let extended = decls.iter().filter_map(|d| match &d.kind {
+                DeclKind::Impl(i) if i.target_ty.split('<').next() == Some(s.name.as_str()) => {
+                    Some(i.methods.iter())
+                }
+                _ => None,
+            });
+            for m in s.methods.iter().chain(extended.flatten()) {
+                if let Some(field) = s.fields.iter().find(|f| f.name == m.name) {
+                    self.errors.push(TypeError::FieldMethodCollision {
+                        ty: s.name.clone(),
+                        name: m.name.clone(),
+                        field_span: field.name_span,
+                        method_span: m.span,
+                    });
+                }
+            }
+        }
+    }
+
     pub(super) fn register_impl_methods(&mut self, i: &ImplDecl) {
         let base_name = i.target_ty.split('<').next().unwrap_or(&i.target_ty);
         let type_id = match self.types.get_type_id(base_name) {
             Some(id) => id,
             None => return,
         };
+        if let Some(trait_name) = &i.trait_name {
+            for m in &i.methods {
+                self.trait_method_providers
+                    .entry((type_id, m.name.clone()))
+                    .or_default()
+                    .push(trait_name.clone());
+            }
+        }
         let new_methods: Vec<_> = i.methods.iter().map(|m| self.method_signature(m)).collect();
         if let Some(def) = self.types.get_mut(type_id) {
             match def {
@@ -170,6 +220,20 @@ impl TypeChecker {
         }
     }
 
+    /// Check `extend Type with Trait` against the trait's declared methods.
+    fn check_trait_impl(&mut self, i: &ImplDecl, trait_name: &str, span: Span) {
+        let base_name = i.target_ty.split('<').next().unwrap_or(&i.target_ty);
+        let Some(type_id) = self.types.get_type_id(base_name) else {
+            return;
+        };
+        let provided: Vec<_> = i.methods.iter()
+            .map(|m| (self.method_signature(m), m.span))
+            .collect();
+        let errors = TraitChecker::new(&self.types)
+            .check_impl(&Type::Named(type_id), trait_name, &provided, span);
+        self.errors.extend(errors.into_iter().map(TypeError::TraitImpl));
+    }
+
     pub(super) fn register_struct(&mut self, s: &StructDecl) {
         let field_tys: Vec<(Span, Type)> = s
             .fields
@@ -285,6 +349,28 @@ impl TypeChecker {
             }
         }
 
+        // E15/E2: every discriminant — explicit or positional — must fit the
+        // representation. Without a backing type the tag is auto-sized, up
+        // to u16.
+        let repr = match &e.backing_type {
+            Some(bt) => parse_type_string(bt, &self.types).ok(),
+            None => Some(Type::U16),
+        };
+        if let Some(repr) = repr {
+            for (index, v) in e.variants.iter().enumerate() {
+                let value = v.discriminant.unwrap_or(index as i128);
+                if !Self::int_literal_fits(value, &repr) {
+                    self.errors.push(TypeError::DiscriminantOutOfRange {
+                        enum_name: e.name.clone(),
+                        variant: v.name.clone(),
+                        value,
+                        backing_type: e.backing_type.clone(),
+                        span,
+                    });
+                }
+            }
+        }
+
         // Parse variant payload types first (immutable borrow of self.types),
         // then validate (mutable borrow of self for errors).
         let variants: Vec<(String, Vec<(Span, Type)>)> = e
@@ -333,12 +419,18 @@ impl TypeChecker {
             .filter(|m| !m.type_params.is_empty())
             .map(|m| m.name.clone())
             .collect();
+        let default_methods = t.methods.iter()
+            .filter(|m| !m.body.is_empty())
+            .map(|m| m.name.clone())
+            .collect();
 
         self.types.register_type(TypeDef::Trait {
             name: t.name.clone(),
             super_traits: t.super_traits.clone(),
             methods,
             generic_methods,
+            default_methods,
+            method_spans: t.methods.iter().map(|m| m.span).collect(),
             is_unsafe: t.is_unsafe,
         });
     }
@@ -777,9 +869,36 @@ impl TypeChecker {
     // Pass 2: Check Declarations
     // ------------------------------------------------------------------------
 
+    /// `main` takes nothing or the command-line arguments as `[]string`.
+    fn check_main_signature(&mut self, f: &FnDecl) {
+        let accepted = match f.params.as_slice() {
+            [] => true,
+            [p] => {
+                matches!(parse_type_string(&p.ty, &self.types), Ok(Type::Slice(inner)) if *inner == Type::String)
+                    || p.ty.replace(' ', "") == "Vec<string>"
+            }
+            _ => false,
+        };
+        if !accepted {
+            let found = f.params.iter()
+                .map(|p| format!("{}: {}", p.name, p.ty))
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.errors.push(TypeError::InvalidMainSignature { found, span: f.span });
+        }
+    }
+
     pub(super) fn check_decl(&mut self, decl: &Decl) {
         match &decl.kind {
-            DeclKind::Fn(f) => self.check_fn(f),
+            DeclKind::Fn(f) => {
+                if f.name == "main" {
+                    self.check_main_signature(f);
+                }
+                if let Some(abi) = &f.abi {
+                    self.check_abi(abi, f.span);
+                }
+                self.check_fn(f)
+            }
             DeclKind::Struct(s) => {
                 // PC2: field types must name declared types (single letters
                 // stay auto-generic, matching function signatures)
@@ -825,6 +944,9 @@ impl TypeChecker {
                         }
                     }
                 }
+                if let Some(trait_name) = &i.trait_name {
+                    self.check_trait_impl(i, trait_name, decl.span);
+                }
                 self.current_self_type = self.resolve_impl_self_type(&i.target_ty);
                 for method in &i.methods {
                     self.check_fn(method);
@@ -854,6 +976,11 @@ impl TypeChecker {
                 }
                 // ESAD Phase 2: reject volatile views at module level too
                 self.check_view_at_binding(&c.name, &c.init, decl.span);
+                // Globals that fold to a constant can size arrays later on;
+                // the rest are runtime values and simply stay unknown.
+                if let Ok(value) = self.comptime.eval_const(&c.init) {
+                    self.comptime.define_const(c.name.clone(), value);
+                }
             }
             DeclKind::Test(t) => {
                 for stmt in &t.body {
@@ -896,11 +1023,31 @@ impl TypeChecker {
                     }
                 }
             }
+            DeclKind::Extern(e) => self.check_abi(&e.abi, decl.span),
             DeclKind::Union(_) => {} // No methods to check
             _ => {}
         }
     }
 
+    /// CI2: only calling conventions codegen knows how to emit are accepted.
+    fn check_abi(&mut self, abi: &str, span: Span) {
+        if SUPPORTED_ABIS.contains(&abi) {
+            return;
+        }
+        // `"Cdecl"`, `"c"`, `"C-unwind"` — prefix/case slips are the usual mistake
+        let lower = abi.to_lowercase();
+        let suggestion = SUPPORTED_ABIS
+            .iter()
+            .find(|cand| lower.starts_with(&cand.to_lowercase()))
+            .map(|cand| cand.to_string());
+        self.errors.push(TypeError::UnknownAbi {
+            abi: abi.to_string(),
+            supported: SUPPORTED_ABIS.iter().map(|a| a.to_string()).collect(),
+            suggestion,
+            span,
+        });
+    }
+
     /// G1–G4: Register parse/build/build_into methods and SIZE/SIZE_BITS for @binary structs.
     fn register_binary_methods(&mut self) {
         use crate::types::TypeId;
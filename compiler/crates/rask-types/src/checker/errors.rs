@@ -14,6 +14,22 @@ pub enum TypeError {
         found: Type,
         span: Span,
     },
+    #[error("branches have incompatible types `{first}` and `{second}`")]
+    IncompatibleBranches {
+        first: Type,
+        second: Type,
+        first_span: Span,
+        second_span: Span,
+        /// What the enclosing context expects, if known
+        expected: Option<Type>,
+    },
+    #[error("loop breaks with both `{first}` and `{second}`")]
+    IncompatibleBreaks {
+        first: Type,
+        second: Type,
+        first_span: Span,
+        second_span: Span,
+    },
     #[error("undefined type: {0}")]
     Undefined(String),
     #[error("arity mismatch: expected {expected} arguments, found {found}")]
@@ -32,10 +48,30 @@ pub enum TypeError {
         method: String,
         span: Span,
     },
+    /// Unqualified call to a method that several implemented traits provide
+    #[error("ambiguous method `{method}`: found in traits {traits:?}")]
+    AmbiguousMethod {
+        method: String,
+        traits: Vec<String>,
+        span: Span,
+    },
+    /// std.fmt/D4: `{x}` where `x` has no `to_string`
+    #[error("value of type {ty} cannot be interpolated")]
+    NotInterpolable { ty: Type, span: Span },
+    /// `a == b`, `a < b`, ... with operands of unrelated types
+    #[error("cannot compare {left} with {right}")]
+    IncomparableTypes { left: Type, right: Type, span: Span },
     #[error("infinite type: type variable would create infinite type")]
     InfiniteType { var: TypeVarId, ty: Type, span: Span },
     #[error("cannot infer type")]
     CannotInfer { span: Span },
+    /// A generic call leaves a type parameter unconstrained
+    #[error("cannot infer type parameter `{param}`")]
+    CannotInferTypeParam {
+        param: String,
+        function: String,
+        span: Span,
+    },
     #[error("invalid type string: {0}")]
     InvalidTypeString(String),
     /// type.gradual/PC2: PascalCase name in a signature resolves to nothing
@@ -90,6 +126,11 @@ pub enum TypeError {
         slice_span: Span,
         store_span: Span,
     },
+    #[error("returns a borrow of local `{name}` which does not live long enough")]
+    ReturnsLocalBorrow {
+        name: String,
+        span: Span,
+    },
     #[error("cannot hold view from growable source `{source_var}`")]
     VolatileViewStored {
         source_var: String,
@@ -144,6 +185,11 @@ pub enum TypeError {
         operation: String,
         span: Span,
     },
+    /// `unsafe` block with no unsafe operations inside (warning)
+    #[error("unnecessary `unsafe` block")]
+    UnnecessaryUnsafe {
+        span: Span,
+    },
     #[error("method `{method}` returns Self and cannot be called through `any {trait_name}`")]
     TraitObjectSelfReturn {
         trait_name: String,
@@ -156,12 +202,23 @@ pub enum TypeError {
         method: String,
         span: Span,
     },
+    /// TR7: a collection of concrete values where one of `any Trait` is
+    /// expected — only single values convert, each element boxes separately
+    #[error("`{found}` elements can't convert to `any {trait_name}` in place")]
+    TraitObjectElements {
+        found: Type,
+        trait_name: String,
+        span: Span,
+    },
     #[error("`{ty}` does not implement `{trait_name}`")]
     TraitNotSatisfied {
         ty: String,
         trait_name: String,
         span: Span,
     },
+    /// `extend T with Trait` that omits or mis-declares a trait method
+    #[error(transparent)]
+    TraitImpl(crate::traits::TraitError),
 
     #[error("the `+` operator cannot be used on strings")]
     StringAddForbidden {
@@ -190,6 +247,14 @@ pub enum TypeError {
         span: Span,
     },
 
+    /// A match on a type whose values can't be listed (`string`) has no
+    /// unguarded catch-all arm
+    #[error("match on `{ty}` needs a catch-all arm")]
+    MissingCatchAll {
+        ty: String,
+        span: Span,
+    },
+
     #[error("undefined name `{name}`")]
     UndefinedName {
         name: String,
@@ -199,6 +264,17 @@ pub enum TypeError {
     #[error("unknown context `{name}` in `using` block")]
     UnknownContext {
         name: String,
+        /// Closest runtime context name, if any
+        suggestion: Option<String>,
+        span: Span,
+    },
+
+    /// `using Ctx(arg: ..)` names a parameter the context's constructor lacks
+    #[error("`{ctx}` has no parameter `{arg}`")]
+    UnknownContextArg {
+        ctx: String,
+        arg: String,
+        params: Vec<String>,
         span: Span,
     },
 
@@ -209,12 +285,104 @@ pub enum TypeError {
         span: Span,
     },
 
+    /// CC1/AL7: `using` clause names a type that can't be threaded as a context
+    #[error("`{ty}` is not a context type")]
+    UnknownContextType {
+        ty: String,
+        /// Closest context type name, if any
+        suggestion: Option<String>,
+        span: Span,
+    },
+
+    /// CI2: `extern "..."` names a calling convention we don't support
+    #[error("unknown ABI `{abi}`")]
+    UnknownAbi {
+        abi: String,
+        supported: Vec<String>,
+        /// Closest supported ABI, if any
+        suggestion: Option<String>,
+        span: Span,
+    },
+
+    /// Suffixed integer literal outside its type's range (`300u8`)
+    #[error("literal `{value}` out of range for `{ty}`")]
+    IntLiteralOutOfRange {
+        value: i128,
+        ty: Type,
+        span: Span,
+    },
+
+    /// Unsuffixed float literal where an integer is expected (`const y: i32 = 5.0`).
+    /// The reverse is fine — an integer literal adopts a float type (L6).
+    #[error("expected `{expected}`, found float literal")]
+    FloatLiteralForInteger {
+        expected: Type,
+        span: Span,
+    },
+
+    /// `main` with parameters other than `args: []string`
+    #[error("`main` must take no parameters or `args: []string`, found `({found})`")]
+    InvalidMainSignature {
+        found: String,
+        span: Span,
+    },
+
     /// CC1: `spawn` used outside any `using Multitasking` block
     #[error("`spawn` must be inside a `using Multitasking {{ ... }}` block")]
     SpawnOutsideBlock {
         span: Span,
     },
 
+    /// CC4: `ThreadPool.spawn` used outside any `using ThreadPool` block
+    #[error("`ThreadPool.spawn` must be inside a `using ThreadPool {{ ... }}` block")]
+    PoolSpawnOutsideBlock {
+        span: Span,
+    },
+
+    /// CC5: a task closure captures a borrowed value
+    #[error("cannot capture borrow `{name}` into a spawned task; move it or wrap in Shared")]
+    BorrowCapturedIntoTask {
+        name: String,
+        span: Span,
+    },
+
+    /// CT56: a type-level constant (array length) the comptime engine
+    /// couldn't evaluate
+    #[error("cannot evaluate constant: {message}")]
+    ConstEval {
+        message: String,
+        span: Span,
+    },
+
+    /// Array length names a runtime binding
+    #[error("array length must be a constant")]
+    ArrayLengthNotConstant {
+        name: String,
+        span: Span,
+    },
+
+    /// Array length folded to a negative value
+    #[error("array length cannot be negative")]
+    NegativeArrayLength {
+        value: i128,
+        span: Span,
+    },
+
+    /// CT3: a `comptime func` called from runtime code
+    #[error("cannot call comptime-only function `{name}` at runtime")]
+    ComptimeOnlyCall {
+        name: String,
+        span: Span,
+    },
+
+    /// CT6/CT7: a runtime-only function called in a comptime position
+    #[error("cannot call runtime-only function `{name}` at compile time: it {reason}")]
+    RuntimeOnlyCallAtComptime {
+        name: String,
+        reason: String,
+        span: Span,
+    },
+
     /// T6: cyclic type alias
     #[error("cyclic type alias: {cycle}")]
     CyclicTypeAlias {
@@ -247,6 +415,38 @@ pub enum TypeError {
         span: Span,
     },
 
+    /// Match arm that an earlier arm already covers (warning)
+    #[error("unreachable match arm")]
+    UnreachableArm {
+        span: Span,
+        /// The earlier arm that matches everything this one does
+        shadowed_by: Span,
+    },
+
+    /// `mutate self` method called on a temporary in statement position (warning)
+    #[error("`mutate self` method `{method}` called on a temporary has no effect")]
+    MutateTemporary {
+        method: String,
+        span: Span,
+    },
+
+    /// `??`, `?.`, `!`, or `try` on a value that is never optional (warning)
+    #[error("redundant `{op}` on non-optional `{found}`")]
+    RedundantOptionalOp {
+        op: String,
+        found: Type,
+        span: Span,
+    },
+
+    /// A struct declares a field and a method with the same name (warning)
+    #[error("`{ty}` has both a field and a method named `{name}`")]
+    FieldMethodCollision {
+        ty: String,
+        name: String,
+        field_span: Span,
+        method_span: Span,
+    },
+
     /// D3: discard on @resource type (error)
     #[error("cannot `discard` resource `{name}` — use its consuming method instead")]
     DiscardResourceType {
@@ -321,6 +521,17 @@ pub enum TypeError {
         span: Span,
     },
 
+    /// E15: discriminant doesn't fit the backing type (or the default u16)
+    #[error("enum `{enum_name}`: discriminant {value} on `{variant}` does not fit `{}`", backing_type.as_deref().unwrap_or("u16"))]
+    DiscriminantOutOfRange {
+        enum_name: String,
+        variant: String,
+        value: i128,
+        /// `None` when the enum uses default sizing (E2).
+        backing_type: Option<String>,
+        span: Span,
+    },
+
     /// ER3: success and error types in `T or E` must be distinct
     #[error("`T or E` requires T and E to be distinct types — both sides are `{ty}`")]
     ResultNotDisjoint {
@@ -435,6 +646,9 @@ pub enum IndexErrorKind {
     ExpectedHandle(Type),
     /// A range was used to slice a container that isn't sliceable (Map, Pool).
     NotSliceable,
+    /// `s[i]` on a string — position is ambiguous between byte and char
+    /// [std.strings/S9]. Slicing with a range is still allowed.
+    StringPosition,
 }
 
 /// Why an `as` cast is rejected — drives the diagnostic and suggested fix.
@@ -78,6 +78,48 @@ impl TypeChecker {
         }
     }
 
+    /// Bind explicit call-site type args (`first<Point>(xs)`, parsed into the
+    /// callee name) to the fresh vars standing in for the type params.
+    pub(super) fn bind_explicit_type_args(&mut self, callee: &str, pairs: &[(String, Type)], span: rask_ast::Span) {
+        if !callee.contains('<') {
+            return;
+        }
+        let args = match super::parse_type::parse_type_string(callee, &self.types) {
+            Ok(Type::UnresolvedGeneric { args, .. }) | Ok(Type::Generic { args, .. }) => args,
+            _ => return,
+        };
+        for ((_, var), arg) in pairs.iter().zip(args) {
+            if let GenericArg::Type(ty) = arg {
+                self.ctx.add_constraint(super::inference::TypeConstraint::Equal(var.clone(), *ty, span));
+            }
+        }
+    }
+
+    /// Report generic call sites whose type params neither the arguments nor
+    /// the surrounding context pinned down — e.g. `T` only in the return type
+    /// of a call whose result is unused. Calls with an erroneous argument are
+    /// skipped; the argument's own error already explains them.
+    pub(super) fn validate_type_params_inferred(&mut self) {
+        let pending = std::mem::take(&mut self.pending_type_param_checks);
+        for (callee, pairs, arg_ids, span) in pending {
+            let arg_failed = arg_ids.iter().any(|id| {
+                matches!(self.node_types.get(id).map(|t| self.ctx.apply(t)), Some(Type::Error))
+            });
+            if arg_failed {
+                continue;
+            }
+            for (param, var) in &pairs {
+                if matches!(self.ctx.apply(var), Type::Var(_)) {
+                    self.errors.push(super::errors::TypeError::CannotInferTypeParam {
+                        param: param.clone(),
+                        function: callee.clone(),
+                        span,
+                    });
+                }
+            }
+        }
+    }
+
     /// Replace type parameter names (UnresolvedNamed) with concrete types.
     pub(super) fn substitute_type_params(ty: &Type, subst: &HashMap<&str, Type>) -> Type {
         match ty {
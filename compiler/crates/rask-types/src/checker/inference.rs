@@ -21,6 +21,16 @@ pub enum TypeConstraint {
         /// V5: Self type at constraint creation site (for private field checks)
         self_type: Option<Type>,
     },
+    /// `object?.field` (OPT10): `result` is the field's type made optional,
+    /// flattened when the field is already `T?`. Deferred until `object`
+    /// and its inner type resolve, so the flattening sees the real field type.
+    OptionalField {
+        object: Type,
+        field: String,
+        result: Type,
+        span: Span,
+        self_type: Option<Type>,
+    },
     /// Type must have a method with given signature.
     HasMethod {
         ty: Type,
@@ -42,6 +52,24 @@ pub enum TypeConstraint {
         position: WrapPosition,
         span: Span,
     },
+    /// Value-position branches (if/else, match arms) must agree. Solved like
+    /// `Equal`, but a failure names both branch types and where they come from.
+    BranchesAgree {
+        first: Type,
+        second: Type,
+        first_span: Span,
+        second_span: Span,
+        /// What the enclosing context expects (e.g. the return type), if known
+        expected: Option<Type>,
+    },
+    /// CF16: every `break` out of a value-position `loop` must produce the
+    /// same type. Solved like `Equal`, reported as a break mismatch.
+    BreaksAgree {
+        first: Type,
+        second: Type,
+        first_span: Span,
+        second_span: Span,
+    },
     /// ER27: scrutinee is a `T or E`, and `narrow_ty` must match either `T`
     /// or a component of `E`. Deferred so method-call return types can
     /// resolve before the pattern side is decided.
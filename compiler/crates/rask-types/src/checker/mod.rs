@@ -1,13 +1,13 @@
 // SPDX-License-Identifier: (MIT OR Apache-2.0)
 //! Type checker implementation.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use rask_ast::decl::Decl;
 use rask_ast::NodeId;
 use rask_resolve::{ResolvedProgram, SymbolId};
 
-use crate::types::Type;
+use crate::types::{Type, TypeId};
 
 mod type_defs;
 mod builtins;
@@ -25,8 +25,10 @@ mod unify;
 mod generics;
 mod resolve;
 mod validate;
+mod staging;
 
 pub use type_defs::{TypeDef, MethodSig, SelfParam, ParamMode, TypedProgram};
+pub(crate) use type_defs::method_base;
 pub use type_table::TypeTable;
 pub use inference::{TypeConstraint, InferenceContext};
 pub use errors::{TypeError, InvalidCastClass, IndexErrorKind};
@@ -90,13 +92,22 @@ pub struct TypeChecker {
     pub(super) borrow_stack: Vec<ActiveBorrow>,
     /// Persistent borrows across statements within a scope (ESAD Phase 2).
     pub(super) persistent_borrows: Vec<PersistentBorrow>,
+    /// `mutate` parameters of the current function. They bind like locals
+    /// but the caller owns the value, so views into them may be returned.
+    pub(super) mutate_params: std::collections::HashSet<String>,
     /// Pending generic call sites: (call NodeId, fresh type vars for type params).
     /// Resolved after constraint solving to populate TypedProgram.call_type_args.
     pub(super) pending_call_type_args: Vec<(NodeId, Vec<Type>)>,
+    /// Generic call sites checked after solving for uninferred type params:
+    /// (callee name, param name → fresh var, argument node ids, call span).
+    pub(super) pending_type_param_checks: Vec<(String, Vec<(String, Type)>, Vec<NodeId>, rask_ast::Span)>,
     /// SymbolId → type param names for generic functions.
     /// Keyed by SymbolId (not name) to avoid collisions between
     /// same-named functions in different scopes.
     pub(super) fn_type_params: HashMap<SymbolId, Vec<String>>,
+    /// The `comptime N: usize` subset of `fn_type_params` — values, not
+    /// types, so they're never inferred through unification.
+    pub(super) fn_comptime_params: HashMap<SymbolId, Vec<String>>,
     /// Whether we're inside an `unsafe {}` block (for validating pointer ops and extern calls).
     pub(super) in_unsafe: bool,
     /// Collected unsafe operations with their locations (for tooling/auditing).
@@ -106,6 +117,17 @@ pub struct TypeChecker {
     /// Whether we're inferring an expression in statement position (value discarded).
     /// Suppresses branch-type agreement for if/else and match.
     pub(super) in_stmt_expr: bool,
+    /// Enclosing loops, innermost last, so `break value` can find its target.
+    pub(super) loops: Vec<check_stmt::LoopFrame>,
+    /// Expected type for the next `if`/`match`/block/array expression,
+    /// consumed on entry to `infer_expr` so it only reaches branch tails.
+    pub(super) expected_ty: Option<Type>,
+    /// Suffixed integer literal currently being checked as the operand of
+    /// a desugared unary minus, so its range check sees the negative value.
+    pub(super) negated_literal: Option<NodeId>,
+    /// Traits whose `extend T with Trait` blocks provide each method, keyed
+    /// by (type, method name). Two providers make an unqualified call ambiguous.
+    pub(super) trait_method_providers: HashMap<(TypeId, String), Vec<String>>,
     /// GC1/GC2: Pre-created type vars for functions with inferred params/return.
     /// Key is function name, value is (param_type_vars, return_type_var).
     pub(super) inferred_fn_types: HashMap<String, (Vec<(String, Type)>, Type)>,
@@ -122,12 +144,37 @@ pub struct TypeChecker {
     pub(super) discarded_bindings: HashMap<String, rask_ast::Span>,
     /// CC1: nesting depth of `using Multitasking { }` blocks in current function.
     pub(super) multitasking_depth: u32,
+    /// Nesting depth of `using ThreadPool { }` blocks in current function.
+    pub(super) threadpool_depth: u32,
+    /// Scope depth where the closure handed to `ThreadPool.spawn` begins.
+    /// Locals found in shallower scopes are captures into the task.
+    pub(super) task_capture_depth: Option<usize>,
+    /// Captures already reported for the current task closure.
+    pub(super) reported_task_captures: HashSet<String>,
+    /// CT3/CT6: call-site stage per top-level function, keyed by name.
+    pub(super) fn_stages: HashMap<String, staging::FnStage>,
+    /// CT56: nesting depth of comptime positions (comptime blocks, bodies of
+    /// `comptime func`, `comptime for` iterables). Zero means runtime code.
+    pub(super) comptime_depth: u32,
+    /// Comptime engine for type-level constants (array lengths, repeat
+    /// counts). Knows the program's comptime funcs and constant globals.
+    pub(super) comptime: rask_comptime::ComptimeInterpreter,
     /// CV1–CV10: cast/convert sites validated after literal defaults resolve
     /// their source types. Deferred so `1 as bool` sees `i32`, not a fresh var.
     pub(super) pending_casts: Vec<check_expr::PendingCast>,
     /// #310: index sites validated after literal defaults resolve their index
     /// type. Deferred so `v[0]` sees `i32`, not a fresh literal var.
     pub(super) pending_index: Vec<check_expr::PendingIndex>,
+    /// Method calls on temporaries whose result is discarded: (receiver
+    /// type, method name, call span). A `mutate self` method among them has
+    /// no observable effect; checked once the receiver type is solved.
+    pub(super) pending_temporary_mutations: Vec<(Type, String, rask_ast::Span)>,
+    /// std.fmt/D4: spans of the `to_string()` calls the desugarer generates
+    /// for `{x}` interpolation, keyed like `span_types`.
+    pub(super) interp_spans: HashSet<(usize, usize, u16)>,
+    /// Spans of the `eq`/`lt`/... calls the desugarer generates for `==`,
+    /// `<`, and friends, so mismatches read as comparisons.
+    pub(super) comparison_spans: HashSet<(usize, usize, u16)>,
 }
 
 impl TypeChecker {
@@ -145,21 +192,37 @@ impl TypeChecker {
             local_types: Vec::new(),
             borrow_stack: Vec::new(),
             persistent_borrows: Vec::new(),
+            mutate_params: std::collections::HashSet::new(),
             pending_call_type_args: Vec::new(),
+            pending_type_param_checks: Vec::new(),
             fn_type_params: HashMap::new(),
+            fn_comptime_params: HashMap::new(),
             in_unsafe: false,
             unsafe_ops: Vec::new(),
             inferred_fn_types: HashMap::new(),
             in_assign_target: false,
             in_stmt_expr: false,
+            loops: Vec::new(),
+            expected_ty: None,
+            negated_literal: None,
+            trait_method_providers: HashMap::new(),
             trait_coercions: HashMap::new(),
             inferred_errors: Vec::new(),
             span_types: HashMap::new(),
             accumulate_errors: false,
             discarded_bindings: HashMap::new(),
             multitasking_depth: 0,
+            threadpool_depth: 0,
+            task_capture_depth: None,
+            reported_task_captures: HashSet::new(),
+            fn_stages: HashMap::new(),
+            comptime_depth: 0,
+            comptime: rask_comptime::ComptimeInterpreter::new(),
             pending_casts: Vec::new(),
             pending_index: Vec::new(),
+            pending_temporary_mutations: Vec::new(),
+            interp_spans: HashSet::new(),
+            comparison_spans: HashSet::new(),
         }
     }
 
@@ -180,6 +243,8 @@ impl TypeChecker {
     /// diagnostics in a single pipeline pass.
     pub fn check_lenient(mut self, decls: &[Decl]) -> (TypedProgram, Vec<TypeError>) {
         self.collect_type_declarations(decls);
+        self.classify_fn_stages(decls);
+        self.comptime.register_functions(decls);
 
         // Global scope for module-level bindings (imports, etc.)
         self.push_scope();
@@ -202,6 +267,12 @@ impl TypeChecker {
         // are concrete (e.g. `1 as bool` sees `i32`).
         self.validate_pending_casts();
 
+        // Type params still unbound once literals have defaulted can't be
+        // inferred from this call at all.
+        self.validate_type_params_inferred();
+
+        self.validate_temporary_mutations();
+
         let node_types: HashMap<_, _> = self
             .node_types
             .iter()
@@ -319,6 +390,15 @@ impl TypeChecker {
                 method,
                 span,
             },
+            TypeError::NotInterpolable { ty, span } => TypeError::NotInterpolable {
+                ty: ctx.apply(&ty),
+                span,
+            },
+            TypeError::IncomparableTypes { left, right, span } => TypeError::IncomparableTypes {
+                left: ctx.apply(&left),
+                right: ctx.apply(&right),
+                span,
+            },
             TypeError::MissingReturn { function_name, expected_type, span } => TypeError::MissingReturn {
                 function_name,
                 expected_type: ctx.apply(&expected_type),
@@ -333,6 +413,11 @@ impl TypeChecker {
                 ty: ctx.apply(&ty),
                 span,
             },
+            TypeError::RedundantOptionalOp { op, found, span } => TypeError::RedundantOptionalOp {
+                op,
+                found: ctx.apply(&found),
+                span,
+            },
             TypeError::TryOnNonResult { found, span } => TypeError::TryOnNonResult {
                 found: ctx.apply(&found),
                 span,
@@ -200,6 +200,21 @@ impl TypeChecker {
         }
     }
 
+    /// std.fmt/D2–D5: primitives and builtins display themselves; structs
+    /// opt in with `to_string`, or `message` via the error bridge. Enums get
+    /// an auto-derived `message` (ER6).
+    fn is_displayable(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Named(type_id) => match self.types.get(*type_id) {
+                Some(TypeDef::Struct { methods, .. }) => methods
+                    .iter()
+                    .any(|m| m.name == "to_string" || m.name == "message"),
+                _ => true,
+            },
+            _ => true,
+        }
+    }
+
     pub(super) fn resolve_method(
         &mut self,
         ty: Type,
@@ -214,8 +229,36 @@ impl TypeChecker {
             return self.unify(&ty, &ret, span);
         }
 
+        // Desugared `==`/`<`/...: operands from unrelated type families get
+        // a comparison error instead of a method-resolution one.
+        if args.len() == 1 && self.comparison_spans.contains(&(span.start, span.end, span.file_id)) {
+            let rhs = self.resolve_named(&self.ctx.apply(&args[0]));
+            if is_incomparable(&ty, &rhs) {
+                let _ = self.unify(&ret, &Type::Bool, span);
+                return Err(TypeError::IncomparableTypes { left: ty, right: rhs, span });
+            }
+        }
+
         // to_string() on any type returns string
         if method == "to_string" && args.is_empty() {
+            if self.interp_spans.contains(&(span.start, span.end, span.file_id)) {
+                // D4: recheck once the operand's type is known. The result
+                // is a string either way, so later concats aren't held up.
+                if let Type::Var(_) = ty {
+                    let progress = self.unify(&ret, &Type::String, span)?;
+                    self.ctx.add_constraint(TypeConstraint::HasMethod {
+                        ty,
+                        method,
+                        args,
+                        ret: Type::String,
+                        span,
+                    });
+                    return Ok(progress);
+                }
+                if !self.is_displayable(&ty) {
+                    return Err(TypeError::NotInterpolable { ty, span });
+                }
+            }
             return self.unify(&ret, &Type::String, span);
         }
 
@@ -228,6 +271,15 @@ impl TypeChecker {
         match &ty {
             // Source error already reported — suppress cascading method errors
             Type::Error => Ok(false),
+            // Imported `thread` stubs can't name the closure's result type;
+            // resolve spawn the same way as the bare builtin names.
+            Type::Named(type_id)
+                if method == "spawn"
+                    && args.len() == 1
+                    && matches!(self.types.type_name(*type_id).as_str(), "Thread" | "ThreadPool") =>
+            {
+                self.resolve_thread_spawn(&args[0], &ret, span)
+            }
             Type::Var(_) => {
                 self.ctx.add_constraint(TypeConstraint::HasMethod {
                     ty,
@@ -255,6 +307,19 @@ impl TypeChecker {
                     }
                 };
 
+                // Same-named methods from two traits, and none inherent to
+                // prefer: the caller has to say which one.
+                if let Some(traits) = self.trait_method_providers.get(&(*type_id, method.clone())) {
+                    let inherent = methods.iter().filter(|m| m.name == method).count() > traits.len();
+                    if traits.len() > 1 && !inherent {
+                        return Err(TypeError::AmbiguousMethod {
+                            method,
+                            traits: traits.clone(),
+                            span,
+                        });
+                    }
+                }
+
                 if let Some(method_sig) = methods.iter().find(|m| m.name == method) {
                     if method_sig.params.len() != args.len() {
                         return Err(TypeError::ArityMismatch {
@@ -465,17 +530,7 @@ impl TypeChecker {
             // Thread.spawn(closure) → ThreadHandle<T>
             Type::UnresolvedNamed(name) if name == "Thread" || name == "ThreadPool" => {
                 if method == "spawn" && args.len() == 1 {
-                    // Extract closure return type for ThreadHandle<T>
-                    let inner = if let Type::Fn { ret: fn_ret, .. } = &args[0] {
-                        *fn_ret.clone()
-                    } else {
-                        self.ctx.fresh_var()
-                    };
-                    let handle_ty = Type::UnresolvedGeneric {
-                        name: "ThreadHandle".to_string(),
-                        args: vec![GenericArg::Type(Box::new(inner))],
-                    };
-                    self.unify(&ret, &handle_ty, span)
+                    self.resolve_thread_spawn(&args[0], &ret, span)
                 } else {
                     Err(TypeError::NoSuchMethod {
                         ty,
@@ -1087,6 +1142,21 @@ impl TypeChecker {
         }
     }
 
+    /// `Thread.spawn(f)` / `ThreadPool.spawn(f)` → `ThreadHandle<T>` for the
+    /// closure's result `T`.
+    fn resolve_thread_spawn(&mut self, closure: &Type, ret: &Type, span: Span) -> Result<bool, TypeError> {
+        let inner = if let Type::Fn { ret: fn_ret, .. } = closure {
+            *fn_ret.clone()
+        } else {
+            self.ctx.fresh_var()
+        };
+        let handle_ty = Type::UnresolvedGeneric {
+            name: "ThreadHandle".to_string(),
+            args: vec![GenericArg::Type(Box::new(inner))],
+        };
+        self.unify(ret, &handle_ty, span)
+    }
+
     pub(super) fn resolve_task_handle_method(
         &mut self,
         type_args: &[GenericArg],
@@ -1096,7 +1166,8 @@ impl TypeChecker {
         span: Span,
     ) -> Result<bool, TypeError> {
         match method {
-            "join" if args.is_empty() => {
+            // cancel still yields the result if the task finished first
+            "join" | "cancel" if args.is_empty() => {
                 let inner_type = if let Some(GenericArg::Type(t)) = type_args.first() {
                     *t.clone()
                 } else {
@@ -1111,9 +1182,6 @@ impl TypeChecker {
             "detach" if args.is_empty() => {
                 self.unify(ret, &Type::Unit, span)
             }
-            "cancel" if args.is_empty() => {
-                self.unify(ret, &Type::Unit, span)
-            }
             _ => Err(TypeError::NoSuchMethod {
                 ty: Type::UnresolvedGeneric {
                     name: "TaskHandle".to_string(),
@@ -1712,6 +1780,15 @@ impl TypeChecker {
                 };
                 self.unify(ret, &vec_ty, span)
             }
+            "with_capacity" if args.len() == 1 => {
+                let _ = self.unify(&args[0], &Type::I64, span);
+                let fresh = self.ctx.fresh_var();
+                let vec_ty = Type::UnresolvedGeneric {
+                    name: "Vec".to_string(),
+                    args: vec![GenericArg::Type(Box::new(fresh))],
+                };
+                self.unify(ret, &vec_ty, span)
+            }
             // Vec.from(array) — construct Vec from array literal
             "from" if args.len() == 1 => {
                 // Extract element type from the argument (array literal or Vec)
@@ -1805,6 +1882,10 @@ impl TypeChecker {
             "capacity" if args.is_empty() => {
                 self.unify(ret, &Type::U64, span)
             }
+            "reserve" if args.len() == 1 => {
+                let _ = self.unify(&args[0], &Type::I64, span);
+                self.unify(ret, &Type::Unit, span)
+            }
             // vec.insert(index, value) -> ()
             "insert" if args.len() == 2 => {
                 let _ = self.unify(&args[0], &Type::I64, span);
@@ -2639,3 +2720,25 @@ impl TypeChecker {
         }
     }
 }
+
+/// Operands of `==`/`<` from different families (integer, float, bool, char,
+/// string, user type) can never compare. Width mismatches within a family
+/// and unresolved operands are left to unification.
+fn is_incomparable(left: &Type, right: &Type) -> bool {
+    fn family(ty: &Type) -> Option<u8> {
+        match ty {
+            Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128
+            | Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128 => Some(0),
+            Type::F32 | Type::F64 => Some(1),
+            Type::Bool => Some(2),
+            Type::Char => Some(3),
+            Type::String => Some(4),
+            Type::Named(_) => Some(5),
+            _ => None,
+        }
+    }
+    match (family(left), family(right)) {
+        (Some(a), Some(b)) => a != b,
+        _ => false,
+    }
+}
@@ -0,0 +1,286 @@
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+//! Function staging: which functions run at compile time, which at runtime,
+//! and which in both (CT3, CT6, CT7).
+//!
+//! Classified once per program before bodies are checked, so `check_call`
+//! can reject a comptime-only call in runtime code and a runtime-only call
+//! in comptime code without re-walking callee bodies.
+
+use std::collections::HashMap;
+
+use rask_ast::decl::{Decl, DeclKind};
+use rask_ast::expr::{Expr, ExprKind, SelectArmKind, StringSegment};
+use rask_ast::stmt::{Stmt, StmtKind};
+use rask_resolve::{BuiltinFunctionKind, BuiltinModuleKind, SymbolKind};
+
+use super::check_fn::is_runtime_context;
+use super::TypeChecker;
+
+/// Where a function may be called from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) enum FnStage {
+    /// Comptime-evaluable, so callable from either phase (CT6).
+    Both,
+    /// `comptime func` — compile time only (CT3).
+    ComptimeOnly,
+    /// Reaches I/O, tasks, or foreign code (CT7). `reason` names the first
+    /// offending operation so the diagnostic can point at it.
+    RuntimeOnly { reason: String },
+}
+
+/// Direct findings for one function body.
+#[derive(Default)]
+struct StageScan {
+    reason: Option<String>,
+    callees: Vec<String>,
+}
+
+impl TypeChecker {
+    /// CT6/CT7: classify top-level functions. Direct runtime operations seed
+    /// the set; callers of runtime-only functions join it by fixed point.
+    pub(super) fn classify_fn_stages(&mut self, decls: &[Decl]) {
+        let mut pending: Vec<(String, Vec<String>)> = Vec::new();
+        for decl in decls {
+            let DeclKind::Fn(f) = &decl.kind else { continue };
+            if f.is_comptime {
+                self.fn_stages.insert(f.name.clone(), FnStage::ComptimeOnly);
+                continue;
+            }
+            let mut scan = StageScan::default();
+            self.scan_stage_stmts(&f.body, &mut scan);
+            match scan.reason {
+                Some(reason) => {
+                    self.fn_stages.insert(f.name.clone(), FnStage::RuntimeOnly { reason });
+                }
+                None => pending.push((f.name.clone(), scan.callees)),
+            }
+        }
+
+        loop {
+            let mut changed = false;
+            pending.retain(|(name, callees)| {
+                let runtime_callee = callees.iter().find(|c| {
+                    matches!(self.fn_stages.get(*c), Some(FnStage::RuntimeOnly { .. }))
+                });
+                match runtime_callee {
+                    Some(callee) => {
+                        let reason = format!("calls `{}`", callee);
+                        self.fn_stages.insert(name.clone(), FnStage::RuntimeOnly { reason });
+                        changed = true;
+                        false
+                    }
+                    None => true,
+                }
+            });
+            if !changed {
+                break;
+            }
+        }
+
+        for (name, _) in pending {
+            self.fn_stages.insert(name, FnStage::Both);
+        }
+    }
+
+    fn scan_stage_stmts(&self, stmts: &[Stmt], scan: &mut StageScan) {
+        for stmt in stmts {
+            self.scan_stage_stmt(stmt, scan);
+        }
+    }
+
+    fn scan_stage_stmt(&self, stmt: &Stmt, scan: &mut StageScan) {
+        match &stmt.kind {
+            StmtKind::Expr(e) => self.scan_stage_expr(e, scan),
+            StmtKind::Mut { init, .. }
+            | StmtKind::Const { init, .. }
+            | StmtKind::MutTuple { init, .. }
+            | StmtKind::ConstTuple { init, .. } => self.scan_stage_expr(init, scan),
+            StmtKind::Assign { target, value } => {
+                self.scan_stage_expr(target, scan);
+                self.scan_stage_expr(value, scan);
+            }
+            StmtKind::Return(Some(e)) => self.scan_stage_expr(e, scan),
+            StmtKind::Break { value: Some(e), .. } => self.scan_stage_expr(e, scan),
+            StmtKind::While { cond, body } => {
+                self.scan_stage_expr(cond, scan);
+                self.scan_stage_stmts(body, scan);
+            }
+            StmtKind::WhileLet { expr, body, .. } | StmtKind::For { iter: expr, body, .. } => {
+                self.scan_stage_expr(expr, scan);
+                self.scan_stage_stmts(body, scan);
+            }
+            StmtKind::Loop { body, .. } => self.scan_stage_stmts(body, scan),
+            StmtKind::Ensure { body, else_handler } => {
+                self.scan_stage_stmts(body, scan);
+                if let Some((_, handler)) = else_handler {
+                    self.scan_stage_stmts(handler, scan);
+                }
+            }
+            // CT57: a comptime-for body is runtime residue; its iterable and
+            // nested comptime blocks run in the compiler and don't make the
+            // enclosing function runtime-only.
+            StmtKind::ComptimeFor { body, .. } => self.scan_stage_stmts(body, scan),
+            StmtKind::Comptime(_)
+            | StmtKind::Return(None)
+            | StmtKind::Break { value: None, .. }
+            | StmtKind::Continue(_)
+            | StmtKind::Discard { .. } => {}
+        }
+    }
+
+    fn scan_stage_expr(&self, expr: &Expr, scan: &mut StageScan) {
+        if scan.reason.is_some() {
+            return;
+        }
+        match &expr.kind {
+            ExprKind::Ident(name) => self.scan_stage_ident(expr, name, scan),
+            ExprKind::Spawn { .. } => {
+                scan.reason = Some("spawns a task".to_string());
+            }
+            ExprKind::Unsafe { .. } => {
+                scan.reason = Some("contains an `unsafe` block".to_string());
+            }
+            ExprKind::Select { .. } => {
+                scan.reason = Some("waits on channels with `select`".to_string());
+            }
+            ExprKind::UsingBlock { name, args, body } => {
+                if is_runtime_context(name) {
+                    scan.reason = Some(format!("enters `using {}`", name));
+                    return;
+                }
+                for a in args { self.scan_stage_expr(&a.expr, scan); }
+                self.scan_stage_stmts(body, scan);
+            }
+            ExprKind::Comptime { .. } => {}
+
+            ExprKind::Int(..) | ExprKind::Float(..) | ExprKind::String(_)
+            | ExprKind::Char(_) | ExprKind::Bool(_) | ExprKind::Null | ExprKind::None => {}
+            ExprKind::StringInterp(segments) => {
+                for seg in segments {
+                    if let StringSegment::Expr(e) = seg {
+                        self.scan_stage_expr(e, scan);
+                    }
+                }
+            }
+            ExprKind::Binary { left, right, .. } => {
+                self.scan_stage_expr(left, scan);
+                self.scan_stage_expr(right, scan);
+            }
+            ExprKind::Unary { operand, .. } => self.scan_stage_expr(operand, scan),
+            ExprKind::Call { func, args } => {
+                self.scan_stage_expr(func, scan);
+                for a in args { self.scan_stage_expr(&a.expr, scan); }
+            }
+            ExprKind::MethodCall { object, args, .. } => {
+                self.scan_stage_expr(object, scan);
+                for a in args { self.scan_stage_expr(&a.expr, scan); }
+            }
+            ExprKind::Field { object, .. } | ExprKind::OptionalField { object, .. } => {
+                self.scan_stage_expr(object, scan);
+            }
+            ExprKind::DynamicField { object, field_expr } => {
+                self.scan_stage_expr(object, scan);
+                self.scan_stage_expr(field_expr, scan);
+            }
+            ExprKind::Index { object, index } => {
+                self.scan_stage_expr(object, scan);
+                self.scan_stage_expr(index, scan);
+            }
+            ExprKind::Block(stmts)
+            | ExprKind::Loop { body: stmts, .. }
+            | ExprKind::BlockCall { body: stmts, .. } => self.scan_stage_stmts(stmts, scan),
+            ExprKind::If { cond, then_branch, else_branch, .. } => {
+                self.scan_stage_expr(cond, scan);
+                self.scan_stage_expr(then_branch, scan);
+                if let Some(e) = else_branch { self.scan_stage_expr(e, scan); }
+            }
+            ExprKind::IfLet { expr: inner, then_branch, else_branch, .. } => {
+                self.scan_stage_expr(inner, scan);
+                self.scan_stage_expr(then_branch, scan);
+                if let Some(e) = else_branch { self.scan_stage_expr(e, scan); }
+            }
+            ExprKind::GuardPattern { expr: inner, else_branch, .. } => {
+                self.scan_stage_expr(inner, scan);
+                self.scan_stage_expr(else_branch, scan);
+            }
+            ExprKind::IsPattern { expr: inner, .. }
+            | ExprKind::IsPresent { expr: inner, .. }
+            | ExprKind::Unwrap { expr: inner, .. }
+            | ExprKind::Cast { expr: inner, .. }
+            | ExprKind::Convert { expr: inner, .. } => self.scan_stage_expr(inner, scan),
+            ExprKind::Match { scrutinee, arms } => {
+                self.scan_stage_expr(scrutinee, scan);
+                for arm in arms {
+                    if let Some(g) = &arm.guard { self.scan_stage_expr(g, scan); }
+                    self.scan_stage_expr(&arm.body, scan);
+                }
+            }
+            ExprKind::Try { expr: inner, else_clause } => {
+                self.scan_stage_expr(inner, scan);
+                if let Some(clause) = else_clause { self.scan_stage_expr(&clause.body, scan); }
+            }
+            ExprKind::NullCoalesce { value, default } => {
+                self.scan_stage_expr(value, scan);
+                self.scan_stage_expr(default, scan);
+            }
+            ExprKind::Range { start, end, .. } => {
+                if let Some(s) = start { self.scan_stage_expr(s, scan); }
+                if let Some(e) = end { self.scan_stage_expr(e, scan); }
+            }
+            ExprKind::StructLit { fields, spread, .. } => {
+                for f in fields { self.scan_stage_expr(&f.value, scan); }
+                if let Some(s) = spread { self.scan_stage_expr(s, scan); }
+            }
+            ExprKind::Array(elems) | ExprKind::Tuple(elems) => {
+                for e in elems { self.scan_stage_expr(e, scan); }
+            }
+            ExprKind::ArrayRepeat { value, count } => {
+                self.scan_stage_expr(value, scan);
+                self.scan_stage_expr(count, scan);
+            }
+            ExprKind::WithAs { bindings, body } => {
+                for b in bindings { self.scan_stage_expr(&b.source, scan); }
+                self.scan_stage_stmts(body, scan);
+            }
+            ExprKind::Closure { body, .. } => self.scan_stage_expr(body, scan),
+            ExprKind::Assert { condition, message } | ExprKind::Check { condition, message } => {
+                self.scan_stage_expr(condition, scan);
+                if let Some(m) = message { self.scan_stage_expr(m, scan); }
+            }
+        }
+    }
+
+    /// Names are where runtime capabilities enter: runtime stdlib modules,
+    /// `spawn`, and foreign or unsafe functions. Plain functions become
+    /// call-graph edges for the fixed point.
+    fn scan_stage_ident(&self, expr: &Expr, name: &str, scan: &mut StageScan) {
+        let Some(&sym_id) = self.resolved.resolutions.get(&expr.id) else { return };
+        let Some(sym) = self.resolved.symbols.get(sym_id) else { return };
+        match &sym.kind {
+            SymbolKind::Function { is_unsafe: true, .. } => {
+                scan.reason = Some(format!("calls unsafe function `{}`", name));
+            }
+            SymbolKind::Function { .. } => scan.callees.push(name.to_string()),
+            SymbolKind::ExternFunction { .. } => {
+                scan.reason = Some(format!("calls extern function `{}`", name));
+            }
+            SymbolKind::BuiltinFunction { builtin: BuiltinFunctionKind::Spawn } => {
+                scan.reason = Some("spawns a task".to_string());
+            }
+            SymbolKind::BuiltinModule { module } if is_runtime_module(*module) => {
+                scan.reason = Some(format!("uses the `{}` module", name));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// CT7: stdlib modules that reach the OS or the task runtime. `print` and
+/// friends are builtins, not `io` members, and stay comptime-evaluable.
+fn is_runtime_module(module: BuiltinModuleKind) -> bool {
+    use BuiltinModuleKind as M;
+    matches!(
+        module,
+        M::Io | M::Fs | M::Env | M::Cli | M::Random | M::Time | M::Os | M::Net | M::Async | M::Http
+    )
+}
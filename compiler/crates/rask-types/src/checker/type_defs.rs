@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use rask_ast::NodeId;
+use rask_ast::{NodeId, Span};
 use rask_resolve::SymbolId;
 
 use super::type_table::TypeTable;
@@ -47,6 +47,10 @@ pub enum TypeDef {
         /// TR3: names of methods that declare their own type parameters.
         /// These can't be dispatched through `any` — no vtable slot.
         generic_methods: Vec<String>,
+        /// TD2: names of methods with a default body — impls may omit them.
+        default_methods: Vec<String>,
+        /// Declaration span of each entry in `methods`, same order.
+        method_spans: Vec<Span>,
         is_unsafe: bool,
     },
     Union {
@@ -62,7 +66,7 @@ pub enum TypeDef {
 }
 
 /// Method name without its type-parameter suffix: `convert<T>` → `convert`.
-fn method_base(name: &str) -> &str {
+pub(crate) fn method_base(name: &str) -> &str {
     name.split('<').next().unwrap_or(name)
 }
 
@@ -442,6 +442,23 @@ impl TypeTable {
                 found: self.resolve_type_names(&found),
                 span,
             },
+            TypeError::IncompatibleBranches { first, second, first_span, second_span, expected } => {
+                TypeError::IncompatibleBranches {
+                    first: self.resolve_type_names(&first),
+                    second: self.resolve_type_names(&second),
+                    first_span,
+                    second_span,
+                    expected: expected.map(|t| self.resolve_type_names(&t)),
+                }
+            }
+            TypeError::IncompatibleBreaks { first, second, first_span, second_span } => {
+                TypeError::IncompatibleBreaks {
+                    first: self.resolve_type_names(&first),
+                    second: self.resolve_type_names(&second),
+                    first_span,
+                    second_span,
+                }
+            }
             TypeError::NotCallable { ty, span } => TypeError::NotCallable {
                 ty: self.resolve_type_names(&ty),
                 span,
@@ -456,6 +473,15 @@ impl TypeTable {
                 method,
                 span,
             },
+            TypeError::NotInterpolable { ty, span } => TypeError::NotInterpolable {
+                ty: self.resolve_type_names(&ty),
+                span,
+            },
+            TypeError::IncomparableTypes { left, right, span } => TypeError::IncomparableTypes {
+                left: self.resolve_type_names(&left),
+                right: self.resolve_type_names(&right),
+                span,
+            },
             TypeError::MissingReturn { function_name, expected_type, span } => TypeError::MissingReturn {
                 function_name,
                 expected_type: self.resolve_type_names(&expected_type),
@@ -470,6 +496,11 @@ impl TypeTable {
                 ty: self.resolve_type_names(&ty),
                 span,
             },
+            TypeError::RedundantOptionalOp { op, found, span } => TypeError::RedundantOptionalOp {
+                op,
+                found: self.resolve_type_names(&found),
+                span,
+            },
             TypeError::TryOnNonResult { found, span } => TypeError::TryOnNonResult {
                 found: self.resolve_type_names(&found),
                 span,
@@ -480,6 +511,11 @@ impl TypeTable {
                 nominal_name,
                 span,
             },
+            TypeError::TraitObjectElements { found, trait_name, span } => TypeError::TraitObjectElements {
+                found: self.resolve_type_names(&found),
+                trait_name,
+                span,
+            },
             TypeError::GuardElseMustDiverge { found, span } => TypeError::GuardElseMustDiverge {
                 found: self.resolve_type_names(&found),
                 span,
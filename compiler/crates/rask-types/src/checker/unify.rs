@@ -7,7 +7,7 @@ use super::inference::{TypeConstraint, WrapPosition};
 use super::errors::TypeError;
 use super::TypeChecker;
 
-use crate::types::{GenericArg, Type};
+use crate::types::{GenericArg, Type, TypeVarId};
 
 impl TypeChecker {
     pub(super) fn solve_constraints(&mut self) {
@@ -115,6 +115,13 @@ impl TypeChecker {
                 if matches!(self.ctx.apply(&ty), Type::Error) { return Ok(false); }
                 self.resolve_field(ty, field, expected, span, self_type)
             }
+            TypeConstraint::OptionalField {
+                object,
+                field,
+                result,
+                span,
+                self_type,
+            } => self.resolve_optional_field(object, field, result, span, self_type),
             TypeConstraint::HasMethod {
                 ty,
                 method,
@@ -137,6 +144,97 @@ impl TypeChecker {
                 ty_name,
                 span,
             } => self.resolve_type_pattern(scrutinee, narrow_ty, ty_name, span),
+            TypeConstraint::BranchesAgree {
+                first,
+                second,
+                first_span,
+                second_span,
+                expected,
+            } => match self.unify(&first, &second, second_span) {
+                Err(TypeError::Mismatch { .. }) => Err(TypeError::IncompatibleBranches {
+                    first: self.branch_type_for_display(&first),
+                    second: self.branch_type_for_display(&second),
+                    first_span,
+                    second_span,
+                    expected: expected.map(|t| self.ctx.apply(&t)),
+                }),
+                other => other,
+            },
+            TypeConstraint::BreaksAgree {
+                first,
+                second,
+                first_span,
+                second_span,
+            } => match self.unify(&first, &second, second_span) {
+                Err(TypeError::Mismatch { .. }) => Err(TypeError::IncompatibleBreaks {
+                    first: self.ctx.apply(&first),
+                    second: self.ctx.apply(&second),
+                    first_span,
+                    second_span,
+                }),
+                other => other,
+            },
+        }
+    }
+
+    /// OPT10: `object?.field` unwraps `object` if optional, reads `field`,
+    /// and re-wraps — `a?.b?.c` is `C?` however many links are optional.
+    /// Defer while the receiver (or the type inside its `?`) is unresolved.
+    fn resolve_optional_field(
+        &mut self,
+        object: Type,
+        field: String,
+        result: Type,
+        span: Span,
+        self_type: Option<Type>,
+    ) -> Result<bool, TypeError> {
+        let resolved = self.ctx.apply(&object);
+        let inner = match resolved.as_option() {
+            Some(inner) => inner.clone(),
+            None => resolved.clone(),
+        };
+        match inner {
+            Type::Error => return Ok(false),
+            Type::Var(_) => {
+                self.ctx.add_constraint(TypeConstraint::OptionalField {
+                    object,
+                    field,
+                    result,
+                    span,
+                    self_type,
+                });
+                return Ok(false);
+            }
+            _ => {}
+        }
+        if resolved.is_never_optional() {
+            self.errors.push(TypeError::RedundantOptionalOp {
+                op: "?.".to_string(),
+                found: resolved.clone(),
+                span,
+            });
+        }
+        let field_ty = self.ctx.fresh_var();
+        self.resolve_field(inner, field, field_ty.clone(), span, self_type)?;
+        let field_ty = self.ctx.apply(&field_ty);
+        let chained = if field_ty.is_option() { field_ty } else { Type::option(field_ty) };
+        self.unify(&result, &chained, span)?;
+        Ok(true)
+    }
+
+    /// A branch type as the user wrote it: a bare literal shows as its
+    /// default type (`i32`/`f64`) rather than an inference variable.
+    fn branch_type_for_display(&self, ty: &Type) -> Type {
+        use super::inference::LiteralKind;
+
+        let ty = self.ctx.apply(ty);
+        match &ty {
+            Type::Var(id) => match self.ctx.literal_vars.get(id) {
+                Some(LiteralKind::Integer) => Type::I32,
+                Some(LiteralKind::Float) => Type::F64,
+                None => ty,
+            },
+            _ => ty,
         }
     }
 
@@ -323,6 +421,37 @@ impl TypeChecker {
         }
     }
 
+    /// A bare literal only ever becomes a number: binding its var to a
+    /// string, bool, array, etc. is a mismatch. Returns the literal's default
+    /// type for the error when that's the case.
+    fn literal_mismatch(&self, id: TypeVarId, other: &Type) -> Option<Type> {
+        use super::inference::LiteralKind;
+
+        let default = match self.ctx.literal_vars.get(&id)? {
+            LiteralKind::Integer => Type::I32,
+            LiteralKind::Float => Type::F64,
+        };
+        let non_numeric = matches!(
+            other,
+            Type::Unit | Type::Bool | Type::Char | Type::String
+            | Type::Tuple(_) | Type::Array { .. } | Type::Slice(_) | Type::Fn { .. }
+        );
+        non_numeric.then_some(default)
+    }
+
+    /// L6: a float literal never becomes an integer (`const y: i32 = 5.0`).
+    /// An integer literal binding to a float type is allowed.
+    fn float_literal_for_integer(&self, id: TypeVarId, other: &Type) -> bool {
+        use super::inference::LiteralKind;
+
+        matches!(self.ctx.literal_vars.get(&id), Some(LiteralKind::Float))
+            && matches!(
+                other,
+                Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128
+                | Type::U8 | Type::U16 | Type::U32 | Type::U64 | Type::U128
+            )
+    }
+
     pub(super) fn unify(&mut self, t1: &Type, t2: &Type, span: Span) -> Result<bool, TypeError> {
         let t1 = self.ctx.apply(t1);
         let t2 = self.ctx.apply(t2);
@@ -352,6 +481,16 @@ impl TypeChecker {
                         span,
                     });
                 }
+                if let Some(literal) = self.literal_mismatch(*id, other) {
+                    return Err(TypeError::Mismatch {
+                        expected: other.clone(),
+                        found: literal,
+                        span,
+                    });
+                }
+                if self.float_literal_for_integer(*id, other) {
+                    return Err(TypeError::FloatLiteralForInteger { expected: other.clone(), span });
+                }
                 // Literal vars cannot implicitly coerce to nominal types
                 if self.ctx.literal_vars.contains_key(id) {
                     if let Type::Named(type_id) = other {
@@ -377,6 +516,16 @@ impl TypeChecker {
                         span,
                     });
                 }
+                if let Some(literal) = self.literal_mismatch(*id, other) {
+                    return Err(TypeError::Mismatch {
+                        expected: other.clone(),
+                        found: literal,
+                        span,
+                    });
+                }
+                if self.float_literal_for_integer(*id, other) {
+                    return Err(TypeError::FloatLiteralForInteger { expected: other.clone(), span });
+                }
                 // Literal vars cannot implicitly coerce to nominal types
                 if self.ctx.literal_vars.contains_key(id) {
                     if let Type::Named(type_id) = other {
@@ -489,10 +638,10 @@ impl TypeChecker {
                         span,
                     });
                 }
-                self.unify(e1, e2, span)
+                self.unify_element(e1, e2, span)
             }
 
-            (Type::Slice(e1), Type::Slice(e2)) => self.unify(e1, e2, span),
+            (Type::Slice(e1), Type::Slice(e2)) => self.unify_element(e1, e2, span),
 
             (Type::RawPtr(inner1), Type::RawPtr(inner2)) => self.unify(inner1, inner2, span),
 
@@ -637,9 +786,34 @@ impl TypeChecker {
         }
     }
 
+    /// Unify the element types of two containers. The concrete → `any Trait`
+    /// coercion only applies to whole values: a `Vec<Point>` holds `Point`s
+    /// inline, a `Vec<any Show>` holds fat pointers, so one can't stand in
+    /// for the other (TR7).
+    fn unify_element(&mut self, e1: &Type, e2: &Type, span: Span) -> Result<bool, TypeError> {
+        let r1 = self.ctx.apply(e1);
+        let r2 = self.ctx.apply(e2);
+        match (&r1, &r2) {
+            (Type::TraitObject { trait_name }, concrete)
+            | (concrete, Type::TraitObject { trait_name })
+                if !matches!(
+                    concrete,
+                    Type::TraitObject { .. } | Type::Var(_) | Type::Error | Type::Never
+                ) =>
+            {
+                Err(TypeError::TraitObjectElements {
+                    found: concrete.clone(),
+                    trait_name: trait_name.clone(),
+                    span,
+                })
+            }
+            _ => self.unify(&r1, &r2, span),
+        }
+    }
+
     pub(super) fn unify_generic_arg(&mut self, arg1: &GenericArg, arg2: &GenericArg, span: Span) -> Result<bool, TypeError> {
         match (arg1, arg2) {
-            (GenericArg::Type(t1), GenericArg::Type(t2)) => self.unify(t1, t2, span),
+            (GenericArg::Type(t1), GenericArg::Type(t2)) => self.unify_element(t1, t2, span),
             (GenericArg::ConstUsize(n1), GenericArg::ConstUsize(n2)) => {
                 if n1 == n2 {
                     Ok(false)
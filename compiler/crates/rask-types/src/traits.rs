@@ -4,8 +4,8 @@
 //! Implements structural trait satisfaction: a type satisfies a trait if it has
 //! all required methods with matching signatures.
 
-use crate::types::Type;
-use crate::checker::{TypeTable, TypeDef, MethodSig, SelfParam, ParamMode};
+use crate::types::{GenericArg, Type};
+use crate::checker::{TypeTable, TypeDef, MethodSig, SelfParam, ParamMode, method_base};
 use rask_ast::Span;
 use std::collections::HashMap;
 use thiserror::Error;
@@ -57,13 +57,24 @@ pub enum TraitError {
         span: Span,
     },
 
-    #[error("Method '{method}' signature mismatch: expected {expected}, found {found}")]
+    #[error("Type {ty} does not implement all methods of trait {trait_name}: missing {missing:?}")]
+    MissingMethods {
+        ty: String,
+        trait_name: String,
+        missing: Vec<String>,
+        span: Span,
+    },
+
+    #[error("Method '{method}' has signature {found} but trait {trait_name} requires {expected}")]
     SignatureMismatch {
         ty: String,
+        trait_name: String,
         method: String,
         expected: String,
         found: String,
         span: Span,
+        /// Where the trait declares the method, when known.
+        trait_span: Option<Span>,
     },
 
     #[error("Unknown trait: {0}")]
@@ -152,10 +163,12 @@ impl<'a> TraitChecker<'a> {
                 if !self.signatures_match(required, found) {
                     return Err(TraitError::SignatureMismatch {
                         ty: self.type_name(ty),
+                        trait_name: trait_name.to_string(),
                         method: required.name.clone(),
                         expected: self.format_signature(required),
                         found: self.format_signature(found),
                         span,
+                        trait_span: None,
                     });
                 }
             } else {
@@ -194,6 +207,100 @@ impl<'a> TraitChecker<'a> {
         errors
     }
 
+    /// Check an `extend Type with Trait` block against the trait's declaration.
+    ///
+    /// `provided` pairs each impl method's signature with its span. Every
+    /// provided method the trait declares must match its self kind, parameter
+    /// modes and types, and return type; every declared method without a
+    /// default body (TD2) must be provided. Builtin traits and methods
+    /// inherited from super-traits aren't checked here — the former have no
+    /// declaration to point at, the latter may come from another block.
+    pub fn check_impl(
+        &self,
+        ty: &Type,
+        trait_name: &str,
+        provided: &[(MethodSig, Span)],
+        span: Span,
+    ) -> Vec<TraitError> {
+        let base_name = trait_name.split('<').next().unwrap_or(trait_name);
+        let Some(TypeDef::Trait { methods, default_methods, method_spans, .. }) =
+            self.types.get_type_id(base_name).and_then(|id| self.types.get(id))
+        else {
+            return Vec::new();
+        };
+
+        let mut errors = Vec::new();
+        for (found, found_span) in provided {
+            let pos = methods.iter().position(|m| method_base(&m.name) == method_base(&found.name));
+            let Some(pos) = pos else { continue };
+            let required = &methods[pos];
+            if !self.impl_signature_matches(required, found) {
+                errors.push(TraitError::SignatureMismatch {
+                    ty: self.type_name(ty),
+                    trait_name: base_name.to_string(),
+                    method: method_base(&found.name).to_string(),
+                    expected: self.format_signature(required),
+                    found: self.format_signature(found),
+                    span: *found_span,
+                    trait_span: method_spans.get(pos).copied(),
+                });
+            }
+        }
+
+        let missing: Vec<String> = methods
+            .iter()
+            .filter(|m| !default_methods.contains(&m.name))
+            .filter(|m| !provided.iter().any(|(p, _)| method_base(&p.name) == method_base(&m.name)))
+            .map(|m| method_base(&m.name).to_string())
+            .collect();
+        if !missing.is_empty() {
+            errors.push(TraitError::MissingMethods {
+                ty: self.type_name(ty),
+                trait_name: base_name.to_string(),
+                missing,
+                span,
+            });
+        }
+
+        errors
+    }
+
+    /// Signature comparison for declared impls. Types are compared by name,
+    /// so a trait registered before the types it mentions still matches.
+    /// Positions naming `Self` or a type parameter accept anything.
+    fn impl_signature_matches(&self, required: &MethodSig, found: &MethodSig) -> bool {
+        if required.self_param != found.self_param || required.params.len() != found.params.len() {
+            return false;
+        }
+        let types_match = |req: &Type, found: &Type| {
+            let req = self.types.resolve_type_names(req);
+            let found = self.types.resolve_type_names(found);
+            self.is_open(&req) || self.is_open(&found) || req == found
+        };
+        required.params.iter().zip(found.params.iter()).all(|((rt, rm), (ft, fm))| {
+            rm == fm && types_match(rt, ft)
+        }) && types_match(&required.ret, &found.ret)
+    }
+
+    /// True if a name-resolved signature type mentions `Self`, a type
+    /// parameter, or an inference/error placeholder.
+    fn is_open(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Var(_) | Type::Error => true,
+            Type::UnresolvedNamed(name) => {
+                name == "Self" || self.types.get_type_id(name.split('<').next().unwrap_or(name)).is_none()
+            }
+            Type::UnresolvedGeneric { args, .. } | Type::Generic { args, .. } => {
+                args.iter().any(|a| matches!(a, GenericArg::Type(t) if self.is_open(t)))
+            }
+            Type::Result { ok, err } => self.is_open(ok) || self.is_open(err),
+            Type::Tuple(elems) | Type::Union(elems) => elems.iter().any(|e| self.is_open(e)),
+            Type::Array { elem, .. } | Type::Slice(elem) | Type::RawPtr(elem) => self.is_open(elem),
+            Type::Fn { params, ret } => params.iter().any(|p| self.is_open(p)) || self.is_open(ret),
+            _ => false,
+        }
+    }
+
     /// Get methods required by a trait (public accessor for trait object resolution).
     pub fn get_trait_methods_public(&self, trait_name: &str) -> Vec<MethodSig> {
         self.get_trait_methods(trait_name).unwrap_or_default()
@@ -448,13 +555,19 @@ impl<'a> TraitChecker<'a> {
             SelfParam::Take => "take self, ",
         };
         let params_str: Vec<String> = sig.params.iter().map(|(t, mode)| {
+            let t = self.types.resolve_type_names(t);
             match mode {
-                ParamMode::Take => format!("take {:?}", t),
-                ParamMode::Mutate => format!("mutate {:?}", t),
-                ParamMode::Default => format!("{:?}", t),
+                ParamMode::Take => format!("take {}", t),
+                ParamMode::Mutate => format!("mutate {}", t),
+                ParamMode::Default => format!("{}", t),
             }
         }).collect();
-        format!("fn {}({}{}) -> {:?}", sig.name, self_str, params_str.join(", "), sig.ret)
+        let params_str = format!("{}{}", self_str, params_str.join(", "));
+        let params_str = params_str.trim_end_matches(", ");
+        match &sig.ret {
+            Type::Unit => format!("func {}({})", sig.name, params_str),
+            ret => format!("func {}({}) -> {}", sig.name, params_str, self.types.resolve_type_names(ret)),
+        }
     }
 
     /// Get a human-readable name for a type.
@@ -154,6 +154,22 @@ impl Type {
         None
     }
 
+    /// True if no value of this type is ever `none` — a concrete type that
+    /// isn't a union, not a type parameter, and not yet to be inferred.
+    pub fn is_never_optional(&self) -> bool {
+        !matches!(
+            self,
+            Type::Result { .. }
+                | Type::Union(_)
+                | Type::Var(_)
+                | Type::UnresolvedNamed(_)
+                | Type::UnresolvedGeneric { .. }
+                | Type::Never
+                | Type::None
+                | Type::Error
+        )
+    }
+
     /// Check if this type is a subset of another union type.
     pub fn is_subset_of(&self, other: &Type) -> bool {
         let self_types = match self {
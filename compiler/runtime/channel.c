@@ -19,6 +19,7 @@
 #include <string.h>
 #include <pthread.h>
 #include <stdatomic.h>
+#include <time.h>
 
 // ─── Channel internals ─────────────────────────────────────
 
@@ -603,3 +604,133 @@ int64_t rask_channel_recv_async(int64_t rx) {
     }
     return data;
 }
+
+// ─── Select (conc.select) ──────────────────────────────────
+//
+// Polls each registered operation with try_send/try_recv, yielding and
+// backing off between rounds — the same strategy as the interpreter.
+// Received values land in a per-arm buffer owned by the select; codegen
+// copies them out with take_* before freeing.
+
+typedef struct {
+    int         is_send;
+    void       *half;   // RaskRecver* or RaskSender*
+    void       *buf;    // recv: owned buffer; send: caller's data
+} RaskSelectOp;
+
+typedef struct {
+    int64_t      len;
+    int64_t      cap;
+    RaskSelectOp ops[];
+} RaskSelect;
+
+static _Thread_local uint64_t select_rng;
+
+// Fisher-Yates over the poll order (P1: random among ready arms).
+static void select_shuffle(int64_t *order, int64_t n) {
+    if (select_rng == 0) {
+        struct timespec ts;
+        clock_gettime(CLOCK_MONOTONIC, &ts);
+        select_rng = ((uint64_t)ts.tv_nsec << 1) | 1;
+    }
+    for (int64_t i = n - 1; i > 0; i--) {
+        select_rng ^= select_rng << 13;
+        select_rng ^= select_rng >> 7;
+        select_rng ^= select_rng << 17;
+        int64_t j = (int64_t)(select_rng % (uint64_t)(i + 1));
+        int64_t tmp = order[i];
+        order[i] = order[j];
+        order[j] = tmp;
+    }
+}
+
+int64_t rask_select_new(int64_t arm_count) {
+    RaskSelect *sel = (RaskSelect *)rask_alloc(
+        (int64_t)sizeof(RaskSelect) + arm_count * (int64_t)sizeof(RaskSelectOp));
+    sel->len = 0;
+    sel->cap = arm_count;
+    return (int64_t)(intptr_t)sel;
+}
+
+void rask_select_add_recv(int64_t sel_ptr, int64_t rx) {
+    RaskSelect *sel = (RaskSelect *)(intptr_t)sel_ptr;
+    if (sel->len >= sel->cap) rask_panic("select: too many arms registered");
+    RaskRecver *recver = (RaskRecver *)(intptr_t)rx;
+    RaskSelectOp *op = &sel->ops[sel->len++];
+    op->is_send = 0;
+    op->half = recver;
+    op->buf = rask_alloc(recver->chan->elem_size);
+}
+
+void rask_select_add_send(int64_t sel_ptr, int64_t tx, int64_t data_ptr) {
+    RaskSelect *sel = (RaskSelect *)(intptr_t)sel_ptr;
+    if (sel->len >= sel->cap) rask_panic("select: too many arms registered");
+    RaskSelectOp *op = &sel->ops[sel->len++];
+    op->is_send = 1;
+    op->half = (void *)(intptr_t)tx;
+    op->buf = (void *)(intptr_t)data_ptr;
+}
+
+// One pass over the arms. Returns the fired arm, -1 if something may still
+// become ready, or -2 if every channel is closed (CL1/CL2).
+static int64_t select_round(RaskSelect *sel, int64_t priority) {
+    int64_t order[sel->len > 0 ? sel->len : 1];
+    for (int64_t i = 0; i < sel->len; i++) order[i] = i;
+    if (!priority) select_shuffle(order, sel->len);
+
+    int all_closed = 1;
+    for (int64_t k = 0; k < sel->len; k++) {
+        RaskSelectOp *op = &sel->ops[order[k]];
+        int64_t status = op->is_send
+            ? rask_channel_try_send((RaskSender *)op->half, op->buf)
+            : rask_channel_try_recv((RaskRecver *)op->half, op->buf);
+        if (status == RASK_CHAN_OK) return order[k];
+        if (status != RASK_CHAN_CLOSED) all_closed = 0;
+    }
+    return all_closed ? -2 : -1;
+}
+
+int64_t rask_select_wait(int64_t sel_ptr, int64_t priority) {
+    RaskSelect *sel = (RaskSelect *)(intptr_t)sel_ptr;
+    int64_t backoff_ns = 10000;       // 10us
+    const int64_t max_backoff_ns = 1000000; // 1ms
+    for (;;) {
+        int64_t fired = select_round(sel, priority);
+        if (fired >= 0) return fired;
+        if (fired == -2) rask_panic("select: all channels closed");
+        rask_yield();
+        if (rask_green_task_is_cancelled()) rask_panic("select cancelled");
+        struct timespec ts = { 0, (long)backoff_ns };
+        nanosleep(&ts, NULL);
+        backoff_ns = backoff_ns * 2 < max_backoff_ns ? backoff_ns * 2 : max_backoff_ns;
+    }
+}
+
+int64_t rask_select_poll(int64_t sel_ptr, int64_t priority) {
+    int64_t fired = select_round((RaskSelect *)(intptr_t)sel_ptr, priority);
+    return fired >= 0 ? fired : -1;
+}
+
+int64_t rask_select_take_i64(int64_t sel_ptr, int64_t arm) {
+    RaskSelect *sel = (RaskSelect *)(intptr_t)sel_ptr;
+    RaskSelectOp *op = &sel->ops[arm];
+    int64_t value = 0;
+    int64_t size = ((RaskRecver *)op->half)->chan->elem_size;
+    memcpy(&value, op->buf, (size_t)(size < 8 ? size : 8));
+    return value;
+}
+
+void rask_select_take_into(int64_t sel_ptr, int64_t arm, int64_t out_ptr) {
+    RaskSelect *sel = (RaskSelect *)(intptr_t)sel_ptr;
+    RaskSelectOp *op = &sel->ops[arm];
+    int64_t size = ((RaskRecver *)op->half)->chan->elem_size;
+    memcpy((void *)(intptr_t)out_ptr, op->buf, (size_t)size);
+}
+
+void rask_select_free(int64_t sel_ptr) {
+    RaskSelect *sel = (RaskSelect *)(intptr_t)sel_ptr;
+    for (int64_t i = 0; i < sel->len; i++) {
+        if (!sel->ops[i].is_send) rask_free(sel->ops[i].buf);
+    }
+    rask_free(sel);
+}
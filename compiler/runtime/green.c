@@ -204,6 +204,8 @@ typedef struct {
     RaskIoEngine    *io;
     atomic_int       active_tasks;
     atomic_int       shutdown;
+    atomic_int       cancel_all;   // shutdown grace expired: every task reads as cancelled
+    int64_t          max_tasks;    // in-flight bound (conc.async/C8); 0 = unbounded
 
     // Parking: workers sleep here when no work found
     pthread_mutex_t  park_lock;
@@ -212,6 +214,8 @@ typedef struct {
     // Shutdown barrier: main thread waits here
     pthread_mutex_t  done_lock;
     pthread_cond_t   done_cond;
+    // Bounded spawn: spawners wait here for a task to finish
+    pthread_cond_t   slot_cond;
 } GreenScheduler;
 
 // Singleton scheduler
@@ -364,6 +368,13 @@ static void execute_task(GreenScheduler *s, GreenTask *t) {
         atomic_fetch_sub_explicit(&s->active_tasks, 1, memory_order_relaxed);
         task_release(t); // scheduler's ref
 
+        // Free a slot for a spawner blocked on the task bound
+        if (s->max_tasks > 0) {
+            pthread_mutex_lock(&s->done_lock);
+            pthread_cond_signal(&s->slot_cond);
+            pthread_mutex_unlock(&s->done_lock);
+        }
+
         // Signal shutdown waiter if all tasks done
         if (atomic_load_explicit(&s->active_tasks, memory_order_acquire) == 0) {
             pthread_mutex_lock(&s->done_lock);
@@ -453,9 +464,15 @@ static void *worker_entry(void *arg) {
 
 // ─── Public API ─────────────────────────────────────────────
 
-void rask_runtime_init(int64_t worker_count) {
+// max_tasks bounds the tasks in flight, queued or running (conc.async/C8);
+// 0 leaves spawn unbounded.
+void rask_runtime_init(int64_t worker_count, int64_t max_tasks) {
     if (g_sched) return; // already initialized
 
+    if (max_tasks < 0) {
+        rask_panic("runtime `tasks` bound cannot be negative");
+    }
+
     GreenScheduler *s = (GreenScheduler *)calloc(1, sizeof(GreenScheduler));
     if (!s) {
         fprintf(stderr, "rask: scheduler alloc failed\n");
@@ -468,6 +485,7 @@ void rask_runtime_init(int64_t worker_count) {
     }
 
     s->worker_count = (int)worker_count;
+    s->max_tasks = max_tasks;
     s->workers = (pthread_t *)calloc((size_t)worker_count, sizeof(pthread_t));
     s->local   = (WorkDeque *)calloc((size_t)worker_count, sizeof(WorkDeque));
     if (!s->workers || !s->local) {
@@ -482,10 +500,12 @@ void rask_runtime_init(int64_t worker_count) {
     gq_init(&s->global);
     atomic_init(&s->active_tasks, 0);
     atomic_init(&s->shutdown, 0);
+    atomic_init(&s->cancel_all, 0);
     pthread_mutex_init(&s->park_lock, NULL);
     pthread_cond_init(&s->park_cond, NULL);
     pthread_mutex_init(&s->done_lock, NULL);
     pthread_cond_init(&s->done_cond, NULL);
+    pthread_cond_init(&s->slot_cond, NULL);
 
     // Create I/O engine
     s->io = rask_io_create();
@@ -504,15 +524,40 @@ void rask_runtime_init(int64_t worker_count) {
     }
 }
 
-void rask_runtime_shutdown(void) {
-    GreenScheduler *s = g_sched;
-    if (!s) return;
+// How long shutdown waits for outstanding tasks before cancelling them.
+// RASK_SHUTDOWN_GRACE_MS overrides the default.
+static int64_t shutdown_grace_ms(void) {
+    const char *env = getenv("RASK_SHUTDOWN_GRACE_MS");
+    if (env && *env) {
+        char *end;
+        long long ms = strtoll(env, &end, 10);
+        if (*end == '\0' && ms >= 0) return (int64_t)ms;
+    }
+    return 5000;
+}
+
+// Wait up to grace_ms for active_tasks to reach zero. Returns how many
+// tasks are still active.
+static int wait_for_tasks(GreenScheduler *s, int64_t grace_ms) {
+    struct timespec deadline;
+    clock_gettime(CLOCK_REALTIME, &deadline);
+    deadline.tv_sec  += grace_ms / 1000;
+    deadline.tv_nsec += (grace_ms % 1000) * 1000000L;
+    if (deadline.tv_nsec >= 1000000000L) {
+        deadline.tv_sec += 1;
+        deadline.tv_nsec -= 1000000000L;
+    }
 
-    // Wait for all active tasks to complete
     pthread_mutex_lock(&s->done_lock);
-    while (atomic_load_explicit(&s->active_tasks, memory_order_acquire) > 0) {
+    int active;
+    while ((active = atomic_load_explicit(&s->active_tasks, memory_order_acquire)) > 0) {
+        // Short slices: completion signals can race with the load above.
         struct timespec ts;
         clock_gettime(CLOCK_REALTIME, &ts);
+        if (ts.tv_sec > deadline.tv_sec
+            || (ts.tv_sec == deadline.tv_sec && ts.tv_nsec >= deadline.tv_nsec)) {
+            break;
+        }
         ts.tv_nsec += 10000000; // 10ms
         if (ts.tv_nsec >= 1000000000L) {
             ts.tv_sec += 1;
@@ -521,6 +566,24 @@ void rask_runtime_shutdown(void) {
         pthread_cond_timedwait(&s->done_cond, &s->done_lock, &ts);
     }
     pthread_mutex_unlock(&s->done_lock);
+    return active;
+}
+
+// Block exit (conc.async/C4): drain outstanding tasks, detached ones
+// included, for up to the grace period. Tasks still active after that are
+// cancelled — rask_green_task_is_cancelled() turns true for all of them —
+// and get one more grace period to return. A task that ignores
+// cancellation and is parked on I/O is abandoned; one that is mid-poll
+// holds up the worker join until it returns.
+void rask_runtime_shutdown(void) {
+    GreenScheduler *s = g_sched;
+    if (!s) return;
+
+    int64_t grace_ms = shutdown_grace_ms();
+    if (wait_for_tasks(s, grace_ms) > 0) {
+        atomic_store_explicit(&s->cancel_all, 1, memory_order_release);
+        wait_for_tasks(s, grace_ms);
+    }
 
     // Signal shutdown and wake all workers
     atomic_store_explicit(&s->shutdown, 1, memory_order_release);
@@ -541,6 +604,7 @@ void rask_runtime_shutdown(void) {
     pthread_cond_destroy(&s->park_cond);
     pthread_mutex_destroy(&s->done_lock);
     pthread_cond_destroy(&s->done_cond);
+    pthread_cond_destroy(&s->slot_cond);
     free(s->local);
     free(s->workers);
     free(s);
@@ -555,8 +619,21 @@ void *rask_green_spawn(void *poll_fn, void *state, int64_t state_size) {
         rask_panic("spawn outside `using Multitasking {}` block");
     }
 
+    // Full pool: block the spawner until a task finishes. A spawn from a
+    // worker is admitted over the bound — parking the worker could leave
+    // no one to run the tasks that would free a slot.
+    if (s->max_tasks > 0 && tl_worker_id < 0) {
+        pthread_mutex_lock(&s->done_lock);
+        while (atomic_load_explicit(&s->active_tasks, memory_order_acquire) >= s->max_tasks) {
+            pthread_cond_wait(&s->slot_cond, &s->done_lock);
+        }
+        atomic_fetch_add_explicit(&s->active_tasks, 1, memory_order_relaxed);
+        pthread_mutex_unlock(&s->done_lock);
+    } else {
+        atomic_fetch_add_explicit(&s->active_tasks, 1, memory_order_relaxed);
+    }
+
     GreenTask *t = task_new((rask_poll_fn)poll_fn, state, state_size);
-    atomic_fetch_add_explicit(&s->active_tasks, 1, memory_order_relaxed);
     sched_enqueue(s, t);
 
     GreenHandle *h = (GreenHandle *)malloc(sizeof(GreenHandle));
@@ -679,6 +756,8 @@ void rask_yield(void) {
 int rask_green_task_is_cancelled(void) {
     GreenTask *t = tl_current_task;
     if (!t) return 0;
+    GreenScheduler *s = g_sched;
+    if (s && atomic_load_explicit(&s->cancel_all, memory_order_acquire)) return 1;
     return atomic_load_explicit(&t->cancel_flag, memory_order_acquire);
 }
 
@@ -511,7 +511,7 @@ void          rask_panic_remove(void);
 // Work-stealing scheduler with io_uring/epoll I/O engine.
 // Tasks are stackless state machines: poll_fn(state, ctx) → 0=READY, 1=PENDING.
 
-void      rask_runtime_init(int64_t worker_count);
+void      rask_runtime_init(int64_t worker_count, int64_t max_tasks);
 void      rask_runtime_shutdown(void);
 
 // Spawn a green task. poll_fn signature: int (*)(void *state, void *task_ctx).
@@ -727,6 +727,19 @@ int64_t rask_channel_recv_ptr(int64_t rx, int64_t out_ptr);
 int64_t rask_channel_send_async_ptr(int64_t tx, int64_t data_ptr);
 int64_t rask_channel_recv_async_ptr(int64_t rx, int64_t out_ptr);
 
+// Select over channel operations (conc.select). Arms are registered in
+// source order; wait/poll return the index of the arm that fired.
+// wait blocks (panics if every channel is closed, CL1); poll returns -1
+// when nothing is ready so the caller can take the default arm (A3).
+int64_t rask_select_new(int64_t arm_count);
+void    rask_select_add_recv(int64_t sel, int64_t rx);
+void    rask_select_add_send(int64_t sel, int64_t tx, int64_t data_ptr);
+int64_t rask_select_wait(int64_t sel, int64_t priority);
+int64_t rask_select_poll(int64_t sel, int64_t priority);
+int64_t rask_select_take_i64(int64_t sel, int64_t arm);
+void    rask_select_take_into(int64_t sel, int64_t arm, int64_t out_ptr);
+void    rask_select_free(int64_t sel);
+
 // ── Error origin (ER15/ER16) ────────────────────────────────────
 // Set the source file name for error origin formatting.
 void rask_set_origin_file(const char *file);